@@ -0,0 +1,56 @@
+//! Benchmarks for integrating the motion of many objects at once
+//!
+//! Run with `cargo bench --bench batch`.
+//! The `components` function integrates the same objects stored as individual components,
+//! as [`linear_kinematics`](leafwing_2d::kinematics::systems::linear_kinematics) does.
+
+use core::time::Duration;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use leafwing_2d::batch::KinematicBatch;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::position::Position;
+
+const N_OBJECTS: usize = 100_000;
+
+fn objects() -> Vec<(Position<F32>, Velocity<F32>, Acceleration<F32>)> {
+    (0..N_OBJECTS)
+        .map(|i| {
+            let i = i as f32;
+            (
+                Position::new(i, -i),
+                Velocity {
+                    x: F32(1.0),
+                    y: F32(i * 0.01),
+                },
+                Acceleration {
+                    x: F32(0.0),
+                    y: F32(-9.8),
+                },
+            )
+        })
+        .collect()
+}
+
+fn integrate(c: &mut Criterion) {
+    let delta_time = Duration::from_secs_f32(1. / 60.);
+    let mut components = objects();
+    let mut batch: KinematicBatch<F32> = objects().into_iter().collect();
+
+    let mut group = c.benchmark_group("integrate");
+    group.bench_function("batch", |b| {
+        b.iter(|| batch.integrate(black_box(delta_time)))
+    });
+    group.bench_function("components", |b| {
+        b.iter(|| {
+            for (position, velocity, acceleration) in components.iter_mut() {
+                *velocity += *acceleration * black_box(delta_time);
+                *position += *velocity * black_box(delta_time);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, integrate);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Benchmarks for keeping the collision broad phase up to date
+//!
+//! Run with `cargo bench --bench broad_phase`.
+//! Most entities in a typical level never move, so only a few need to be rechecked each frame.
+
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::collision::BroadPhase;
+use leafwing_2d::continuous::F32;
+
+const N_ENTITIES: u32 = 2_000;
+const N_MOVING: u32 = 20;
+
+/// Small boxes scattered in rows, with a few neighbors overlapping
+fn bounds(index: u32, offset: f32) -> AxisAlignedBoundingBox<F32> {
+    let x = (index % 50) as f32 * 1.5 + offset;
+    let y = (index / 50) as f32 * 1.5;
+    AxisAlignedBoundingBox::new(x, x + 2.0, y, y + 1.0)
+}
+
+fn scene() -> BroadPhase<F32> {
+    let mut broad_phase = BroadPhase::default();
+    for index in 0..N_ENTITIES {
+        broad_phase.insert(Entity::from_raw(index), bounds(index, 0.0));
+    }
+    broad_phase
+}
+
+fn update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("broad_phase_update");
+
+    group.bench_function("incremental", |b| {
+        b.iter_batched_ref(
+            scene,
+            |broad_phase| {
+                for index in 0..N_MOVING {
+                    broad_phase.insert(Entity::from_raw(index), bounds(index, 0.25));
+                }
+                black_box(broad_phase.pairs().count())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("rebuild", |b| {
+        b.iter(|| {
+            let mut broad_phase = BroadPhase::default();
+            for index in 0..N_ENTITIES {
+                let offset = if index < N_MOVING { 0.25 } else { 0.0 };
+                broad_phase.insert(Entity::from_raw(index), bounds(index, offset));
+            }
+            black_box(broad_phase.pairs().count())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, update);
+criterion_main!(benches);
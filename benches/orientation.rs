@@ -0,0 +1,145 @@
+//! Benchmarks for converting between orientation types, and for synchronizing them with `Transform`
+//!
+//! Run with `cargo bench --bench orientation`.
+//! The `baseline` functions reproduce the previous conversions, which went through `mul_vec3` and an angle,
+//! so that the improvement can be measured in a single run.
+
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::orientation::{Direction, Rotation};
+use leafwing_2d::plugin::{sync_direction_and_rotation, sync_transform_with_2d};
+use leafwing_2d::position::Position;
+
+const N_ENTITIES: usize = 10_000;
+
+/// Quaternions spread around the full circle
+fn quats() -> Vec<Quat> {
+    (0..N_ENTITIES)
+        .map(|i| Quat::from_rotation_z(i as f32 * 0.001))
+        .collect()
+}
+
+fn baseline_quat_to_direction(quat: Quat) -> Direction {
+    let vec2 = quat.mul_vec3(Vec3::Y).truncate();
+    Direction::try_from(vec2).unwrap_or_default()
+}
+
+fn baseline_quat_to_rotation(quat: Quat) -> Rotation {
+    baseline_quat_to_direction(quat).into()
+}
+
+fn baseline_direction_to_quat(direction: Direction) -> Quat {
+    let rotation: Rotation = direction.into();
+    rotation.into()
+}
+
+fn conversions(c: &mut Criterion) {
+    let quats = quats();
+    let directions: Vec<Direction> = quats.iter().map(|&quat| quat.into()).collect();
+
+    let mut group = c.benchmark_group("conversions");
+    group.bench_function("quat_to_rotation", |b| {
+        b.iter(|| {
+            for &quat in &quats {
+                black_box(Rotation::from(black_box(quat)));
+            }
+        })
+    });
+    group.bench_function("quat_to_rotation_baseline", |b| {
+        b.iter(|| {
+            for &quat in &quats {
+                black_box(baseline_quat_to_rotation(black_box(quat)));
+            }
+        })
+    });
+    group.bench_function("quat_to_direction", |b| {
+        b.iter(|| {
+            for &quat in &quats {
+                black_box(Direction::from(black_box(quat)));
+            }
+        })
+    });
+    group.bench_function("quat_to_direction_baseline", |b| {
+        b.iter(|| {
+            for &quat in &quats {
+                black_box(baseline_quat_to_direction(black_box(quat)));
+            }
+        })
+    });
+    group.bench_function("direction_to_quat", |b| {
+        b.iter(|| {
+            for &direction in &directions {
+                black_box(Quat::from(black_box(direction)));
+            }
+        })
+    });
+    group.bench_function("direction_to_quat_baseline", |b| {
+        b.iter(|| {
+            for &direction in &directions {
+                black_box(baseline_direction_to_quat(black_box(direction)));
+            }
+        })
+    });
+    group.finish();
+}
+
+/// A world full of entities with every synchronized component, and a stage that runs the sync systems
+fn sync_world() -> (World, SystemStage) {
+    let mut world = World::new();
+    world.spawn_batch(quats().into_iter().map(|quat| {
+        (
+            Transform::from_rotation(quat),
+            Rotation::from(quat),
+            Direction::from(quat),
+            Position::<F32>::default(),
+        )
+    }));
+
+    let mut stage = SystemStage::parallel();
+    stage.add_system(sync_direction_and_rotation.label("sync_direction_and_rotation"));
+    stage.add_system(sync_transform_with_2d::<F32>.after("sync_direction_and_rotation"));
+    // Clear the change detection flags from spawning
+    stage.run(&mut world);
+    world.clear_trackers();
+
+    (world, stage)
+}
+
+fn sync(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sync_transform_with_2d");
+
+    group.bench_function("transform_changed", |b| {
+        b.iter_batched_ref(
+            || {
+                let (mut world, stage) = sync_world();
+                for mut transform in world.query::<&mut Transform>().iter_mut(&mut world) {
+                    transform.rotate(Quat::from_rotation_z(0.5));
+                }
+                (world, stage)
+            },
+            |(world, stage)| stage.run(world),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("rotation_changed", |b| {
+        b.iter_batched_ref(
+            || {
+                let (mut world, stage) = sync_world();
+                for mut rotation in world.query::<&mut Rotation>().iter_mut(&mut world) {
+                    *rotation += Rotation::new(5);
+                }
+                (world, stage)
+            },
+            |(world, stage)| stage.run(world),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, conversions, sync);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+//! Benchmarks for snapping orientations to a `DirectionParitioning`
+//!
+//! Run with `cargo bench --bench partitioning`.
+//! The `linear` functions use the default implementation of `snap`, which compares against every partition,
+//! so that it can be compared to the arithmetic snapping used by the built-in partitionings.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+use leafwing_2d::partitioning::{CardinalOctant, CardinalQuadrant, DirectionParitioning};
+
+const N_ROTATIONS: u16 = 10_000;
+
+/// Rotations spread around the full circle
+fn rotations() -> Vec<Rotation> {
+    (0..N_ROTATIONS)
+        .map(|i| Rotation::new(i % Rotation::FULL_CIRCLE))
+        .collect()
+}
+
+/// Snaps to the nearest of the `partitions` by checking each of them in turn
+fn linear_snap<P: DirectionParitioning>(rotation: Rotation) -> P {
+    P::PARTITIONS
+        .iter()
+        .copied()
+        .min_by_key(|&partition| rotation.distance(partition.into()).deci_degrees())
+        .unwrap()
+}
+
+fn snap(c: &mut Criterion) {
+    let rotations = rotations();
+    let directions: Vec<Direction> = rotations.iter().map(|&rotation| rotation.into()).collect();
+
+    let mut group = c.benchmark_group("snap");
+    group.bench_function("quadrant", |b| {
+        b.iter(|| {
+            for &rotation in &rotations {
+                black_box(CardinalQuadrant::snap(black_box(rotation)));
+            }
+        })
+    });
+    group.bench_function("quadrant_linear", |b| {
+        b.iter(|| {
+            for &rotation in &rotations {
+                black_box(linear_snap::<CardinalQuadrant>(black_box(rotation)));
+            }
+        })
+    });
+    group.bench_function("octant", |b| {
+        b.iter(|| {
+            for &rotation in &rotations {
+                black_box(CardinalOctant::snap(black_box(rotation)));
+            }
+        })
+    });
+    group.bench_function("octant_linear", |b| {
+        b.iter(|| {
+            for &rotation in &rotations {
+                black_box(linear_snap::<CardinalOctant>(black_box(rotation)));
+            }
+        })
+    });
+    group.bench_function("octant_direction", |b| {
+        b.iter(|| {
+            for &direction in &directions {
+                black_box(CardinalOctant::snap_direction(black_box(direction)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, snap);
+criterion_main!(benches);
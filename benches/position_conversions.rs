@@ -0,0 +1,70 @@
+//! Benchmarks for converting many positions to and from `Vec2` at once
+//!
+//! Run with `cargo bench --bench position_conversions`.
+//! The `collect` functions allocate a new `Vec` for every conversion,
+//! for comparison with [`positions_to_vec2`] and [`vec2_to_positions`], which reuse their output buffer.
+
+use bevy::math::Vec2;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::position::{positions_to_vec2, vec2_to_positions, Position};
+
+const N_POSITIONS: usize = 100_000;
+
+fn positions_to_vectors(c: &mut Criterion) {
+    let positions: Vec<Position<F32>> = (0..N_POSITIONS)
+        .map(|i| Position::new(i as f32, -(i as f32)))
+        .collect();
+    let mut buffer: Vec<Vec2> = Vec::with_capacity(N_POSITIONS);
+
+    let mut group = c.benchmark_group("positions_to_vec2");
+    group.bench_function("batch", |b| {
+        b.iter(|| positions_to_vec2(black_box(&positions), &mut buffer))
+    });
+    group.bench_function("collect", |b| {
+        b.iter(|| {
+            black_box(&positions)
+                .iter()
+                .map(|&position| Vec2::from(position))
+                .collect::<Vec<Vec2>>()
+        })
+    });
+    group.finish();
+}
+
+fn vectors_to_positions(c: &mut Criterion) {
+    let vectors: Vec<Vec2> = (0..N_POSITIONS)
+        .map(|i| Vec2::new(i as f32 * 0.5, -(i as f32) * 0.5))
+        .collect();
+    let mut continuous: Vec<Position<F32>> = Vec::with_capacity(N_POSITIONS);
+    let mut discrete: Vec<Position<OrthogonalGrid>> = Vec::with_capacity(N_POSITIONS);
+
+    let mut group = c.benchmark_group("vec2_to_positions");
+    group.bench_function("batch", |b| {
+        b.iter(|| vec2_to_positions(black_box(&vectors), &mut continuous))
+    });
+    group.bench_function("collect", |b| {
+        b.iter(|| {
+            black_box(&vectors)
+                .iter()
+                .map(|&vec| Position::from(vec))
+                .collect::<Vec<Position<F32>>>()
+        })
+    });
+    group.bench_function("batch_discrete", |b| {
+        b.iter(|| vec2_to_positions(black_box(&vectors), &mut discrete))
+    });
+    group.bench_function("collect_discrete", |b| {
+        b.iter(|| {
+            black_box(&vectors)
+                .iter()
+                .map(|&vec| Position::from(vec))
+                .collect::<Vec<Position<OrthogonalGrid>>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, positions_to_vectors, vectors_to_positions);
+criterion_main!(benches);
@@ -0,0 +1,205 @@
+use crate::trivial_coordinate::{crate_path, trivial_coordinate_inner};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Expr, Fields, Ident, Token, Type};
+
+/// The settings provided by the `#[coordinate(...)]` attribute
+#[derive(Default)]
+struct CoordinateArgs {
+    data: Option<Type>,
+    scale: Option<Expr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl Parse for CoordinateArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = CoordinateArgs::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "data" => args.data = Some(input.parse()?),
+                "scale" => args.scale = Some(input.parse()?),
+                "min" => args.min = Some(input.parse()?),
+                "max" => args.max = Some(input.parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "Expected one of `data`, `scale`, `min` or `max`.",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// The kinds of wrapped number that need different conversions to and from `f32`
+enum DataKind {
+    F32,
+    F64,
+    Integer,
+}
+
+pub(crate) fn coordinate_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    // Only tuple structs with a single field can be wrapped
+    let wrapped_type = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed[0].ty.clone(),
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    &ast.ident,
+                    "Coordinate can only be derived for tuple structs with exactly one field.",
+                ))
+            }
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "Coordinate can only be derived for tuple structs with exactly one field.",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "Coordinate can only be derived for struct types.",
+            ))
+        }
+    };
+
+    let trivial_impls = trivial_coordinate_inner(ast);
+
+    let struct_name = ast.ident.clone();
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let crate_path = crate_path();
+
+    let mut args = CoordinateArgs::default();
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("coordinate"))
+    {
+        let parsed: CoordinateArgs = attr.parse_args()?;
+        args.data = parsed.data.or(args.data);
+        args.scale = parsed.scale.or(args.scale);
+        args.min = parsed.min.or(args.min);
+        args.max = parsed.max.or(args.max);
+    }
+
+    // `Coordinate::Data` must be the wrapped type, as `TrivialCoordinate` only converts to and from that
+    if let Some(data) = &args.data {
+        if data.to_token_stream().to_string() != wrapped_type.to_token_stream().to_string() {
+            return Err(syn::Error::new_spanned(
+                data,
+                "`data` must match the type of the wrapped field.",
+            ));
+        }
+    }
+
+    let data_kind = match &wrapped_type {
+        Type::Path(path) if path.path.is_ident("f32") => DataKind::F32,
+        Type::Path(path) if path.path.is_ident("f64") => DataKind::F64,
+        _ => DataKind::Integer,
+    };
+
+    // `TrivialCoordinate` already converts to and from the wrapped `f32`, which cannot also be scaled
+    if let (DataKind::F32, Some(scale)) = (&data_kind, &args.scale) {
+        return Err(syn::Error::new_spanned(
+            scale,
+            "`scale` is not supported for coordinates that wrap an `f32`. Wrap an `f64` instead.",
+        ));
+    }
+
+    let scale = args
+        .scale
+        .map_or_else(|| quote!(1.0), |scale| quote!(#scale));
+    let min = args
+        .min
+        .map_or_else(|| quote!(<#wrapped_type>::MIN), |min| quote!(#min));
+    let max = args
+        .max
+        .map_or_else(|| quote!(<#wrapped_type>::MAX), |max| quote!(#max));
+    let (zero, one) = match data_kind {
+        DataKind::F32 | DataKind::F64 => (quote!(0.0), quote!(1.0)),
+        DataKind::Integer => (quote!(0), quote!(1)),
+    };
+
+    let coordinate_trait = quote!(#crate_path::coordinate::Coordinate);
+    let from_float = match data_kind {
+        DataKind::F32 => None,
+        DataKind::F64 => Some(quote!(f64::from(value))),
+        DataKind::Integer => {
+            Some(quote!(#crate_path::coordinate::__private::round(value) as #wrapped_type))
+        }
+    };
+
+    let float_conversions = from_float.map(|from_float| quote! {
+        // Conversion to and from `f32`, scaled by `COORD_TO_TRANSFORM`
+        impl #impl_generics From<#struct_name #type_generics> for f32 #where_clause {
+            fn from(coordinate: #struct_name #type_generics) -> f32 {
+                coordinate.0 as f32 * <#struct_name #type_generics as #coordinate_trait>::COORD_TO_TRANSFORM
+            }
+        }
+
+        impl #impl_generics From<f32> for #struct_name #type_generics #where_clause {
+            fn from(float: f32) -> Self {
+                let value = float / <Self as #coordinate_trait>::COORD_TO_TRANSFORM;
+                let value: #wrapped_type = #from_float;
+
+                Self(value.clamp(
+                    <Self as #coordinate_trait>::MIN.0,
+                    <Self as #coordinate_trait>::MAX.0,
+                ))
+            }
+        }
+    });
+
+    // Integers are totally ordered, so integer coordinates can be stored in sets and used as map keys
+    let integer_ordering = matches!(data_kind, DataKind::Integer).then(|| {
+        quote! {
+            impl #impl_generics core::cmp::Eq for #struct_name #type_generics #where_clause {}
+
+            impl #impl_generics core::cmp::Ord for #struct_name #type_generics #where_clause {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    core::cmp::Ord::cmp(&self.0, &other.0)
+                }
+            }
+
+            impl #impl_generics core::hash::Hash for #struct_name #type_generics #where_clause {
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    core::hash::Hash::hash(&self.0, state);
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #trivial_impls
+
+        #float_conversions
+
+        #integer_ordering
+
+        // The coordinate itself
+        impl #impl_generics #coordinate_trait for #struct_name #type_generics #where_clause {
+            type Data = #wrapped_type;
+
+            const COORD_TO_TRANSFORM: f32 = #scale;
+            const MIN: Self = Self(#min);
+            const MAX: Self = Self(#max);
+            const ZERO: Self = Self(#zero);
+
+            const DATA_ZERO: #wrapped_type = #zero;
+            const DATA_ONE: #wrapped_type = #one;
+        }
+    })
+}
@@ -0,0 +1,151 @@
+use crate::trivial_coordinate::crate_path;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{DeriveInput, Ident, LitStr, Token};
+
+/// The shapes of grid that can be derived, matching the grids in `leafwing_2d::discrete`
+enum GridKind {
+    Orthogonal,
+    Adjacent,
+    HexFlat,
+    HexPointy,
+}
+
+impl GridKind {
+    /// The offset to each neighbor, clockwise from north (+y)
+    fn neighbor_offsets(&self) -> &'static [(i8, i8)] {
+        match self {
+            GridKind::Orthogonal => &[(0, 1), (1, 0), (0, -1), (-1, 0)],
+            GridKind::Adjacent => &[
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+            ],
+            GridKind::HexFlat => &[(0, 1), (1, 1), (1, -1), (0, -1), (-1, -1), (-1, 1)],
+            GridKind::HexPointy => &[(1, 1), (1, 0), (1, -1), (-1, -1), (-1, 0), (-1, 1)],
+        }
+    }
+}
+
+impl Parse for GridKind {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "grid" {
+            return Err(syn::Error::new(key.span(), "Expected `grid`."));
+        }
+        input.parse::<Token![=]>()?;
+
+        let grid: LitStr = input.parse()?;
+        match grid.value().as_str() {
+            "orthogonal" => Ok(GridKind::Orthogonal),
+            "adjacent" => Ok(GridKind::Adjacent),
+            "hex_flat" => Ok(GridKind::HexFlat),
+            "hex_pointy" => Ok(GridKind::HexPointy),
+            _ => Err(syn::Error::new(
+                grid.span(),
+                "Expected one of \"orthogonal\", \"adjacent\", \"hex_flat\" or \"hex_pointy\".",
+            )),
+        }
+    }
+}
+
+pub(crate) fn discrete_coordinate_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = ast.ident.clone();
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let crate_path = crate_path();
+
+    let grid_kind: GridKind = ast
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("discrete"))
+        .ok_or_else(|| {
+            syn::Error::new(
+                Span::call_site(),
+                "Deriving `DiscreteCoordinate` requires a `#[discrete(grid = \"...\")]` attribute.",
+            )
+        })?
+        .parse_args()?;
+
+    let coordinate_trait = quote!(#crate_path::coordinate::Coordinate);
+    let position = quote!(#crate_path::position::Position);
+    let neighbor_offsets = grid_kind.neighbor_offsets();
+    let n_neighbors = neighbor_offsets.len();
+    let offsets = neighbor_offsets.iter().map(|&(dx, dy)| {
+        let (dx, dy) = (f32::from(dx), f32::from(dy));
+        quote!(#crate_path::coordinate::__private::Vec2::new(#dx, #dy))
+    });
+
+    // Each neighbor is only included if moving to it stays within `MIN..=MAX`
+    let neighbors = neighbor_offsets.iter().map(|&(dx, dy)| {
+        let mut in_range = Vec::new();
+        let mut step = |value: TokenStream, delta: i8| match delta {
+            1 => {
+                in_range.push(quote!(#value < max));
+                quote!(#value + 1)
+            }
+            -1 => {
+                in_range.push(quote!(#value > min));
+                quote!(#value - 1)
+            }
+            _ => value,
+        };
+        let (x, y) = (step(quote!(x), dx), step(quote!(y), dy));
+
+        quote! {
+            if #(#in_range)&&* {
+                neighbors.push(#position {
+                    x: Self(#x),
+                    y: Self(#y),
+                });
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::discrete::DiscreteCoordinate for #struct_name #type_generics #where_clause {
+            type Parititions = #crate_path::partitioning::CardinalQuadrant;
+            const N_NEIGHBORS: usize = #n_neighbors;
+
+            #[inline]
+            fn next(&self) -> Self {
+                if *self < <Self as #coordinate_trait>::MAX {
+                    Self(self.0 + 1)
+                } else {
+                    <Self as #coordinate_trait>::MAX
+                }
+            }
+
+            #[inline]
+            fn prev(&self) -> Self {
+                if *self > <Self as #coordinate_trait>::MIN {
+                    Self(self.0 - 1)
+                } else {
+                    <Self as #coordinate_trait>::MIN
+                }
+            }
+
+            fn neighbors(position: #position<Self>) -> #crate_path::coordinate::__private::Vec<#position<Self>> {
+                let (x, y) = (position.x.0, position.y.0);
+                let (min, max) = (<Self as #coordinate_trait>::MIN.0, <Self as #coordinate_trait>::MAX.0);
+
+                let mut neighbors = #crate_path::coordinate::__private::Vec::with_capacity(#n_neighbors);
+                #(#neighbors)*
+                neighbors
+            }
+
+            /// The directions to every neighbor, ignoring the edges of the range `MIN..=MAX`
+            fn neighbor_directions() -> #crate_path::coordinate::__private::Vec<#crate_path::orientation::Direction> {
+                [#(#offsets),*]
+                    .iter()
+                    .map(|&offset| #crate_path::orientation::Direction::new(offset))
+                    .collect()
+            }
+        }
+    })
+}
@@ -1,10 +1,12 @@
-//! Derives the [`Actionlike`] trait
+//! Derives the [`Coordinate`], [`DiscreteCoordinate`] and [`TrivialCoordinate`] traits
 //
 //! This derive macro was inspired by the `strum` crate's `EnumIter` macro.
 //! Original source: https://github.com/Peternator7/strum,
 //! Copyright (c) 2019 Peter Glotfelty under the MIT License
 
 extern crate proc_macro;
+mod coordinate;
+mod discrete_coordinate;
 mod trivial_coordinate;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
@@ -15,3 +17,21 @@ pub fn trivialcoordinate(input: TokenStream) -> TokenStream {
 
     crate::trivial_coordinate::trivial_coordinate_inner(&ast).into()
 }
+
+#[proc_macro_derive(Coordinate, attributes(coordinate))]
+pub fn coordinate(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    crate::coordinate::coordinate_inner(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(DiscreteCoordinate, attributes(discrete))]
+pub fn discrete_coordinate(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    crate::discrete_coordinate::discrete_coordinate_inner(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
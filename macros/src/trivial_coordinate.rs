@@ -9,26 +9,7 @@ pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
     let struct_name = ast.ident.clone();
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let crate_path = if let Ok(found_crate) = crate_name("leafwing_2d") {
-        // The crate was found in the Cargo.toml
-        match found_crate {
-            FoundCrate::Itself => quote!(leafwing_2d),
-            FoundCrate::Name(name) => {
-                let ident = Ident::new(&name, Span::call_site());
-                quote!(#ident)
-            }
-        }
-    } else {
-        // The crate was not found in the Cargo.toml,
-        // so we assume that we are in the owning_crate itself
-        //
-        // In order for this to play nicely with unit tests within the crate itself,
-        // `use crate as leafwing_2d` at the top of each test module where this macro is needed
-        //
-        // Note that doc tests, integration tests and examples want the full standard import,
-        // as they are evaluated as if they were external
-        quote!(leafwing_2d)
-    };
+    let crate_path = crate_path();
 
     // Fetch the wrapped field
     let data_struct = match &ast.data {
@@ -77,8 +58,8 @@ pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
         }
 
         // Debug
-        impl #impl_generics std::fmt::Debug for #struct_name #type_generics #where_clause {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error>{
+        impl #impl_generics core::fmt::Debug for #struct_name #type_generics #where_clause {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error>{
                 f.debug_struct(stringify!(#struct_name))
                     .field("0", &self.0)
                     .finish()
@@ -86,7 +67,7 @@ pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
         }
 
         // Clone and Copy
-        impl #impl_generics std::clone::Clone for #struct_name #type_generics #where_clause {
+        impl #impl_generics core::clone::Clone for #struct_name #type_generics #where_clause {
             fn clone(&self) -> Self {
                 Self(self.0.clone())
             }
@@ -95,21 +76,21 @@ pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
         impl #impl_generics core::marker::Copy for #struct_name #type_generics #where_clause {}
 
         // Default
-        impl #impl_generics std::default::Default for #struct_name #type_generics #where_clause {
+        impl #impl_generics core::default::Default for #struct_name #type_generics #where_clause {
             fn default() -> Self {
                 Self(#wrapped_type::default())
             }
         }
 
         // Equality and ordering
-        impl #impl_generics std::cmp::PartialEq for #struct_name #type_generics #where_clause {
+        impl #impl_generics core::cmp::PartialEq for #struct_name #type_generics #where_clause {
             fn eq(&self, other: &Self) -> bool {
                 self.0.eq(&other.0)
             }
         }
 
-        impl #impl_generics std::cmp::PartialOrd for #struct_name #type_generics #where_clause {
-            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        impl #impl_generics core::cmp::PartialOrd for #struct_name #type_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
                 self.0.partial_cmp(&other.0)
             }
         }
@@ -237,3 +218,27 @@ pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
 
     }
 }
+
+/// The path to the `leafwing_2d` crate, as seen from the crate invoking the macro
+pub(crate) fn crate_path() -> TokenStream {
+    if let Ok(found_crate) = crate_name("leafwing_2d") {
+        // The crate was found in the Cargo.toml
+        match found_crate {
+            FoundCrate::Itself => quote!(leafwing_2d),
+            FoundCrate::Name(name) => {
+                let ident = Ident::new(&name, Span::call_site());
+                quote!(#ident)
+            }
+        }
+    } else {
+        // The crate was not found in the Cargo.toml,
+        // so we assume that we are in the owning_crate itself
+        //
+        // In order for this to play nicely with unit tests within the crate itself,
+        // `use crate as leafwing_2d` at the top of each test module where this macro is needed
+        //
+        // Note that doc tests, integration tests and examples want the full standard import,
+        // as they are evaluated as if they were external
+        quote!(leafwing_2d)
+    }
+}
@@ -0,0 +1,308 @@
+//! Integrate the motion of huge numbers of objects at once
+//!
+//! A [`KinematicBatch`] stores its positions, velocities and accelerations as a structure of arrays,
+//! with one tightly packed array per axis. Integrating these arrays in simple loops is far friendlier
+//! to the cache (and to the auto-vectorizer) than visiting each entity's components in turn,
+//! which makes it a good fit for bullet-hell quantities of projectiles.
+
+use crate::coordinate::Coordinate;
+use crate::kinematics::{Acceleration, Velocity};
+use crate::position::Position;
+use alloc::vec::Vec;
+use core::ops::DerefMut;
+use core::time::Duration;
+
+/// The [`Position`], [`Velocity`] and [`Acceleration`] of many objects, stored as parallel arrays
+///
+/// Each object is identified by its index in the batch.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::batch::KinematicBatch;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Acceleration, Velocity};
+/// use leafwing_2d::position::Position;
+///
+/// let mut bullets = KinematicBatch::<F32>::default();
+/// for i in 0..100 {
+///     bullets.push(
+///         Position::new(0.0, 0.0),
+///         Velocity { x: F32(i as f32), y: F32(0.0) },
+///         Acceleration::default(),
+///     );
+/// }
+///
+/// bullets.integrate(Duration::from_secs(2));
+/// assert_eq!(bullets.position(10), Some(Position::new(20.0, 0.0)));
+///
+/// // Bullets that hit something can be removed cheaply, at the cost of reordering the batch
+/// bullets.swap_remove(10);
+/// assert_eq!(bullets.len(), 99);
+/// assert_eq!(bullets.position(10), Some(Position::new(198.0, 0.0)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct KinematicBatch<C: Coordinate> {
+    position_x: Vec<C>,
+    position_y: Vec<C>,
+    velocity_x: Vec<C>,
+    velocity_y: Vec<C>,
+    acceleration_x: Vec<C>,
+    acceleration_y: Vec<C>,
+}
+
+impl<C: Coordinate> KinematicBatch<C> {
+    /// Creates a new, empty [`KinematicBatch`] with room for `capacity` objects before reallocating
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            position_x: Vec::with_capacity(capacity),
+            position_y: Vec::with_capacity(capacity),
+            velocity_x: Vec::with_capacity(capacity),
+            velocity_y: Vec::with_capacity(capacity),
+            acceleration_x: Vec::with_capacity(capacity),
+            acceleration_y: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// The number of objects in the batch
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.position_x.len()
+    }
+
+    /// Are there no objects in the batch?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.position_x.is_empty()
+    }
+
+    /// Adds a new object to the end of the batch, returning its index
+    pub fn push(
+        &mut self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        acceleration: Acceleration<C>,
+    ) -> usize {
+        self.position_x.push(position.x);
+        self.position_y.push(position.y);
+        self.velocity_x.push(velocity.x);
+        self.velocity_y.push(velocity.y);
+        self.acceleration_x.push(acceleration.x);
+        self.acceleration_y.push(acceleration.y);
+
+        self.len() - 1
+    }
+
+    /// Removes the object at `index`, replacing it with the last object in the batch
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> (Position<C>, Velocity<C>, Acceleration<C>) {
+        (
+            Position {
+                x: self.position_x.swap_remove(index),
+                y: self.position_y.swap_remove(index),
+            },
+            Velocity {
+                x: self.velocity_x.swap_remove(index),
+                y: self.velocity_y.swap_remove(index),
+            },
+            Acceleration {
+                x: self.acceleration_x.swap_remove(index),
+                y: self.acceleration_y.swap_remove(index),
+            },
+        )
+    }
+
+    /// Removes every object from the batch, keeping the allocated memory for reuse
+    pub fn clear(&mut self) {
+        self.position_x.clear();
+        self.position_y.clear();
+        self.velocity_x.clear();
+        self.velocity_y.clear();
+        self.acceleration_x.clear();
+        self.acceleration_y.clear();
+    }
+
+    /// The [`Position`] of the object at `index`, if it exists
+    #[inline]
+    #[must_use]
+    pub fn position(&self, index: usize) -> Option<Position<C>> {
+        Some(Position {
+            x: *self.position_x.get(index)?,
+            y: *self.position_y.get(index)?,
+        })
+    }
+
+    /// The [`Velocity`] of the object at `index`, if it exists
+    #[inline]
+    #[must_use]
+    pub fn velocity(&self, index: usize) -> Option<Velocity<C>> {
+        Some(Velocity {
+            x: *self.velocity_x.get(index)?,
+            y: *self.velocity_y.get(index)?,
+        })
+    }
+
+    /// The [`Acceleration`] of the object at `index`, if it exists
+    #[inline]
+    #[must_use]
+    pub fn acceleration(&self, index: usize) -> Option<Acceleration<C>> {
+        Some(Acceleration {
+            x: *self.acceleration_x.get(index)?,
+            y: *self.acceleration_y.get(index)?,
+        })
+    }
+
+    /// Sets the [`Position`] of the object at `index`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_position(&mut self, index: usize, position: Position<C>) {
+        self.position_x[index] = position.x;
+        self.position_y[index] = position.y;
+    }
+
+    /// Sets the [`Velocity`] of the object at `index`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_velocity(&mut self, index: usize, velocity: Velocity<C>) {
+        self.velocity_x[index] = velocity.x;
+        self.velocity_y[index] = velocity.y;
+    }
+
+    /// Sets the [`Acceleration`] of the object at `index`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_acceleration(&mut self, index: usize, acceleration: Acceleration<C>) {
+        self.acceleration_x[index] = acceleration.x;
+        self.acceleration_y[index] = acceleration.y;
+    }
+
+    /// Iterates over the [`Position`] of each object, in order
+    pub fn positions(&self) -> impl Iterator<Item = Position<C>> + '_ {
+        self.position_x
+            .iter()
+            .zip(&self.position_y)
+            .map(|(&x, &y)| Position { x, y })
+    }
+
+    /// Iterates over the [`Velocity`] of each object, in order
+    pub fn velocities(&self) -> impl Iterator<Item = Velocity<C>> + '_ {
+        self.velocity_x
+            .iter()
+            .zip(&self.velocity_y)
+            .map(|(&x, &y)| Velocity { x, y })
+    }
+
+    /// Iterates over the [`Acceleration`] of each object, in order
+    pub fn accelerations(&self) -> impl Iterator<Item = Acceleration<C>> + '_ {
+        self.acceleration_x
+            .iter()
+            .zip(&self.acceleration_y)
+            .map(|(&x, &y)| Acceleration { x, y })
+    }
+
+    /// Applies each object's [`Acceleration`] and [`Velocity`] over `delta_time`
+    ///
+    /// This matches [`linear_kinematics`](crate::kinematics::systems::linear_kinematics):
+    /// velocities are updated first, and the new velocities are then used to update the positions.
+    pub fn integrate(&mut self, delta_time: Duration) {
+        let delta_time = C::from(delta_time.as_secs_f32());
+
+        // Each axis is processed separately, so that every loop walks over contiguous memory
+        integrate_axis(&mut self.velocity_x, &self.acceleration_x, delta_time);
+        integrate_axis(&mut self.velocity_y, &self.acceleration_y, delta_time);
+        integrate_axis(&mut self.position_x, &self.velocity_x, delta_time);
+        integrate_axis(&mut self.position_y, &self.velocity_y, delta_time);
+    }
+
+    /// Writes the [`Position`] and [`Velocity`] of each object back into `targets`, in order
+    ///
+    /// This accepts anything that dereferences to the components, including `&mut` references and Bevy's `Mut` smart pointers.
+    /// Values are only written if they have changed, to avoid triggering change detection pointlessly.
+    /// If `targets` and the batch differ in length, the extra elements of the longer one are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_2d::batch::KinematicBatch;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::kinematics::{Acceleration, Velocity};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// #[derive(Component)]
+    /// struct Bullet;
+    ///
+    /// fn move_bullets(
+    ///     time: Res<Time>,
+    ///     mut batch: Local<KinematicBatch<F32>>,
+    ///     mut query: Query<(&mut Position<F32>, &mut Velocity<F32>, &Acceleration<F32>), With<Bullet>>,
+    /// ) {
+    ///     batch.clear();
+    ///     batch.extend(query.iter().map(|(&p, &v, &a)| (p, v, a)));
+    ///
+    ///     batch.integrate(time.delta());
+    ///
+    ///     // The query is visited in the same order both times, so each entity gets its own results back
+    ///     batch.scatter_into(query.iter_mut().map(|(position, velocity, _)| (position, velocity)));
+    /// }
+    ///
+    /// App::new().add_system(move_bullets);
+    /// ```
+    pub fn scatter_into<P, V>(&self, targets: impl IntoIterator<Item = (P, V)>)
+    where
+        P: DerefMut<Target = Position<C>>,
+        V: DerefMut<Target = Velocity<C>>,
+    {
+        for ((mut position, mut velocity), (new_position, new_velocity)) in targets
+            .into_iter()
+            .zip(self.positions().zip(self.velocities()))
+        {
+            if *position != new_position {
+                *position = new_position;
+            }
+            if *velocity != new_velocity {
+                *velocity = new_velocity;
+            }
+        }
+    }
+}
+
+/// Adds `rates * delta_time` onto each of the `values`
+#[inline]
+fn integrate_axis<C: Coordinate>(values: &mut [C], rates: &[C], delta_time: C) {
+    for (value, &rate) in values.iter_mut().zip(rates) {
+        *value += rate * delta_time;
+    }
+}
+
+impl<C: Coordinate> Extend<(Position<C>, Velocity<C>, Acceleration<C>)> for KinematicBatch<C> {
+    fn extend<T: IntoIterator<Item = (Position<C>, Velocity<C>, Acceleration<C>)>>(
+        &mut self,
+        iter: T,
+    ) {
+        for (position, velocity, acceleration) in iter {
+            self.push(position, velocity, acceleration);
+        }
+    }
+}
+
+impl<C: Coordinate> FromIterator<(Position<C>, Velocity<C>, Acceleration<C>)>
+    for KinematicBatch<C>
+{
+    fn from_iter<T: IntoIterator<Item = (Position<C>, Velocity<C>, Acceleration<C>)>>(
+        iter: T,
+    ) -> Self {
+        let mut batch = KinematicBatch::default();
+        batch.extend(iter);
+        batch
+    }
+}
@@ -1,8 +1,23 @@
 //! Structs that bound regions that contain [`Positions`](crate::position::Position)
 
+use crate::continuous::F32;
 use crate::coordinate::Coordinate;
+use crate::discrete::DiscreteCoordinate;
+use crate::geometry::{
+    closest_point_on_segment, closest_points_between_segments, segment_segment_distance,
+    segment_segment_intersection, LineIntersection,
+};
+use crate::kinematics::Velocity;
+use crate::math;
+use crate::orientation::{AngularCone, Direction, Rotation};
 use crate::position::Position;
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(feature = "bevy")]
 use bevy_ecs::prelude::Component;
+use core::time::Duration;
+use glam::{DVec2, Vec2};
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore};
 
 /// A 2D region that could contain a [`Position`]
 pub trait BoundingRegion {
@@ -13,18 +28,111 @@ pub trait BoundingRegion {
     fn vertexes(&self) -> Vec<Position<Self::C>>;
 
     /// Tightly draw a new region around the provided collection of [`Positions`](Position)
-    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self;
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self
+    where
+        Self: Sized;
 
     /// Does this region contain the `point`?
     fn contains(&self, position: Position<Self::C>) -> bool;
 
+    /// Checks whether this region contains each of the `positions` at once
+    ///
+    /// `results` is cleared, then filled with the result of [`contains`](BoundingRegion::contains) for each position, in order.
+    /// Reuse the same `results` between calls to avoid reallocating.
+    ///
+    /// This is much faster than calling `contains` in a loop when culling large numbers of particles,
+    /// especially for [`BoxedRegions`](BoxedRegion), which only make one dynamic call per batch.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::{BoundingRegion, Circle};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let screen = Circle::<F32>::new(Position::new(0.0, 0.0), 10.0);
+    /// let particles = [Position::new(1.0, 2.0), Position::new(20.0, 0.0), Position::new(-3.0, -4.0)];
+    ///
+    /// let mut visible = Vec::new();
+    /// screen.contains_batch(&particles, &mut visible);
+    /// assert_eq!(visible, vec![true, false, true]);
+    /// ```
+    fn contains_batch(&self, positions: &[Position<Self::C>], results: &mut Vec<bool>) {
+        results.clear();
+        results.extend(positions.iter().map(|&position| self.contains(position)));
+    }
+
     /// Does this region intersect with the `other` region of the same type?
-    fn intersects(&self, other: Self) -> Intersects;
+    fn intersects(&self, other: Self) -> Intersects
+    where
+        Self: Sized;
 
     /// Clamp the provided position to the limits of this region, taking the shortest path
     fn clamp(&self, position: Position<Self::C>) -> Position<Self::C>;
+
+    /// Samples `n` positions, evenly spaced along the edge of this region
+    ///
+    /// Use this to spawn enemies around the edge of an arena, or to place props along a fence.
+    ///
+    /// By default, this walks the closed outline through the [`vertexes`](BoundingRegion::vertexes),
+    /// starting at the first vertex.
+    /// Regions without any vertexes or without a finite edge (such as [`HalfPlane`]) return no positions.
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        let outline: Vec<Vec2> = self.vertexes().into_iter().map(Vec2::from).collect();
+
+        sample_outline(&outline, n)
+            .into_iter()
+            .map(Position::from)
+            .collect()
+    }
+
+    /// Picks a random position inside of this region, with every position equally likely
+    ///
+    /// Use this to scatter pickups and spawn points across a play area.
+    ///
+    /// By default, random positions are drawn from the box around the [`vertexes`](BoundingRegion::vertexes)
+    /// until one lands inside of this region.
+    /// Regions with a tiny area compared to that box can give up after [`SAMPLING_ATTEMPTS`],
+    /// returning the last attempt [clamped](BoundingRegion::clamp) into the region instead.
+    ///
+    /// # Panics
+    /// Regions without any vertexes must override this method: the default implementation panics for them.
+    /// Regions with an infinite area (such as [`HalfPlane`]) always panic.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::{BoundingRegion, Circle};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::Position;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let arena = Circle::<F32>::new(Position::new(0.0, 0.0), 10.0);
+    /// let mut rng = StdRng::seed_from_u64(42);
+    ///
+    /// for _ in 0..100 {
+    ///     assert!(arena.contains(arena.sample(&mut rng)));
+    /// }
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        let vertexes = self.vertexes();
+        assert!(
+            !vertexes.is_empty(),
+            "Regions without vertexes cannot be sampled by default."
+        );
+
+        rejection_sample(
+            &AxisAlignedBoundingBox::draw_around(vertexes),
+            |position| self.contains(position),
+            |position| self.clamp(position),
+            rng,
+        )
+    }
 }
 
+/// The number of random positions tried by [`BoundingRegion::sample`] before giving up on rejection sampling
+#[cfg(feature = "rand")]
+pub const SAMPLING_ATTEMPTS: usize = 1000;
+
 /// How do two [`BoundingRegions`](BoundingRegion) intersect?
 pub enum Intersects {
     /// The regions overlap, including if one region is contained within the other
@@ -33,6 +141,89 @@ pub enum Intersects {
     No,
 }
 
+/// How two overlapping regions touch, as reported by [`CollisionInfo::collision_info`]
+///
+/// This contains everything needed to push the regions apart, without re-deriving their geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact<C: Coordinate> {
+    /// Where the regions touch: midway between the deepest point of each region inside of the other
+    pub point: Position<C>,
+    /// The direction that the `other` region must move in to stop overlapping, pointing away from `self`
+    pub normal: Direction,
+    /// How far the `other` region must move along the `normal` to stop overlapping
+    ///
+    /// Regions that only touch at their edges have a depth of zero.
+    pub depth: f32,
+}
+
+impl<C: Coordinate> Contact<C> {
+    /// The same contact, seen from the `other` region
+    ///
+    /// The `point` and `depth` are unchanged, but the `normal` is flipped.
+    #[inline]
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        Contact {
+            normal: -self.normal,
+            ..self
+        }
+    }
+
+    /// The contact between two rounded points: the circles of `a_radius` around `a` and `b_radius` around `b`
+    ///
+    /// When `a` and `b` coincide, `fallback` is used as the normal.
+    fn between_points(
+        (a, a_radius): (Vec2, f32),
+        (b, b_radius): (Vec2, f32),
+        fallback: Direction,
+    ) -> Option<Self> {
+        let distance = a.distance(b);
+        if distance > a_radius + b_radius {
+            return None;
+        }
+
+        let normal = Direction::try_from(b - a).unwrap_or(fallback);
+        let deepest_a = a + normal.unit_vector() * a_radius;
+        let deepest_b = b - normal.unit_vector() * b_radius;
+
+        Some(Contact {
+            point: deepest_a.lerp(deepest_b, 0.5).into(),
+            normal,
+            depth: a_radius + b_radius - distance,
+        })
+    }
+}
+
+/// Regions that can report exactly how they overlap with another region of type `R`
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Circle, CollisionInfo};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Direction;
+/// use leafwing_2d::position::Position;
+///
+/// let floor = AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -1.0, 0.0);
+/// let ball = Circle::new(Position::new(0.0, 0.25), 0.5);
+///
+/// let contact = floor.collision_info(&ball).unwrap();
+/// assert_eq!(contact.normal, Direction::NORTH);
+/// assert_eq!(contact.depth, 0.25);
+///
+/// // Push the ball out of the floor
+/// let resolved = Circle::new(
+///     ball.center + Position::from(contact.normal.unit_vector() * contact.depth),
+///     ball.radius,
+/// );
+/// assert_eq!(floor.collision_info(&resolved).unwrap().depth, 0.0);
+/// ```
+pub trait CollisionInfo<R = Self>: BoundingRegion {
+    /// Describes how `self` and `other` overlap, or returns [`None`] if they do not touch
+    ///
+    /// The [`Contact::normal`] points away from `self`, towards `other`.
+    fn collision_info(&self, other: &R) -> Option<Contact<Self::C>>;
+}
+
 /// A 2-dimensional axis-aligned bounding box with coordinate type C
 ///
 /// # Warning
@@ -44,20 +235,21 @@ pub enum Intersects {
 /// # Examples
 /// ```rust
 /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+/// use leafwing_2d::continuous::F32;
 /// use leafwing_2d::position::Position;
 ///
-/// let positions: Vec<Position<f32>> = vec![
+/// let positions: Vec<Position<F32>> = vec![
 ///         Position::new(0.0, 0.0),
 ///         Position::new(-1.0, 1.0),
 ///         Position::new(3.0, 4.0),
 ///         Position::new(-1.0, 17.0),
 ///     ];
 ///
-/// let aabb = AxisAlignedBoundingBox::<f32> {
-///     low_x: -1.0,
-///     low_y: 0.0,
-///     high_x: 3.0,
-///     high_y: 17.0,
+/// let aabb = AxisAlignedBoundingBox::<F32> {
+///     left: F32(-1.0),
+///     right: F32(3.0),
+///     bottom: F32(0.0),
+///     top: F32(17.0),
 /// };
 ///
 /// assert_eq!(aabb, AxisAlignedBoundingBox::draw_around(positions.iter().cloned()));
@@ -73,15 +265,16 @@ pub enum Intersects {
 /// assert_eq!(clamped_outlier, aabb.top_right());
 /// assert!(aabb.contains(clamped_outlier))
 /// ```
-#[derive(Debug, Component, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct AxisAlignedBoundingBox<C: Coordinate> {
     /// The left extent of the bounding box
     pub left: C,
-    /// The top extent of the bounding box
+    /// The right extent of the bounding box
     pub right: C,
     /// The bottom extent of the bounding box
     pub bottom: C,
-    /// The right extent of the bounding box
+    /// The top extent of the bounding box
     pub top: C,
 }
 
@@ -97,25 +290,32 @@ impl<C: Coordinate> BoundingRegion for AxisAlignedBoundingBox<C> {
         ]
     }
 
+    /// If no positions are provided, an empty box at the origin is returned.
     fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let mut positions = positions.into_iter();
+        let first = match positions.next() {
+            Some(position) => position,
+            None => return Self::default(),
+        };
+
         let mut aabb = Self {
-            left: C::default(),
-            bottom: C::default(),
-            top: C::default(),
-            right: C::default(),
+            left: first.x,
+            right: first.x,
+            bottom: first.y,
+            top: first.y,
         };
 
-        for position in positions.into_iter() {
+        for position in positions {
             if position.x < aabb.left {
                 aabb.left = position.x;
-            } else if position.x > aabb.top {
-                aabb.top = position.x;
+            } else if position.x > aabb.right {
+                aabb.right = position.x;
             }
 
             if position.y < aabb.bottom {
                 aabb.bottom = position.y;
-            } else if position.y > aabb.right {
-                aabb.right = position.y;
+            } else if position.y > aabb.top {
+                aabb.top = position.y;
             }
         }
 
@@ -125,15 +325,28 @@ impl<C: Coordinate> BoundingRegion for AxisAlignedBoundingBox<C> {
     fn contains(&self, position: Position<Self::C>) -> bool {
         (self.left <= position.x)
             & (self.bottom <= position.y)
-            & (self.top >= position.x)
-            & (self.right >= position.y)
+            & (self.right >= position.x)
+            & (self.top >= position.y)
+    }
+
+    fn contains_batch(&self, positions: &[Position<Self::C>], results: &mut Vec<bool>) {
+        let (left, right, bottom, top) = (self.left, self.right, self.bottom, self.top);
+
+        results.clear();
+        results.reserve(positions.len());
+        results.extend(positions.iter().map(|position| {
+            (left <= position.x)
+                & (bottom <= position.y)
+                & (right >= position.x)
+                & (top >= position.y)
+        }));
     }
 
     fn intersects(&self, other: Self) -> Intersects {
-        if (self.left > other.top)
-            | (other.left > self.top)
-            | (self.bottom > other.right)
-            | (other.bottom > self.right)
+        if (self.left > other.right)
+            | (other.left > self.right)
+            | (self.bottom > other.top)
+            | (other.bottom > self.top)
         {
             Intersects::No
         } else {
@@ -146,18 +359,24 @@ impl<C: Coordinate> BoundingRegion for AxisAlignedBoundingBox<C> {
 
         if position.x < self.left {
             new_position.x = self.left;
-        } else if position.x > self.top {
-            new_position.x = self.top;
+        } else if position.x > self.right {
+            new_position.x = self.right;
         }
 
         if position.y < self.bottom {
             new_position.y = self.bottom;
-        } else if position.y > self.right {
-            new_position.y = self.right;
+        } else if position.y > self.top {
+            new_position.y = self.top;
         }
 
         new_position
     }
+
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        let (min, max) = (Vec2::from(self.bottom_left()), Vec2::from(self.top_right()));
+        (min + (max - min) * Vec2::new(rng.gen(), rng.gen())).into()
+    }
 }
 
 impl<C: Coordinate> AxisAlignedBoundingBox<C> {
@@ -226,7 +445,7 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     #[must_use]
     pub fn bottom_right(&self) -> Position<C> {
         Position {
-            x: self.top,
+            x: self.right,
             y: self.bottom,
         }
     }
@@ -237,7 +456,7 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     pub fn top_left(&self) -> Position<C> {
         Position {
             x: self.left,
-            y: self.right,
+            y: self.top,
         }
     }
 
@@ -246,8 +465,2620 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     #[must_use]
     pub fn top_right(&self) -> Position<C> {
         Position {
-            x: self.top,
-            y: self.right,
+            x: self.right,
+            y: self.top,
+        }
+    }
+
+    /// Computes the region covered by both this bounding box and `other`
+    ///
+    /// Boxes that only touch along an edge or at a corner intersect in a box with zero width or height.
+    /// Returns [`None`] if the boxes do not overlap at all.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let room = AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 10.0);
+    /// let corridor = AxisAlignedBoundingBox::<F32>::new(8.0, 20.0, 4.0, 6.0);
+    ///
+    /// assert_eq!(
+    ///     room.intersection(&corridor),
+    ///     Some(AxisAlignedBoundingBox::new(8.0, 10.0, 4.0, 6.0))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let max = |a: C, b: C| if a > b { a } else { b };
+        let min = |a: C, b: C| if a < b { a } else { b };
+
+        let left = max(self.left, other.left);
+        let right = min(self.right, other.right);
+        let bottom = max(self.bottom, other.bottom);
+        let top = min(self.top, other.top);
+
+        if left > right || bottom > top {
+            None
+        } else {
+            Some(Self {
+                left,
+                right,
+                bottom,
+                top,
+            })
+        }
+    }
+
+    /// Computes the smallest bounding box that contains both this bounding box and `other`
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let player = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 2.0);
+    /// let sidekick = AxisAlignedBoundingBox::<F32>::new(3.0, 4.0, -1.0, 0.0);
+    ///
+    /// assert_eq!(
+    ///     player.union(&sidekick),
+    ///     AxisAlignedBoundingBox::new(0.0, 4.0, -1.0, 2.0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let max = |a: C, b: C| if a > b { a } else { b };
+        let min = |a: C, b: C| if a < b { a } else { b };
+
+        Self {
+            left: min(self.left, other.left),
+            right: max(self.right, other.right),
+            bottom: min(self.bottom, other.bottom),
+            top: max(self.top, other.top),
+        }
+    }
+
+    /// Computes the region covered by this bounding box, but not by `other`
+    ///
+    /// The result is made up of at most four non-overlapping boxes:
+    /// the full-height slices to the left and right of `other`, and the slices directly below and above it.
+    /// If the boxes do not overlap (or only touch), this box is returned unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let floor = AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 10.0);
+    /// let pillar = AxisAlignedBoundingBox::<F32>::new(4.0, 6.0, 4.0, 6.0);
+    ///
+    /// let walkable = floor.difference(&pillar);
+    /// assert_eq!(walkable.len(), 4);
+    /// assert!(walkable.iter().any(|aabb| aabb.contains(Position::new(1.0, 1.0))));
+    /// assert!(!walkable.iter().any(|aabb| aabb.contains(Position::new(5.0, 5.0))));
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let overlap = match self.intersection(other) {
+            Some(overlap) if overlap.left < overlap.right && overlap.bottom < overlap.top => {
+                overlap
+            }
+            _ => return vec![self.clone()],
+        };
+
+        let mut pieces = Vec::with_capacity(4);
+        if self.left < overlap.left {
+            pieces.push(Self {
+                right: overlap.left,
+                ..*self
+            });
+        }
+        if overlap.right < self.right {
+            pieces.push(Self {
+                left: overlap.right,
+                ..*self
+            });
+        }
+        if self.bottom < overlap.bottom {
+            pieces.push(Self {
+                bottom: self.bottom,
+                top: overlap.bottom,
+                ..overlap
+            });
+        }
+        if overlap.top < self.top {
+            pieces.push(Self {
+                bottom: overlap.top,
+                top: self.top,
+                ..overlap
+            });
+        }
+
+        pieces
+    }
+
+    /// Grows this box by `amount` in every direction
+    ///
+    /// Negative amounts shrink the box instead; boxes that shrink past nothing collapse to their center.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let player = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 2.0);
+    ///
+    /// assert_eq!(player.expanded_by(0.5), AxisAlignedBoundingBox::new(-0.5, 1.5, -0.5, 2.5));
+    /// assert_eq!(player.expanded_by(-0.75), AxisAlignedBoundingBox::new(0.5, 0.5, 0.75, 1.25));
+    /// ```
+    #[must_use]
+    pub fn expanded_by(&self, amount: impl Into<C>) -> Self {
+        let amount = amount.into();
+        let two = C::from(2.0);
+
+        let (mut left, mut right) = (self.left - amount, self.right + amount);
+        if left > right {
+            left = (self.left + self.right) / two;
+            right = left;
+        }
+        let (mut bottom, mut top) = (self.bottom - amount, self.top + amount);
+        if bottom > top {
+            bottom = (self.bottom + self.top) / two;
+            top = bottom;
+        }
+
+        Self {
+            left,
+            right,
+            bottom,
+            top,
+        }
+    }
+
+    /// Moves this box by `offset`, without changing its size
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let player = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 2.0);
+    ///
+    /// assert_eq!(
+    ///     player.translated_by(Position::new(3.0, -1.0)),
+    ///     AxisAlignedBoundingBox::new(3.0, 4.0, -1.0, 1.0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn translated_by(&self, offset: Position<C>) -> Self {
+        Self {
+            left: self.left + offset.x,
+            right: self.right + offset.x,
+            bottom: self.bottom + offset.y,
+            top: self.top + offset.y,
+        }
+    }
+
+    /// Scales the width and height of this box by `factor`, keeping it centered in the same place
+    ///
+    /// Negative factors flip the box around its center, which has the same result as the matching positive factor.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let player = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 2.0);
+    ///
+    /// assert_eq!(player.scaled_by(2.0), AxisAlignedBoundingBox::new(-0.5, 1.5, -1.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn scaled_by(&self, factor: C::Data) -> Self {
+        let factor: f32 = C::from(factor).into();
+        // Work in floats, so that discrete coordinates only round the final result
+        let scale_around = |low: C, high: C| {
+            let (low, high): (f32, f32) = (low.into(), high.into());
+            let center = 0.5 * (low + high);
+            let half_size = 0.5 * (high - low) * factor.abs();
+            (C::from(center - half_size), C::from(center + half_size))
+        };
+
+        let (left, right) = scale_around(self.left, self.right);
+        let (bottom, top) = scale_around(self.bottom, self.top);
+
+        Self {
+            left,
+            right,
+            bottom,
+            top,
+        }
+    }
+
+    /// Every position that can be reached by adding a position in this box to a position in `other`
+    ///
+    /// Growing obstacles by the size of a moving entity lets the entity be treated as a single point,
+    /// which turns swept collision checks into simple raycasts.
+    /// The `other` box is usually centered on the origin, so that the result stays in place.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let wall = AxisAlignedBoundingBox::<F32>::new(10.0, 11.0, 0.0, 5.0);
+    /// let player = AxisAlignedBoundingBox::<F32>::new(-0.5, 0.5, -1.0, 1.0);
+    ///
+    /// // The player's center can't enter this box without the player touching the wall
+    /// assert_eq!(
+    ///     wall.minkowski_sum(&player),
+    ///     AxisAlignedBoundingBox::new(9.5, 11.5, -1.0, 6.0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        Self {
+            left: self.left + other.left,
+            right: self.right + other.right,
+            bottom: self.bottom + other.bottom,
+            top: self.top + other.top,
+        }
+    }
+
+    /// Every position that `other` can be moved by while staying entirely inside of this box,
+    /// or [`None`] if `other` is too big to fit
+    ///
+    /// This undoes [`minkowski_sum`](Self::minkowski_sum), shrinking this box by the size of `other`.
+    /// Use it to find where the center of an entity can go while keeping the whole entity inside of the world.
+    /// For the positions that are in this box but not `other`, see [`difference`](Self::difference) instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let world = AxisAlignedBoundingBox::<F32>::new(0.0, 100.0, 0.0, 50.0);
+    /// let player = AxisAlignedBoundingBox::<F32>::new(-0.5, 0.5, -1.0, 1.0);
+    ///
+    /// assert_eq!(
+    ///     world.minkowski_difference(&player),
+    ///     Some(AxisAlignedBoundingBox::new(0.5, 99.5, 1.0, 49.0))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn minkowski_difference(&self, other: &Self) -> Option<Self> {
+        let left = self.left - other.left;
+        let right = self.right - other.right;
+        let bottom = self.bottom - other.bottom;
+        let top = self.top - other.top;
+
+        if left > right || bottom > top {
+            None
+        } else {
+            Some(Self {
+                left,
+                right,
+                bottom,
+                top,
+            })
+        }
+    }
+
+    /// The region covered by this box as it moves at `velocity` for `duration`
+    ///
+    /// Check this against other regions to catch anything that a fast-moving box passes through between frames.
+    #[must_use]
+    pub fn sweep(&self, velocity: Velocity<C>, duration: Duration) -> SweptRegion<C> {
+        SweptRegion {
+            start: self.clone(),
+            displacement: Vec2::new(velocity.x.into(), velocity.y.into()) * duration.as_secs_f32(),
+        }
+    }
+
+    /// How long it takes this box to first touch `other` while moving at `velocity`, if it touches within `duration`
+    ///
+    /// The `other` box stays still; to sweep two moving boxes, pass the difference between their velocities.
+    /// If the boxes are already touching, [`Duration::ZERO`] is returned.
+    ///
+    /// Unlike [`intersects`](BoundingRegion::intersects), this can't miss thin walls that a fast box would skip over between frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::time::Duration;
+    /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion, Intersects};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::kinematics::Velocity;
+    ///
+    /// let bullet = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 1.0);
+    /// let wall = AxisAlignedBoundingBox::<F32>::new(10.0, 10.5, -5.0, 5.0);
+    /// let velocity = Velocity { x: F32(100.0), y: F32(0.0) };
+    ///
+    /// // By the end of the frame, the bullet is already past the wall
+    /// let frame = Duration::from_millis(200);
+    /// let end = bullet.sweep(velocity, frame).end();
+    /// assert!(matches!(end.intersects(wall.clone()), Intersects::No));
+    ///
+    /// // But it hit the wall along the way
+    /// let impact = bullet.sweep_intersects(velocity, &wall, frame).unwrap();
+    /// assert!((impact.as_secs_f32() - 0.09).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn sweep_intersects(
+        &self,
+        velocity: Velocity<C>,
+        other: &Self,
+        duration: Duration,
+    ) -> Option<Duration> {
+        let displacement = Vec2::new(velocity.x.into(), velocity.y.into()) * duration.as_secs_f32();
+        let (start_min, start_max) = (Vec2::from(self.bottom_left()), Vec2::from(self.top_right()));
+        let (other_min, other_max) = (
+            Vec2::from(other.bottom_left()),
+            Vec2::from(other.top_right()),
+        );
+
+        // The boxes touch once they have been displaced into this range along both axes
+        let (enter_x, exit_x) = slab_times(
+            other_min.x - start_max.x,
+            other_max.x - start_min.x,
+            displacement.x,
+        );
+        let (enter_y, exit_y) = slab_times(
+            other_min.y - start_max.y,
+            other_max.y - start_min.y,
+            displacement.y,
+        );
+        let (enter, exit) = (enter_x.max(enter_y).max(0.), exit_x.min(exit_y).min(1.));
+
+        if enter <= exit {
+            Some(duration.mul_f32(enter))
+        } else {
+            None
+        }
+    }
+}
+
+impl<C: DiscreteCoordinate> AxisAlignedBoundingBox<C> {
+    /// Every cell inside of this box, including those on its edges
+    ///
+    /// Cells are returned row by row, starting from the bottom left.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::discrete::OrthogonalGrid;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let blast = AxisAlignedBoundingBox::<OrthogonalGrid>::new(-1., 1., 2., 3.);
+    /// let cells: Vec<_> = blast.cells().collect();
+    ///
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[0], Position::new(OrthogonalGrid(-1), OrthogonalGrid(2)));
+    /// assert_eq!(cells[5], Position::new(OrthogonalGrid(1), OrthogonalGrid(3)));
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = Position<C>> {
+        let (left, right) = (self.left, self.right);
+
+        cell_range(self.bottom, self.top)
+            .flat_map(move |y| cell_range(left, right).map(move |x| Position { x, y }))
+    }
+}
+
+/// Every value from `start` to `end` inclusive, stepping with [`DiscreteCoordinate::next`]
+fn cell_range<C: DiscreteCoordinate>(start: C, end: C) -> impl Iterator<Item = C> {
+    core::iter::successors((start <= end).then_some(start), move |&value| {
+        (value < end).then(|| value.next())
+    })
+}
+
+/// The region covered by an [`AxisAlignedBoundingBox`] as it moves in a straight line, created by [`AxisAlignedBoundingBox::sweep`]
+///
+/// This is the convex hull of the box at the start and end of its motion.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Velocity;
+/// use leafwing_2d::position::Position;
+///
+/// let player = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 1.0);
+/// let swept = player.sweep(Velocity { x: F32(4.0), y: F32(4.0) }, Duration::from_secs(1));
+///
+/// assert_eq!(swept.end(), AxisAlignedBoundingBox::new(4.0, 5.0, 4.0, 5.0));
+/// assert!(swept.contains(Position::new(2.5, 2.5)));
+/// // Inside the box around the whole motion, but never touched by the player
+/// assert!(!swept.contains(Position::new(4.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct SweptRegion<C: Coordinate> {
+    /// The box at the start of its motion
+    pub start: AxisAlignedBoundingBox<C>,
+    /// How far the box moves, in the [`f32`] units of the coordinate type
+    pub displacement: Vec2,
+}
+
+impl<C: Coordinate> SweptRegion<C> {
+    /// The box at the end of its motion
+    #[must_use]
+    pub fn end(&self) -> AxisAlignedBoundingBox<C> {
+        let (min, max) = self.corners_at(1.);
+        AxisAlignedBoundingBox::new(min.x, max.x, min.y, max.y)
+    }
+
+    /// The smallest [`AxisAlignedBoundingBox`] that contains the whole motion
+    ///
+    /// This is a cheap, loose test to run before more precise checks.
+    #[must_use]
+    pub fn aabb(&self) -> AxisAlignedBoundingBox<C> {
+        self.start.union(&self.end())
+    }
+
+    /// The bottom left and top right corners of the box, after it has moved for the fraction `t` of its motion
+    fn corners_at(&self, t: f32) -> (Vec2, Vec2) {
+        let offset = self.displacement * t;
+        (
+            Vec2::from(self.start.bottom_left()) + offset,
+            Vec2::from(self.start.top_right()) + offset,
+        )
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for SweptRegion<C> {
+    type C = C;
+
+    /// Lists the corners of the convex hull of the motion, clockwise
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        let ((start_min, start_max), (end_min, end_max)) =
+            (self.corners_at(0.), self.corners_at(1.));
+        let corners = [
+            start_min,
+            Vec2::new(start_max.x, start_min.y),
+            start_max,
+            Vec2::new(start_min.x, start_max.y),
+            end_min,
+            Vec2::new(end_max.x, end_min.y),
+            end_max,
+            Vec2::new(end_min.x, end_max.y),
+        ];
+
+        convex_hull(&corners)
+            .into_iter()
+            .rev()
+            .map(Position::from)
+            .collect()
+    }
+
+    /// Draws a box around the `positions` that doesn't move
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        SweptRegion {
+            start: AxisAlignedBoundingBox::draw_around(positions),
+            displacement: Vec2::ZERO,
+        }
+    }
+
+    /// Positions on the edge of the swept region are contained.
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        let point = Vec2::from(position);
+        let (start_min, start_max) = self.corners_at(0.);
+
+        // The box covers the point once it has been displaced into this range along both axes
+        let (enter_x, exit_x) = slab_times(
+            point.x - start_max.x,
+            point.x - start_min.x,
+            self.displacement.x,
+        );
+        let (enter_y, exit_y) = slab_times(
+            point.y - start_max.y,
+            point.y - start_min.y,
+            self.displacement.y,
+        );
+
+        enter_x.max(enter_y).max(0.) <= exit_x.min(exit_y).min(1.)
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        if overlap(self, &other) {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.contains(position) {
+            return position;
         }
+
+        let point = Vec2::from(position);
+        let outline: Vec<Vec2> = self.vertexes().into_iter().map(Vec2::from).collect();
+        (0..outline.len())
+            .map(|i| closest_point_on_segment(point, outline[i], outline[(i + 1) % outline.len()]))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+            .map_or(position, Position::from)
+    }
+}
+
+/// A circle: every position within `radius` of `center`
+///
+/// When drawn around a collection of positions, this is the smallest circle that contains all of them,
+/// found using Welzl's algorithm.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{BoundingRegion, Circle};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let sprite_vertexes = [
+///     Position::<F32>::new(-3.0, 0.0),
+///     Position::new(3.0, 0.0),
+///     Position::new(0.0, 1.0),
+///     Position::new(1.0, -1.0),
+/// ];
+///
+/// let circle = Circle::draw_around(sprite_vertexes);
+/// assert_eq!(circle, Circle::new(Position::new(0.0, 0.0), 3.0));
+///
+/// for vertex in sprite_vertexes {
+///     assert!(circle.contains(vertex));
+/// }
+///
+/// assert_eq!(circle.clamp(Position::new(0.0, 5.0)), Position::new(0.0, 3.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Circle<C: Coordinate> {
+    /// The center of the circle
+    pub center: Position<C>,
+    /// The distance from `center` to the edge of the circle
+    pub radius: C,
+}
+
+impl<C: Coordinate> Circle<C> {
+    /// Creates a new [`Circle`]
+    ///
+    /// # Panics
+    /// `radius` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new(center: Position<C>, radius: impl Into<C>) -> Self {
+        let radius = radius.into();
+        assert!(radius >= C::ZERO);
+
+        Self { center, radius }
     }
+
+    /// Every position that can be reached by adding a position in this circle to a position in `other`
+    ///
+    /// The `other` circle is usually centered on the origin, so that the result stays in place.
+    /// See [`AxisAlignedBoundingBox::minkowski_sum`] for more details.
+    #[must_use]
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        Circle {
+            center: self.center + other.center,
+            radius: self.radius + other.radius,
+        }
+    }
+
+    /// Every position that `other` can be moved by while staying entirely inside of this circle,
+    /// or [`None`] if `other` is too big to fit
+    ///
+    /// See [`AxisAlignedBoundingBox::minkowski_difference`] for more details.
+    #[must_use]
+    pub fn minkowski_difference(&self, other: &Self) -> Option<Self> {
+        if other.radius > self.radius {
+            return None;
+        }
+
+        Some(Circle {
+            center: self.center - other.center,
+            radius: self.radius - other.radius,
+        })
+    }
+}
+
+impl<C: DiscreteCoordinate> Circle<C> {
+    /// Every cell whose center lies inside of this circle, including those exactly on its edge
+    ///
+    /// Distances are compared using `C` rather than [`f32`], so this is exact.
+    /// Cells are returned row by row, starting from the bottom.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::Circle;
+    /// use leafwing_2d::discrete::OrthogonalGrid;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let center = Position::<OrthogonalGrid>::new(OrthogonalGrid(5), OrthogonalGrid(5));
+    /// let fireball = Circle::new(center, OrthogonalGrid(1));
+    ///
+    /// // The center and its four orthogonal neighbors
+    /// assert_eq!(fireball.cells().count(), 5);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = Position<C>> {
+        let Circle { center, radius } = *self;
+        let bounds = AxisAlignedBoundingBox {
+            left: center.x - radius,
+            right: center.x + radius,
+            bottom: center.y - radius,
+            top: center.y + radius,
+        };
+
+        bounds.cells().filter(move |&cell| {
+            let offset = cell - center;
+            offset.x * offset.x + offset.y * offset.y <= radius * radius
+        })
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for Circle<C> {
+    type C = C;
+
+    /// Circles have no corners, so no vertexes are returned
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    /// Draws the smallest circle that contains every position
+    ///
+    /// If no positions are provided, a circle with zero radius at the origin is returned.
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let mut points: Vec<DVec2> = positions
+            .into_iter()
+            .map(|position| Vec2::from(position).as_dvec2())
+            .collect();
+
+        // Welzl's algorithm runs in expected linear time when the points are visited in a random order,
+        // so shuffle them with a fixed seed to keep the result deterministic
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for i in (1..points.len()).rev() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            points.swap(i, (state >> 33) as usize % (i + 1));
+        }
+
+        let (center, radius) = smallest_enclosing_circle(&points);
+
+        Circle {
+            center: center.as_vec2().into(),
+            radius: C::from(radius as f32),
+        }
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        let radius: f32 = self.radius.into();
+        Vec2::from(self.center).distance_squared(position.into()) <= radius * radius
+    }
+
+    fn contains_batch(&self, positions: &[Position<Self::C>], results: &mut Vec<bool>) {
+        let center = Vec2::from(self.center);
+        let radius: f32 = self.radius.into();
+        let radius_squared = radius * radius;
+
+        results.clear();
+        results.reserve(positions.len());
+        results.extend(
+            positions
+                .iter()
+                .map(|&position| center.distance_squared(position.into()) <= radius_squared),
+        );
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        let reach = self.radius + other.radius;
+        let reach: f32 = reach.into();
+        if Vec2::from(self.center).distance_squared(other.center.into()) <= reach * reach {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.contains(position) {
+            return position;
+        }
+
+        let center = Vec2::from(self.center);
+        let offset = Vec2::from(position) - center;
+        let radius: f32 = self.radius.into();
+        (center + offset.normalize() * radius).into()
+    }
+
+    /// Samples `n` positions evenly spaced around the edge of this circle, clockwise from north
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        circle_points(Vec2::from(self.center), self.radius.into(), n)
+            .map(Position::from)
+            .collect()
+    }
+
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        let radius: f32 = self.radius.into();
+        (Vec2::from(self.center) + sample_unit_disk(rng) * radius).into()
+    }
+}
+
+/// Computes the center and radius of the smallest circle containing all of the `points`
+///
+/// This is the iterative form of Welzl's algorithm.
+fn smallest_enclosing_circle(points: &[DVec2]) -> (DVec2, f64) {
+    // Allow for the rounding error in computing circles through points on their edge
+    let contains = |(center, radius): (DVec2, f64), point: DVec2| {
+        center.distance(point) <= radius * (1. + 1e-9) + 1e-9
+    };
+
+    let mut circle = (DVec2::ZERO, 0.0);
+    for (i, &a) in points.iter().enumerate() {
+        if i > 0 && contains(circle, a) {
+            continue;
+        }
+
+        // `a` must be on the edge of the circle around the first `i + 1` points
+        circle = (a, 0.0);
+        for (j, &b) in points[..i].iter().enumerate() {
+            if contains(circle, b) {
+                continue;
+            }
+
+            // Both `a` and `b` must be on the edge
+            circle = ((a + b) / 2., a.distance(b) / 2.);
+            for &c in &points[..j] {
+                if !contains(circle, c) {
+                    circle = circumcircle(a, b, c).unwrap_or(circle);
+                }
+            }
+        }
+    }
+
+    circle
+}
+
+/// The circle passing through `a`, `b` and `c`, or [`None`] if they are collinear
+fn circumcircle(a: DVec2, b: DVec2, c: DVec2) -> Option<(DVec2, f64)> {
+    let (ab, ac) = (b - a, c - a);
+    let denominator = 2. * ab.perp_dot(ac);
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let offset = DVec2::new(
+        ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+        ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+    ) / denominator;
+
+    Some((a + offset, offset.length()))
+}
+
+/// An ellipse with semi-axes of `half_width` and `half_height`, rotated clockwise around its `center` by `rotation`
+///
+/// Before rotation, `half_width` is measured along the x-axis and `half_height` along the y-axis,
+/// so a `rotation` of [`Rotation::NORTH`] produces an axis-aligned ellipse.
+///
+/// Use this for elliptical vision and blast shapes, which are poorly approximated by circles and boxes.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{BoundingRegion, Ellipse};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::Position;
+///
+/// let sprite_vertexes = [
+///     Position::<F32>::new(-2.0, -1.0),
+///     Position::new(2.0, -1.0),
+///     Position::new(2.0, 1.0),
+///     Position::new(-2.0, 1.0),
+/// ];
+///
+/// let ellipse = Ellipse::fit(sprite_vertexes);
+/// assert_eq!(ellipse.center, Position::new(0.0, 0.0));
+/// assert_eq!(ellipse.rotation, Rotation::NORTH);
+/// assert!(ellipse.half_width > ellipse.half_height);
+///
+/// for vertex in sprite_vertexes {
+///     assert!(ellipse.contains(vertex));
+/// }
+/// assert!(!ellipse.contains(Position::new(0.0, 2.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Ellipse<C: Coordinate> {
+    /// The center of the ellipse
+    pub center: Position<C>,
+    /// Half of the width of the ellipse, before it is rotated
+    pub half_width: C,
+    /// Half of the height of the ellipse, before it is rotated
+    pub half_height: C,
+    /// How far the ellipse is rotated clockwise around its `center`
+    pub rotation: Rotation,
+}
+
+impl<C: Coordinate> Ellipse<C> {
+    /// Creates a new [`Ellipse`]
+    ///
+    /// # Panics
+    /// `half_width` and `half_height` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new(
+        center: Position<C>,
+        half_width: impl Into<C>,
+        half_height: impl Into<C>,
+        rotation: Rotation,
+    ) -> Self {
+        let half_width = half_width.into();
+        let half_height = half_height.into();
+        assert!(half_width >= C::ZERO);
+        assert!(half_height >= C::ZERO);
+
+        Self {
+            center,
+            half_width,
+            half_height,
+            rotation,
+        }
+    }
+
+    /// Fits an ellipse around the provided `positions`
+    ///
+    /// The ellipse is centered on the mean of the `positions`, aligned with their principal axes
+    /// and proportioned to match how far they spread along each axis.
+    /// It is then scaled up until it contains every position.
+    /// This is a good, cheap fit for the outlines of sprites, but is not necessarily the smallest possible enclosing ellipse.
+    ///
+    /// If no positions are provided, an ellipse with zero size at the origin is returned.
+    #[must_use]
+    pub fn fit(positions: impl IntoIterator<Item = Position<C>>) -> Self {
+        let points: Vec<DVec2> = positions
+            .into_iter()
+            .map(|position| Vec2::from(position).as_dvec2())
+            .collect();
+
+        if points.is_empty() {
+            return Ellipse::new(Position::default(), C::ZERO, C::ZERO, Rotation::NORTH);
+        }
+
+        let mean = points.iter().sum::<DVec2>() / points.len() as f64;
+        let (mut xx, mut xy, mut yy) = (0.0, 0.0, 0.0);
+        for &point in &points {
+            let offset = point - mean;
+            xx += offset.x * offset.x;
+            xy += offset.x * offset.y;
+            yy += offset.y * offset.y;
+        }
+
+        // The major axis is this many radians counterclockwise from the x-axis,
+        // which is the same as rotating the ellipse clockwise by its negation
+        let major_axis = 0.5 * math::atan2((2. * xy) as f32, (xx - yy) as f32);
+        let rotation = Rotation::from_radians(-major_axis);
+        let center: Position<C> = mean.as_vec2().into();
+
+        // Measure the spread along the (rounded) axes of the ellipse
+        let unrotate = -rotation;
+        let local: Vec<Vec2> = points
+            .iter()
+            .map(|&point| unrotate.rotate_vec2(point.as_vec2() - Vec2::from(center)))
+            .collect();
+        let spread = local
+            .iter()
+            .fold(Vec2::ZERO, |sum, &point| sum + point * point);
+        let spread = Vec2::new(math::sqrt(spread.x), math::sqrt(spread.y));
+
+        // Scale the axes together until every point fits
+        let scale = local
+            .iter()
+            .map(|&point| normalized_distance_squared(point, spread))
+            .fold(0.0, f32::max);
+        let scale = math::sqrt(scale) * (1. + 1e-5);
+
+        Ellipse {
+            center,
+            half_width: C::from(spread.x * scale),
+            half_height: C::from(spread.y * scale),
+            rotation,
+        }
+    }
+
+    /// The `half_width` and `half_height`, as a [`Vec2`]
+    fn half_size(&self) -> Vec2 {
+        Vec2::new(self.half_width.into(), self.half_height.into())
+    }
+
+    /// Converts the `position` into the frame of this ellipse, where it is centered at the origin and axis-aligned
+    fn local_point(&self, position: Position<C>) -> Vec2 {
+        (-self.rotation).rotate_vec2(Vec2::from(position) - Vec2::from(self.center))
+    }
+
+    /// Converts the `point` from the frame of this ellipse back into a [`Position`]
+    fn global_position(&self, point: Vec2) -> Position<C> {
+        (Vec2::from(self.center) + self.rotation.rotate_vec2(point)).into()
+    }
+
+    /// Finds the position in this ellipse that is closest to `position`
+    ///
+    /// Positions inside of the ellipse are returned unchanged.
+    /// Positions outside of the ellipse are moved onto its edge, up to floating point error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::Ellipse;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::position::{Position, Positionlike};
+    ///
+    /// let ellipse = Ellipse::<F32>::new(Position::new(0.0, 0.0), 4.0, 2.0, Rotation::NORTH);
+    ///
+    /// ellipse
+    ///     .closest_point(Position::new(10.0, 0.0))
+    ///     .assert_approx_eq(Position::<F32>::new(4.0, 0.0));
+    /// ellipse
+    ///     .closest_point(Position::new(0.0, -5.0))
+    ///     .assert_approx_eq(Position::<F32>::new(0.0, -2.0));
+    /// ```
+    #[must_use]
+    pub fn closest_point(&self, position: Position<C>) -> Position<C> {
+        let local = self.local_point(position);
+        let half_size = self.half_size();
+
+        if normalized_distance_squared(local, half_size) <= 1.0 {
+            position
+        } else {
+            self.global_position(closest_point_on_ellipse(local, half_size))
+        }
+    }
+
+    /// Does this ellipse overlap the `circle`?
+    #[must_use]
+    pub fn intersects_circle(&self, circle: &Circle<C>) -> bool {
+        circle.contains(self.closest_point(circle.center))
+    }
+
+    /// Does this ellipse overlap the `aabb`?
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Ellipse};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let blast = Ellipse::<F32>::new(Position::new(0.0, 0.0), 4.0, 1.0, Rotation::NORTH);
+    ///
+    /// assert!(blast.intersects_aabb(&AxisAlignedBoundingBox::new(3.0, 5.0, -1.0, 1.0)));
+    /// // Inside the box containing the ellipse, but not inside the ellipse itself
+    /// assert!(!blast.intersects_aabb(&AxisAlignedBoundingBox::new(3.0, 5.0, 0.8, 2.0)));
+    /// ```
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> bool {
+        if aabb.contains(self.center) {
+            return true;
+        }
+
+        let half_size = self.half_size();
+        let corners = [
+            aabb.bottom_left(),
+            aabb.bottom_right(),
+            aabb.top_right(),
+            aabb.top_left(),
+        ]
+        .map(|corner| self.local_point(corner));
+
+        if half_size.x > 0.0 && half_size.y > 0.0 {
+            // Stretch space so that the ellipse becomes a unit circle, and the box becomes a parallelogram
+            let corners = corners.map(|corner| corner / half_size);
+            (0..4).any(|i| {
+                closest_point_on_segment(Vec2::ZERO, corners[i], corners[(i + 1) % 4])
+                    .length_squared()
+                    <= 1.0
+            })
+        } else {
+            // Degenerate ellipses are line segments, which can be checked against the box's edges directly
+            let (start, end) = (-half_size, half_size);
+            corners
+                .iter()
+                .any(|&corner| normalized_distance_squared(corner, half_size) <= 1.0)
+                || (0..4).any(|i| {
+                    matches!(
+                        segment_segment_intersection::<F32>(
+                            start.into(),
+                            end.into(),
+                            corners[i].into(),
+                            corners[(i + 1) % 4].into(),
+                        ),
+                        LineIntersection::Point(_) | LineIntersection::Overlap(_, _)
+                    )
+                })
+        }
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for Ellipse<C> {
+    type C = C;
+
+    /// Ellipses have no corners, so no vertexes are returned
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    /// Fits an ellipse around the `positions`, using [`Ellipse::fit`]
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        Ellipse::fit(positions)
+    }
+
+    /// Positions on the edge of the ellipse are contained.
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        normalized_distance_squared(self.local_point(position), self.half_size()) <= 1.0
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        if overlap(self, &other) {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        self.closest_point(position)
+    }
+
+    /// Samples `n` positions along the edge of this ellipse, starting from the end of its rotated vertical axis
+    ///
+    /// The edge is approximated by a fine polygon, so the spacing is very nearly (but not exactly) even.
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        let outline: Vec<Vec2> = circle_points(Vec2::ZERO, 1.0, (8 * n).max(64))
+            .map(|point| point * self.half_size())
+            .collect();
+
+        sample_outline(&outline, n)
+            .into_iter()
+            .map(|point| self.global_position(point))
+            .collect()
+    }
+
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        // Stretching a circle into an ellipse stretches every part of it equally, so uniformity is preserved
+        self.global_position(sample_unit_disk(rng) * self.half_size())
+    }
+}
+
+/// The point on the edge of the axis-aligned ellipse centered at the origin that is closest to `point`
+///
+/// This uses a few rounds of a trigonometry-free Newton's method,
+/// which converges quickly for every point outside of the ellipse.
+fn closest_point_on_ellipse(point: Vec2, half_size: Vec2) -> Vec2 {
+    let (a, b) = (half_size.x, half_size.y);
+    if a == 0.0 || b == 0.0 {
+        return point.clamp(-half_size, half_size);
+    }
+
+    // By symmetry, it is enough to solve the problem in the first quadrant
+    let target = point.abs();
+    let mut t = Vec2::splat(core::f32::consts::FRAC_1_SQRT_2);
+
+    for _ in 0..4 {
+        let evolute = Vec2::new(
+            (a * a - b * b) * t.x * t.x * t.x / a,
+            (b * b - a * a) * t.y * t.y * t.y / b,
+        );
+        let to_edge = half_size * t - evolute;
+        let to_target = target - evolute;
+
+        let target_length = to_target.length();
+        if target_length == 0.0 {
+            break;
+        }
+
+        t = ((to_target * to_edge.length() / target_length + evolute) / half_size)
+            .clamp(Vec2::ZERO, Vec2::ONE);
+        t /= t.length();
+    }
+
+    half_size * t * point.signum()
+}
+
+/// The squared length of `point` after dividing it by `half_size` componentwise
+///
+/// Axes with a size of zero only allow points that lie exactly on them.
+fn normalized_distance_squared(point: Vec2, half_size: Vec2) -> f32 {
+    let axis = |value: f32, size: f32| {
+        if size > 0.0 {
+            (value / size) * (value / size)
+        } else if math::abs(value) <= f32::EPSILON {
+            0.0
+        } else {
+            f32::INFINITY
+        }
+    };
+
+    axis(point.x, half_size.x) + axis(point.y, half_size.y)
+}
+
+/// A capsule: every position within `radius` of the segment from `start` to `end`
+///
+/// Use this to tightly bound long, thin entities like swinging swords and trains of cars,
+/// which are poorly approximated by circles and axis-aligned boxes.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion, Capsule};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let sword = Capsule::<F32>::new(Position::new(0.0, 0.0), Position::new(4.0, 4.0), 0.5);
+///
+/// assert!(sword.contains(Position::new(2.0, 2.0)));
+/// // Inside the box around the sword, but nowhere near the blade
+/// assert!(!sword.contains(Position::new(4.0, 0.0)));
+/// assert_eq!(sword.clamp(Position::new(-3.0, 0.0)), Position::new(-0.5, 0.0));
+///
+/// let crate_box = AxisAlignedBoundingBox::new(3.0, 5.0, 0.0, 1.0);
+/// assert!(!sword.intersects_aabb(&crate_box));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Capsule<C: Coordinate> {
+    /// One end of the segment running down the middle of the capsule
+    pub start: Position<C>,
+    /// The other end of the segment running down the middle of the capsule
+    pub end: Position<C>,
+    /// The distance from the segment to the edge of the capsule
+    pub radius: C,
+}
+
+impl<C: Coordinate> Capsule<C> {
+    /// Creates a new [`Capsule`]
+    ///
+    /// # Panics
+    /// `radius` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new(start: Position<C>, end: Position<C>, radius: impl Into<C>) -> Self {
+        let radius = radius.into();
+        assert!(radius >= C::ZERO);
+
+        Self { start, end, radius }
+    }
+
+    /// The `start` and `end` of the segment running down the middle of the capsule, as [`Vec2`]s
+    fn segment(&self) -> (Vec2, Vec2) {
+        (self.start.into(), self.end.into())
+    }
+
+    /// Finds the position in this capsule that is closest to `position`
+    ///
+    /// Positions inside of the capsule are returned unchanged.
+    #[must_use]
+    pub fn closest_point(&self, position: Position<C>) -> Position<C> {
+        let (start, end) = self.segment();
+        let point = Vec2::from(position);
+        let on_segment = closest_point_on_segment(point, start, end);
+        let radius: f32 = self.radius.into();
+
+        if on_segment.distance_squared(point) <= radius * radius {
+            position
+        } else {
+            (on_segment + (point - on_segment).normalize() * radius).into()
+        }
+    }
+
+    /// Does this capsule overlap the `circle`?
+    #[must_use]
+    pub fn intersects_circle(&self, circle: &Circle<C>) -> bool {
+        let (start, end) = self.segment();
+        let center = Vec2::from(circle.center);
+        let reach: f32 = (self.radius + circle.radius).into();
+
+        closest_point_on_segment(center, start, end).distance_squared(center) <= reach * reach
+    }
+
+    /// Does this capsule overlap the `aabb`?
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> bool {
+        if aabb.contains(self.start) {
+            return true;
+        }
+
+        // Segments that enter the box must cross one of its edges
+        let corners: [Vec2; 4] = [
+            aabb.bottom_left(),
+            aabb.bottom_right(),
+            aabb.top_right(),
+            aabb.top_left(),
+        ]
+        .map(Vec2::from);
+        let radius: f32 = self.radius.into();
+
+        (0..4).any(|i| {
+            segment_segment_distance(self.segment(), (corners[i], corners[(i + 1) % 4])) <= radius
+        })
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for Capsule<C> {
+    type C = C;
+
+    /// Capsules have no corners, so no vertexes are returned
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    /// Draws a capsule around the `positions`, running along the axis that they are most spread out along
+    ///
+    /// The radius is the furthest that any position is from that axis,
+    /// and the segment is then made as short as possible while still containing every position.
+    /// If no positions are provided, a capsule with zero size at the origin is returned.
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let points: Vec<Vec2> = positions.into_iter().map(Vec2::from).collect();
+
+        if points.is_empty() {
+            return Capsule::new(Position::default(), Position::default(), C::ZERO);
+        }
+
+        let mean = points.iter().sum::<Vec2>() / points.len() as f32;
+        let (mut xx, mut xy, mut yy) = (0.0, 0.0, 0.0);
+        for &point in &points {
+            let offset = point - mean;
+            xx += offset.x * offset.x;
+            xy += offset.x * offset.y;
+            yy += offset.y * offset.y;
+        }
+
+        // The principal axis is this many radians counterclockwise from the x-axis
+        let angle = 0.5 * math::atan2(2. * xy, xx - yy);
+        let axis = Vec2::new(math::cos(angle), math::sin(angle));
+
+        // How far along and how far away from the axis each position is
+        let local: Vec<(f32, f32)> = points
+            .iter()
+            .map(|&point| {
+                let offset = point - mean;
+                (offset.dot(axis), axis.perp_dot(offset).abs())
+            })
+            .collect();
+        let radius = local
+            .iter()
+            .fold(0.0, |radius, &(_, away)| away.max(radius));
+
+        // Each position is covered as long as the segment reaches within `slack` of how far along the axis it is
+        let (mut low, mut high) = (f32::INFINITY, f32::NEG_INFINITY);
+        for &(along, away) in &local {
+            let slack = math::sqrt((radius * radius - away * away).max(0.0));
+            low = low.min(along + slack);
+            high = high.max(along - slack);
+        }
+        if low > high {
+            // Every position fits within the round end of the capsule
+            let middle = 0.5 * (low + high);
+            low = middle;
+            high = middle;
+        }
+
+        Capsule {
+            start: (mean + axis * low).into(),
+            end: (mean + axis * high).into(),
+            radius: C::from(radius * (1. + 1e-5)),
+        }
+    }
+
+    /// Positions on the edge of the capsule are contained.
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        let (start, end) = self.segment();
+        let point = Vec2::from(position);
+        let radius: f32 = self.radius.into();
+
+        closest_point_on_segment(point, start, end).distance_squared(point) <= radius * radius
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        let reach: f32 = (self.radius + other.radius).into();
+        if segment_segment_distance(self.segment(), other.segment()) <= reach {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        self.closest_point(position)
+    }
+
+    /// Samples `n` positions along the edge of this capsule, starting from the left of its `end` and sweeping clockwise
+    ///
+    /// The round ends are approximated by fine polygons, so the spacing is very nearly (but not exactly) even.
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        let (start, end) = self.segment();
+        let axis = (end - start).try_normalize().unwrap_or(Vec2::Y);
+        let radius: f32 = self.radius.into();
+        let segments = (4 * n).max(32);
+
+        // Half a circle around `center`, sweeping clockwise from the left of `facing` to its right
+        let cap = |center: Vec2, facing: Vec2| {
+            (0..=segments).map(move |i| {
+                let radians = core::f32::consts::PI * i as f32 / segments as f32;
+                center + radius * (facing.perp() * math::cos(radians) + facing * math::sin(radians))
+            })
+        };
+        let outline: Vec<Vec2> = cap(end, axis).chain(cap(start, -axis)).collect();
+
+        sample_outline(&outline, n)
+            .into_iter()
+            .map(Position::from)
+            .collect()
+    }
+
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        let (start, end) = self.segment();
+        let radius: f32 = self.radius.into();
+        let (min, max) = (start.min(end) - radius, start.max(end) + radius);
+
+        rejection_sample(
+            &AxisAlignedBoundingBox::new(min.x, max.x, min.y, max.y),
+            |position| self.contains(position),
+            |position| self.clamp(position),
+            rng,
+        )
+    }
+}
+
+/// A ring: every position between `inner_radius` and `outer_radius` of `center`
+///
+/// Use this for aggro bands, orbit ranges and donut-shaped areas of effect.
+/// For a ring that only covers some orientations, use an [`Arc`] instead.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{Annulus, BoundingRegion};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let orbit = Annulus::<F32>::new(Position::new(0.0, 0.0), 4.0, 6.0);
+///
+/// assert!(orbit.contains(Position::new(0.0, 5.0)));
+/// assert!(!orbit.contains(Position::new(1.0, 1.0)));
+///
+/// // Positions are pushed out of the hole, or pulled in from outside
+/// assert_eq!(orbit.clamp(Position::new(1.0, 0.0)), Position::new(4.0, 0.0));
+/// assert_eq!(orbit.clamp(Position::new(0.0, -10.0)), Position::new(0.0, -6.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Annulus<C: Coordinate> {
+    /// The center of the ring
+    pub center: Position<C>,
+    /// The distance from `center` to the inside edge of the ring
+    pub inner_radius: C,
+    /// The distance from `center` to the outside edge of the ring
+    pub outer_radius: C,
+}
+
+impl<C: Coordinate> Annulus<C> {
+    /// Creates a new [`Annulus`]
+    ///
+    /// # Panics
+    /// `inner_radius` must be greater than or equal to [`Coordinate::ZERO`],
+    /// and less than or equal to `outer_radius`.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        center: Position<C>,
+        inner_radius: impl Into<C>,
+        outer_radius: impl Into<C>,
+    ) -> Self {
+        let inner_radius = inner_radius.into();
+        let outer_radius = outer_radius.into();
+        assert!(inner_radius >= C::ZERO);
+        assert!(inner_radius <= outer_radius);
+
+        Self {
+            center,
+            inner_radius,
+            outer_radius,
+        }
+    }
+
+    /// The area covered by this ring
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        let (inner, outer): (f32, f32) = (self.inner_radius.into(), self.outer_radius.into());
+        core::f32::consts::PI * (outer * outer - inner * inner)
+    }
+
+    /// Maps a pair of numbers between 0 and 1 to a position in this ring
+    ///
+    /// `u` controls the distance from the `center`, while `v` controls the orientation (clockwise from north).
+    /// If `u` and `v` are uniformly distributed random numbers, the resulting positions are uniformly distributed over the area of the ring,
+    /// making this useful for choosing spawn locations.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::{Annulus, BoundingRegion};
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let spawn_ring = Annulus::<F32>::new(Position::new(0.0, 0.0), 4.0, 6.0);
+    ///
+    /// assert_eq!(spawn_ring.sample(0.0, 0.0), Position::new(0.0, 4.0));
+    /// assert!(spawn_ring.contains(spawn_ring.sample(0.3, 0.8)));
+    /// ```
+    #[must_use]
+    pub fn sample(&self, u: f32, v: f32) -> Position<C> {
+        let (inner, outer): (f32, f32) = (self.inner_radius.into(), self.outer_radius.into());
+
+        // Area grows with the square of the radius, so the radius must grow with the square root of `u`
+        let inner_squared = inner * inner;
+        let distance = math::sqrt(inner_squared + u * (outer * outer - inner_squared));
+        let radians = v * core::f32::consts::TAU;
+
+        (Vec2::from(self.center) + distance * Vec2::new(math::sin(radians), math::cos(radians)))
+            .into()
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for Annulus<C> {
+    type C = C;
+
+    /// Rings have no corners, so no vertexes are returned
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    /// Draws the ring centered on the smallest circle around the `positions`
+    ///
+    /// The outer edge is that circle, while the inner edge passes through the position closest to its center.
+    /// If no positions are provided, a ring with zero radius at the origin is returned.
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let positions: Vec<Position<C>> = positions.into_iter().collect();
+        let circle = Circle::draw_around(positions.iter().copied());
+        let center = Vec2::from(circle.center);
+
+        let outer_radius: f32 = circle.radius.into();
+        let inner_radius = positions
+            .iter()
+            .map(|&position| center.distance(position.into()))
+            .fold(outer_radius, f32::min);
+
+        Annulus {
+            center: circle.center,
+            inner_radius: C::from(inner_radius),
+            outer_radius: circle.radius,
+        }
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        within_cone(
+            self.center,
+            AngularCone::FULL_CIRCLE,
+            self.inner_radius.into(),
+            self.outer_radius.into(),
+            position,
+        )
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        let distance = Vec2::from(self.center).distance(other.center.into());
+        let (inner, outer): (f32, f32) = (self.inner_radius.into(), self.outer_radius.into());
+        let (other_inner, other_outer): (f32, f32) =
+            (other.inner_radius.into(), other.outer_radius.into());
+
+        // Every position in this ring is between `nearest` and `farthest` from the other center,
+        // and every distance in between is reached by some position
+        let nearest = if (inner..=outer).contains(&distance) {
+            0.0
+        } else {
+            math::abs(distance - inner).min(math::abs(distance - outer))
+        };
+        let farthest = distance + outer;
+
+        if nearest <= other_outer && farthest >= other_inner {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.contains(position) {
+            return position;
+        }
+
+        let center = Vec2::from(self.center);
+        let offset = Vec2::from(position) - center;
+        let (inner, outer): (f32, f32) = (self.inner_radius.into(), self.outer_radius.into());
+        // Positions at the very center are equally close to every part of the ring
+        let direction = offset.try_normalize().unwrap_or(Vec2::Y);
+
+        let distance = if offset.length_squared() < inner * inner {
+            inner
+        } else {
+            outer
+        };
+
+        (center + direction * distance).into()
+    }
+
+    /// Samples `n` positions evenly spaced along both edges of this ring
+    ///
+    /// The positions are shared between the outer and inner edges in proportion to their lengths.
+    /// The outer edge comes first, followed by the inner edge, each sampled clockwise from north.
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        let (inner, outer): (f32, f32) = (self.inner_radius.into(), self.outer_radius.into());
+        let center = Vec2::from(self.center);
+
+        let n_outer = if inner + outer > 0.0 {
+            math::round(n as f32 * outer / (inner + outer)) as usize
+        } else {
+            n
+        };
+
+        circle_points(center, outer, n_outer)
+            .chain(circle_points(center, inner, n - n_outer))
+            .map(Position::from)
+            .collect()
+    }
+
+    /// Uses [`Annulus::sample`], with uniformly distributed random numbers
+    ///
+    /// As [`Annulus`] has an inherent `sample` method, call this as `BoundingRegion::sample(&annulus, &mut rng)`.
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        Annulus::sample(self, rng.gen(), rng.gen())
+    }
+}
+
+/// Every position on one side of an infinite line: the line through `point` that is perpendicular to `normal`
+///
+/// The `normal` points out of the region, so positions behind it are contained.
+/// Use this for infinite walls and kill planes; intersecting several half-planes describes any convex region.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{BoundingRegion, HalfPlane};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Direction;
+/// use leafwing_2d::position::Position;
+///
+/// // Everything below the water line
+/// let sea = HalfPlane::<F32>::new(Position::new(0.0, -2.0), Direction::NORTH);
+///
+/// assert!(sea.contains(Position::new(100.0, -3.0)));
+/// assert!(!sea.contains(Position::new(0.0, 0.0)));
+/// assert_eq!(sea.signed_distance(Position::new(5.0, 1.0)), 3.0);
+/// assert_eq!(sea.clamp(Position::new(5.0, 1.0)), Position::new(5.0, -2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct HalfPlane<C: Coordinate> {
+    /// Any position on the boundary of the region
+    pub point: Position<C>,
+    /// The direction that points directly away from the region
+    pub normal: Direction,
+}
+
+impl<C: Coordinate> HalfPlane<C> {
+    /// Creates a new [`HalfPlane`]
+    #[inline]
+    #[must_use]
+    pub fn new(point: Position<C>, normal: impl Into<Direction>) -> Self {
+        Self {
+            point,
+            normal: normal.into(),
+        }
+    }
+
+    /// How far the `position` is from the boundary of this region
+    ///
+    /// Positions outside of the region have a positive distance, while positions inside have a negative distance.
+    #[inline]
+    #[must_use]
+    pub fn signed_distance(&self, position: Position<C>) -> f32 {
+        (Vec2::from(position) - Vec2::from(self.point)).dot(self.normal.unit_vector())
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for HalfPlane<C> {
+    type C = C;
+
+    /// Half-planes are infinite, so no vertexes are returned
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    /// Draws the region below the highest position, with a `normal` of [`Direction::NORTH`]
+    ///
+    /// If no positions are provided, the boundary passes through the origin.
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let highest = positions.into_iter().reduce(|highest, position| {
+            if position.y > highest.y {
+                position
+            } else {
+                highest
+            }
+        });
+
+        HalfPlane::new(highest.unwrap_or_default(), Direction::NORTH)
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        self.signed_distance(position) <= 0.0
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        // Only half-planes facing in exactly opposite directions can fail to overlap
+        let normal = self.normal.unit_vector();
+        if normal != -other.normal.unit_vector() {
+            return Intersects::Yes;
+        }
+
+        let boundary = Vec2::from(self.point).dot(normal);
+        let other_boundary = Vec2::from(other.point).dot(normal);
+        if other_boundary <= boundary {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        let distance = self.signed_distance(position);
+        if distance <= 0.0 {
+            return position;
+        }
+
+        (Vec2::from(position) - self.normal.unit_vector() * distance).into()
+    }
+}
+
+/// A pie slice of a circle: every position within `radius` of `center` whose orientation from `center` lies within `cone`
+///
+/// Use this for vision cones, radar sweeps and other regions with both a range and a field of view.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::Sector;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::{AngularCone, Rotation};
+/// use leafwing_2d::position::Position;
+///
+/// let radar = Sector::<F32>::new(
+///     Position::new(0.0, 0.0),
+///     10.0,
+///     AngularCone::new(Rotation::EAST, Rotation::from_degrees(30.0)),
+/// );
+///
+/// assert!(radar.contains(Position::new(5.0, 1.0)));
+/// // Out of range
+/// assert!(!radar.contains(Position::new(15.0, 0.0)));
+/// // Outside of the cone
+/// assert!(!radar.contains(Position::new(0.0, 5.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Sector<C: Coordinate> {
+    /// The tip of the slice
+    pub center: Position<C>,
+    /// How far the slice extends from its `center`
+    pub radius: C,
+    /// The range of orientations (measured from `center`) covered by this slice
+    pub cone: AngularCone,
+}
+
+impl<C: Coordinate> Sector<C> {
+    /// Creates a new [`Sector`]
+    ///
+    /// # Panics
+    /// `radius` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new(center: Position<C>, radius: impl Into<C>, cone: AngularCone) -> Self {
+        let radius = radius.into();
+        assert!(radius >= C::ZERO);
+
+        Self {
+            center,
+            radius,
+            cone,
+        }
+    }
+
+    /// Creates a new [`Sector`] that looks out from `center` towards `facing`,
+    /// seeing `half_angle` to either side and up to `radius` away
+    ///
+    /// This is the natural way to describe a vision cone.
+    ///
+    /// # Panics
+    /// `radius` must be greater than or equal to [`Coordinate::ZERO`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::Sector;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::orientation::{Direction, Rotation};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let guard = Position::<F32>::new(0.0, 0.0);
+    /// let vision = Sector::from_facing(guard, Direction::NORTH, Rotation::from_degrees(45.0), 8.0);
+    ///
+    /// // Right in front of the guard
+    /// assert!(vision.contains(Position::new(1.0, 5.0)));
+    /// // Sneaking up from behind
+    /// assert!(!vision.contains(Position::new(0.0, -1.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_facing(
+        center: Position<C>,
+        facing: impl Into<Rotation>,
+        half_angle: Rotation,
+        radius: impl Into<C>,
+    ) -> Self {
+        Self::new(center, radius, AngularCone::new(facing, half_angle))
+    }
+
+    /// The direction that this sector is centered on
+    #[inline]
+    #[must_use]
+    pub fn facing(&self) -> Direction {
+        self.cone.facing().into()
+    }
+
+    /// Does this sector contain the `position`?
+    ///
+    /// The `center` is always contained.
+    #[must_use]
+    pub fn contains(&self, position: Position<C>) -> bool {
+        within_cone(self.center, self.cone, 0.0, self.radius.into(), position)
+    }
+
+    /// Samples the outline of this sector, walking clockwise along its curved edge
+    ///
+    /// The `center` comes first, followed by `arc_segments + 1` evenly spaced positions along the curved edge.
+    /// If the sector covers the full circle, the `center` is omitted.
+    #[must_use]
+    pub fn vertexes(&self, arc_segments: usize) -> Vec<Position<C>> {
+        let center = Vec2::from(self.center);
+        let mut vertexes = Vec::with_capacity(arc_segments + 2);
+
+        if !self.cone.is_full_circle() {
+            vertexes.push(self.center);
+        }
+        vertexes.extend(
+            arc_points(center, self.radius.into(), self.cone, arc_segments).map(Position::from),
+        );
+
+        vertexes
+    }
+}
+
+/// A curved band: every position between `inner_radius` and `outer_radius` of `center`
+/// whose orientation from `center` lies within `cone`
+///
+/// Use this for melee swings and other regions that only affect targets at a certain range.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::Arc;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::{AngularCone, Rotation};
+/// use leafwing_2d::position::Position;
+///
+/// let sword_swing = Arc::<F32>::new(
+///     Position::new(0.0, 0.0),
+///     1.0,
+///     2.0,
+///     AngularCone::new(Rotation::NORTH, Rotation::from_degrees(60.0)),
+/// );
+///
+/// assert!(sword_swing.contains(Position::new(0.0, 1.5)));
+/// // Too close to hit with the blade
+/// assert!(!sword_swing.contains(Position::new(0.0, 0.5)));
+/// // Behind the swing
+/// assert!(!sword_swing.contains(Position::new(0.0, -1.5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Arc<C: Coordinate> {
+    /// The center of the circle that this band is curved around
+    pub center: Position<C>,
+    /// The distance from `center` to the inside edge of the band
+    pub inner_radius: C,
+    /// The distance from `center` to the outside edge of the band
+    pub outer_radius: C,
+    /// The range of orientations (measured from `center`) covered by this band
+    pub cone: AngularCone,
+}
+
+impl<C: Coordinate> Arc<C> {
+    /// Creates a new [`Arc`]
+    ///
+    /// # Panics
+    /// `inner_radius` must be greater than or equal to [`Coordinate::ZERO`],
+    /// and less than or equal to `outer_radius`.
+    #[inline]
+    #[must_use]
+    pub fn new(
+        center: Position<C>,
+        inner_radius: impl Into<C>,
+        outer_radius: impl Into<C>,
+        cone: AngularCone,
+    ) -> Self {
+        let inner_radius = inner_radius.into();
+        let outer_radius = outer_radius.into();
+        assert!(inner_radius >= C::ZERO);
+        assert!(inner_radius <= outer_radius);
+
+        Self {
+            center,
+            inner_radius,
+            outer_radius,
+            cone,
+        }
+    }
+
+    /// Does this arc contain the `position`?
+    #[must_use]
+    pub fn contains(&self, position: Position<C>) -> bool {
+        within_cone(
+            self.center,
+            self.cone,
+            self.inner_radius.into(),
+            self.outer_radius.into(),
+            position,
+        )
+    }
+
+    /// Samples the outline of this arc
+    ///
+    /// The outer edge is walked clockwise, then the inner edge is walked counterclockwise,
+    /// each with `arc_segments + 1` evenly spaced positions.
+    #[must_use]
+    pub fn vertexes(&self, arc_segments: usize) -> Vec<Position<C>> {
+        let center = Vec2::from(self.center);
+        let mut vertexes: Vec<Position<C>> =
+            arc_points(center, self.outer_radius.into(), self.cone, arc_segments)
+                .map(Position::from)
+                .collect();
+
+        let inner: Vec<Position<C>> =
+            arc_points(center, self.inner_radius.into(), self.cone, arc_segments)
+                .map(Position::from)
+                .collect();
+        vertexes.extend(inner.into_iter().rev());
+
+        vertexes
+    }
+}
+
+/// A type-erased [`BoundingRegion`], which can be stored in a [`CompositeRegion`]
+pub type BoxedRegion<C> = Box<dyn BoundingRegion<C = C> + Send + Sync>;
+
+/// How the children of a [`CompositeRegion`] are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositeMode {
+    /// Positions in any of the children are contained
+    #[default]
+    Union,
+    /// Only positions in all of the children are contained
+    Intersection,
+}
+
+/// A region made by combining any number of other regions, of any type
+///
+/// Use this to describe L-shaped levels, or play areas made up of several rooms.
+///
+/// An empty union contains nothing, while an empty intersection contains everything.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion, Circle, CompositeMode, CompositeRegion};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// // Two rooms joined by a corridor
+/// let level = CompositeRegion::<F32>::new(CompositeMode::Union)
+///     .with(AxisAlignedBoundingBox::new(0.0, 10.0, 0.0, 10.0))
+///     .with(AxisAlignedBoundingBox::new(10.0, 20.0, 4.0, 6.0))
+///     .with(Circle::new(Position::new(25.0, 5.0), 5.0));
+///
+/// assert!(level.contains(Position::new(15.0, 5.0)));
+/// assert!(!level.contains(Position::new(15.0, 8.0)));
+///
+/// // Players that wander out of bounds are returned to the closest room
+/// assert_eq!(level.clamp(Position::new(15.0, 8.0)), Position::new(15.0, 6.0));
+/// ```
+#[derive(Default)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct CompositeRegion<C: Coordinate> {
+    /// How the `children` are combined
+    pub mode: CompositeMode,
+    children: Vec<BoxedRegion<C>>,
+}
+
+impl<C: Coordinate> CompositeRegion<C> {
+    /// The number of rounds of projection used to find positions in multiple children at once
+    const PROJECTION_ITERATIONS: usize = 64;
+
+    /// How many positions are sampled along the edges of the children for each position on the combined edge
+    const PERIMETER_SAMPLES: usize = 64;
+
+    /// Creates a new [`CompositeRegion`] with no children
+    #[inline]
+    #[must_use]
+    pub fn new(mode: CompositeMode) -> Self {
+        Self {
+            mode,
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds the `region` as a child of this composite region
+    #[inline]
+    pub fn push(&mut self, region: impl BoundingRegion<C = C> + Send + Sync + 'static) {
+        self.children.push(Box::new(region));
+    }
+
+    /// Adds the `region` as a child of this composite region, returning it for chaining
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, region: impl BoundingRegion<C = C> + Send + Sync + 'static) -> Self {
+        self.push(region);
+        self
+    }
+
+    /// The regions that make up this composite region
+    #[inline]
+    #[must_use]
+    pub fn children(&self) -> &[BoxedRegion<C>] {
+        &self.children
+    }
+
+    /// The regions that must be checked against each other to test for intersection
+    ///
+    /// Children of a union can be checked individually, while intersections must be checked as a whole.
+    fn parts(&self) -> Vec<&dyn BoundingRegion<C = C>> {
+        match self.mode {
+            CompositeMode::Union => self
+                .children
+                .iter()
+                .map(|child| child.as_ref() as &dyn BoundingRegion<C = C>)
+                .collect(),
+            CompositeMode::Intersection => vec![self],
+        }
+    }
+}
+
+impl<C: Coordinate> core::fmt::Debug for CompositeRegion<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeRegion")
+            .field("mode", &self.mode)
+            .field("children", &self.children.len())
+            .finish()
+    }
+}
+
+impl<C: Coordinate> BoundingRegion for CompositeRegion<C> {
+    type C = C;
+
+    /// Collects the vertexes of every child
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        self.children
+            .iter()
+            .flat_map(|child| child.vertexes())
+            .collect()
+    }
+
+    /// Draws a union containing a single [`AxisAlignedBoundingBox`] around the `positions`
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        CompositeRegion::new(CompositeMode::Union)
+            .with(AxisAlignedBoundingBox::draw_around(positions))
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        match self.mode {
+            CompositeMode::Union => self.children.iter().any(|child| child.contains(position)),
+            CompositeMode::Intersection => {
+                self.children.iter().all(|child| child.contains(position))
+            }
+        }
+    }
+
+    /// Checks each child against the whole batch in turn, then combines the results
+    fn contains_batch(&self, positions: &[Position<Self::C>], results: &mut Vec<bool>) {
+        results.clear();
+        results.resize(positions.len(), self.mode == CompositeMode::Intersection);
+
+        let mut child_results = Vec::with_capacity(positions.len());
+        for child in &self.children {
+            child.contains_batch(positions, &mut child_results);
+
+            for (result, &child_result) in results.iter_mut().zip(&child_results) {
+                match self.mode {
+                    CompositeMode::Union => *result |= child_result,
+                    CompositeMode::Intersection => *result &= child_result,
+                }
+            }
+        }
+    }
+
+    /// Does this region overlap with the `other` composite region?
+    ///
+    /// Overlaps are found by alternately clamping a position to each region.
+    /// This is reliable when every child is convex (such as boxes, circles and half-planes),
+    /// but may miss overlaps between non-convex children, or regions that only touch.
+    fn intersects(&self, other: Self) -> Intersects {
+        let overlapping = self.parts().into_iter().any(|part| {
+            other
+                .parts()
+                .into_iter()
+                .any(|other_part| overlap(part, other_part))
+        });
+
+        if overlapping {
+            Intersects::Yes
+        } else {
+            Intersects::No
+        }
+    }
+
+    /// Clamps the `position` into this region
+    ///
+    /// Unions clamp to the closest child.
+    /// Intersections use Dykstra's projection algorithm,
+    /// which finds the closest position when every child is convex.
+    /// If an intersection of children is empty, the `position` may be clamped to a position outside of the region.
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.contains(position) {
+            return position;
+        }
+
+        match self.mode {
+            CompositeMode::Union => {
+                let target = Vec2::from(position);
+                self.children
+                    .iter()
+                    .map(|child| child.clamp(position))
+                    .min_by(|a, b| {
+                        let a = Vec2::from(*a).distance_squared(target);
+                        let b = Vec2::from(*b).distance_squared(target);
+                        a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal)
+                    })
+                    .unwrap_or(position)
+            }
+            CompositeMode::Intersection => {
+                let mut point = Vec2::from(position);
+                let mut corrections = vec![Vec2::ZERO; self.children.len()];
+
+                for _ in 0..Self::PROJECTION_ITERATIONS {
+                    let previous = point;
+                    for (child, correction) in self.children.iter().zip(corrections.iter_mut()) {
+                        let corrected = point + *correction;
+                        let projected = Vec2::from(child.clamp(corrected.into()));
+                        *correction = corrected - projected;
+                        point = projected;
+                    }
+
+                    if point == previous {
+                        break;
+                    }
+                }
+
+                point.into()
+            }
+        }
+    }
+
+    /// Samples `n` positions spread along the outer edge of this composite region
+    ///
+    /// The edge of each child is sampled densely, keeping only the samples that lie on the edge of the combined region.
+    /// The spacing is approximately even, but may be uneven where children meet.
+    /// Fewer than `n` positions are returned if too few samples are kept.
+    fn sample_perimeter(&self, n: usize) -> Vec<Position<Self::C>> {
+        // Sample every child at the same spacing, by estimating the length of their edges
+        let lengths: Vec<f32> = self
+            .children
+            .iter()
+            .map(|child| {
+                let outline: Vec<Vec2> = child
+                    .sample_perimeter(Self::PERIMETER_SAMPLES)
+                    .into_iter()
+                    .map(Vec2::from)
+                    .collect();
+                outline_length(&outline)
+            })
+            .collect();
+        let total_length: f32 = lengths.iter().sum();
+        if n == 0 || total_length == 0.0 {
+            return Vec::new();
+        }
+
+        let mut on_edge = Vec::new();
+        for (i, (child, length)) in self.children.iter().zip(lengths).enumerate() {
+            let count = math::round((n * Self::PERIMETER_SAMPLES) as f32 * length / total_length);
+            let others = self
+                .children
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, other)| other);
+
+            on_edge.extend(child.sample_perimeter(count as usize).into_iter().filter(
+                |&position| match self.mode {
+                    CompositeMode::Union => !others.clone().any(|other| other.contains(position)),
+                    CompositeMode::Intersection => {
+                        others.clone().all(|other| other.contains(position))
+                    }
+                },
+            ));
+        }
+
+        if on_edge.len() <= n {
+            return on_edge;
+        }
+        (0..n).map(|i| on_edge[i * on_edge.len() / n]).collect()
+    }
+
+    /// Intersections are sampled by picking positions from their first child until one lands in every other child.
+    /// Unions are sampled from the box around the [`vertexes`](BoundingRegion::vertexes) of their children,
+    /// so every child of a union must have vertexes.
+    ///
+    /// # Panics
+    /// Panics if this is an empty intersection (which contains everything),
+    /// or a union with a child without any vertexes.
+    #[cfg(feature = "rand")]
+    fn sample(&self, rng: &mut dyn RngCore) -> Position<Self::C> {
+        match self.mode {
+            CompositeMode::Intersection => {
+                let (first, others) = self
+                    .children
+                    .split_first()
+                    .expect("Empty intersections are infinite, so cannot be sampled.");
+
+                let mut position = first.sample(rng);
+                for _ in 1..SAMPLING_ATTEMPTS {
+                    if others.iter().all(|other| other.contains(position)) {
+                        return position;
+                    }
+                    position = first.sample(rng);
+                }
+                self.clamp(position)
+            }
+            CompositeMode::Union => {
+                assert!(
+                    self.children
+                        .iter()
+                        .all(|child| !child.vertexes().is_empty()),
+                    "Every child of a union must have vertexes for it to be sampled."
+                );
+
+                rejection_sample(
+                    &AxisAlignedBoundingBox::draw_around(self.vertexes()),
+                    |position| self.contains(position),
+                    |position| self.clamp(position),
+                    rng,
+                )
+            }
+        }
+    }
+}
+
+impl<C: Coordinate> CollisionInfo for Circle<C> {
+    fn collision_info(&self, other: &Circle<C>) -> Option<Contact<C>> {
+        Contact::between_points(
+            (self.center.into(), self.radius.into()),
+            (other.center.into(), other.radius.into()),
+            Direction::NORTH,
+        )
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<AxisAlignedBoundingBox<C>> for Circle<C> {
+    fn collision_info(&self, other: &AxisAlignedBoundingBox<C>) -> Option<Contact<C>> {
+        let center = Vec2::from(self.center);
+        let radius: f32 = self.radius.into();
+
+        if !other.contains(self.center) {
+            let closest = Vec2::from(other.clamp(self.center));
+            return Contact::between_points((center, radius), (closest, 0.), Direction::NORTH);
+        }
+
+        // The box must be pushed past the center of the circle, through whichever of its edges is closest
+        let (left, right, bottom, top): (f32, f32, f32, f32) = (
+            other.left.into(),
+            other.right.into(),
+            other.bottom.into(),
+            other.top.into(),
+        );
+        let (gap, normal, edge) = [
+            (center.x - left, Direction::EAST, Vec2::new(left, center.y)),
+            (
+                right - center.x,
+                Direction::WEST,
+                Vec2::new(right, center.y),
+            ),
+            (
+                center.y - bottom,
+                Direction::NORTH,
+                Vec2::new(center.x, bottom),
+            ),
+            (top - center.y, Direction::SOUTH, Vec2::new(center.x, top)),
+        ]
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+
+        let deepest = center + normal.unit_vector() * radius;
+        Some(Contact {
+            point: deepest.lerp(edge, 0.5).into(),
+            normal,
+            depth: gap + radius,
+        })
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<Capsule<C>> for Circle<C> {
+    fn collision_info(&self, other: &Capsule<C>) -> Option<Contact<C>> {
+        other.collision_info(self).map(Contact::reversed)
+    }
+}
+
+impl<C: Coordinate> CollisionInfo for AxisAlignedBoundingBox<C> {
+    fn collision_info(&self, other: &AxisAlignedBoundingBox<C>) -> Option<Contact<C>> {
+        let overlap = self.intersection(other)?;
+        let (overlap_min, overlap_max) = (
+            Vec2::from(overlap.bottom_left()),
+            Vec2::from(overlap.top_right()),
+        );
+        let size = overlap_max - overlap_min;
+        let offset = Vec2::from(other.bottom_left()) + Vec2::from(other.top_right())
+            - Vec2::from(self.bottom_left())
+            - Vec2::from(self.top_right());
+
+        // Separate the boxes along whichever axis they overlap the least
+        let (depth, normal) = if size.x <= size.y {
+            let normal = if offset.x >= 0. {
+                Direction::EAST
+            } else {
+                Direction::WEST
+            };
+            (size.x, normal)
+        } else {
+            let normal = if offset.y >= 0. {
+                Direction::NORTH
+            } else {
+                Direction::SOUTH
+            };
+            (size.y, normal)
+        };
+
+        Some(Contact {
+            point: overlap_min.lerp(overlap_max, 0.5).into(),
+            normal,
+            depth,
+        })
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<Circle<C>> for AxisAlignedBoundingBox<C> {
+    fn collision_info(&self, other: &Circle<C>) -> Option<Contact<C>> {
+        other.collision_info(self).map(Contact::reversed)
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<Capsule<C>> for AxisAlignedBoundingBox<C> {
+    fn collision_info(&self, other: &Capsule<C>) -> Option<Contact<C>> {
+        other.collision_info(self).map(Contact::reversed)
+    }
+}
+
+impl<C: Coordinate> CollisionInfo for Capsule<C> {
+    /// When the segments running down the middle of the capsules cross,
+    /// the `other` capsule is pushed out sideways, perpendicular to the segment of `self`.
+    fn collision_info(&self, other: &Capsule<C>) -> Option<Contact<C>> {
+        let (self_radius, other_radius): (f32, f32) = (self.radius.into(), other.radius.into());
+        let (on_self, on_other) = closest_points_between_segments(self.segment(), other.segment());
+
+        if on_self != on_other {
+            return Contact::between_points(
+                (on_self, self_radius),
+                (on_other, other_radius),
+                Direction::NORTH,
+            );
+        }
+
+        // Push the other capsule off of the line through this one, on the side that its middle is already on
+        let (start, end) = self.segment();
+        let (other_start, other_end) = other.segment();
+        let mut normal = Direction::try_from((end - start).perp()).unwrap_or(Direction::NORTH);
+        if normal
+            .unit_vector()
+            .dot(0.5 * (other_start + other_end) - start)
+            < 0.
+        {
+            normal = -normal;
+        }
+        let behind = [other_start, other_end]
+            .map(|point| normal.unit_vector().dot(point - on_self))
+            .into_iter()
+            .fold(0., f32::min);
+
+        Some(Contact {
+            point: on_self.into(),
+            normal,
+            depth: self_radius + other_radius - behind,
+        })
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<Circle<C>> for Capsule<C> {
+    fn collision_info(&self, other: &Circle<C>) -> Option<Contact<C>> {
+        let (start, end) = self.segment();
+        let center = Vec2::from(other.center);
+
+        Contact::between_points(
+            (
+                closest_point_on_segment(center, start, end),
+                self.radius.into(),
+            ),
+            (center, other.radius.into()),
+            Direction::NORTH,
+        )
+    }
+}
+
+impl<C: Coordinate> CollisionInfo<AxisAlignedBoundingBox<C>> for Capsule<C> {
+    /// When the segment running down the middle of the capsule enters the box,
+    /// the box is pushed out along whichever of its axes or the capsule's sideways axis needs the shortest push.
+    fn collision_info(&self, other: &AxisAlignedBoundingBox<C>) -> Option<Contact<C>> {
+        let radius: f32 = self.radius.into();
+        let segment = self.segment();
+        let corners: [Vec2; 4] = [
+            other.bottom_left(),
+            other.bottom_right(),
+            other.top_right(),
+            other.top_left(),
+        ]
+        .map(Vec2::from);
+
+        if !other.contains(self.start) {
+            let (on_self, on_other) = (0..4)
+                .map(|i| {
+                    closest_points_between_segments(segment, (corners[i], corners[(i + 1) % 4]))
+                })
+                .min_by(|a, b| {
+                    a.0.distance_squared(a.1)
+                        .total_cmp(&b.0.distance_squared(b.1))
+                })
+                .unwrap();
+
+            if on_self != on_other {
+                return Contact::between_points(
+                    (on_self, radius),
+                    (on_other, 0.),
+                    Direction::NORTH,
+                );
+            }
+        }
+
+        // The segment is inside of the box: find the axis with the least overlap
+        let (start, end) = segment;
+        let box_center = 0.5 * (corners[0] + corners[2]);
+        let sideways = (end - start).perp().try_normalize();
+        let (depth, normal) = [Some(Vec2::X), Some(Vec2::Y), sideways]
+            .into_iter()
+            .flatten()
+            .flat_map(|axis| [axis, -axis])
+            .map(|axis| {
+                // How far the box must move along this axis to clear the capsule
+                let reach = start.dot(axis).max(end.dot(axis)) + radius;
+                let trailing_edge = corners
+                    .iter()
+                    .map(|corner| corner.dot(axis))
+                    .fold(f32::INFINITY, f32::min);
+                (reach - trailing_edge, axis)
+            })
+            .filter(|&(_, axis)| axis.dot(box_center - start.lerp(end, 0.5)) >= 0.)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap();
+
+        let deepest = closest_point_on_segment(box_center, start, end);
+        Some(Contact {
+            point: deepest.into(),
+            normal: Direction::new(normal),
+            depth,
+        })
+    }
+}
+
+/// Do the regions `a` and `b` have any positions in common?
+///
+/// This repeatedly clamps a position into each region in turn,
+/// which converges to a shared position if one exists and both regions are convex.
+fn overlap<C: Coordinate>(a: &dyn BoundingRegion<C = C>, b: &dyn BoundingRegion<C = C>) -> bool {
+    let mut position = a.clamp(Position::default());
+
+    for _ in 0..CompositeRegion::<C>::PROJECTION_ITERATIONS {
+        if b.contains(position) {
+            return true;
+        }
+
+        let next = a.clamp(b.clamp(position));
+        if next == position {
+            return false;
+        }
+        position = next;
+    }
+
+    false
+}
+
+/// The range of times `t` for which `t * motion` lies between `low` and `high`
+///
+/// If it never does, the range is empty, and its start is after its end.
+fn slab_times(low: f32, high: f32, motion: f32) -> (f32, f32) {
+    if motion == 0. {
+        if low <= 0. && 0. <= high {
+            (f32::NEG_INFINITY, f32::INFINITY)
+        } else {
+            (f32::INFINITY, f32::NEG_INFINITY)
+        }
+    } else {
+        let (a, b) = (low / motion, high / motion);
+        (a.min(b), a.max(b))
+    }
+}
+
+/// The corners of the convex hull of the `points`, listed counterclockwise
+///
+/// This uses Andrew's monotone chain algorithm.
+fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(2 * points.len());
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let floor = hull.len();
+        for point in pass {
+            while hull.len() >= floor + 2
+                && (hull[hull.len() - 1] - hull[hull.len() - 2])
+                    .perp_dot(point - hull[hull.len() - 2])
+                    <= 0.
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point of each pass is the first point of the next
+        hull.pop();
+    }
+
+    hull
+}
+
+/// Draws random positions from the `bounds` until one is inside the region, as judged by `contains`
+///
+/// After [`SAMPLING_ATTEMPTS`], the last position is moved into the region with `clamp` instead.
+#[cfg(feature = "rand")]
+fn rejection_sample<C: Coordinate>(
+    bounds: &AxisAlignedBoundingBox<C>,
+    contains: impl Fn(Position<C>) -> bool,
+    clamp: impl Fn(Position<C>) -> Position<C>,
+    rng: &mut dyn RngCore,
+) -> Position<C> {
+    let mut position = bounds.sample(rng);
+    for _ in 1..SAMPLING_ATTEMPTS {
+        if contains(position) {
+            return position;
+        }
+        position = bounds.sample(rng);
+    }
+
+    clamp(position)
+}
+
+/// A uniformly distributed random point inside of the circle with a radius of 1 around the origin
+#[cfg(feature = "rand")]
+fn sample_unit_disk(rng: &mut dyn RngCore) -> Vec2 {
+    // Area grows with the square of the radius, so the radius must grow with the square root of a uniform number
+    let distance = math::sqrt(rng.gen());
+    let radians = core::f32::consts::TAU * rng.gen::<f32>();
+    distance * Vec2::new(math::sin(radians), math::cos(radians))
+}
+
+/// Is `position` between `min_distance` and `max_distance` of `center`, in a direction within `cone`?
+fn within_cone<C: Coordinate>(
+    center: Position<C>,
+    cone: AngularCone,
+    min_distance: f32,
+    max_distance: f32,
+    position: Position<C>,
+) -> bool {
+    let offset = Vec2::from(position) - Vec2::from(center);
+    let distance_squared = offset.length_squared();
+
+    if distance_squared < min_distance * min_distance
+        || distance_squared > max_distance * max_distance
+    {
+        return false;
+    }
+
+    match Rotation::from_vec2(offset) {
+        Ok(rotation) => cone.contains(rotation),
+        // Positions at the center are in every direction at once
+        Err(_) => true,
+    }
+}
+
+/// Evenly spaced points on the circle of `radius` around `center`, sweeping clockwise through the `cone`
+fn arc_points(
+    center: Vec2,
+    radius: f32,
+    cone: AngularCone,
+    segments: usize,
+) -> impl Iterator<Item = Vec2> {
+    let segments = segments.max(1);
+    let start = cone.start().into_radians();
+    let width = cone.width_radians();
+
+    (0..=segments).map(move |i| {
+        let radians = start + width * i as f32 / segments as f32;
+        // Rotations are measured clockwise from midnight
+        center + radius * Vec2::new(math::sin(radians), math::cos(radians))
+    })
+}
+
+/// `n` evenly spaced points on the circle of `radius` around `center`, sweeping clockwise from north
+fn circle_points(center: Vec2, radius: f32, n: usize) -> impl Iterator<Item = Vec2> {
+    (0..n).map(move |i| {
+        let radians = core::f32::consts::TAU * i as f32 / n as f32;
+        center + radius * Vec2::new(math::sin(radians), math::cos(radians))
+    })
+}
+
+/// The total length of the closed loop through the points of the `outline`
+fn outline_length(outline: &[Vec2]) -> f32 {
+    (0..outline.len())
+        .map(|i| outline[i].distance(outline[(i + 1) % outline.len()]))
+        .sum()
+}
+
+/// `n` evenly spaced points along the closed loop through the points of the `outline`, starting at its first point
+fn sample_outline(outline: &[Vec2], n: usize) -> Vec<Vec2> {
+    let first = match outline.first() {
+        Some(&first) => first,
+        None => return Vec::new(),
+    };
+
+    let spacing = outline_length(outline) / n as f32;
+    let mut samples = Vec::with_capacity(n);
+    let mut travelled = 0.0;
+
+    for i in 0..outline.len() {
+        let (start, end) = (outline[i], outline[(i + 1) % outline.len()]);
+        let length = start.distance(end);
+
+        while samples.len() < n && samples.len() as f32 * spacing < travelled + length {
+            let t = (samples.len() as f32 * spacing - travelled) / length;
+            samples.push(start.lerp(end, t));
+        }
+        travelled += length;
+    }
+
+    // Outlines with no length collapse onto their first point
+    samples.resize(n, first);
+    samples
 }
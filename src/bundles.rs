@@ -2,7 +2,10 @@
 
 use crate::bounding::AxisAlignedBoundingBox;
 use crate::coordinate::Coordinate;
-use crate::kinematics::{Acceleration, AngularAcceleration, AngularVelocity, Velocity};
+use crate::kinematics::{
+    Acceleration, AngularAcceleration, AngularVelocity, IntegratorState, KinematicState,
+    RotationRemainder, Velocity,
+};
 use crate::orientation::{Direction, Rotation};
 use crate::position::Position;
 use crate::scale::Scale;
@@ -38,6 +41,8 @@ pub struct TwoDBundle<C: Coordinate> {
     pub velocity: Velocity<C>,
     /// The rate at which velocity changes in `C` per second per second
     pub acceleration: Acceleration<C>,
+    /// What is carried over between frames when applying velocity and acceleration
+    pub integrator_state: IntegratorState<C>,
     /// Which way the entity is facing, stored as an angle from due north
     pub rotation: Rotation,
     /// Which way the entity is facing, stored as a unit vector
@@ -46,8 +51,25 @@ pub struct TwoDBundle<C: Coordinate> {
     pub angular_velocity: AngularVelocity,
     /// The rate at which angular velocity changes in deci-degrees per second per second
     pub angular_acceleration: AngularAcceleration,
+    /// The part of a turn that is too small to be stored in the rotation yet
+    pub rotation_remainder: RotationRemainder,
     /// The axis-aligned bounding box of this entity
     pub aabb: AxisAlignedBoundingBox<C>,
     /// The relative scale of this entity
     pub scale: Scale<C>,
 }
+
+impl<C: Coordinate> TwoDBundle<C> {
+    /// A [`KinematicState`] snapshot of this bundle's motion, which can be used to [`predict`](KinematicState::predict) where it will be
+    #[must_use]
+    pub fn kinematic_state(&self) -> KinematicState<C> {
+        KinematicState {
+            position: self.position,
+            velocity: self.velocity,
+            acceleration: self.acceleration,
+            rotation: self.rotation,
+            angular_velocity: self.angular_velocity,
+            angular_acceleration: self.angular_acceleration,
+        }
+    }
+}
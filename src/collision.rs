@@ -0,0 +1,1007 @@
+//! Find out which entities are touching
+//!
+//! The [`BroadPhase`] keeps track of which entities have overlapping bounding boxes.
+//! Only the entities whose bounds have changed are rechecked, so mostly-static scenes are very cheap to update.
+//! Choose a [`BroadphaseStrategy`] to control how those entities are compared against the rest.
+//!
+//! The [`CollisionPlugin`] then checks the exact shapes of each of those pairs, and sends a [`CollisionEvent`] for every pair that is touching.
+//! Shapes marked as a [`Sensor`] are triggers instead: they track which entities are inside of them in [`SensorOccupants`],
+//! and send [`RegionEntered`] and [`RegionExited`] events rather than collision events.
+//!
+//! Detected collisions can optionally be resolved by [`resolve_collisions`], which simply stops moving entities from sinking into each other.
+//! For player characters, the [`CharacterController`] instead moves the entity itself, sliding along anything that it runs into.
+
+use crate::bounding::{AxisAlignedBoundingBox, Capsule, Circle, CollisionInfo, Contact};
+use crate::coordinate::Coordinate;
+use crate::kinematics::{Acceleration, Gravity, GravityAffected, Velocity};
+use crate::math;
+use crate::orientation::Rotation;
+use crate::position::Position;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use bevy_app::prelude::*;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use glam::Vec2;
+
+/// How the [`BroadPhase`] finds the entities whose bounds might overlap those of an entity that has changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BroadphaseStrategy {
+    /// Compare the changed entity against every other entity
+    ///
+    /// This has no bookkeeping overhead, so it works best when there are few entities.
+    #[default]
+    BruteForce,
+    /// Keep the entities sorted by the left edge of their bounds, and only compare against those that overlap horizontally
+    ///
+    /// This works best when entities are spread out along the x-axis, such as in side-scrolling levels.
+    /// A few very wide entities make every check scan further, so levels with huge floors may be better off with [`BroadphaseStrategy::BruteForce`].
+    SweepAndPrune,
+}
+
+/// A persistent cache of every pair of entities whose bounding boxes overlap
+///
+/// Use this to quickly narrow down which entities could be colliding, before checking their exact shapes.
+/// Whenever an entity's bounds change, only that entity is compared against the others,
+/// rather than rebuilding every candidate pair from scratch.
+///
+/// Bounding boxes that only touch along an edge or corner count as overlapping.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::collision::BroadPhase;
+/// use leafwing_2d::continuous::F32;
+///
+/// let (player, wall, coin) = (Entity::from_raw(0), Entity::from_raw(1), Entity::from_raw(2));
+///
+/// let mut broad_phase = BroadPhase::<F32>::default();
+/// broad_phase.insert(player, AxisAlignedBoundingBox::new(0.0, 1.0, 0.0, 2.0));
+/// broad_phase.insert(wall, AxisAlignedBoundingBox::new(0.5, 10.0, -1.0, 0.0));
+/// broad_phase.insert(coin, AxisAlignedBoundingBox::new(5.0, 5.5, 5.0, 5.5));
+/// assert_eq!(broad_phase.pairs().collect::<Vec<_>>(), vec![(player, wall)]);
+///
+/// // The player jumps up to collect the coin
+/// broad_phase.insert(player, AxisAlignedBoundingBox::new(4.5, 5.5, 4.0, 6.0));
+/// assert_eq!(broad_phase.pairs().collect::<Vec<_>>(), vec![(player, coin)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadPhase<C: Coordinate> {
+    strategy: BroadphaseStrategy,
+    bounds: BTreeMap<Entity, AxisAlignedBoundingBox<C>>,
+    overlaps: BTreeMap<Entity, BTreeSet<Entity>>,
+    /// The left edge of each entity's bounds, sorted, when using [`BroadphaseStrategy::SweepAndPrune`]
+    sorted_lefts: Vec<(C, Entity)>,
+    /// The widest that any entity's bounds have been since the last [`clear`](BroadPhase::clear)
+    max_width: C,
+}
+
+impl<C: Coordinate> Default for BroadPhase<C> {
+    fn default() -> Self {
+        Self::new(BroadphaseStrategy::default())
+    }
+}
+
+impl<C: Coordinate> BroadPhase<C> {
+    /// Creates an empty [`BroadPhase`], which uses the provided `strategy` to find overlapping pairs
+    #[must_use]
+    pub fn new(strategy: BroadphaseStrategy) -> Self {
+        Self {
+            strategy,
+            bounds: BTreeMap::new(),
+            overlaps: BTreeMap::new(),
+            sorted_lefts: Vec::new(),
+            max_width: C::ZERO,
+        }
+    }
+
+    /// The strategy used to find overlapping pairs
+    #[inline]
+    #[must_use]
+    pub fn strategy(&self) -> BroadphaseStrategy {
+        self.strategy
+    }
+
+    /// Adds the `entity` with the provided `bounds`, or moves it if it was already present
+    ///
+    /// The entity is rechecked against the other entities chosen by the [`BroadphaseStrategy`], unless its bounds are unchanged.
+    pub fn insert(&mut self, entity: Entity, bounds: AxisAlignedBoundingBox<C>) {
+        if self.bounds.get(&entity) == Some(&bounds) {
+            return;
+        }
+
+        self.remove(entity);
+
+        let overlapping: BTreeSet<Entity> = match self.strategy {
+            BroadphaseStrategy::BruteForce => self
+                .bounds
+                .iter()
+                .filter(|(_, other_bounds)| overlaps(&bounds, other_bounds))
+                .map(|(&other, _)| other)
+                .collect(),
+            BroadphaseStrategy::SweepAndPrune => {
+                // Entities that start further left than this are too narrow to reach the new bounds
+                let reach = bounds.left - self.max_width;
+                let start = self.sorted_lefts.partition_point(|&(left, _)| left < reach);
+                let end = self
+                    .sorted_lefts
+                    .partition_point(|&(left, _)| left <= bounds.right);
+
+                self.sorted_lefts[start..end]
+                    .iter()
+                    .map(|&(_, other)| other)
+                    .filter(|other| overlaps(&bounds, &self.bounds[other]))
+                    .collect()
+            }
+        };
+
+        for &other in &overlapping {
+            self.overlaps.entry(other).or_default().insert(entity);
+        }
+        self.overlaps.insert(entity, overlapping);
+
+        if self.strategy == BroadphaseStrategy::SweepAndPrune {
+            let width = bounds.right - bounds.left;
+            if width > self.max_width {
+                self.max_width = width;
+            }
+
+            let index = self.sorted_index(bounds.left, entity);
+            self.sorted_lefts.insert(index, (bounds.left, entity));
+        }
+        self.bounds.insert(entity, bounds);
+    }
+
+    /// Where the `entity`, whose bounds start at `left`, belongs in the sorted list of left edges
+    fn sorted_index(&self, left: C, entity: Entity) -> usize {
+        self.sorted_lefts.partition_point(|&(other_left, other)| {
+            other_left < left || (other_left == left && other < entity)
+        })
+    }
+
+    /// Removes the `entity`, and every pair that it was part of
+    ///
+    /// Returns the bounds that it had, if it was present.
+    pub fn remove(&mut self, entity: Entity) -> Option<AxisAlignedBoundingBox<C>> {
+        for other in self.overlaps.remove(&entity).unwrap_or_default() {
+            if let Some(others) = self.overlaps.get_mut(&other) {
+                others.remove(&entity);
+            }
+        }
+
+        let bounds = self.bounds.remove(&entity)?;
+        if self.strategy == BroadphaseStrategy::SweepAndPrune {
+            let index = self.sorted_index(bounds.left, entity);
+            self.sorted_lefts.remove(index);
+        }
+
+        Some(bounds)
+    }
+
+    /// The bounds of the `entity`, if it is present
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.bounds.get(&entity)
+    }
+
+    /// Iterates over every pair of entities whose bounds overlap
+    ///
+    /// Each pair is returned once, with the lower [`Entity`] first, in sorted order.
+    pub fn pairs(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.overlaps.iter().flat_map(|(&entity, others)| {
+            others
+                .range(entity..)
+                .filter(move |&&other| other != entity)
+                .map(move |&other| (entity, other))
+        })
+    }
+
+    /// Iterates over every entity whose bounds overlap those of the `entity`
+    pub fn overlapping(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.overlaps
+            .get(&entity)
+            .into_iter()
+            .flat_map(|others| others.iter().copied())
+    }
+
+    /// Do the bounds of these two entities overlap?
+    #[inline]
+    #[must_use]
+    pub fn overlap(&self, a: Entity, b: Entity) -> bool {
+        self.overlaps
+            .get(&a)
+            .is_some_and(|others| others.contains(&b))
+    }
+
+    /// The number of entities being tracked
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Are no entities being tracked?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Removes every entity
+    pub fn clear(&mut self) {
+        self.bounds.clear();
+        self.overlaps.clear();
+        self.sorted_lefts.clear();
+        self.max_width = C::ZERO;
+    }
+}
+
+/// Do these two bounding boxes overlap, including along their edges?
+fn overlaps<C: Coordinate>(a: &AxisAlignedBoundingBox<C>, b: &AxisAlignedBoundingBox<C>) -> bool {
+    a.left <= b.right && b.left <= a.right && a.bottom <= b.top && b.bottom <= a.top
+}
+
+/// The smallest [`AxisAlignedBoundingBox`] that contains the `circle`
+fn circle_bounds<C: Coordinate>(circle: &Circle<C>) -> AxisAlignedBoundingBox<C> {
+    AxisAlignedBoundingBox {
+        left: circle.center.x - circle.radius,
+        right: circle.center.x + circle.radius,
+        bottom: circle.center.y - circle.radius,
+        top: circle.center.y + circle.radius,
+    }
+}
+
+/// The smallest [`AxisAlignedBoundingBox`] that contains the `capsule`
+fn capsule_bounds<C: Coordinate>(capsule: &Capsule<C>) -> AxisAlignedBoundingBox<C> {
+    let (start, end) = (capsule.start, capsule.end);
+    let min = |a: C, b: C| if a < b { a } else { b };
+    let max = |a: C, b: C| if a > b { a } else { b };
+
+    AxisAlignedBoundingBox {
+        left: min(start.x, end.x) - capsule.radius,
+        right: max(start.x, end.x) + capsule.radius,
+        bottom: min(start.y, end.y) - capsule.radius,
+        top: max(start.y, end.y) + capsule.radius,
+    }
+}
+
+/// Updates the [`BroadPhase`] resource from entities whose [`AxisAlignedBoundingBox`], [`Circle`] or [`Capsule`] components have changed
+///
+/// Entities that are despawned or lose these components are removed.
+/// Each entity should only have one of these components.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::collision::{update_broad_phase, BroadPhase};
+/// use leafwing_2d::continuous::F32;
+///
+/// App::new()
+///     .init_resource::<BroadPhase<F32>>()
+///     .add_system(update_broad_phase::<F32>);
+/// ```
+pub fn update_broad_phase<C: Coordinate>(
+    mut broad_phase: ResMut<BroadPhase<C>>,
+    boxes: Query<(Entity, &AxisAlignedBoundingBox<C>), Changed<AxisAlignedBoundingBox<C>>>,
+    circles: Query<(Entity, &Circle<C>), Changed<Circle<C>>>,
+    capsules: Query<(Entity, &Capsule<C>), Changed<Capsule<C>>>,
+    removed_boxes: RemovedComponents<AxisAlignedBoundingBox<C>>,
+    removed_circles: RemovedComponents<Circle<C>>,
+    removed_capsules: RemovedComponents<Capsule<C>>,
+) {
+    for entity in removed_boxes
+        .iter()
+        .chain(removed_circles.iter())
+        .chain(removed_capsules.iter())
+    {
+        broad_phase.remove(entity);
+    }
+
+    for (entity, bounds) in boxes.iter() {
+        broad_phase.insert(entity, bounds.clone());
+    }
+    for (entity, circle) in circles.iter() {
+        broad_phase.insert(entity, circle_bounds(circle));
+    }
+    for (entity, capsule) in capsules.iter() {
+        broad_phase.insert(entity, capsule_bounds(capsule));
+    }
+}
+
+/// Sent by [`detect_collisions`] for every pair of entities whose shapes are touching
+///
+/// These events are sent every frame for as long as the entities keep touching.
+/// Pairs that include a [`Sensor`] never send these events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionEvent<C: Coordinate> {
+    /// The first entity in the pair, which always has the lower [`Entity`] of the two
+    pub entity_a: Entity,
+    /// The second entity in the pair
+    pub entity_b: Entity,
+    /// How the shapes touch, with a [`Contact::normal`] that points away from `entity_a` towards `entity_b`
+    pub contact: Contact<C>,
+}
+
+/// The shape of an entity, as used for collision detection
+enum Shape<'a, C: Coordinate> {
+    Box(&'a AxisAlignedBoundingBox<C>),
+    Circle(&'a Circle<C>),
+    Capsule(&'a Capsule<C>),
+}
+
+impl<'a, C: Coordinate> Shape<'a, C> {
+    /// The shape stored in these components, preferring boxes, then circles, then capsules
+    fn new(
+        (aabb, circle, capsule): (
+            Option<&'a AxisAlignedBoundingBox<C>>,
+            Option<&'a Circle<C>>,
+            Option<&'a Capsule<C>>,
+        ),
+    ) -> Option<Self> {
+        aabb.map(Shape::Box)
+            .or_else(|| circle.map(Shape::Circle))
+            .or_else(|| capsule.map(Shape::Capsule))
+    }
+
+    /// How this shape touches `other`, if it does
+    fn contact(&self, other: &Shape<C>) -> Option<Contact<C>> {
+        match (self, other) {
+            (Shape::Box(a), Shape::Box(b)) => a.collision_info(*b),
+            (Shape::Box(a), Shape::Circle(b)) => a.collision_info(*b),
+            (Shape::Box(a), Shape::Capsule(b)) => a.collision_info(*b),
+            (Shape::Circle(a), Shape::Box(b)) => a.collision_info(*b),
+            (Shape::Circle(a), Shape::Circle(b)) => a.collision_info(*b),
+            (Shape::Circle(a), Shape::Capsule(b)) => a.collision_info(*b),
+            (Shape::Capsule(a), Shape::Box(b)) => a.collision_info(*b),
+            (Shape::Capsule(a), Shape::Circle(b)) => a.collision_info(*b),
+            (Shape::Capsule(a), Shape::Capsule(b)) => a.collision_info(*b),
+        }
+    }
+}
+
+/// An owned copy of the shape of an entity, which can be moved around without touching its components
+#[derive(Clone)]
+enum OwnedShape<C: Coordinate> {
+    Box(AxisAlignedBoundingBox<C>),
+    Circle(Circle<C>),
+    Capsule(Capsule<C>),
+}
+
+impl<C: Coordinate> OwnedShape<C> {
+    fn new(shape: Shape<C>) -> Self {
+        match shape {
+            Shape::Box(aabb) => OwnedShape::Box(aabb.clone()),
+            Shape::Circle(circle) => OwnedShape::Circle(*circle),
+            Shape::Capsule(capsule) => OwnedShape::Capsule(*capsule),
+        }
+    }
+
+    fn as_shape(&self) -> Shape<'_, C> {
+        match self {
+            OwnedShape::Box(aabb) => Shape::Box(aabb),
+            OwnedShape::Circle(circle) => Shape::Circle(circle),
+            OwnedShape::Capsule(capsule) => Shape::Capsule(capsule),
+        }
+    }
+
+    /// The same shape, moved by `offset`
+    fn translated(&self, offset: Position<C>) -> Self {
+        match self {
+            OwnedShape::Box(aabb) => OwnedShape::Box(aabb.translated_by(offset)),
+            OwnedShape::Circle(circle) => OwnedShape::Circle(Circle {
+                center: circle.center + offset,
+                ..*circle
+            }),
+            OwnedShape::Capsule(capsule) => OwnedShape::Capsule(Capsule {
+                start: capsule.start + offset,
+                end: capsule.end + offset,
+                ..*capsule
+            }),
+        }
+    }
+
+    /// The same shape, grown outwards by `amount` in every direction
+    fn expanded(&self, amount: C) -> Self {
+        match self {
+            OwnedShape::Box(aabb) => OwnedShape::Box(aabb.expanded_by(amount)),
+            OwnedShape::Circle(circle) => OwnedShape::Circle(Circle {
+                radius: circle.radius + amount,
+                ..*circle
+            }),
+            OwnedShape::Capsule(capsule) => OwnedShape::Capsule(Capsule {
+                radius: capsule.radius + amount,
+                ..*capsule
+            }),
+        }
+    }
+
+    /// Half of the width of the narrowest part of this shape
+    fn narrowest_half_extent(&self) -> f32 {
+        match self {
+            OwnedShape::Box(aabb) => {
+                let (width, height): (f32, f32) = (
+                    (aabb.right - aabb.left).into(),
+                    (aabb.top - aabb.bottom).into(),
+                );
+                width.min(height) / 2.
+            }
+            OwnedShape::Circle(circle) => circle.radius.into(),
+            OwnedShape::Capsule(capsule) => capsule.radius.into(),
+        }
+    }
+}
+
+/// Checks the exact shapes of every pair of entities in the [`BroadPhase`], sending a [`CollisionEvent`] for each pair that is touching
+///
+/// Pairs that include a [`Sensor`] are skipped: see [`detect_sensor_overlaps`] instead.
+pub fn detect_collisions<C: Coordinate>(
+    broad_phase: Res<BroadPhase<C>>,
+    shapes: Query<(
+        Option<&AxisAlignedBoundingBox<C>>,
+        Option<&Circle<C>>,
+        Option<&Capsule<C>>,
+    )>,
+    sensors: Query<(), With<Sensor>>,
+    mut collisions: EventWriter<CollisionEvent<C>>,
+) {
+    for (entity_a, entity_b) in broad_phase.pairs() {
+        if sensors.contains(entity_a) || sensors.contains(entity_b) {
+            continue;
+        }
+
+        let shape_a = shapes.get(entity_a).ok().and_then(Shape::new);
+        let shape_b = shapes.get(entity_b).ok().and_then(Shape::new);
+
+        if let (Some(shape_a), Some(shape_b)) = (shape_a, shape_b) {
+            if let Some(contact) = shape_a.contact(&shape_b) {
+                collisions.send(CollisionEvent {
+                    entity_a,
+                    entity_b,
+                    contact,
+                });
+            }
+        }
+    }
+}
+
+/// Marks an entity's [`AxisAlignedBoundingBox`], [`Circle`] or [`Capsule`] as a trigger region, such as a checkpoint or damage zone
+///
+/// Sensors don't collide: instead, [`detect_sensor_overlaps`] tracks which entities are inside of them in [`SensorOccupants`],
+/// and sends [`RegionEntered`] and [`RegionExited`] events as entities come and go.
+/// Sensors don't detect each other.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sensor;
+
+/// Sent when an entity's shape starts touching a [`Sensor`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionEntered {
+    /// The entity with the [`Sensor`] component
+    pub sensor: Entity,
+    /// The entity that entered the sensor
+    pub entity: Entity,
+}
+
+/// Sent when an entity's shape stops touching a [`Sensor`]
+///
+/// Entities that are despawned or lose their shape while inside of a sensor exit it,
+/// as do all of the occupants of sensors that are despawned or lose their [`Sensor`] component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionExited {
+    /// The entity with the [`Sensor`] component
+    pub sensor: Entity,
+    /// The entity that exited the sensor
+    pub entity: Entity,
+}
+
+/// The entities that are currently inside of each [`Sensor`]
+///
+/// This is kept up to date by [`detect_sensor_overlaps`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SensorOccupants {
+    occupants: BTreeMap<Entity, BTreeSet<Entity>>,
+}
+
+impl SensorOccupants {
+    /// Iterates over every entity inside of the `sensor`, in sorted order
+    pub fn occupants(&self, sensor: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.occupants
+            .get(&sensor)
+            .into_iter()
+            .flat_map(|occupants| occupants.iter().copied())
+    }
+
+    /// Is the `entity` inside of the `sensor`?
+    #[inline]
+    #[must_use]
+    pub fn is_inside(&self, sensor: Entity, entity: Entity) -> bool {
+        self.occupants
+            .get(&sensor)
+            .is_some_and(|occupants| occupants.contains(&entity))
+    }
+
+    /// Iterates over every `(sensor, entity)` pair where the entity is inside of the sensor, in sorted order
+    pub fn pairs(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.occupants
+            .iter()
+            .flat_map(|(&sensor, occupants)| occupants.iter().map(move |&entity| (sensor, entity)))
+    }
+}
+
+/// Checks the exact shapes of every pair in the [`BroadPhase`] that includes one [`Sensor`],
+/// updating [`SensorOccupants`] and sending [`RegionEntered`] and [`RegionExited`] events for entities that have come or gone
+pub fn detect_sensor_overlaps<C: Coordinate>(
+    broad_phase: Res<BroadPhase<C>>,
+    shapes: Query<(
+        Option<&AxisAlignedBoundingBox<C>>,
+        Option<&Circle<C>>,
+        Option<&Capsule<C>>,
+    )>,
+    sensors: Query<(), With<Sensor>>,
+    mut sensor_occupants: ResMut<SensorOccupants>,
+    mut entered: EventWriter<RegionEntered>,
+    mut exited: EventWriter<RegionExited>,
+) {
+    let mut occupants: BTreeMap<Entity, BTreeSet<Entity>> = BTreeMap::new();
+    for (entity_a, entity_b) in broad_phase.pairs() {
+        let (sensor, entity) = match (sensors.contains(entity_a), sensors.contains(entity_b)) {
+            (true, false) => (entity_a, entity_b),
+            (false, true) => (entity_b, entity_a),
+            _ => continue,
+        };
+
+        let sensor_shape = shapes.get(sensor).ok().and_then(Shape::new);
+        let entity_shape = shapes.get(entity).ok().and_then(Shape::new);
+        if let (Some(sensor_shape), Some(entity_shape)) = (sensor_shape, entity_shape) {
+            if sensor_shape.contact(&entity_shape).is_some() {
+                occupants.entry(sensor).or_default().insert(entity);
+            }
+        }
+    }
+
+    let new_occupants = SensorOccupants { occupants };
+    for (sensor, entity) in sensor_occupants.pairs() {
+        if !new_occupants.is_inside(sensor, entity) {
+            exited.send(RegionExited { sensor, entity });
+        }
+    }
+    for (sensor, entity) in new_occupants.pairs() {
+        if !sensor_occupants.is_inside(sensor, entity) {
+            entered.send(RegionEntered { sensor, entity });
+        }
+    }
+
+    // Avoid triggering change detection pointlessly
+    if *sensor_occupants != new_occupants {
+        *sensor_occupants = new_occupants;
+    }
+}
+
+/// Pushes apart the entities in each [`CollisionEvent`], and stops them from moving any further into each other
+///
+/// Entities with a [`Velocity`] are kinematic, and are moved out along the [`Contact::normal`] by the [`Contact::depth`]:
+/// when both entities are kinematic, each moves half of the way.
+/// Entities without a [`Velocity`] are treated as immovable walls.
+/// Both the [`Position`] and the shape of each moved entity are updated.
+///
+/// Any part of a kinematic entity's [`Velocity`] that points into the other entity is removed,
+/// so it slides along surfaces rather than bouncing off of them.
+///
+/// This is a minimal resolver for arcade games: it doesn't model mass, friction or bounciness,
+/// and entities squeezed between several others may still overlap slightly until the next frame.
+pub fn resolve_collisions<C: Coordinate>(
+    mut collisions: EventReader<CollisionEvent<C>>,
+    mut bodies: Query<(
+        Option<&mut Position<C>>,
+        Option<&mut Velocity<C>>,
+        Option<&mut AxisAlignedBoundingBox<C>>,
+        Option<&mut Circle<C>>,
+        Option<&mut Capsule<C>>,
+    )>,
+) {
+    for collision in collisions.iter() {
+        let normal = Vec2::from(collision.contact.normal);
+        let kinematic = |entity| {
+            bodies
+                .get(entity)
+                .is_ok_and(|(_, velocity, ..)| velocity.is_some())
+        };
+
+        // The share of the overlap that each entity is responsible for
+        let (share_a, share_b) =
+            match (kinematic(collision.entity_a), kinematic(collision.entity_b)) {
+                (true, true) => (0.5, 0.5),
+                (true, false) => (1., 0.),
+                (false, true) => (0., 1.),
+                (false, false) => continue,
+            };
+
+        // The normal points away from entity_a, so it must move backwards along it
+        for (entity, share, outwards) in [
+            (collision.entity_a, share_a, -normal),
+            (collision.entity_b, share_b, normal),
+        ] {
+            if share == 0. {
+                continue;
+            }
+
+            let (position, velocity, aabb, circle, capsule) = match bodies.get_mut(entity) {
+                Ok(body) => body,
+                Err(_) => continue,
+            };
+
+            let offset = Position::from(outwards * collision.contact.depth * share);
+            if offset != Position::default() {
+                if let Some(mut position) = position {
+                    *position += offset;
+                }
+                if let Some(mut aabb) = aabb {
+                    *aabb = aabb.translated_by(offset);
+                }
+                if let Some(mut circle) = circle {
+                    circle.center += offset;
+                }
+                if let Some(mut capsule) = capsule {
+                    capsule.start += offset;
+                    capsule.end += offset;
+                }
+            }
+
+            if let Some(mut velocity) = velocity {
+                let current = Vec2::new(velocity.x.into(), velocity.y.into());
+                let into_surface = current.dot(outwards);
+                if into_surface < 0. {
+                    let resolved = current - outwards * into_surface;
+                    *velocity = Velocity {
+                        x: resolved.x.into(),
+                        y: resolved.y.into(),
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Moves an entity by its [`Velocity`], sliding along the shapes that it runs into rather than passing through them
+///
+/// Characters need a [`Position`], a [`Velocity`] and an [`AxisAlignedBoundingBox`], [`Circle`] or [`Capsule`] in world space,
+/// and are moved by [`move_and_slide`]. They are blocked by every other shape that isn't a [`Sensor`], including other characters.
+/// After each move, the controller records which kinds of surface the character is touching,
+/// so you can check [`on_floor`](CharacterController::on_floor) before letting it jump.
+///
+/// Characters should not have an [`Acceleration`](crate::kinematics::Acceleration) component,
+/// or [`linear_kinematics`](crate::kinematics::systems::linear_kinematics) will move them a second time.
+/// Change their [`Velocity`] directly, or make them [`GravityAffected`], instead.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CharacterController<C: Coordinate> {
+    /// The most times that the character can be pushed out of the shapes that it overlaps, in each step of its movement
+    ///
+    /// Default: 4
+    pub max_slides: usize,
+    /// The most steps that the character's movement can be split into each frame
+    ///
+    /// Default: 1000
+    pub max_steps: usize,
+    /// The steepest slope, measured from flat ground, that counts as a floor rather than a wall
+    ///
+    /// Default: 45 degrees
+    pub max_floor_angle: Rotation,
+    /// How close the character must be to a surface to count as touching it
+    ///
+    /// Default: 0.01
+    pub skin_width: C,
+    /// Was the character standing on a floor at the end of its last move?
+    pub on_floor: bool,
+    /// Was the character touching a wall at the end of its last move?
+    pub on_wall: bool,
+    /// Was the character touching a ceiling at the end of its last move?
+    pub on_ceiling: bool,
+}
+
+impl<C: Coordinate> Default for CharacterController<C> {
+    fn default() -> Self {
+        Self {
+            max_slides: 4,
+            max_steps: 1000,
+            max_floor_angle: Rotation::from_degrees(45.0),
+            skin_width: C::from(0.01),
+            on_floor: false,
+            on_wall: false,
+            on_ceiling: false,
+        }
+    }
+}
+
+/// Removes the part of `vector` that points along `normal`, if it points into the surface
+fn slide(vector: Vec2, normal: Vec2) -> Vec2 {
+    let into_surface = vector.dot(normal);
+    if into_surface > 0. {
+        vector - normal * into_surface
+    } else {
+        vector
+    }
+}
+
+/// Moves each entity with a [`CharacterController`] by its [`Velocity`] according to elapsed [`Time`], sliding along whatever it runs into
+///
+/// The movement is split into steps no longer than half of the narrowest part of the character, so that it can't skip through thin walls.
+/// No more than [`max_steps`](CharacterController::max_steps) steps are taken each frame,
+/// so very thin or tiny characters that move quickly take longer steps instead.
+/// After each step, the character is pushed out of the shapes that it overlaps, deepest first,
+/// and any part of its [`Velocity`] and remaining movement that points into those shapes is removed.
+/// Both the [`Position`] and the shape of each character are updated.
+/// Characters that are [`GravityAffected`] have [`Gravity`] applied to their [`Velocity`] before they move.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::collision::{move_and_slide, CollisionPlugin, CollisionSystem};
+/// use leafwing_2d::plugin::TwoDSystem;
+/// use leafwing_2d::prelude::*;
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(CollisionPlugin::<F32>::default())
+///     .add_system_to_stage(
+///         CoreStage::PostUpdate,
+///         move_and_slide::<F32>
+///             .after(TwoDSystem::Kinematics)
+///             .before(CollisionSystem::BroadPhase),
+///     );
+/// ```
+pub fn move_and_slide<C: Coordinate>(
+    time: Res<Time>,
+    gravity: Option<Res<Gravity<C>>>,
+    mut shapes: ParamSet<(
+        Query<
+            (
+                Entity,
+                Option<&AxisAlignedBoundingBox<C>>,
+                Option<&Circle<C>>,
+                Option<&Capsule<C>>,
+            ),
+            Without<Sensor>,
+        >,
+        Query<(
+            Entity,
+            &mut CharacterController<C>,
+            &mut Position<C>,
+            &mut Velocity<C>,
+            Option<&mut AxisAlignedBoundingBox<C>>,
+            Option<&mut Circle<C>>,
+            Option<&mut Capsule<C>>,
+            Option<&GravityAffected>,
+        )>,
+    )>,
+) {
+    let delta_time = time.delta();
+    let gravity = gravity.map_or(Acceleration::default(), |gravity| gravity.0);
+    let obstacles: Vec<(Entity, OwnedShape<C>)> = shapes
+        .p0()
+        .iter()
+        .filter_map(|(entity, aabb, circle, capsule)| {
+            Shape::new((aabb, circle, capsule)).map(|shape| (entity, OwnedShape::new(shape)))
+        })
+        .collect();
+
+    let mut characters = shapes.p1();
+    for (
+        entity,
+        mut controller,
+        mut position,
+        mut velocity,
+        aabb,
+        circle,
+        capsule,
+        gravity_affected,
+    ) in characters.iter_mut()
+    {
+        if gravity_affected.is_some() {
+            *velocity += gravity * delta_time;
+        }
+        let shape = match Shape::new((aabb.as_deref(), circle.as_deref(), capsule.as_deref())) {
+            Some(shape) => OwnedShape::new(shape),
+            None => continue,
+        };
+        let others = || obstacles.iter().filter(move |(other, _)| *other != entity);
+
+        let mut current_velocity = Vec2::new(velocity.x.into(), velocity.y.into());
+        let motion = Vec2::from(*velocity * delta_time);
+        let max_step = shape.narrowest_half_extent().max(f32::EPSILON);
+        // Rounded up, so that no step is longer than `max_step`, unless that would take too many steps
+        let steps = (-math::floor(-motion.length() / max_step))
+            .clamp(1., controller.max_steps.max(1) as f32);
+        let mut step = motion / steps;
+        let mut offset = Vec2::ZERO;
+
+        for _ in 0..steps as usize {
+            offset += step;
+            for _ in 0..controller.max_slides {
+                let moved = shape.translated(offset.into());
+                let deepest = others()
+                    .filter_map(|(_, obstacle)| moved.as_shape().contact(&obstacle.as_shape()))
+                    .filter(|contact| contact.depth > 0.)
+                    .max_by(|a, b| a.depth.total_cmp(&b.depth));
+
+                let contact = match deepest {
+                    Some(contact) => contact,
+                    None => break,
+                };
+                // The normal points away from the character, into the obstacle
+                let normal = Vec2::from(contact.normal);
+                offset -= normal * contact.depth;
+                step = slide(step, normal);
+                current_velocity = slide(current_velocity, normal);
+            }
+        }
+
+        // Check which surfaces are within reach of the character's skin
+        let floor_threshold = math::cos(controller.max_floor_angle.into_radians());
+        let (mut on_floor, mut on_wall, mut on_ceiling) = (false, false, false);
+        let reach = shape
+            .translated(offset.into())
+            .expanded(controller.skin_width);
+        for (_, obstacle) in others() {
+            if let Some(contact) = reach.as_shape().contact(&obstacle.as_shape()) {
+                let upwards = -Vec2::from(contact.normal).y;
+                if upwards >= floor_threshold {
+                    on_floor = true;
+                } else if -upwards >= floor_threshold {
+                    on_ceiling = true;
+                } else {
+                    on_wall = true;
+                }
+            }
+        }
+
+        if offset != Vec2::ZERO {
+            let offset = Position::from(offset);
+            *position += offset;
+            if let Some(mut aabb) = aabb {
+                *aabb = aabb.translated_by(offset);
+            }
+            if let Some(mut circle) = circle {
+                circle.center += offset;
+            }
+            if let Some(mut capsule) = capsule {
+                capsule.start += offset;
+                capsule.end += offset;
+            }
+        }
+
+        let new_velocity = Velocity {
+            x: current_velocity.x.into(),
+            y: current_velocity.y.into(),
+        };
+        // Avoid triggering change detection pointlessly
+        if *velocity != new_velocity {
+            *velocity = new_velocity;
+        }
+        if (
+            controller.on_floor,
+            controller.on_wall,
+            controller.on_ceiling,
+        ) != (on_floor, on_wall, on_ceiling)
+        {
+            controller.on_floor = on_floor;
+            controller.on_wall = on_wall;
+            controller.on_ceiling = on_ceiling;
+        }
+    }
+}
+
+/// [`SystemLabel`] for [`CollisionPlugin`]
+#[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollisionSystem {
+    /// Finds the pairs of entities whose bounding boxes overlap
+    ///
+    /// Contains [`update_broad_phase::<C>`].
+    BroadPhase,
+    /// Checks the exact shapes of those pairs, and sends [`CollisionEvent`]s
+    ///
+    /// Contains [`detect_collisions::<C>`].
+    NarrowPhase,
+    /// Checks which entities are inside of each [`Sensor`], and sends [`RegionEntered`] and [`RegionExited`] events
+    ///
+    /// Contains [`detect_sensor_overlaps::<C>`].
+    Sensors,
+    /// Pushes colliding entities apart, if [`CollisionPlugin::resolve`] is enabled
+    ///
+    /// Contains [`resolve_collisions::<C>`].
+    Resolution,
+}
+
+/// Adds the [`BroadPhase`] and [`SensorOccupants`] resources, the [`CollisionEvent`], [`RegionEntered`] and [`RegionExited`] events,
+/// and the systems that detect collisions
+///
+/// Entities collide using their [`AxisAlignedBoundingBox`], [`Circle`] or [`Capsule`] component, which should be kept in world space.
+/// Add a [`Sensor`] component to make a shape into a trigger region instead.
+/// The systems run in [`CoreStage::PostUpdate`], and are labelled with [`CollisionSystem`].
+/// Set the [`strategy`](CollisionPlugin::strategy) to choose how the [`BroadPhase`] finds overlapping pairs.
+///
+/// By default, this only detects overlaps, leaving it up to you to resolve them.
+/// Enable [`resolve`](CollisionPlugin::resolve) to have [`resolve_collisions`] keep entities with a [`Velocity`] from sinking into walls.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::Circle;
+/// use leafwing_2d::collision::{CollisionEvent, CollisionPlugin};
+/// use leafwing_2d::prelude::*;
+///
+/// fn spawn_balls(mut commands: Commands) {
+///     commands.spawn().insert(Circle::<F32>::new(Position::new(0.0, 0.0), 1.0));
+///     commands.spawn().insert(Circle::<F32>::new(Position::new(1.5, 0.0), 1.0));
+/// }
+///
+/// fn report_collisions(mut events: EventReader<CollisionEvent<F32>>) {
+///     for event in events.iter() {
+///         println!("{:?} hit {:?}, overlapping by {}", event.entity_a, event.entity_b, event.contact.depth);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(CollisionPlugin::<F32>::default())
+///     .add_startup_system(spawn_balls)
+///     .add_system(report_collisions);
+/// ```
+#[derive(Debug)]
+pub struct CollisionPlugin<C: Coordinate> {
+    /// How should the [`BroadPhase`] find overlapping pairs?
+    ///
+    /// Default: [`BroadphaseStrategy::BruteForce`]
+    pub strategy: BroadphaseStrategy,
+    /// Should [`resolve_collisions`] push colliding entities apart?
+    ///
+    /// Default: [`false`](bool)
+    pub resolve: bool,
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for CollisionPlugin<C> {
+    fn default() -> Self {
+        Self {
+            strategy: BroadphaseStrategy::default(),
+            resolve: false,
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for CollisionPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(BroadPhase::<C>::new(self.strategy))
+            .init_resource::<SensorOccupants>()
+            .add_event::<CollisionEvent<C>>()
+            .add_event::<RegionEntered>()
+            .add_event::<RegionExited>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_broad_phase::<C>.label(CollisionSystem::BroadPhase),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                detect_collisions::<C>
+                    .label(CollisionSystem::NarrowPhase)
+                    .after(CollisionSystem::BroadPhase),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                detect_sensor_overlaps::<C>
+                    .label(CollisionSystem::Sensors)
+                    .after(CollisionSystem::BroadPhase),
+            );
+
+        if self.resolve {
+            app.add_system_to_stage(
+                CoreStage::PostUpdate,
+                resolve_collisions::<C>
+                    .label(CollisionSystem::Resolution)
+                    .after(CollisionSystem::NarrowPhase),
+            );
+        }
+    }
+}
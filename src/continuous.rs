@@ -2,6 +2,10 @@
 
 use crate as leafwing_2d;
 use crate::coordinate::{Coordinate, TrivialCoordinate};
+use crate::errors::NonFiniteCoordinate;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 /// A [`f32`]-backed [`Coordinate`]
 #[derive(TrivialCoordinate)]
@@ -18,3 +22,186 @@ impl Coordinate for F32 {
     const DATA_ZERO: f32 = 0.;
     const DATA_ONE: f32 = 1.;
 }
+
+/// A [`f64`]-backed [`Coordinate`]
+///
+/// Use this for very large worlds, where the precision of [`F32`] breaks down far from the origin.
+/// Convert [`Positions`](crate::position::Position) of this type to and from [`DVec2`](glam::DVec2) to work with them losslessly:
+/// conversions to [`f32`] (and so [`Transform`](bevy_transform::components::Transform)) will lose precision.
+///
+/// # Example
+/// ```rust
+/// use glam::DVec2;
+/// use leafwing_2d::continuous::F64;
+/// use leafwing_2d::position::Position;
+///
+/// let far_away = Position::<F64>::new(1e12, 0.5);
+/// let dvec2: DVec2 = far_away.into();
+///
+/// assert_eq!(dvec2, DVec2::new(1e12, 0.5));
+/// assert_eq!(Position::<F64>::from(dvec2), far_away);
+/// ```
+#[derive(TrivialCoordinate)]
+pub struct F64(pub f64);
+
+impl Coordinate for F64 {
+    type Data = f64;
+
+    const COORD_TO_TRANSFORM: f32 = 1.0;
+    const MIN: Self = F64(f64::MIN);
+    const MAX: Self = F64(f64::MAX);
+    const ZERO: Self = F64(0.0);
+
+    const DATA_ZERO: f64 = 0.;
+    const DATA_ONE: f64 = 1.;
+}
+
+impl From<f32> for F64 {
+    fn from(float: f32) -> F64 {
+        F64(float.into())
+    }
+}
+
+impl From<F64> for f32 {
+    fn from(coordinate: F64) -> f32 {
+        coordinate.0 as f32
+    }
+}
+
+/// A [`f32`]-backed [`Coordinate`] that is guaranteed to be finite: never NaN or infinite
+///
+/// NaN is replaced by `0.0`, and infinities (including arithmetic that overflows) saturate at [`f32::MAX`] or [`f32::MIN`].
+/// This applies to every conversion, including those from [`Transform`](bevy_transform::components::Transform),
+/// so a single bad value can never spread through your positions and into your rendering.
+/// Use [`FiniteF32::new`] instead to find out when a value is rejected.
+///
+/// As every value is comparable (and `-0.0` is stored as `0.0`), this implements [`Eq`], [`Ord`] and [`Hash`],
+/// so coordinates can be sorted, and both coordinates and [`Positions`](crate::position::Position) can be used as keys.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::FiniteF32;
+/// use leafwing_2d::coordinate::Coordinate;
+/// use leafwing_2d::position::Position;
+///
+/// assert!(FiniteF32::new(f32::NAN).is_err());
+/// assert_eq!(FiniteF32::clamped(f32::NAN), FiniteF32::ZERO);
+/// assert_eq!(FiniteF32::clamped(f32::INFINITY), FiniteF32::MAX);
+///
+/// // Dividing by zero cannot poison the position
+/// let position = Position::<FiniteF32>::new(3.0, 0.0) / FiniteF32::ZERO;
+/// assert_eq!(position, Position::new(FiniteF32::MAX, FiniteF32::ZERO));
+///
+/// assert!(FiniteF32::new(1.0).unwrap() < FiniteF32::new(2.0).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FiniteF32(f32);
+
+impl FiniteF32 {
+    /// Creates a new [`FiniteF32`], or returns an error if `value` is NaN or infinite
+    #[inline]
+    pub fn new(value: f32) -> Result<FiniteF32, NonFiniteCoordinate> {
+        if value.is_finite() {
+            Ok(FiniteF32::clamped(value))
+        } else {
+            Err(NonFiniteCoordinate)
+        }
+    }
+
+    /// Creates a new [`FiniteF32`], replacing NaN with `0.0` and clamping infinities to [`f32::MIN`] and [`f32::MAX`]
+    #[inline]
+    #[must_use]
+    pub fn clamped(value: f32) -> FiniteF32 {
+        if value.is_nan() || value == 0. {
+            // Also maps -0.0 to 0.0, so equal values always have equal hashes
+            FiniteF32(0.)
+        } else {
+            FiniteF32(value.clamp(f32::MIN, f32::MAX))
+        }
+    }
+
+    /// The underlying value, which is always finite
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Coordinate for FiniteF32 {
+    type Data = f32;
+
+    const COORD_TO_TRANSFORM: f32 = 1.0;
+    const MIN: Self = FiniteF32(f32::MIN);
+    const MAX: Self = FiniteF32(f32::MAX);
+    const ZERO: Self = FiniteF32(0.0);
+
+    const DATA_ZERO: f32 = 0.;
+    const DATA_ONE: f32 = 1.;
+}
+
+impl From<f32> for FiniteF32 {
+    /// Replaces NaN with `0.0`, and clamps infinities, as in [`FiniteF32::clamped`]
+    fn from(float: f32) -> FiniteF32 {
+        FiniteF32::clamped(float)
+    }
+}
+
+impl From<FiniteF32> for f32 {
+    fn from(coordinate: FiniteF32) -> f32 {
+        coordinate.0
+    }
+}
+
+impl Eq for FiniteF32 {}
+
+impl PartialOrd for FiniteF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FiniteF32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for FiniteF32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+/// Implements an arithmetic operator, and its assigning variant, for [`FiniteF32`], clamping the result
+macro_rules! impl_finite_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl $op for FiniteF32 {
+            type Output = FiniteF32;
+
+            fn $method(self, rhs: FiniteF32) -> FiniteF32 {
+                FiniteF32::clamped($op::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $op<f32> for FiniteF32 {
+            type Output = FiniteF32;
+
+            fn $method(self, rhs: f32) -> FiniteF32 {
+                FiniteF32::clamped($op::$method(self.0, rhs))
+            }
+        }
+
+        impl $assign_op for FiniteF32 {
+            fn $assign_method(&mut self, rhs: FiniteF32) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_finite_op!(Add, add, AddAssign, add_assign);
+impl_finite_op!(Sub, sub, SubAssign, sub_assign);
+impl_finite_op!(Mul, mul, MulAssign, mul_assign);
+impl_finite_op!(Div, div, DivAssign, div_assign);
+impl_finite_op!(Rem, rem, RemAssign, rem_assign);
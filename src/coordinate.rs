@@ -1,8 +1,9 @@
 //! Traits to definite units that define distances
 
 use crate::position::Position;
-pub use leafwing_2d_macros::TrivialCoordinate;
-use std::{fmt::Debug, ops::*};
+use core::{fmt::Debug, ops::*};
+use glam::Vec2;
+pub use leafwing_2d_macros::{Coordinate, TrivialCoordinate};
 
 /// A type that can be used as a coordinate type for [`Position`]
 ///
@@ -12,7 +13,8 @@ use std::{fmt::Debug, ops::*};
 ///   - the [`DiscreteCoordinate`](crate::discrete::DiscreteCoordinate) trait provides other useful functionality for your game!
 /// - if you have unusual needs (such as extremely large worlds or tight memory constraints),
 /// implement [`Coordinate`] and optionally [`DiscreteCoordinate`](discrete_coordinates::DiscreteCoordinate) for your own type
-///    - if you are simply wrapping a single value, strongly consider deriving [`TrivialCoordinate`] to reduce the required boilerplate
+///    - if you are simply wrapping a single number, derive [`Coordinate`](macro@Coordinate) to generate the entire implementation
+///    - if you need custom conversions to and from [`f32`], derive [`TrivialCoordinate`] instead to reduce the required boilerplate
 ///    - in this type, you can wrap your own [`f64`], [`u8`], fixed-point number type or so on.
 ///
 /// # Conversion
@@ -25,6 +27,36 @@ use std::{fmt::Debug, ops::*};
 /// To account for this, write custom [`From<f32>`] and [`Into<f32>`] impls for your coordinate type.
 /// Remember to multiply by [`Coordinate::COORD_TO_TRANSFORM`] when converting into [`f32`],
 /// and divide by it when converting back!
+///
+/// # Deriving
+///
+/// Tuple structs that wrap a single primitive integer or float can derive this trait,
+/// configured by an optional `#[coordinate(...)]` attribute:
+/// - `data`: the wrapped type, which must match the type of the field
+/// - `scale`: the [`COORD_TO_TRANSFORM`](Coordinate::COORD_TO_TRANSFORM) ratio, which defaults to `1.0`
+/// - `min` and `max`: the [`MIN`](Coordinate::MIN) and [`MAX`](Coordinate::MAX) values, which default to those of the wrapped type
+///
+/// This generates all of the [`TrivialCoordinate`] boilerplate, along with scaled conversions to and from [`f32`].
+/// Conversions from [`f32`] are rounded to the nearest value for integers, and clamped to the range `min..=max`.
+/// Integer coordinates also implement [`Eq`], [`Ord`] and [`Hash`], so they can be stored in sets and used as map keys.
+///
+/// ```rust
+/// use leafwing_2d::coordinate::Coordinate;
+/// use leafwing_2d::position::Position;
+///
+/// // Each tile is 32 pixels wide
+/// #[derive(Coordinate)]
+/// #[coordinate(data = i32, scale = 32.0, min = -1000, max = 1000)]
+/// struct Tile(i32);
+///
+/// assert_eq!(Tile::COORD_TO_TRANSFORM, 32.0);
+/// assert_eq!(f32::from(Tile(2)), 64.0);
+/// assert_eq!(Tile::from(70.0), Tile(2));
+/// assert_eq!(Tile::from(1e9), Tile::MAX);
+///
+/// let position = Position::<Tile>::new(1, -1);
+/// assert_eq!(position + position, Position::new(2, -2));
+/// ```
 pub trait Coordinate:
     Copy
     + Debug
@@ -56,6 +88,7 @@ pub trait Coordinate:
         + Sub<Output = <Self as Coordinate>::Data>
         + Mul<Output = <Self as Coordinate>::Data>
         + Div<Output = <Self as Coordinate>::Data>
+        + PartialEq
         + Send
         + Sync
         + Debug
@@ -90,6 +123,30 @@ pub trait Coordinate:
         y: Self::ZERO,
     };
 
+    /// Converts a [`Position`] in this coordinate system into world space
+    ///
+    /// This is used for all conversions from a [`Position`] into [`Vec2`], [`Transform`](bevy_transform::components::Transform) and so on.
+    /// By default, each axis is converted independently, using [`Into<f32>`].
+    /// Override this (and [`vec2_to_position`](Coordinate::vec2_to_position)) for grids whose axes are skewed in world space,
+    /// such as [`Isometric`](crate::discrete::Isometric).
+    #[inline]
+    #[must_use]
+    fn position_to_vec2(position: Position<Self>) -> Vec2 {
+        Vec2::new(position.x.into(), position.y.into())
+    }
+
+    /// Converts a point in world space into a [`Position`] in this coordinate system
+    ///
+    /// This should be the inverse of [`position_to_vec2`](Coordinate::position_to_vec2).
+    #[inline]
+    #[must_use]
+    fn vec2_to_position(vec2: Vec2) -> Position<Self> {
+        Position {
+            x: Self::from(vec2.x),
+            y: Self::from(vec2.y),
+        }
+    }
+
     /// Checks that [`Coordinate::MIN`] and [`Coordinate::MAX`] can be converted to and from `f32` in a reasonable fashion
     ///
     /// This should not panic, and the min value should be less than the max in both forms.
@@ -191,3 +248,17 @@ pub trait TrivialCoordinate: Sized {
         *self = Self::new(value);
     }
 }
+
+/// Implementation details of the derive macros, which are not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+    pub use glam::Vec2;
+
+    /// Rounds to the nearest integer, with or without the standard library
+    #[inline]
+    #[must_use]
+    pub fn round(x: f32) -> f32 {
+        crate::math::round(x)
+    }
+}
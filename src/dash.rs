@@ -0,0 +1,241 @@
+//! Short bursts of speed in a fixed direction, followed by a cooldown
+//!
+//! Add a [`Dash`] to entities with a [`Velocity`], and call [`Dash::start`] to dash.
+//! The [`DashPlugin`] overrides their velocity for the duration of the dash,
+//! restores it afterwards, and sends [`DashStarted`] and [`DashEnded`] events.
+
+use crate::coordinate::Coordinate;
+use crate::kinematics::{Kinematic, Velocity};
+use crate::orientation::Direction;
+use crate::plugin::TwoDSystem;
+use bevy_app::prelude::*;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use core::time::Duration;
+
+/// A dash ability, which briefly replaces an entity's [`Velocity`]
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::dash::Dash;
+///
+/// // Dashes in whichever direction the entity is facing
+/// let mut dash = Dash::<F32>::new(None, 20.0, Duration::from_millis(150), Duration::from_secs(1));
+/// assert!(dash.is_ready());
+///
+/// assert!(dash.start());
+/// // Dashing again has to wait until the dash has ended and cooled down
+/// assert!(!dash.start());
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Dash<C: Coordinate> {
+    /// Which way to dash
+    ///
+    /// If `None`, the entity dashes in the way its [`Direction`] faces when the dash starts,
+    /// or [`Direction::NORTH`] if it doesn't have one.
+    pub direction: Option<Direction>,
+    /// How fast to dash, in `C` per second
+    pub speed: C,
+    /// How long each dash lasts
+    pub duration: Duration,
+    /// How long to wait after a dash has ended before dashing again
+    pub cooldown: Duration,
+    state: DashState<C>,
+}
+
+/// What a [`Dash`] is currently doing
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DashState<C: Coordinate> {
+    Ready,
+    Starting,
+    Dashing {
+        remaining: Duration,
+        direction: Direction,
+        /// The velocity to restore once the dash ends
+        velocity: Velocity<C>,
+    },
+    CoolingDown {
+        remaining: Duration,
+    },
+}
+
+impl<C: Coordinate> Dash<C> {
+    /// Creates a new [`Dash`], which is ready to be used
+    #[inline]
+    #[must_use]
+    pub fn new(
+        direction: Option<Direction>,
+        speed: impl Into<C>,
+        duration: Duration,
+        cooldown: Duration,
+    ) -> Self {
+        Self {
+            direction,
+            speed: speed.into(),
+            duration,
+            cooldown,
+            state: DashState::Ready,
+        }
+    }
+
+    /// Starts dashing the next time that [`dashes`] runs
+    ///
+    /// Returns `false`, and does nothing, if the entity is already dashing or the dash is cooling down.
+    pub fn start(&mut self) -> bool {
+        let ready = self.is_ready();
+        if ready {
+            self.state = DashState::Starting;
+        }
+        ready
+    }
+
+    /// Can [`Dash::start`] be used right now?
+    #[inline]
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.state == DashState::Ready
+    }
+
+    /// Is the entity currently dashing, or about to start?
+    #[inline]
+    #[must_use]
+    pub fn is_dashing(&self) -> bool {
+        matches!(self.state, DashState::Starting | DashState::Dashing { .. })
+    }
+
+    /// How much longer until the dash can be used again, once it has ended
+    ///
+    /// Returns [`Duration::ZERO`] while dashing or when ready.
+    #[inline]
+    #[must_use]
+    pub fn cooldown_remaining(&self) -> Duration {
+        match self.state {
+            DashState::CoolingDown { remaining } => remaining,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Sent when an entity starts to [`Dash`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DashStarted {
+    /// The entity that is dashing
+    pub entity: Entity,
+    /// The direction that it is dashing in
+    pub direction: Direction,
+}
+
+/// Sent when an entity stops dashing, and its normal [`Velocity`] has been restored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DashEnded {
+    /// The entity that was dashing
+    pub entity: Entity,
+}
+
+/// Starts, continues and ends every [`Dash`] according to elapsed [`Time`]
+///
+/// While dashing, the entity's [`Velocity`] is overwritten each frame.
+/// The velocity that it had when the dash started is restored when the dash ends.
+pub fn dashes<C: Coordinate>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Dash<C>, &mut Velocity<C>)>,
+    facing: Query<&Direction>,
+    mut started: EventWriter<DashStarted>,
+    mut ended: EventWriter<DashEnded>,
+) {
+    let delta_time = time.delta();
+    for (entity, mut dash, mut velocity) in query.iter_mut() {
+        match dash.state {
+            DashState::Ready => (),
+            DashState::Starting => {
+                let direction = dash
+                    .direction
+                    .or_else(|| facing.get(entity).ok().copied())
+                    .unwrap_or_default();
+
+                dash.state = DashState::Dashing {
+                    remaining: dash.duration,
+                    direction,
+                    velocity: *velocity,
+                };
+                *velocity = Velocity::new(dash.speed, direction);
+                started.send(DashStarted { entity, direction });
+            }
+            DashState::Dashing {
+                remaining,
+                direction,
+                velocity: normal_velocity,
+            } => {
+                let remaining = remaining.saturating_sub(delta_time);
+                if remaining.is_zero() {
+                    dash.state = DashState::CoolingDown {
+                        remaining: dash.cooldown,
+                    };
+                    *velocity = normal_velocity;
+                    ended.send(DashEnded { entity });
+                } else {
+                    dash.state = DashState::Dashing {
+                        remaining,
+                        direction,
+                        velocity: normal_velocity,
+                    };
+                    let dash_velocity = Velocity::new(dash.speed, direction);
+                    // Undo any changes made by other systems
+                    if *velocity != dash_velocity {
+                        *velocity = dash_velocity;
+                    }
+                }
+            }
+            DashState::CoolingDown { remaining } => {
+                let remaining = remaining.saturating_sub(delta_time);
+                dash.state = if remaining.is_zero() {
+                    DashState::Ready
+                } else {
+                    DashState::CoolingDown { remaining }
+                };
+            }
+        }
+    }
+}
+
+/// Adds the [`DashStarted`] and [`DashEnded`] events, and the [`dashes`] system
+///
+/// The system runs in [`CoreStage::PostUpdate`], before [`TwoDSystem::Kinematics`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::dash::DashPlugin;
+/// use leafwing_2d::prelude::*;
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(DashPlugin::<F32>::default());
+/// ```
+#[derive(Debug)]
+pub struct DashPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for DashPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for DashPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DashStarted>()
+            .add_event::<DashEnded>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                dashes::<C>.before(TwoDSystem::Kinematics),
+            );
+    }
+}
@@ -1,19 +1,63 @@
 //! Coordinate types for [`Position`] designed for operation on discrete grids
 //!
-//! The provided types all store an `isize` under the hood for maximum flexbility.
-//! If you require a different storage type,
-//! please feel free to copy-paste the relevant struct def and trait impls into your game
+//! Most of the provided types store an `isize` under the hood for maximum flexbility.
+//! If you require a smaller storage type for a square grid, use [`I8`], [`I16`] or [`I32`],
+//! whose entire range can be losslessly converted to and from [`f32`].
+//! For other needs, please feel free to copy-paste the relevant struct def and trait impls into your game
 //! and modify `isize` to your desired integer type.
 use crate::coordinate::{Coordinate, TrivialCoordinate};
+use crate::errors::CoordinateOutOfBounds;
+use crate::math;
 use crate::orientation::Direction;
-use crate::partitioning::{CardinalQuadrant, DirectionParitioning};
+use crate::partitioning::{CardinalQuadrant, DirectionParitioning, OffsetQuadrant};
 use crate::position::Position;
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "bevy")]
+use bevy_ecs::component::Component;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use derive_more::{Add, AddAssign, Sub, SubAssign};
+use glam::Vec2;
+pub use leafwing_2d_macros::DiscreteCoordinate;
 
 use crate as leafwing_2d;
 
 /// A type that can be used to represent a discrete 2-dimensional coordinate
 ///
 /// Typically used to create and work with [`Positions`](Position)
+///
+/// # Deriving
+///
+/// Integer [`Coordinate`] types can derive this trait,
+/// configured by a `#[discrete(grid = "...")]` attribute that selects the shape of the grid:
+/// - `"orthogonal"`: the 4 neighbors of an [`OrthogonalGrid`]
+/// - `"adjacent"`: the 8 neighbors of an [`AdjacentGrid`]
+/// - `"hex_flat"`: the 6 neighbors of a [`FlatHex`] grid
+/// - `"hex_pointy"`: the 6 neighbors of a [`PointyHex`] grid
+///
+/// [`next`](DiscreteCoordinate::next) and [`prev`](DiscreteCoordinate::prev) saturate at
+/// [`Coordinate::MIN`] and [`Coordinate::MAX`], and neighbors outside of that range are skipped.
+///
+/// ```rust
+/// use leafwing_2d::coordinate::Coordinate;
+/// use leafwing_2d::discrete::DiscreteCoordinate;
+/// use leafwing_2d::position::Position;
+///
+/// #[derive(Coordinate, DiscreteCoordinate)]
+/// #[coordinate(min = 0, max = 9)]
+/// #[discrete(grid = "adjacent")]
+/// struct Small(i16);
+///
+/// assert_eq!(Small::N_NEIGHBORS, 8);
+/// assert_eq!(Small(9).next(), Small(9));
+///
+/// // Only 3 of the neighbors of a corner lie within the grid
+/// let corner = Position::<Small>::new(0, 0);
+/// assert_eq!(Small::neighbors(corner).len(), 3);
+/// ```
 pub trait DiscreteCoordinate: Coordinate {
     /// The number of neighbors
     const N_NEIGHBORS: usize;
@@ -59,6 +103,50 @@ pub trait DiscreteCoordinate: Coordinate {
             .collect()
     }
 
+    /// Every [`Position`] that can be reached from `center` in at most `n` steps between neighbors
+    ///
+    /// This follows the shape of the grid: a diamond for an [`OrthogonalGrid`], a square for an [`AdjacentGrid`]
+    /// and a disc for hexagonal grids.
+    /// Positions are returned in order of the number of steps needed to reach them, starting with the `center`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::discrete::{AdjacentGrid, DiscreteCoordinate, OrthogonalGrid};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let center = Position::new(OrthogonalGrid(0), OrthogonalGrid(0));
+    /// assert_eq!(OrthogonalGrid::within_range(center, 2).count(), 13);
+    ///
+    /// let center = Position::new(AdjacentGrid(0), AdjacentGrid(0));
+    /// assert_eq!(AdjacentGrid::within_range(center, 2).count(), 25);
+    /// ```
+    #[must_use]
+    fn within_range(center: Position<Self>, n: usize) -> vec::IntoIter<Position<Self>> {
+        let mut reached = vec![center];
+        // Neighbors of one ring can only lie in the previous ring, the same ring or the next one
+        let mut previous_ring = 0..0;
+        let mut ring = 0..1;
+
+        for _ in 0..n {
+            let next_start = reached.len();
+            for i in ring.clone() {
+                for neighbor in Self::neighbors(reached[i]) {
+                    if !reached[previous_ring.start..].contains(&neighbor) {
+                        reached.push(neighbor);
+                    }
+                }
+            }
+
+            previous_ring = ring;
+            ring = next_start..reached.len();
+            if ring.is_empty() {
+                break;
+            }
+        }
+
+        reached.into_iter()
+    }
+
     /// Asserts that the values near the end of this range can be losslessly converted to and from [`f32`]
     ///
     /// If this assertion fails, your values are too tightly packed.
@@ -79,6 +167,53 @@ pub trait DiscreteCoordinate: Coordinate {
     }
 }
 
+/// Exact distances between [`Positions`](Position) on a discrete grid, counted in whole cells
+///
+/// Unlike converting to [`f32`], these never lose precision, which makes them ideal for pathfinding heuristics and range checks.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::{AdjacentGrid, GridMetric, OrthogonalGrid};
+/// use leafwing_2d::position::Position;
+///
+/// let a = Position::new(OrthogonalGrid(0), OrthogonalGrid(0));
+/// let b = Position::new(OrthogonalGrid(3), OrthogonalGrid(-4));
+/// assert_eq!(OrthogonalGrid::manhattan_distance(a, b), 7);
+/// assert_eq!(OrthogonalGrid::chebyshev_distance(a, b), 4);
+/// assert_eq!(OrthogonalGrid::grid_distance(a, b), 7);
+///
+/// // Diagonal moves are allowed on an `AdjacentGrid`
+/// let a = Position::new(AdjacentGrid(0), AdjacentGrid(0));
+/// let b = Position::new(AdjacentGrid(3), AdjacentGrid(-4));
+/// assert_eq!(AdjacentGrid::grid_distance(a, b), 4);
+/// ```
+pub trait GridMetric: DiscreteCoordinate {
+    /// The number of cells between `self` and `other` along a single axis
+    #[must_use]
+    fn abs_diff(self, other: Self) -> usize;
+
+    /// The number of steps between `a` and `b`, moving only between neighbors
+    ///
+    /// This is the length of the shortest path between them on an empty grid,
+    /// and so always an admissible pathfinding heuristic.
+    /// Depending on the grid, this is the [`manhattan_distance`](GridMetric::manhattan_distance),
+    /// the [`chebyshev_distance`](GridMetric::chebyshev_distance) or a hex distance.
+    #[must_use]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize;
+
+    /// The sum of the distances between `a` and `b` along each axis
+    #[must_use]
+    fn manhattan_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+    }
+
+    /// The larger of the distances between `a` and `b` along each axis
+    #[must_use]
+    fn chebyshev_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        a.x.abs_diff(b.x).max(a.y.abs_diff(b.y))
+    }
+}
+
 /// [`DiscreteCoordinate`] primitive for a square grid, where each cell has four neighbors
 ///
 /// Neighboring tiles must touch on their faces
@@ -93,7 +228,7 @@ impl From<OrthogonalGrid> for f32 {
 
 impl From<f32> for OrthogonalGrid {
     fn from(float: f32) -> OrthogonalGrid {
-        OrthogonalGrid(float.round() as isize)
+        OrthogonalGrid(math::round(float) as isize)
     }
 }
 
@@ -149,6 +284,18 @@ impl DiscreteCoordinate for OrthogonalGrid {
     }
 }
 
+impl GridMetric for OrthogonalGrid {
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.0.abs_diff(other.0)
+    }
+
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        Self::manhattan_distance(a, b)
+    }
+}
+
 /// [`DiscreteCoordinate`] primitive for a square grid, where each cell has eight neighbors
 ///
 /// Neighboring tiles are a king's move away: either touching faces or diagonally adjacent
@@ -163,7 +310,7 @@ impl From<AdjacentGrid> for f32 {
 
 impl From<f32> for AdjacentGrid {
     fn from(float: f32) -> AdjacentGrid {
-        AdjacentGrid(float.round() as isize)
+        AdjacentGrid(math::round(float) as isize)
     }
 }
 
@@ -243,6 +390,18 @@ impl DiscreteCoordinate for AdjacentGrid {
     }
 }
 
+impl GridMetric for AdjacentGrid {
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.0.abs_diff(other.0)
+    }
+
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        Self::chebyshev_distance(a, b)
+    }
+}
+
 /// [`DiscreteCoordinate`] primitive for a hexagonal grid, where each cell points sideways
 ///
 /// These hexes tile vertically, but not horizontally
@@ -257,7 +416,7 @@ impl From<FlatHex> for f32 {
 
 impl From<f32> for FlatHex {
     fn from(float: f32) -> FlatHex {
-        FlatHex(float.round() as isize)
+        FlatHex(math::round(float) as isize)
     }
 }
 
@@ -327,6 +486,19 @@ impl DiscreteCoordinate for FlatHex {
     }
 }
 
+impl GridMetric for FlatHex {
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.0.abs_diff(other.0)
+    }
+
+    /// Every step moves one cell north or south, and optionally one cell east or west
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        hex_distance(a.y.abs_diff(b.y), a.x.abs_diff(b.x))
+    }
+}
+
 /// [`DiscreteCoordinate`] primitive for a hexagonal grid, where each cell points up
 ///
 /// These hexes tile horizontally, but not vertically
@@ -341,7 +513,7 @@ impl From<PointyHex> for f32 {
 
 impl From<f32> for PointyHex {
     fn from(float: f32) -> PointyHex {
-        PointyHex(float.round() as isize)
+        PointyHex(math::round(float) as isize)
     }
 }
 
@@ -410,3 +582,942 @@ impl DiscreteCoordinate for PointyHex {
         ]
     }
 }
+
+impl GridMetric for PointyHex {
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.0.abs_diff(other.0)
+    }
+
+    /// Every step moves one cell east or west, and optionally one cell north or south
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        hex_distance(a.x.abs_diff(b.x), a.y.abs_diff(b.y))
+    }
+}
+
+/// [`DiscreteCoordinate`] primitive for an isometric grid, where each tile is a diamond twice as wide as it is tall
+///
+/// The `x` axis runs up and to the right on screen, while the `y` axis runs up and to the left.
+/// Converting a whole [`Position<Isometric>`] into world space (such as a [`Vec2`] or `Transform`) applies this 2:1 diamond projection,
+/// so sprites line up without writing any custom transform synchronization.
+/// Converting a single [`Isometric`] value into an [`f32`] is not projected, as the projection mixes both axes.
+///
+/// Each cell has four neighbors, which share an edge with it.
+///
+/// # Example
+/// ```rust
+/// use bevy::math::Vec2;
+/// use leafwing_2d::discrete::Isometric;
+/// use leafwing_2d::position::Position;
+///
+/// let tile = Position::new(Isometric(1), Isometric(0));
+/// let screen = Vec2::from(tile);
+/// assert_eq!(screen, Vec2::new(Isometric::TILE_WIDTH / 2., Isometric::TILE_HEIGHT / 2.));
+///
+/// // Points inside of the diamond map back onto the tile
+/// assert_eq!(Position::<Isometric>::from(screen + Vec2::new(0.4, 0.1)), tile);
+/// ```
+#[derive(TrivialCoordinate)]
+pub struct Isometric(pub isize);
+
+impl Isometric {
+    /// The width of each diamond-shaped tile in world space
+    pub const TILE_WIDTH: f32 = 2.;
+
+    /// The height of each diamond-shaped tile in world space
+    pub const TILE_HEIGHT: f32 = 1.;
+}
+
+impl From<Isometric> for f32 {
+    fn from(coordinate: Isometric) -> f32 {
+        coordinate.0 as f32
+    }
+}
+
+impl From<f32> for Isometric {
+    fn from(float: f32) -> Isometric {
+        Isometric(math::round(float) as isize)
+    }
+}
+
+impl Coordinate for Isometric {
+    type Data = isize;
+
+    const COORD_TO_TRANSFORM: f32 = 1.;
+    const ZERO: Isometric = Isometric(0);
+    const MIN: Isometric = Isometric(isize::MIN);
+    const MAX: Isometric = Isometric(isize::MAX);
+
+    const DATA_ZERO: isize = 0;
+    const DATA_ONE: isize = 1;
+
+    fn position_to_vec2(position: Position<Isometric>) -> Vec2 {
+        let (x, y): (f32, f32) = (position.x.into(), position.y.into());
+
+        Vec2::new(
+            (x - y) * Isometric::TILE_WIDTH / 2.,
+            (x + y) * Isometric::TILE_HEIGHT / 2.,
+        )
+    }
+
+    fn vec2_to_position(vec2: Vec2) -> Position<Isometric> {
+        let across = vec2.x / (Isometric::TILE_WIDTH / 2.);
+        let up = vec2.y / (Isometric::TILE_HEIGHT / 2.);
+
+        Position {
+            x: Isometric::from((up + across) / 2.),
+            y: Isometric::from((up - across) / 2.),
+        }
+    }
+}
+
+impl DiscreteCoordinate for Isometric {
+    type Parititions = OffsetQuadrant;
+    const N_NEIGHBORS: usize = 4;
+
+    #[inline]
+    fn next(&self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    #[inline]
+    fn prev(&self) -> Self {
+        Self(self.0 - 1)
+    }
+
+    #[inline]
+    fn neighbors(position: Position<Self>) -> Vec<Position<Self>> {
+        vec![
+            // NE
+            Position {
+                x: Self(position.x.0 + 1),
+                y: Self(position.y.0),
+            },
+            // SE
+            Position {
+                x: Self(position.x.0),
+                y: Self(position.y.0 - 1),
+            },
+            // SW
+            Position {
+                x: Self(position.x.0 - 1),
+                y: Self(position.y.0),
+            },
+            // NW
+            Position {
+                x: Self(position.x.0),
+                y: Self(position.y.0 + 1),
+            },
+        ]
+    }
+}
+
+impl GridMetric for Isometric {
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.0.abs_diff(other.0)
+    }
+
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        Self::manhattan_distance(a, b)
+    }
+}
+
+/// Defines a compact [`DiscreteCoordinate`] for a square grid with four neighbors, backed by the integer type `$int`
+///
+/// `$min` and `$max` must be exactly representable as [`f32`], so that every value survives the round trip.
+macro_rules! integer_grid_coordinate {
+    ($(#[$meta:meta])* $name:ident, $int:ty, $min:expr, $max:expr) => {
+        $(#[$meta])*
+        #[derive(TrivialCoordinate)]
+        pub struct $name(pub $int);
+
+        impl $name {
+            /// Moves `delta` cells along this axis, returning `None` if that would leave the range `MIN..=MAX`
+            #[inline]
+            #[must_use]
+            pub fn checked_offset(self, delta: $int) -> Option<$name> {
+                self.0
+                    .checked_add(delta)
+                    .filter(|value| ($min..=$max).contains(value))
+                    .map($name)
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(coordinate: $name) -> f32 {
+                coordinate.0 as f32
+            }
+        }
+
+        impl From<f32> for $name {
+            /// Rounds to the nearest cell, clamping values outside of the range `MIN..=MAX`
+            fn from(float: f32) -> $name {
+                $name((math::round(float) as $int).clamp($min, $max))
+            }
+        }
+
+        impl Coordinate for $name {
+            type Data = $int;
+
+            const COORD_TO_TRANSFORM: f32 = 1.;
+            const ZERO: $name = $name(0);
+            const MIN: $name = $name($min);
+            const MAX: $name = $name($max);
+
+            const DATA_ZERO: $int = 0;
+            const DATA_ONE: $int = 1;
+        }
+
+        impl DiscreteCoordinate for $name {
+            type Parititions = CardinalQuadrant;
+            const N_NEIGHBORS: usize = 4;
+
+            #[inline]
+            fn next(&self) -> Self {
+                self.checked_offset(1).unwrap_or(Self::MAX)
+            }
+
+            #[inline]
+            fn prev(&self) -> Self {
+                self.checked_offset(-1).unwrap_or(Self::MIN)
+            }
+
+            /// The four neighbors of `position`, excluding any outside of the range `MIN..=MAX`
+            fn neighbors(position: Position<Self>) -> Vec<Position<Self>> {
+                [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                    .into_iter()
+                    .filter_map(|(dx, dy)| {
+                        Some(Position {
+                            x: position.x.checked_offset(dx)?,
+                            y: position.y.checked_offset(dy)?,
+                        })
+                    })
+                    .collect()
+            }
+
+            /// The directions to all four neighbors, ignoring the edges of the range
+            fn neighbor_directions() -> Vec<Direction> {
+                OrthogonalGrid::neighbor_directions()
+            }
+        }
+
+        impl GridMetric for $name {
+            #[inline]
+            fn abs_diff(self, other: Self) -> usize {
+                self.0.abs_diff(other.0) as usize
+            }
+
+            #[inline]
+            fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+                Self::manhattan_distance(a, b)
+            }
+        }
+    };
+}
+
+integer_grid_coordinate!(
+    /// An [`i8`]-backed [`DiscreteCoordinate`] for a square grid, where each cell has four neighbors
+    ///
+    /// Every [`i8`] can be converted to [`f32`] and back without loss,
+    /// and [`next`](DiscreteCoordinate::next), [`prev`](DiscreteCoordinate::prev) and [`neighbors`](DiscreteCoordinate::neighbors)
+    /// stop at the edges of the range rather than overflowing.
+    /// Use this in place of [`OrthogonalGrid`] for small boards, where memory or bandwidth is tight.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::coordinate::Coordinate;
+    /// use leafwing_2d::discrete::{DiscreteCoordinate, I8};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// assert_eq!(I8::MAX, I8(i8::MAX));
+    /// assert_eq!(I8::MAX.next(), I8::MAX);
+    ///
+    /// // The corner of the grid only has two neighbors
+    /// let corner = Position::new(I8::MAX, I8::MIN);
+    /// assert_eq!(I8::neighbors(corner).len(), 2);
+    /// ```
+    I8,
+    i8,
+    i8::MIN,
+    i8::MAX
+);
+
+integer_grid_coordinate!(
+    /// An [`i16`]-backed [`DiscreteCoordinate`] for a square grid, where each cell has four neighbors
+    ///
+    /// Every [`i16`] can be converted to [`f32`] and back without loss,
+    /// and [`next`](DiscreteCoordinate::next), [`prev`](DiscreteCoordinate::prev) and [`neighbors`](DiscreteCoordinate::neighbors)
+    /// stop at the edges of the range rather than overflowing.
+    I16,
+    i16,
+    i16::MIN,
+    i16::MAX
+);
+
+integer_grid_coordinate!(
+    /// An [`i32`]-backed [`DiscreteCoordinate`] for a square grid, where each cell has four neighbors
+    ///
+    /// [`f32`] can only represent every integer up to 2^24 exactly,
+    /// so [`Coordinate::MIN`] and [`Coordinate::MAX`] are limited to `-2^24` and `2^24`, rather than the full range of [`i32`].
+    /// [`next`](DiscreteCoordinate::next), [`prev`](DiscreteCoordinate::prev) and [`neighbors`](DiscreteCoordinate::neighbors)
+    /// stop at the edges of this range, and conversions from [`f32`] are clamped to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::coordinate::Coordinate;
+    /// use leafwing_2d::discrete::{DiscreteCoordinate, I32};
+    ///
+    /// assert_eq!(I32::MAX, I32(1 << 24));
+    /// assert_eq!(I32::from(1e9), I32::MAX);
+    ///
+    /// I32::assert_values_distinct();
+    /// ```
+    I32,
+    i32,
+    -(1 << 24),
+    1 << 24
+);
+
+/// Implements [`Eq`], [`Ord`] and [`Hash`] for integer-backed coordinates,
+/// so they (and their [`Positions`](Position)) can be stored in sets and used as map keys
+macro_rules! integer_coordinate_ordering {
+    ($($name:ident),*) => {
+        $(
+            impl Eq for $name {}
+
+            impl Ord for $name {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp(&other.0)
+                }
+            }
+
+            impl Hash for $name {
+                #[inline]
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.0.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+integer_coordinate_ordering!(
+    OrthogonalGrid,
+    AdjacentGrid,
+    FlatHex,
+    PointyHex,
+    Isometric,
+    I8,
+    I16,
+    I32
+);
+
+/// A [`DiscreteCoordinate`] that can never leave the range `MIN..=MAX`, giving grids hard edges
+///
+/// This wraps another grid `G`, such as [`OrthogonalGrid`] or [`AdjacentGrid`], and shares its shape and neighbors.
+/// Arithmetic, [`next`](DiscreteCoordinate::next) and [`prev`](DiscreteCoordinate::prev) saturate at the edges of the range,
+/// rather than overflowing or wrapping around.
+/// Use [`Bounded::checked_add`] or [`Position::checked_add`] instead to find out when a move would leave the board.
+/// [`neighbors`](DiscreteCoordinate::neighbors) never includes cells outside of the range.
+///
+/// `MIN` must be at most 0, and `MAX` must be at least 0.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::{Bounded, DiscreteCoordinate, OrthogonalGrid};
+/// use leafwing_2d::position::Position;
+///
+/// type Chessboard = Bounded<OrthogonalGrid, 0, 7>;
+///
+/// let rook = Position::<Chessboard>::new(7, 0);
+/// let step = Position::<Chessboard>::new(1, 0);
+///
+/// // Moving off the board stops at the edge...
+/// assert_eq!(rook + step, rook);
+/// // ...or can be rejected outright
+/// assert!(rook.checked_add(step).is_err());
+///
+/// // Corners only have two neighbors
+/// assert_eq!(Chessboard::neighbors(rook).len(), 2);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<G, const MIN: isize, const MAX: isize> {
+    value: isize,
+    grid: PhantomData<G>,
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> Bounded<G, MIN, MAX> {
+    /// The range `MIN..=MAX`, which fails to compile when it does not contain 0
+    const RANGE: (isize, isize) = {
+        assert!(
+            MIN <= 0 && 0 <= MAX,
+            "The range of a bounded coordinate must contain 0."
+        );
+        (MIN, MAX)
+    };
+
+    /// Creates a new [`Bounded`] coordinate, clamping `value` into the range `MIN..=MAX`
+    ///
+    /// Every [`Bounded`] coordinate is created by this function, so that its range is always checked.
+    #[inline]
+    #[must_use]
+    pub const fn saturating(value: isize) -> Self {
+        let (min, max) = Self::RANGE;
+        let value = if value < min {
+            min
+        } else if value > max {
+            max
+        } else {
+            value
+        };
+
+        Bounded {
+            value,
+            grid: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Bounded`] coordinate, failing if `value` is outside of the range `MIN..=MAX`
+    #[inline]
+    pub fn new(value: isize) -> Result<Self, CoordinateOutOfBounds> {
+        if (MIN..=MAX).contains(&value) {
+            Ok(Self::saturating(value))
+        } else {
+            Err(CoordinateOutOfBounds)
+        }
+    }
+
+    /// The underlying value, which always lies in the range `MIN..=MAX`
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> isize {
+        self.value
+    }
+
+    /// Adds `rhs`, failing if the result would be outside of the range `MIN..=MAX`
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Result<Self, CoordinateOutOfBounds> {
+        self.value()
+            .checked_add(rhs.value())
+            .ok_or(CoordinateOutOfBounds)
+            .and_then(Self::new)
+    }
+
+    /// Subtracts `rhs`, failing if the result would be outside of the range `MIN..=MAX`
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, CoordinateOutOfBounds> {
+        self.value()
+            .checked_sub(rhs.value())
+            .ok_or(CoordinateOutOfBounds)
+            .and_then(Self::new)
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize>
+    Position<Bounded<G, MIN, MAX>>
+{
+    /// Adds `rhs` to each axis, failing if the result would be outside of the range `MIN..=MAX`
+    pub fn checked_add(self, rhs: Self) -> Result<Self, CoordinateOutOfBounds> {
+        Ok(Position {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Subtracts `rhs` from each axis, failing if the result would be outside of the range `MIN..=MAX`
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, CoordinateOutOfBounds> {
+        Ok(Position {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+}
+
+impl<G, const MIN: isize, const MAX: isize> Debug for Bounded<G, MIN, MAX> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Bounded({})", self.value)
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> Default
+    for Bounded<G, MIN, MAX>
+{
+    fn default() -> Self {
+        Self::saturating(0)
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize>
+    From<Bounded<G, MIN, MAX>> for f32
+{
+    fn from(coordinate: Bounded<G, MIN, MAX>) -> f32 {
+        G::from(coordinate.value).into()
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> From<f32>
+    for Bounded<G, MIN, MAX>
+{
+    fn from(float: f32) -> Self {
+        Self::saturating(G::from(float).into())
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize>
+    From<Bounded<G, MIN, MAX>> for isize
+{
+    fn from(coordinate: Bounded<G, MIN, MAX>) -> isize {
+        coordinate.value()
+    }
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> From<isize>
+    for Bounded<G, MIN, MAX>
+{
+    /// Clamps `value` into the range `MIN..=MAX`
+    fn from(value: isize) -> Self {
+        Self::saturating(value)
+    }
+}
+
+/// Implements a saturating arithmetic operator, and its assigning variant, for [`Bounded`]
+macro_rules! impl_bounded_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $compute:expr) => {
+        impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> $op
+            for Bounded<G, MIN, MAX>
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.$method(rhs.value())
+            }
+        }
+
+        impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> $op<isize>
+            for Bounded<G, MIN, MAX>
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: isize) -> Self {
+                let compute: fn(isize, isize) -> isize = $compute;
+                Self::saturating(compute(self.value(), rhs))
+            }
+        }
+
+        impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> $assign_op
+            for Bounded<G, MIN, MAX>
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+impl_bounded_op!(Add, add, AddAssign, add_assign, isize::saturating_add);
+impl_bounded_op!(Sub, sub, SubAssign, sub_assign, isize::saturating_sub);
+impl_bounded_op!(Mul, mul, MulAssign, mul_assign, isize::saturating_mul);
+impl_bounded_op!(Div, div, DivAssign, div_assign, isize::saturating_div);
+impl_bounded_op!(Rem, rem, RemAssign, rem_assign, |a, b| a % b);
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> Coordinate
+    for Bounded<G, MIN, MAX>
+{
+    type Data = isize;
+
+    const COORD_TO_TRANSFORM: f32 = G::COORD_TO_TRANSFORM;
+    const ZERO: Self = Self::saturating(0);
+    const MIN: Self = Self::saturating(MIN);
+    const MAX: Self = Self::saturating(MAX);
+
+    const DATA_ZERO: isize = 0;
+    const DATA_ONE: isize = 1;
+}
+
+impl<G: DiscreteCoordinate<Data = isize>, const MIN: isize, const MAX: isize> DiscreteCoordinate
+    for Bounded<G, MIN, MAX>
+{
+    type Parititions = G::Parititions;
+    const N_NEIGHBORS: usize = G::N_NEIGHBORS;
+
+    #[inline]
+    fn next(&self) -> Self {
+        Self::saturating(self.value().saturating_add(1))
+    }
+
+    #[inline]
+    fn prev(&self) -> Self {
+        Self::saturating(self.value().saturating_sub(1))
+    }
+
+    /// The neighbors of `position` in the wrapped grid, excluding any outside of the range `MIN..=MAX`
+    fn neighbors(position: Position<Self>) -> Vec<Position<Self>> {
+        G::neighbors(Position::new(position.x.value, position.y.value))
+            .into_iter()
+            .filter_map(|neighbor| {
+                Some(Position {
+                    x: Self::new(neighbor.x.into()).ok()?,
+                    y: Self::new(neighbor.y.into()).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// The directions to the neighbors in the wrapped grid, ignoring the edges of the range
+    fn neighbor_directions() -> Vec<Direction> {
+        G::neighbor_directions()
+    }
+}
+
+impl<G: GridMetric<Data = isize>, const MIN: isize, const MAX: isize> GridMetric
+    for Bounded<G, MIN, MAX>
+{
+    #[inline]
+    fn abs_diff(self, other: Self) -> usize {
+        self.value.abs_diff(other.value)
+    }
+
+    #[inline]
+    fn grid_distance(a: Position<Self>, b: Position<Self>) -> usize {
+        G::grid_distance(
+            Position::new(a.x.value, a.y.value),
+            Position::new(b.x.value, b.y.value),
+        )
+    }
+}
+
+/// The number of steps between two cells of a [`FlatHex`] or [`PointyHex`] grid
+///
+/// Each step moves exactly one cell along the `major` axis, and at most one cell along the `minor` axis.
+fn hex_distance(major: usize, minor: usize) -> usize {
+    if minor <= major {
+        major
+    } else {
+        // Zig-zagging along the major axis can only cancel out an even number of steps
+        minor + (minor - major) % 2
+    }
+}
+
+/// Which way the corners of the hexagons in a [`AxialHex`] grid point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// Each hexagon has a corner pointing north, so rows of hexes are straight
+    PointyTop,
+    /// Each hexagon has a flat edge facing north, so columns of hexes are straight
+    FlatTop,
+}
+
+/// A cell in a hexagonal grid, stored in axial coordinates
+///
+/// The `q` axis runs east (for [`HexOrientation::PointyTop`]) or northeast (for [`HexOrientation::FlatTop`]),
+/// while the `r` axis runs northeast (for [`HexOrientation::PointyTop`]) or north (for [`HexOrientation::FlatTop`]).
+/// The implied third coordinate, `s`, is available by converting to a [`CubeHex`].
+///
+/// Unlike [`FlatHex`] and [`PointyHex`], this captures the true geometry of a hex grid:
+/// every cell has exactly six neighbors, all at the same distance.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::{AxialHex, HexOrientation};
+///
+/// let hex = AxialHex::new(2, -1);
+/// assert_eq!(hex.neighbors().len(), 6);
+/// assert_eq!(hex.distance(AxialHex::ORIGIN), 2);
+///
+/// let world = hex.to_world(HexOrientation::PointyTop, 10.0);
+/// assert_eq!(AxialHex::from_world(world, HexOrientation::PointyTop, 10.0), hex);
+/// ```
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Add,
+    Sub,
+    AddAssign,
+    SubAssign,
+)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct AxialHex {
+    /// The first axial coordinate
+    pub q: isize,
+    /// The second axial coordinate
+    pub r: isize,
+}
+
+impl AxialHex {
+    /// The hex at the center of the grid
+    pub const ORIGIN: AxialHex = AxialHex { q: 0, r: 0 };
+
+    /// The offsets to each of the six neighbors
+    ///
+    /// The order is clockwise, starting from north (+y) for either [`HexOrientation`].
+    pub const NEIGHBOR_OFFSETS: [AxialHex; 6] = [
+        AxialHex { q: 0, r: 1 },
+        AxialHex { q: 1, r: 0 },
+        AxialHex { q: 1, r: -1 },
+        AxialHex { q: 0, r: -1 },
+        AxialHex { q: -1, r: 0 },
+        AxialHex { q: -1, r: 1 },
+    ];
+
+    /// Creates a new [`AxialHex`]
+    #[inline]
+    #[must_use]
+    pub const fn new(q: isize, r: isize) -> AxialHex {
+        AxialHex { q, r }
+    }
+
+    /// The implied third coordinate, chosen so that `q + r + s == 0`
+    #[inline]
+    #[must_use]
+    pub const fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    /// The six hexes that share an edge with this one
+    ///
+    /// The order is clockwise, starting from north (+y) for either [`HexOrientation`].
+    #[must_use]
+    pub fn neighbors(&self) -> [AxialHex; 6] {
+        AxialHex::NEIGHBOR_OFFSETS.map(|offset| *self + offset)
+    }
+
+    /// The number of steps between neighbors needed to get from this hex to `other`
+    #[must_use]
+    pub fn distance(&self, other: AxialHex) -> usize {
+        let offset = CubeHex::from(other - *self);
+
+        (offset.q.unsigned_abs() + offset.r.unsigned_abs() + offset.s.unsigned_abs()) / 2
+    }
+
+    /// Returns this hex after rotating it clockwise around the `pivot` by `sixth_turns` multiples of 60 degrees
+    ///
+    /// Negative values rotate counterclockwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::discrete::AxialHex;
+    ///
+    /// let north = AxialHex::NEIGHBOR_OFFSETS[0];
+    /// assert_eq!(north.rotated_around(AxialHex::ORIGIN, 1), AxialHex::NEIGHBOR_OFFSETS[1]);
+    /// assert_eq!(north.rotated_around(AxialHex::ORIGIN, -1), AxialHex::NEIGHBOR_OFFSETS[5]);
+    /// ```
+    #[must_use]
+    pub fn rotated_around(&self, pivot: AxialHex, sixth_turns: i32) -> AxialHex {
+        let mut offset = CubeHex::from(*self - pivot);
+        for _ in 0..sixth_turns.rem_euclid(6) {
+            offset = CubeHex {
+                q: -offset.s,
+                r: -offset.q,
+                s: -offset.r,
+            };
+        }
+
+        pivot + offset.into()
+    }
+
+    /// Returns this hex after mirroring it across the line through the `pivot` that runs along the `q` axis
+    ///
+    /// Combine this with [`AxialHex::rotated_around`] to mirror across any other axis of the grid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::discrete::AxialHex;
+    ///
+    /// // Hexes along the `q` axis are unchanged
+    /// assert_eq!(AxialHex::new(3, 0).mirrored_across(AxialHex::ORIGIN), AxialHex::new(3, 0));
+    /// assert_eq!(AxialHex::new(0, 1).mirrored_across(AxialHex::ORIGIN), AxialHex::new(1, -1));
+    /// ```
+    #[must_use]
+    pub fn mirrored_across(&self, pivot: AxialHex) -> AxialHex {
+        let offset = *self - pivot;
+
+        pivot + AxialHex::new(offset.q + offset.r, -offset.r)
+    }
+
+    /// The center of this hex in world space, for hexes with a distance of `size` from their center to each corner
+    #[must_use]
+    pub fn to_world(&self, orientation: HexOrientation, size: f32) -> Vec2 {
+        let (q, r) = (self.q as f32, self.r as f32);
+
+        match orientation {
+            HexOrientation::PointyTop => Vec2::new(SQRT_3 * (q + r / 2.), 1.5 * r) * size,
+            HexOrientation::FlatTop => Vec2::new(1.5 * q, SQRT_3 * (r + q / 2.)) * size,
+        }
+    }
+
+    /// The hex containing the `world` point, for hexes with a distance of `size` from their center to each corner
+    ///
+    /// This is the inverse of [`AxialHex::to_world`].
+    #[must_use]
+    pub fn from_world(world: Vec2, orientation: HexOrientation, size: f32) -> AxialHex {
+        let scaled = world / size;
+        let (x, y) = (scaled.x, scaled.y);
+
+        let (q, r) = match orientation {
+            HexOrientation::PointyTop => (x / SQRT_3 - y / 3., y * 2. / 3.),
+            HexOrientation::FlatTop => (x * 2. / 3., y / SQRT_3 - x / 3.),
+        };
+
+        CubeHex::round(q, r).into()
+    }
+}
+
+/// A cell in a hexagonal grid, stored in cube coordinates
+///
+/// The three coordinates always sum to zero.
+/// This redundant form makes many hex algorithms symmetric, such as rotation and distance.
+/// Convert to and from an [`AxialHex`] with [`From`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CubeHex {
+    /// Matches [`AxialHex::q`]
+    pub q: isize,
+    /// Matches [`AxialHex::r`]
+    pub r: isize,
+    /// Equal to `-q - r`
+    pub s: isize,
+}
+
+impl CubeHex {
+    /// Rounds fractional axial coordinates to the nearest hex
+    fn round(q: f32, r: f32) -> CubeHex {
+        let s = -q - r;
+        let (mut rounded_q, mut rounded_r, rounded_s) =
+            (math::round(q), math::round(r), math::round(s));
+        let (q_error, r_error, s_error) = (
+            (rounded_q - q).abs(),
+            (rounded_r - r).abs(),
+            (rounded_s - s).abs(),
+        );
+
+        // Rounding can break `q + r + s == 0`, so recompute the coordinate that moved the most
+        if q_error > r_error && q_error > s_error {
+            rounded_q = -rounded_r - rounded_s;
+        } else if r_error > s_error {
+            rounded_r = -rounded_q - rounded_s;
+        }
+
+        AxialHex::new(rounded_q as isize, rounded_r as isize).into()
+    }
+}
+
+impl From<AxialHex> for CubeHex {
+    fn from(hex: AxialHex) -> CubeHex {
+        CubeHex {
+            q: hex.q,
+            r: hex.r,
+            s: hex.s(),
+        }
+    }
+}
+
+impl From<CubeHex> for AxialHex {
+    fn from(hex: CubeHex) -> AxialHex {
+        AxialHex { q: hex.q, r: hex.r }
+    }
+}
+
+/// The square root of 3, which relates the width and height of a regular hexagon
+const SQRT_3: f32 = 1.732_050_8;
+
+/// How the cells of a grid are laid out in world space
+///
+/// Insert this as a resource, and [`sync_transform_with_2d`](crate::plugin::sync_transform_with_2d)
+/// will use it to convert between [`Position<C>`] and [`Transform`](bevy_transform::components::Transform),
+/// so a grid of 32 pixel tiles lines up with its sprites without writing a custom [`Coordinate`] type.
+/// Without this resource, positions are converted using [`Coordinate::position_to_vec2`].
+///
+/// When `hex_orientation` is set, the `x` and `y` coordinates of each [`Position<C>`] are treated as
+/// the `q` and `r` coordinates of an [`AxialHex`], laid out with a distance of `cell_size` from each center to its corners.
+///
+/// # Example
+/// ```rust
+/// use bevy::math::Vec2;
+/// use leafwing_2d::discrete::{GridLayout, OrthogonalGrid};
+/// use leafwing_2d::position::Position;
+///
+/// let layout = GridLayout::<OrthogonalGrid> {
+///     origin: Vec2::new(16.0, 16.0),
+///     ..GridLayout::new(32.0)
+/// };
+///
+/// let tile = Position::new(OrthogonalGrid(2), OrthogonalGrid(1));
+/// assert_eq!(layout.cell_to_world(tile), Vec2::new(80.0, 48.0));
+/// assert_eq!(layout.world_to_cell(Vec2::new(90.0, 35.0)), tile);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridLayout<C: Coordinate> {
+    /// The width and height of each cell in world space
+    pub cell_size: Vec2,
+    /// The world-space position of the center of the `(0, 0)` cell
+    pub origin: Vec2,
+    /// Which way the hexes point, for hexagonal grids
+    ///
+    /// If this is `None`, cells are laid out using [`Coordinate::position_to_vec2`], scaled by `cell_size`.
+    pub hex_orientation: Option<HexOrientation>,
+    /// The coordinate type of the grid being laid out
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> GridLayout<C> {
+    /// Creates a new [`GridLayout`] for square cells that are `cell_size` wide, centered on the world origin
+    #[must_use]
+    pub fn new(cell_size: f32) -> GridLayout<C> {
+        GridLayout {
+            cell_size: Vec2::splat(cell_size),
+            origin: Vec2::ZERO,
+            hex_orientation: None,
+            coordinate_type: PhantomData,
+        }
+    }
+
+    /// The world-space position of the center of the cell at `position`
+    #[must_use]
+    pub fn cell_to_world(&self, position: Position<C>) -> Vec2 {
+        let unscaled = match self.hex_orientation {
+            Some(orientation) => {
+                let (q, r): (f32, f32) = (position.x.into(), position.y.into());
+                AxialHex::new(math::round(q) as isize, math::round(r) as isize)
+                    .to_world(orientation, 1.)
+            }
+            None => C::position_to_vec2(position),
+        };
+
+        self.origin + unscaled * self.cell_size
+    }
+
+    /// The cell that contains the world-space point `world`
+    ///
+    /// This is the inverse of [`cell_to_world`](GridLayout::cell_to_world).
+    #[must_use]
+    pub fn world_to_cell(&self, world: Vec2) -> Position<C> {
+        let unscaled = (world - self.origin) / self.cell_size;
+
+        match self.hex_orientation {
+            Some(orientation) => {
+                let hex = AxialHex::from_world(unscaled, orientation, 1.);
+                Position {
+                    x: C::from(hex.q as f32),
+                    y: C::from(hex.r as f32),
+                }
+            }
+            None => C::vec2_to_position(unscaled),
+        }
+    }
+}
+
+impl<C: Coordinate> Default for GridLayout<C> {
+    fn default() -> Self {
+        GridLayout::new(1.)
+    }
+}
@@ -1,6 +1,8 @@
 //! Errors that may occur when working with 2D coordinates
 
-use derive_more::{Display, Error};
+use derive_more::Display;
+#[cfg(feature = "std")]
+use derive_more::Error;
 
 /// The supplied vector-like struct was too close to zero to be converted into a rotation-like type
 ///
@@ -9,5 +11,36 @@ use derive_more::{Display, Error};
 /// such as a [`Vec2`].
 ///
 /// In almost all cases, the correct way to handle this error is to simply not change the rotation.
-#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
 pub struct NearlySingularConversion;
+
+/// The supplied [`Position`](crate::position::Position) was outside of the region it was being mapped into
+///
+/// This error is produced when quantizing a position that lies outside of the bounds used for quantization,
+/// as in [`QuantizedPosition::quantize`](crate::position::QuantizedPosition::quantize).
+///
+/// If you would prefer to snap the position to the nearest representable value instead,
+/// use [`QuantizedPosition::quantize_clamped`](crate::position::QuantizedPosition::quantize_clamped).
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub struct PositionOutOfBounds;
+
+/// The result of arithmetic on a [`Bounded`](crate::discrete::Bounded) coordinate was outside of its range
+///
+/// This error is produced by checked operations such as [`Bounded::checked_add`](crate::discrete::Bounded::checked_add)
+/// and [`Position::checked_add`](crate::position::Position::checked_add).
+///
+/// If you would prefer to stop at the edge of the range instead, use the ordinary arithmetic operators, which saturate.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub struct CoordinateOutOfBounds;
+
+/// The supplied [`f32`] was NaN or infinite, and so cannot be stored in a [`FiniteF32`](crate::continuous::FiniteF32)
+///
+/// This error is produced by [`FiniteF32::new`](crate::continuous::FiniteF32::new).
+///
+/// If you would prefer to replace these values instead, use [`FiniteF32::clamped`](crate::continuous::FiniteF32::clamped).
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub struct NonFiniteCoordinate;
@@ -0,0 +1,110 @@
+//! Find connected regions of discrete grids, for room detection, paint-bucket tools and map validation
+//!
+//! Cells are connected when they are [`neighbors`](DiscreteCoordinate::neighbors),
+//! so diagonal cells are connected on an [`AdjacentGrid`](crate::discrete::AdjacentGrid),
+//! but not on an [`OrthogonalGrid`](crate::discrete::OrthogonalGrid).
+//!
+//! Cells are collected into a [`BTreeSet`], which does not need the standard library,
+//! so the coordinates must implement [`Ord`], as all of the built-in discrete coordinates do.
+//! Iterating over the results visits the positions sorted by their `x` coordinate, then their `y` coordinate, so results are deterministic.
+
+use crate::discrete::DiscreteCoordinate;
+use crate::position::Position;
+use alloc::collections::BTreeSet;
+use alloc::{vec, vec::Vec};
+
+/// Every cell that can be reached from `start` by stepping between `passable` neighbors
+///
+/// The `start` is included, unless it is not `passable`, in which case nothing is returned.
+/// `passable` is checked at most once per cell.
+///
+/// # Warning
+///
+/// The grid is unbounded, so `passable` must enclose the region: if it returns `true` forever, this will never finish.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::flood_fill::flood_fill;
+/// use leafwing_2d::position::Position;
+///
+/// let cell = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+///
+/// // A 3x3 room
+/// let room = flood_fill(cell(0, 0), |position| {
+///     position.x.0.abs() <= 1 && position.y.0.abs() <= 1
+/// });
+///
+/// assert_eq!(room.len(), 9);
+/// assert!(room.contains(&cell(1, -1)));
+/// ```
+#[must_use]
+pub fn flood_fill<C: DiscreteCoordinate + Ord>(
+    start: Position<C>,
+    mut passable: impl FnMut(Position<C>) -> bool,
+) -> BTreeSet<Position<C>> {
+    if !passable(start) {
+        return BTreeSet::new();
+    }
+
+    let mut filled = BTreeSet::from([start]);
+    let mut blocked = BTreeSet::new();
+    let mut frontier = vec![start];
+
+    while let Some(position) = frontier.pop() {
+        for neighbor in C::neighbors(position) {
+            if filled.contains(&neighbor) || blocked.contains(&neighbor) {
+                continue;
+            }
+
+            if passable(neighbor) {
+                filled.insert(neighbor);
+                frontier.push(neighbor);
+            } else {
+                blocked.insert(neighbor);
+            }
+        }
+    }
+
+    filled
+}
+
+/// Splits `cells` into groups that are connected to each other by stepping between neighbors
+///
+/// Duplicate cells are ignored.
+/// Regions are returned in order of their first cell.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::flood_fill::connected_regions;
+/// use leafwing_2d::position::Position;
+/// use std::collections::BTreeSet;
+///
+/// let cell = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+///
+/// let regions = connected_regions([cell(0, 0), cell(0, 1), cell(5, 5), cell(1, 1)]);
+///
+/// // A generated map is only fully connected if there is a single region
+/// assert_eq!(regions.len(), 2);
+/// assert_eq!(regions[0], BTreeSet::from([cell(0, 0), cell(0, 1), cell(1, 1)]));
+/// assert_eq!(regions[1], BTreeSet::from([cell(5, 5)]));
+/// ```
+#[must_use]
+pub fn connected_regions<C: DiscreteCoordinate + Ord>(
+    cells: impl IntoIterator<Item = Position<C>>,
+) -> Vec<BTreeSet<Position<C>>> {
+    let mut remaining: BTreeSet<Position<C>> = cells.into_iter().collect();
+    let mut regions = Vec::new();
+
+    while let Some(&first) = remaining.iter().next() {
+        let region = flood_fill(first, |cell| remaining.contains(&cell));
+        for cell in &region {
+            remaining.remove(cell);
+        }
+
+        regions.push(region);
+    }
+
+    regions
+}
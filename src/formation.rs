@@ -0,0 +1,338 @@
+//! Generate the slots of common formations, for spawning waves and squads
+//!
+//! Each generator returns a list of `(Position<C>, Rotation)` pairs, measured relative to an anchor at the origin that faces [`Rotation::NORTH`].
+//! Use [`place`] to move a formation to where it should be in the world.
+//!
+//! To keep a group in formation as its leader moves, give each member a `FormationSlot` and add the `FormationPlugin`.
+
+use crate::coordinate::Coordinate;
+use crate::orientation::Rotation;
+use crate::position::Position;
+use crate::transform::Transform2D;
+use alloc::vec::Vec;
+use glam::Vec2;
+#[cfg(feature = "bevy")]
+use {
+    crate::kinematics::Velocity,
+    crate::orientation::{Orientation, OrientationPositionInterop},
+    crate::plugin::TwoDSystem,
+    bevy_app::prelude::*,
+    bevy_core::Time,
+    bevy_ecs::prelude::*,
+    core::marker::PhantomData,
+};
+
+/// `rows` by `cols` slots, `spacing` apart and centered on the anchor
+///
+/// Slots are ordered row by row, starting with the front (northernmost) row, from left to right.
+/// Every slot faces [`Rotation::NORTH`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::formation;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::Position;
+///
+/// let slots = formation::grid::<F32>(2, 3, 1.0);
+/// assert_eq!(slots.len(), 6);
+/// assert_eq!(slots[0], (Position::new(-1.0, 0.5), Rotation::NORTH));
+/// assert_eq!(slots[5], (Position::new(1.0, -0.5), Rotation::NORTH));
+/// ```
+#[must_use]
+pub fn grid<C: Coordinate>(rows: usize, cols: usize, spacing: f32) -> Vec<(Position<C>, Rotation)> {
+    let half_width = (cols as f32 - 1.) / 2.;
+    let half_height = (rows as f32 - 1.) / 2.;
+
+    (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .map(|(row, col)| {
+            let offset = Vec2::new(col as f32 - half_width, half_height - row as f32) * spacing;
+            (offset.into(), Rotation::NORTH)
+        })
+        .collect()
+}
+
+/// `n` slots, evenly spaced around a circle of `radius` centered on the anchor
+///
+/// The first slot is directly north of the anchor, and the rest follow clockwise.
+/// Each slot faces away from the center.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::formation;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::{Position, Positionlike};
+///
+/// let slots = formation::circle::<F32>(4, 2.0);
+/// assert_eq!(slots.len(), 4);
+///
+/// let (east_position, east_rotation) = slots[1];
+/// east_position.assert_approx_eq(Position::<F32>::new(2.0, 0.0));
+/// assert_eq!(east_rotation, Rotation::EAST);
+/// ```
+#[must_use]
+pub fn circle<C: Coordinate>(n: usize, radius: f32) -> Vec<(Position<C>, Rotation)> {
+    (0..n)
+        .map(|i| {
+            let rotation = Rotation::new((i * Rotation::FULL_CIRCLE as usize / n) as u16);
+            ((rotation.into_vec2() * radius).into(), rotation)
+        })
+        .collect()
+}
+
+/// `n` slots side by side, `spacing` apart and centered on the anchor
+///
+/// Slots are ordered from left to right, and every slot faces [`Rotation::NORTH`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::formation;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::Position;
+///
+/// let slots = formation::line::<F32>(3, 2.0);
+/// assert_eq!(
+///     slots,
+///     vec![
+///         (Position::new(-2.0, 0.0), Rotation::NORTH),
+///         (Position::new(0.0, 0.0), Rotation::NORTH),
+///         (Position::new(2.0, 0.0), Rotation::NORTH),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn line<C: Coordinate>(n: usize, spacing: f32) -> Vec<(Position<C>, Rotation)> {
+    grid(1, n, spacing)
+}
+
+/// Moves each slot of a formation from the anchor's local space into the space of `anchor`
+///
+/// Slots are rotated and scaled about the anchor, and their rotations are offset by the anchor's rotation.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::formation;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::{Position, Positionlike};
+/// use leafwing_2d::transform::Transform2D;
+///
+/// // A squad marching east, led from the middle of the line
+/// let anchor = Transform2D {
+///     position: Position::new(10.0, 5.0),
+///     rotation: Rotation::EAST,
+///     ..Transform2D::IDENTITY
+/// };
+/// let squad = formation::place(anchor, formation::line::<F32>(3, 1.0));
+///
+/// let (leftmost_position, leftmost_rotation) = squad[0];
+/// leftmost_position.assert_approx_eq(Position::<F32>::new(10.0, 6.0));
+/// assert_eq!(leftmost_rotation, Rotation::EAST);
+/// ```
+#[must_use]
+pub fn place<C: Coordinate>(
+    anchor: Transform2D<C>,
+    slots: impl IntoIterator<Item = (Position<C>, Rotation)>,
+) -> Vec<(Position<C>, Rotation)> {
+    slots
+        .into_iter()
+        .map(|(position, rotation)| (anchor * position, anchor.rotation + rotation))
+        .collect()
+}
+
+/// Steers this entity towards its slot in a formation around the `leader`
+///
+/// The slot is `offset` from the leader, rotated along with the leader's [`Rotation`].
+/// Members steer by setting their [`Velocity`]: they travel at up to `max_speed`,
+/// and slow down as they come within `slowing_distance` of their slot, so that they arrive without overshooting.
+///
+/// Members with a [`Rotation`] turn to face their slot while travelling to it,
+/// and then face the leader's rotation plus `offset_rotation` once they are close.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::formation::{self, FormationPlugin, FormationSlot};
+/// use leafwing_2d::prelude::*;
+///
+/// fn spawn_squad(mut commands: Commands) {
+///     let leader = commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .id();
+///
+///     // Two rows of three, following behind the leader
+///     for (offset, offset_rotation) in formation::grid::<F32>(2, 3, 1.0) {
+///         let offset = offset + Position::new(0.0, -2.0);
+///         commands
+///             .spawn_bundle(TwoDBundle::<F32>::default())
+///             .insert(FormationSlot::new(leader, offset, offset_rotation, 4.0));
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(FormationPlugin::<F32>::default())
+///     .add_startup_system(spawn_squad);
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FormationSlot<C: Coordinate> {
+    /// The entity that this member follows
+    pub leader: Entity,
+    /// Where the slot is, relative to the leader when it faces [`Rotation::NORTH`]
+    pub offset: Position<C>,
+    /// Which way the member faces once it has reached its slot, relative to the leader's [`Rotation`]
+    pub offset_rotation: Rotation,
+    /// The fastest that this member will move, in the [`f32`] conversion of `C` per second
+    pub max_speed: f32,
+    /// How close to the slot this member starts slowing down
+    ///
+    /// Default: half a second's worth of travel at `max_speed`
+    pub slowing_distance: f32,
+    /// The fastest that this member will turn, per second
+    ///
+    /// If `None`, turning is instantaneous.
+    ///
+    /// Default: `None`
+    pub turn_rate: Option<Rotation>,
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> FormationSlot<C> {
+    /// Creates a new [`FormationSlot`], using the default `slowing_distance` and `turn_rate`
+    #[must_use]
+    pub fn new(
+        leader: Entity,
+        offset: Position<C>,
+        offset_rotation: Rotation,
+        max_speed: f32,
+    ) -> Self {
+        Self {
+            leader,
+            offset,
+            offset_rotation,
+            max_speed,
+            slowing_distance: max_speed * 0.5,
+            turn_rate: None,
+        }
+    }
+
+    /// The position and rotation of this slot, for a leader at `leader_position` facing `leader_rotation`
+    #[must_use]
+    pub fn target(
+        &self,
+        leader_position: Position<C>,
+        leader_rotation: Rotation,
+    ) -> (Position<C>, Rotation) {
+        let leader = Transform2D {
+            position: leader_position,
+            rotation: leader_rotation,
+            ..Transform2D::IDENTITY
+        };
+
+        (leader * self.offset, leader_rotation + self.offset_rotation)
+    }
+
+    /// The velocity that moves from `position` towards the `target`, slowing down on arrival
+    fn arrival_velocity(&self, position: Position<C>, target: Position<C>) -> Vec2 {
+        let to_target = Vec2::from(target) - Vec2::from(position);
+        let distance = to_target.length();
+        if distance <= f32::EPSILON {
+            return Vec2::ZERO;
+        }
+
+        let speed = if distance < self.slowing_distance {
+            self.max_speed * distance / self.slowing_distance
+        } else {
+            self.max_speed
+        };
+
+        to_target * (speed / distance)
+    }
+}
+
+/// Steers each entity with a [`FormationSlot`] towards its slot, by setting its [`Velocity`] and [`Rotation`]
+///
+/// Members whose leader no longer has a [`Position`] are left alone.
+/// Leaders without a [`Rotation`] are treated as facing [`Rotation::NORTH`].
+#[cfg(feature = "bevy")]
+pub fn keep_formation<C: Coordinate>(
+    time: Res<Time>,
+    members: Query<(Entity, &FormationSlot<C>)>,
+    mut states: Query<(&Position<C>, Option<&mut Rotation>)>,
+    mut velocities: Query<&mut Velocity<C>>,
+) {
+    let delta_seconds = time.delta_seconds();
+
+    for (member, slot) in members.iter() {
+        let (leader_position, leader_rotation) = match states.get(slot.leader) {
+            Ok((&position, rotation)) => (position, rotation.copied().unwrap_or_default()),
+            Err(_) => continue,
+        };
+        let (target_position, target_rotation) = slot.target(leader_position, leader_rotation);
+
+        let (&position, maybe_rotation) = match states.get_mut(member) {
+            Ok(state) => state,
+            Err(_) => continue,
+        };
+
+        if let Some(mut rotation) = maybe_rotation {
+            let max_rotation = slot.turn_rate.map(|turn_rate| turn_rate * delta_seconds);
+            let mut new_rotation = *rotation;
+            if Vec2::from(position).distance(Vec2::from(target_position)) > slot.slowing_distance {
+                new_rotation.rotate_towards_position(position, target_position, max_rotation);
+            } else {
+                new_rotation.rotate_towards(target_rotation, max_rotation);
+            }
+
+            // Avoid triggering change detection pointlessly
+            if *rotation != new_rotation {
+                *rotation = new_rotation;
+            }
+        }
+
+        if let Ok(mut velocity) = velocities.get_mut(member) {
+            let new_velocity = slot.arrival_velocity(position, target_position);
+            let new_velocity = Velocity {
+                x: C::from(new_velocity.x),
+                y: C::from(new_velocity.y),
+            };
+
+            if *velocity != new_velocity {
+                *velocity = new_velocity;
+            }
+        }
+    }
+}
+
+/// Adds the [`keep_formation`] system, which steers each [`FormationSlot`] towards its leader
+///
+/// Members are steered in [`CoreStage::PostUpdate`], before [`TwoDSystem::Kinematics`].
+#[cfg(feature = "bevy")]
+#[derive(Debug)]
+pub struct FormationPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> Default for FormationPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> Plugin for FormationPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            keep_formation::<C>.before(TwoDSystem::Kinematics),
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Compute which cells of a discrete grid can be seen from a point, as in a roguelike
+//!
+//! [`fov`] uses recursive shadowcasting:
+//! each of the eight octants around the origin is scanned row by row,
+//! and every opaque cell casts a shadow that hides the cells behind it.
+//! Only cells that could be visible are ever checked for opacity.
+//!
+//! These functions treat the grid as square, and so are intended for use with
+//! [`OrthogonalGrid`](crate::discrete::OrthogonalGrid) and [`AdjacentGrid`](crate::discrete::AdjacentGrid).
+
+use crate::discrete::DiscreteCoordinate;
+use crate::position::Position;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// The cells that can be seen from `origin`, no more than `radius` cells away
+///
+/// Cells for which `is_opaque` returns `true` block sight, but are themselves visible, so walls can be drawn.
+/// The `origin` is always visible, and every visible cell is returned exactly once.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::fov::fov;
+/// use leafwing_2d::position::Position;
+///
+/// let cell = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+///
+/// // A wall running north to south, one cell east of the origin
+/// let visible = fov(cell(0, 0), 5, |position| position.x == OrthogonalGrid(1));
+///
+/// assert!(visible.contains(&cell(-5, 0)));
+/// // The wall can be seen, but not the cells behind it
+/// assert!(visible.contains(&cell(1, 0)));
+/// assert!(!visible.contains(&cell(2, 0)));
+/// // Cells further than the radius are never visible
+/// assert!(!visible.contains(&cell(-6, 0)));
+/// ```
+#[must_use]
+pub fn fov<C: DiscreteCoordinate<Data = isize>>(
+    origin: Position<C>,
+    radius: usize,
+    is_opaque: impl FnMut(Position<C>) -> bool,
+) -> Vec<Position<C>> {
+    let origin = (origin.x.into(), origin.y.into());
+    let mut shadowcaster = Shadowcaster {
+        origin,
+        radius: radius as isize,
+        is_opaque,
+        visible: BTreeSet::from([origin]),
+    };
+
+    for octant in OCTANTS {
+        shadowcaster.cast_light(octant, 1, 1.0, 0.0);
+    }
+
+    shadowcaster
+        .visible
+        .into_iter()
+        .map(|(x, y)| Position::new(C::from(x), C::from(y)))
+        .collect()
+}
+
+/// Maps the row and column scanned within an octant onto the grid, as `[xx, xy, yx, yy]`
+const OCTANTS: [[isize; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// The state shared between the recursive scans of [`fov`]
+struct Shadowcaster<F> {
+    origin: (isize, isize),
+    radius: isize,
+    is_opaque: F,
+    visible: BTreeSet<(isize, isize)>,
+}
+
+impl<F> Shadowcaster<F> {
+    /// Scans the rows of an `octant` from `first_row` outwards,
+    /// marking the cells whose centers lie between the `start` and `end` slopes as visible
+    fn cast_light<C: DiscreteCoordinate<Data = isize>>(
+        &mut self,
+        octant: [isize; 4],
+        first_row: isize,
+        mut start: f32,
+        end: f32,
+    ) where
+        F: FnMut(Position<C>) -> bool,
+    {
+        if start < end {
+            return;
+        }
+
+        let [xx, xy, yx, yy] = octant;
+        for row in first_row..=self.radius {
+            let mut blocked = false;
+            let mut next_start = start;
+
+            for column in (0..=row).rev() {
+                // The slopes of the edges of this cell, as seen from the origin
+                let left_slope = (column as f32 + 0.5) / (row as f32 - 0.5);
+                let right_slope = (column as f32 - 0.5) / (row as f32 + 0.5);
+
+                if right_slope > start {
+                    continue;
+                } else if left_slope < end {
+                    break;
+                }
+
+                let cell = (
+                    self.origin.0 + column * xx + row * xy,
+                    self.origin.1 + column * yx + row * yy,
+                );
+                if column * column + row * row <= self.radius * self.radius {
+                    self.visible.insert(cell);
+                }
+
+                let opaque = (self.is_opaque)(Position::new(C::from(cell.0), C::from(cell.1)));
+                if blocked {
+                    if opaque {
+                        next_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && row < self.radius {
+                    // Everything past this cell is in its shadow, so scan the unshadowed part of the next row separately
+                    blocked = true;
+                    self.cast_light(octant, row + 1, start, left_slope);
+                    next_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Intersection tests between lines, segments and circles
+//!
+//! Lines are infinite, and pass through the two [`Positions`](Position) that define them.
+//! Segments are finite, and start and end at the two [`Positions`](Position) that define them.
+//! Calculations are performed using the [`f32`] conversion of the [`Coordinate`] type.
+
+use crate::bounding::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::math;
+use crate::position::Position;
+use glam::Vec2;
+
+/// How do two lines or segments intersect?
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineIntersection<C: Coordinate> {
+    /// They do not touch: they are parallel, or are segments that stop short of each other
+    None,
+    /// They cross (or touch) at exactly one position
+    Point(Position<C>),
+    /// They are collinear segments that share the segment between these two positions
+    Overlap(Position<C>, Position<C>),
+    /// They are the same infinite line
+    Coincident,
+}
+
+/// Where does a segment cross the edge of a circle?
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleIntersection<C: Coordinate> {
+    /// The segment does not cross the edge of the circle
+    ///
+    /// Segments that are entirely inside of the circle do not intersect its edge.
+    None,
+    /// The segment crosses (or touches) the edge of the circle once
+    One(Position<C>),
+    /// The segment crosses the edge of the circle twice, in this order when travelling from its start to its end
+    Two(Position<C>, Position<C>),
+}
+
+/// Are `a` and `b` parallel, allowing for floating point error?
+fn nearly_parallel(a: Vec2, b: Vec2) -> bool {
+    a.perp_dot(b).abs() <= f32::EPSILON * a.length() * b.length()
+}
+
+/// Is `point` on the infinite line through `start` and `end`, allowing for floating point error?
+fn on_line(point: Vec2, start: Vec2, end: Vec2) -> bool {
+    nearly_parallel(point - start, end - start)
+}
+
+/// The point on the segment from `start` to `end` that is closest to `point`
+pub(crate) fn closest_point_on_segment(point: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+
+    if length_squared == 0.0 {
+        return start;
+    }
+
+    let t = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+/// The shortest distance between the segment from `a_start` to `a_end` and the segment from `b_start` to `b_end`
+///
+/// Segments that cross are zero distance apart.
+pub(crate) fn segment_segment_distance(a: (Vec2, Vec2), b: (Vec2, Vec2)) -> f32 {
+    let (on_a, on_b) = closest_points_between_segments(a, b);
+    on_a.distance(on_b)
+}
+
+/// The closest pair of points on the segment from `a_start` to `a_end` and the segment from `b_start` to `b_end`
+///
+/// The first point lies on segment `a`, and the second on segment `b`.
+/// Segments that cross return the crossing point twice.
+pub(crate) fn closest_points_between_segments(
+    (a_start, a_end): (Vec2, Vec2),
+    (b_start, b_end): (Vec2, Vec2),
+) -> (Vec2, Vec2) {
+    let a = a_end - a_start;
+    let b = b_end - b_start;
+    let denominator = a.perp_dot(b);
+    if denominator != 0. {
+        let t = (b_start - a_start).perp_dot(b) / denominator;
+        let u = (b_start - a_start).perp_dot(a) / denominator;
+        if (0. ..=1.).contains(&t) && (0. ..=1.).contains(&u) {
+            let crossing = a_start + a * t;
+            return (crossing, crossing);
+        }
+    }
+
+    // Segments that don't cross are closest at one of their ends
+    [
+        (a_start, closest_point_on_segment(a_start, b_start, b_end)),
+        (a_end, closest_point_on_segment(a_end, b_start, b_end)),
+        (closest_point_on_segment(b_start, a_start, a_end), b_start),
+        (closest_point_on_segment(b_end, a_start, a_end), b_end),
+    ]
+    .into_iter()
+    .min_by(|x, y| {
+        x.0.distance_squared(x.1)
+            .total_cmp(&y.0.distance_squared(y.1))
+    })
+    .unwrap()
+}
+
+/// Finds where the line through `a_start` and `a_end` crosses the line through `b_start` and `b_end`
+///
+/// Parallel lines return [`LineIntersection::None`], unless they are the same line,
+/// in which case [`LineIntersection::Coincident`] is returned.
+/// If both positions that define a line are equal, it is treated as a single point.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::geometry::{line_line_intersection, LineIntersection};
+/// use leafwing_2d::position::Position;
+///
+/// let origin = Position::<F32>::new(0.0, 0.0);
+///
+/// // The lines continue past the positions that define them
+/// assert_eq!(
+///     line_line_intersection(origin, Position::new(1.0, 1.0), Position::new(4.0, 0.0), Position::new(3.0, 1.0)),
+///     LineIntersection::Point(Position::new(2.0, 2.0))
+/// );
+///
+/// assert_eq!(
+///     line_line_intersection(origin, Position::new(1.0, 0.0), Position::new(0.0, 1.0), Position::new(1.0, 1.0)),
+///     LineIntersection::None
+/// );
+///
+/// assert_eq!(
+///     line_line_intersection(origin, Position::new(1.0, 0.0), Position::new(2.0, 0.0), Position::new(3.0, 0.0)),
+///     LineIntersection::Coincident
+/// );
+/// ```
+#[must_use]
+pub fn line_line_intersection<C: Coordinate>(
+    a_start: Position<C>,
+    a_end: Position<C>,
+    b_start: Position<C>,
+    b_end: Position<C>,
+) -> LineIntersection<C> {
+    let (a_start, a_end) = (Vec2::from(a_start), Vec2::from(a_end));
+    let (b_start, b_end) = (Vec2::from(b_start), Vec2::from(b_end));
+    let (a_delta, b_delta) = (a_end - a_start, b_end - b_start);
+
+    match (a_delta == Vec2::ZERO, b_delta == Vec2::ZERO) {
+        (true, true) if a_start == b_start => LineIntersection::Point(a_start.into()),
+        (true, true) => LineIntersection::None,
+        (true, false) if on_line(a_start, b_start, b_end) => {
+            LineIntersection::Point(a_start.into())
+        }
+        (false, true) if on_line(b_start, a_start, a_end) => {
+            LineIntersection::Point(b_start.into())
+        }
+        (true, false) | (false, true) => LineIntersection::None,
+        (false, false) => {
+            if nearly_parallel(a_delta, b_delta) {
+                if on_line(b_start, a_start, a_end) {
+                    LineIntersection::Coincident
+                } else {
+                    LineIntersection::None
+                }
+            } else {
+                let t = (b_start - a_start).perp_dot(b_delta) / a_delta.perp_dot(b_delta);
+                LineIntersection::Point((a_start + a_delta * t).into())
+            }
+        }
+    }
+}
+
+/// Finds where the segment from `a_start` to `a_end` crosses the segment from `b_start` to `b_end`
+///
+/// Collinear segments that overlap return the shared segment as [`LineIntersection::Overlap`];
+/// if they only share an endpoint, [`LineIntersection::Point`] is returned instead.
+/// Segments never return [`LineIntersection::Coincident`].
+/// If both positions that define a segment are equal, it is treated as a single point.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::geometry::{segment_segment_intersection, LineIntersection};
+/// use leafwing_2d::position::Position;
+///
+/// let origin = Position::<F32>::new(0.0, 0.0);
+///
+/// assert_eq!(
+///     segment_segment_intersection(origin, Position::new(2.0, 2.0), Position::new(0.0, 2.0), Position::new(2.0, 0.0)),
+///     LineIntersection::Point(Position::new(1.0, 1.0))
+/// );
+///
+/// // These segments would cross if they were longer
+/// assert_eq!(
+///     segment_segment_intersection(origin, Position::new(1.0, 1.0), Position::new(4.0, 0.0), Position::new(3.0, 1.0)),
+///     LineIntersection::None
+/// );
+///
+/// assert_eq!(
+///     segment_segment_intersection(origin, Position::new(2.0, 0.0), Position::new(1.0, 0.0), Position::new(3.0, 0.0)),
+///     LineIntersection::Overlap(Position::new(1.0, 0.0), Position::new(2.0, 0.0))
+/// );
+/// ```
+#[must_use]
+pub fn segment_segment_intersection<C: Coordinate>(
+    a_start: Position<C>,
+    a_end: Position<C>,
+    b_start: Position<C>,
+    b_end: Position<C>,
+) -> LineIntersection<C> {
+    let (a_start, a_end) = (Vec2::from(a_start), Vec2::from(a_end));
+    let (b_start, b_end) = (Vec2::from(b_start), Vec2::from(b_end));
+    let (a_delta, b_delta) = (a_end - a_start, b_end - b_start);
+
+    // Degenerate segments are points, which intersect if they lie on the other segment
+    let point_on_segment = |point: Vec2, start: Vec2, end: Vec2| {
+        if closest_point_on_segment(point, start, end).distance(point)
+            <= f32::EPSILON * point.length().max(1.0)
+        {
+            LineIntersection::Point(point.into())
+        } else {
+            LineIntersection::None
+        }
+    };
+
+    if a_delta == Vec2::ZERO {
+        return point_on_segment(a_start, b_start, b_end);
+    }
+    if b_delta == Vec2::ZERO {
+        return point_on_segment(b_start, a_start, a_end);
+    }
+
+    if nearly_parallel(a_delta, b_delta) {
+        if !on_line(b_start, a_start, a_end) {
+            return LineIntersection::None;
+        }
+
+        // Project the second segment onto the first, where `a_start` is at 0 and `a_end` is at 1
+        let project = |point: Vec2| (point - a_start).dot(a_delta) / a_delta.length_squared();
+        let (b_t_start, b_t_end) = (project(b_start), project(b_end));
+        let low = b_t_start.min(b_t_end).max(0.0);
+        let high = b_t_start.max(b_t_end).min(1.0);
+
+        return if low > high {
+            LineIntersection::None
+        } else if low == high {
+            LineIntersection::Point((a_start + a_delta * low).into())
+        } else {
+            LineIntersection::Overlap(
+                (a_start + a_delta * low).into(),
+                (a_start + a_delta * high).into(),
+            )
+        };
+    }
+
+    let denominator = a_delta.perp_dot(b_delta);
+    let offset = b_start - a_start;
+    let t = offset.perp_dot(b_delta) / denominator;
+    let u = offset.perp_dot(a_delta) / denominator;
+
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        LineIntersection::Point((a_start + a_delta * t).into())
+    } else {
+        LineIntersection::None
+    }
+}
+
+/// Finds where the segment from `start` to `end` crosses the edge of the circle with the provided `center` and `radius`
+///
+/// Segments that only touch the circle at a tangent return [`CircleIntersection::One`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::geometry::{segment_circle_intersection, CircleIntersection};
+/// use leafwing_2d::position::Position;
+///
+/// let center = Position::<F32>::new(0.0, 0.0);
+///
+/// assert_eq!(
+///     segment_circle_intersection(Position::new(-5.0, 0.0), Position::new(5.0, 0.0), center, 2.0),
+///     CircleIntersection::Two(Position::new(-2.0, 0.0), Position::new(2.0, 0.0))
+/// );
+///
+/// // Starting inside of the circle
+/// assert_eq!(
+///     segment_circle_intersection(center, Position::new(0.0, 5.0), center, 2.0),
+///     CircleIntersection::One(Position::new(0.0, 2.0))
+/// );
+///
+/// assert_eq!(
+///     segment_circle_intersection(Position::new(-5.0, 3.0), Position::new(5.0, 3.0), center, 2.0),
+///     CircleIntersection::None
+/// );
+/// ```
+#[must_use]
+pub fn segment_circle_intersection<C: Coordinate>(
+    start: Position<C>,
+    end: Position<C>,
+    center: Position<C>,
+    radius: impl Into<C>,
+) -> CircleIntersection<C> {
+    let (start, end, center) = (Vec2::from(start), Vec2::from(end), Vec2::from(center));
+    let radius: f32 = radius.into().into();
+
+    let delta = end - start;
+    let offset = start - center;
+
+    // Solve |offset + t * delta| = radius for t, as a quadratic a * t^2 + b * t + c = 0
+    let a = delta.length_squared();
+    let b = 2.0 * offset.dot(delta);
+    let c = offset.length_squared() - radius * radius;
+
+    if a == 0.0 {
+        // The segment is a single point
+        return if c == 0.0 {
+            CircleIntersection::One(start.into())
+        } else {
+            CircleIntersection::None
+        };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return CircleIntersection::None;
+    }
+
+    let root = math::sqrt(discriminant);
+    let point_at = |t: f32| Position::from(start + delta * t);
+    let on_segment = |t: &f32| (0.0..=1.0).contains(t);
+
+    let first = (-b - root) / (2.0 * a);
+    let second = (-b + root) / (2.0 * a);
+
+    match (on_segment(&first), on_segment(&second)) {
+        (true, true) if discriminant == 0.0 => CircleIntersection::One(point_at(first)),
+        (true, true) => CircleIntersection::Two(point_at(first), point_at(second)),
+        (true, false) => CircleIntersection::One(point_at(first)),
+        (false, true) => CircleIntersection::One(point_at(second)),
+        (false, false) => CircleIntersection::None,
+    }
+}
+
+/// A straight line between two [`Positions`](Position), such as a wall or a laser beam
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::geometry::Segment;
+/// use leafwing_2d::position::Position;
+///
+/// let wall = Segment::<F32>::new(Position::new(0.0, 0.0), Position::new(0.0, 10.0));
+/// let laser = Segment::new(Position::new(-5.0, 3.0), Position::new(5.0, 3.0));
+///
+/// assert_eq!(wall.length(), 10.0);
+/// assert_eq!(wall.intersection(&laser), Some(Position::new(0.0, 3.0)));
+/// assert_eq!(wall.closest_point(Position::new(4.0, 12.0)), Position::new(0.0, 10.0));
+///
+/// let player = AxisAlignedBoundingBox::new(-1.0, 1.0, 11.0, 12.0);
+/// assert!(!wall.intersects_aabb(&player));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Segment<C: Coordinate> {
+    /// Where the segment starts
+    pub start: Position<C>,
+    /// Where the segment ends
+    pub end: Position<C>,
+}
+
+impl<C: Coordinate> Segment<C> {
+    /// Creates a new [`Segment`] from `start` to `end`
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Position<C>, end: Position<C>) -> Self {
+        Self { start, end }
+    }
+
+    /// The distance from `start` to `end`
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        Vec2::from(self.start).distance(self.end.into())
+    }
+
+    /// The position on this segment that is closest to `position`
+    #[must_use]
+    pub fn closest_point(&self, position: Position<C>) -> Position<C> {
+        closest_point_on_segment(position.into(), self.start.into(), self.end.into()).into()
+    }
+
+    /// Where this segment crosses the `other` segment, if it does
+    ///
+    /// Collinear segments that overlap return the shared position that is closest to the `start` of this segment.
+    /// Use [`segment_segment_intersection`] to find the whole overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Segment<C>) -> Option<Position<C>> {
+        match segment_segment_intersection(self.start, self.end, other.start, other.end) {
+            LineIntersection::Point(position) => Some(position),
+            LineIntersection::Overlap(a, b) => {
+                let start = Vec2::from(self.start);
+                if start.distance_squared(a.into()) <= start.distance_squared(b.into()) {
+                    Some(a)
+                } else {
+                    Some(b)
+                }
+            }
+            LineIntersection::None | LineIntersection::Coincident => None,
+        }
+    }
+
+    /// Does this segment touch the `aabb`, including if it lies entirely inside of it?
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> bool {
+        self.clip_to_aabb(aabb).is_some()
+    }
+
+    /// The part of this segment that lies inside of the `aabb`, if any
+    ///
+    /// The clipped segment runs in the same direction as this one,
+    /// so its `start` is where this segment enters the box.
+    /// This uses the Liang-Barsky algorithm.
+    #[must_use]
+    pub fn clip_to_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> Option<Segment<C>> {
+        let start = Vec2::from(self.start);
+        let delta = Vec2::from(self.end) - start;
+        let (min, max) = (Vec2::from(aabb.bottom_left()), Vec2::from(aabb.top_right()));
+
+        // The segment must stay on the inner side of each of the four edges of the box
+        let (mut enter, mut exit) = (0.0_f32, 1.0_f32);
+        for (rate, room) in [
+            (-delta.x, start.x - min.x),
+            (delta.x, max.x - start.x),
+            (-delta.y, start.y - min.y),
+            (delta.y, max.y - start.y),
+        ] {
+            if rate == 0. {
+                if room < 0. {
+                    return None;
+                }
+            } else if rate < 0. {
+                enter = enter.max(room / rate);
+            } else {
+                exit = exit.min(room / rate);
+            }
+        }
+
+        if enter > exit {
+            return None;
+        }
+
+        Some(Segment {
+            start: (start + delta * enter).into(),
+            end: (start + delta * exit).into(),
+        })
+    }
+}
@@ -0,0 +1,688 @@
+//! Dense storage of values on a rectangular grid of cells, and tools to analyze them
+//!
+//! Cells of a [`GridMap`] are addressed by [`Position<OrthogonalGrid>`](OrthogonalGrid), starting from `(0, 0)` in the bottom left corner.
+//! A [`TileMap`] can be addressed using any discrete coordinate, and can start anywhere,
+//! while a [`ChunkedMap`] stretches on forever.
+
+use crate::bounding::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::discrete::{DiscreteCoordinate, OrthogonalGrid};
+use crate::path::Polyline;
+use crate::position::Position;
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "bevy")]
+use bevy_ecs::component::Component;
+use core::ops::{Index, IndexMut};
+use glam::Vec2;
+
+/// A rectangular grid of cells, each storing a value of type `T`
+///
+/// Use this to store terrain, scalar fields and other per-cell data for a fixed-size map.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::grid_map::GridMap;
+/// use leafwing_2d::position::Position;
+///
+/// let mut heights = GridMap::new(4, 3, 0.0);
+/// let peak = Position::new(OrthogonalGrid(2), OrthogonalGrid(1));
+/// heights[peak] = 5.0;
+///
+/// assert_eq!(heights.get(peak), Some(&5.0));
+/// assert_eq!(heights.get(Position::new(OrthogonalGrid(4), OrthogonalGrid(0))), None);
+/// assert_eq!(heights.iter().filter(|(_, &height)| height > 0.0).count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GridMap<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> GridMap<T> {
+    /// Creates a new [`GridMap`] with `width` columns and `height` rows, with every cell set to `value`
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> GridMap<T> {
+        GridMap {
+            width,
+            height,
+            cells: alloc::vec![value; width * height],
+        }
+    }
+}
+
+impl<T> GridMap<T> {
+    /// Creates a new [`GridMap`] with `width` columns and `height` rows, computing the value of each cell from its position
+    #[must_use]
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut f: impl FnMut(Position<OrthogonalGrid>) -> T,
+    ) -> GridMap<T> {
+        let cells = (0..width * height)
+            .map(|index| f(Self::position_of(width, index)))
+            .collect();
+
+        GridMap {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// The number of columns in this grid
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows in this grid
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Is the `position` inside of this grid?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, position: Position<OrthogonalGrid>) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    /// Gets a reference to the value stored at `position`, if it is inside of this grid
+    #[inline]
+    #[must_use]
+    pub fn get(&self, position: Position<OrthogonalGrid>) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    /// Gets a mutable reference to the value stored at `position`, if it is inside of this grid
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, position: Position<OrthogonalGrid>) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Iterates over every cell in this grid, row by row from the bottom
+    pub fn iter(&self) -> impl Iterator<Item = (Position<OrthogonalGrid>, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Self::position_of(self.width, index), value))
+    }
+
+    /// Iterates mutably over every cell in this grid, row by row from the bottom
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position<OrthogonalGrid>, &mut T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, value)| (Self::position_of(width, index), value))
+    }
+
+    fn index_of(&self, position: Position<OrthogonalGrid>) -> Option<usize> {
+        let x = usize::try_from(position.x.0).ok()?;
+        let y = usize::try_from(position.y.0).ok()?;
+
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    fn position_of(width: usize, index: usize) -> Position<OrthogonalGrid> {
+        Position {
+            x: OrthogonalGrid((index % width) as isize),
+            y: OrthogonalGrid((index / width) as isize),
+        }
+    }
+}
+
+impl<T> Index<Position<OrthogonalGrid>> for GridMap<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `position` is outside of the grid.
+    fn index(&self, position: Position<OrthogonalGrid>) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid."))
+    }
+}
+
+impl<T> IndexMut<Position<OrthogonalGrid>> for GridMap<T> {
+    /// # Panics
+    /// Panics if `position` is outside of the grid.
+    fn index_mut(&mut self, position: Position<OrthogonalGrid>) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid."))
+    }
+}
+
+/// A rectangular map of tiles on any discrete grid, each storing a value of type `T`
+///
+/// Unlike a [`GridMap`], the tiles of a [`TileMap`] are addressed by [`Position<C>`],
+/// and can start at any `origin` rather than `(0, 0)`.
+/// Each tile is `tile_size` wide in world space, and centered on its [`world_position`](TileMap::world_position).
+///
+/// With the `bevy` feature, this can be used as either a component or a resource.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::grid_map::TileMap;
+/// use leafwing_2d::position::Position;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Terrain {
+///     Grass,
+///     Water,
+/// }
+///
+/// let tile = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+/// let mut map = TileMap::new(tile(-2, -2), 5, 5, Terrain::Grass);
+/// map.tile_size = 16.0;
+///
+/// assert_eq!(map.set(tile(1, 0), Terrain::Water), Some(Terrain::Grass));
+/// assert_eq!(map.get(tile(1, 0)), Some(&Terrain::Water));
+/// assert_eq!(map.get(tile(3, 0)), None);
+///
+/// assert_eq!(map.world_position::<F32>(tile(1, 0)), Position::new(16.0, 0.0));
+/// assert_eq!(map.tile_at(Position::<F32>::new(20.0, -7.0)), Some(tile(1, 0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct TileMap<C: DiscreteCoordinate<Data = isize>, T> {
+    origin: Position<C>,
+    tiles: GridMap<T>,
+    /// The width and height of each tile, in the [`f32`] conversion of world-space positions
+    ///
+    /// Defaults to 1.
+    pub tile_size: f32,
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T: Clone> TileMap<C, T> {
+    /// Creates a new [`TileMap`] with `width` columns and `height` rows, with its bottom-left tile at `origin` and every tile set to `value`
+    #[must_use]
+    pub fn new(origin: Position<C>, width: usize, height: usize, value: T) -> TileMap<C, T> {
+        TileMap {
+            origin,
+            tiles: GridMap::new(width, height, value),
+            tile_size: 1.,
+        }
+    }
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T> TileMap<C, T> {
+    /// Creates a new [`TileMap`] with `width` columns and `height` rows, with its bottom-left tile at `origin`,
+    /// computing the value of each tile from its position
+    #[must_use]
+    pub fn from_fn(
+        origin: Position<C>,
+        width: usize,
+        height: usize,
+        mut f: impl FnMut(Position<C>) -> T,
+    ) -> TileMap<C, T> {
+        TileMap {
+            origin,
+            tiles: GridMap::from_fn(width, height, |local| f(Self::global(origin, local))),
+            tile_size: 1.,
+        }
+    }
+
+    /// The position of the bottom-left tile
+    #[inline]
+    #[must_use]
+    pub fn origin(&self) -> Position<C> {
+        self.origin
+    }
+
+    /// The number of columns in this map
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    /// The number of rows in this map
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    /// The region of world space covered by this map's tiles
+    #[must_use]
+    pub fn bounds<W: Coordinate>(&self) -> AxisAlignedBoundingBox<W> {
+        let bottom_left = Vec2::from(self.origin) * self.tile_size - self.tile_size / 2.;
+        let top_right =
+            bottom_left + Vec2::new(self.width() as f32, self.height() as f32) * self.tile_size;
+
+        AxisAlignedBoundingBox::new(bottom_left.x, top_right.x, bottom_left.y, top_right.y)
+    }
+
+    /// Is the `position` inside of this map?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, position: Position<C>) -> bool {
+        self.tiles.contains(self.local(position))
+    }
+
+    /// Gets a reference to the value stored at `position`, if it is inside of this map
+    #[inline]
+    #[must_use]
+    pub fn get(&self, position: Position<C>) -> Option<&T> {
+        self.tiles.get(self.local(position))
+    }
+
+    /// Gets a mutable reference to the value stored at `position`, if it is inside of this map
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, position: Position<C>) -> Option<&mut T> {
+        let local = self.local(position);
+        self.tiles.get_mut(local)
+    }
+
+    /// Stores `value` at `position`, returning the value that was previously there
+    ///
+    /// If `position` is outside of this map, nothing is stored and `None` is returned.
+    pub fn set(&mut self, position: Position<C>, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|tile| core::mem::replace(tile, value))
+    }
+
+    /// Iterates over every tile in this map, row by row from the bottom
+    pub fn iter(&self) -> impl Iterator<Item = (Position<C>, &T)> + '_ {
+        let origin = self.origin;
+        self.tiles
+            .iter()
+            .map(move |(local, value)| (Self::global(origin, local), value))
+    }
+
+    /// Iterates mutably over every tile in this map, row by row from the bottom
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position<C>, &mut T)> + '_ {
+        let origin = self.origin;
+        self.tiles
+            .iter_mut()
+            .map(move |(local, value)| (Self::global(origin, local), value))
+    }
+
+    /// The world-space position of the center of the tile at `position`
+    ///
+    /// The tile does not need to be inside of this map.
+    #[must_use]
+    pub fn world_position<W: Coordinate>(&self, position: Position<C>) -> Position<W> {
+        (Vec2::from(position) * self.tile_size).into()
+    }
+
+    /// The tile that contains the world-space `position`, if it is inside of this map
+    #[must_use]
+    pub fn tile_at<W: Coordinate>(&self, position: Position<W>) -> Option<Position<C>> {
+        let tile = Position::from(Vec2::from(position) / self.tile_size);
+
+        self.contains(tile).then_some(tile)
+    }
+
+    fn local(&self, position: Position<C>) -> Position<OrthogonalGrid> {
+        let offset = position - self.origin;
+
+        Position::new(
+            OrthogonalGrid(offset.x.into()),
+            OrthogonalGrid(offset.y.into()),
+        )
+    }
+
+    fn global(origin: Position<C>, local: Position<OrthogonalGrid>) -> Position<C> {
+        origin + Position::new(C::from(local.x.0), C::from(local.y.0))
+    }
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T> Index<Position<C>> for TileMap<C, T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `position` is outside of the map.
+    fn index(&self, position: Position<C>) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the map."))
+    }
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T> IndexMut<Position<C>> for TileMap<C, T> {
+    /// # Panics
+    /// Panics if `position` is outside of the map.
+    fn index_mut(&mut self, position: Position<C>) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the map."))
+    }
+}
+
+/// An unbounded map of tiles on any discrete grid, stored in square chunks that are only allocated once written to
+///
+/// Use this for infinite or very large worlds, which cannot fit into a single [`TileMap`].
+/// Tiles in chunks that have not been created yet read as the `default` value.
+///
+/// Chunks are identified by their own [`Position<C>`], so the chunk `(1, 0)` holds the tiles from `(chunk_size, 0)`
+/// to `(2 * chunk_size - 1, chunk_size - 1)`.
+/// Writing to a chunk marks it as changed, so only the chunks that need it are re-rendered or saved.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::grid_map::ChunkedMap;
+/// use leafwing_2d::position::Position;
+///
+/// let position = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+/// let mut world = ChunkedMap::new(16, 0_u8);
+///
+/// // Nothing is allocated until a tile is written
+/// assert_eq!(world.get(position(1_000_000, -5)), &0);
+/// assert_eq!(world.chunks().count(), 0);
+///
+/// world.set(position(1_000_000, -5), 7);
+/// assert_eq!(world.get(position(1_000_000, -5)), &7);
+///
+/// let chunk = world.chunk_of(position(1_000_000, -5));
+/// assert_eq!(chunk, position(62_500, -1));
+/// assert_eq!(world.changed_chunks().collect::<Vec<_>>(), vec![chunk]);
+///
+/// world.clear_changed();
+/// assert_eq!(world.changed_chunks().count(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct ChunkedMap<C: DiscreteCoordinate<Data = isize>, T> {
+    chunk_size: usize,
+    default: T,
+    chunks: BTreeMap<(isize, isize), Chunk<C, T>>,
+}
+
+/// A chunk of a [`ChunkedMap`], along with whether it has changed
+#[derive(Debug, Clone, PartialEq)]
+struct Chunk<C: DiscreteCoordinate<Data = isize>, T> {
+    tiles: TileMap<C, T>,
+    changed: bool,
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T: Clone> ChunkedMap<C, T> {
+    /// Creates a new, empty [`ChunkedMap`], made of square chunks that are `chunk_size` tiles wide
+    ///
+    /// Every tile starts with the `default` value.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn new(chunk_size: usize, default: T) -> ChunkedMap<C, T> {
+        assert!(chunk_size > 0, "Chunks must contain at least one tile.");
+
+        ChunkedMap {
+            chunk_size,
+            default,
+            chunks: BTreeMap::new(),
+        }
+    }
+
+    /// Gets a mutable reference to the value stored at `position`
+    ///
+    /// This creates the chunk containing `position` if needed, and marks it as changed.
+    #[must_use]
+    pub fn get_mut(&mut self, position: Position<C>) -> &mut T {
+        let chunk_size = self.chunk_size;
+        let origin = self.chunk_origin(self.chunk_of(position));
+        let default = &self.default;
+
+        let chunk = self
+            .chunks
+            .entry(Self::key(self.chunk_of(position)))
+            .or_insert_with(|| Chunk {
+                tiles: TileMap::new(origin, chunk_size, chunk_size, default.clone()),
+                changed: false,
+            });
+        chunk.changed = true;
+
+        &mut chunk.tiles[position]
+    }
+
+    /// Stores `value` at `position`, returning the value that was previously there
+    ///
+    /// This creates the chunk containing `position` if needed, and marks it as changed.
+    pub fn set(&mut self, position: Position<C>, value: T) -> T {
+        core::mem::replace(self.get_mut(position), value)
+    }
+}
+
+impl<C: DiscreteCoordinate<Data = isize>, T> ChunkedMap<C, T> {
+    /// The number of tiles along each side of a chunk
+    #[inline]
+    #[must_use]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// The value of tiles in chunks that have not been created yet
+    #[inline]
+    #[must_use]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// The chunk that contains the tile at `position`
+    #[must_use]
+    pub fn chunk_of(&self, position: Position<C>) -> Position<C> {
+        let size = self.chunk_size as isize;
+        let (x, y): (isize, isize) = (position.x.into(), position.y.into());
+
+        Position::new(C::from(x.div_euclid(size)), C::from(y.div_euclid(size)))
+    }
+
+    /// Gets a reference to the value stored at `position`
+    ///
+    /// If its chunk has not been created yet, this is the default value.
+    #[must_use]
+    pub fn get(&self, position: Position<C>) -> &T {
+        self.chunk(self.chunk_of(position))
+            .map_or(&self.default, |tiles| &tiles[position])
+    }
+
+    /// Has the `chunk` been created?
+    #[inline]
+    #[must_use]
+    pub fn is_loaded(&self, chunk: Position<C>) -> bool {
+        self.chunks.contains_key(&Self::key(chunk))
+    }
+
+    /// The tiles of `chunk`, if it has been created
+    #[must_use]
+    pub fn chunk(&self, chunk: Position<C>) -> Option<&TileMap<C, T>> {
+        self.chunks.get(&Self::key(chunk)).map(|chunk| &chunk.tiles)
+    }
+
+    /// Removes `chunk`, returning its tiles if it had been created
+    ///
+    /// Its tiles read as the default value again, until they are next written to.
+    pub fn unload(&mut self, chunk: Position<C>) -> Option<TileMap<C, T>> {
+        self.chunks
+            .remove(&Self::key(chunk))
+            .map(|chunk| chunk.tiles)
+    }
+
+    /// Iterates over every chunk that has been created
+    pub fn chunks(&self) -> impl Iterator<Item = Position<C>> + '_ {
+        self.chunks.keys().map(|&key| Self::position(key))
+    }
+
+    /// Iterates over every chunk that has been written to since the last call to [`clear_changed`](ChunkedMap::clear_changed)
+    pub fn changed_chunks(&self) -> impl Iterator<Item = Position<C>> + '_ {
+        self.chunks
+            .iter()
+            .filter(|(_, chunk)| chunk.changed)
+            .map(|(&key, _)| Self::position(key))
+    }
+
+    /// Marks every chunk as unchanged
+    pub fn clear_changed(&mut self) {
+        for chunk in self.chunks.values_mut() {
+            chunk.changed = false;
+        }
+    }
+
+    /// Iterates over every tile in the chunks that have been created
+    pub fn iter(&self) -> impl Iterator<Item = (Position<C>, &T)> + '_ {
+        self.chunks.values().flat_map(|chunk| chunk.tiles.iter())
+    }
+
+    fn chunk_origin(&self, chunk: Position<C>) -> Position<C> {
+        let size = self.chunk_size as isize;
+        let (x, y) = Self::key(chunk);
+
+        Position::new(C::from(x * size), C::from(y * size))
+    }
+
+    fn key(position: Position<C>) -> (isize, isize) {
+        (position.x.into(), position.y.into())
+    }
+
+    fn position((x, y): (isize, isize)) -> Position<C> {
+        Position::new(C::from(x), C::from(y))
+    }
+}
+
+/// Identifies the edge between two neighboring samples in a [`GridMap`]
+///
+/// Horizontal edges run from `(x, y)` to `(x + 1, y)`, while vertical edges run from `(x, y)` to `(x, y + 1)`.
+/// Coordinates may be -1, for edges that leave the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Edge {
+    Horizontal(isize, isize),
+    Vertical(isize, isize),
+}
+
+/// Traces the outlines of every region of the `grid_map` whose values are at least `threshold`, using marching squares
+///
+/// Each cell is treated as a sample at the position `(x, y)`, measured in the [`f32`] conversion of `C`.
+/// The outline is linearly interpolated between neighboring samples, so smooth fields produce smooth outlines.
+///
+/// Every outline is a closed loop, whose first point is repeated at its end.
+/// Outlines wind counterclockwise around filled regions, and clockwise around holes inside of them.
+/// Regions that touch the edge of the grid are closed off along its outermost samples.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::grid_map::{contours, GridMap};
+/// use leafwing_2d::position::Position;
+///
+/// // A single solid cell, in the middle of an empty grid
+/// let mut terrain = GridMap::new(3, 3, 0.0);
+/// terrain[Position::new(OrthogonalGrid(1), OrthogonalGrid(1))] = 1.0;
+///
+/// let outlines = contours::<F32>(&terrain, 0.5);
+/// assert_eq!(outlines.len(), 1);
+/// // A diamond around the solid cell, with its first point repeated at the end
+/// assert_eq!(outlines[0].points().len(), 5);
+/// assert!((outlines[0].length() - 4.0 * 0.5_f32.sqrt()).abs() < 1e-5);
+/// ```
+#[must_use]
+pub fn contours<C: Coordinate>(grid_map: &GridMap<f32>, threshold: f32) -> Vec<Polyline<C>> {
+    let (width, height) = (grid_map.width() as isize, grid_map.height() as isize);
+
+    // Samples outside of the grid are always empty
+    let sample = |x: isize, y: isize| {
+        grid_map
+            .get(Position {
+                x: OrthogonalGrid(x),
+                y: OrthogonalGrid(y),
+            })
+            .copied()
+    };
+    let filled = |x: isize, y: isize| sample(x, y).is_some_and(|value| value >= threshold);
+
+    // Where the outline crosses the edge between two samples
+    let crossing = |start: (isize, isize), end: (isize, isize)| {
+        let (start_point, end_point) = (
+            Vec2::new(start.0 as f32, start.1 as f32),
+            Vec2::new(end.0 as f32, end.1 as f32),
+        );
+
+        match (sample(start.0, start.1), sample(end.0, end.1)) {
+            (Some(start_value), Some(end_value)) => {
+                let t = (threshold - start_value) / (end_value - start_value);
+                start_point.lerp(end_point, t)
+            }
+            // The outline hugs the outermost samples of the grid
+            (Some(_), None) => start_point,
+            _ => end_point,
+        }
+    };
+    let edge_point = |edge: Edge| match edge {
+        Edge::Horizontal(x, y) => crossing((x, y), (x + 1, y)),
+        Edge::Vertical(x, y) => crossing((x, y), (x, y + 1)),
+    };
+
+    // Each square contributes directed segments that keep filled regions on their left
+    let mut next_edge: BTreeMap<Edge, Edge> = BTreeMap::new();
+    for y in -1..height {
+        for x in -1..width {
+            let bottom = Edge::Horizontal(x, y);
+            let top = Edge::Horizontal(x, y + 1);
+            let left = Edge::Vertical(x, y);
+            let right = Edge::Vertical(x + 1, y);
+
+            let case = filled(x, y) as u8
+                | (filled(x + 1, y) as u8) << 1
+                | (filled(x + 1, y + 1) as u8) << 2
+                | (filled(x, y + 1) as u8) << 3;
+
+            // Saddle points are resolved using the average of the four corners
+            let center_filled = || {
+                let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+                let total: f32 = corners
+                    .iter()
+                    .map(|&(x, y)| sample(x, y).unwrap_or(threshold))
+                    .sum();
+                total / 4. >= threshold
+            };
+
+            let segments: &[(Edge, Edge)] = match case {
+                1 => &[(bottom, left)],
+                2 => &[(right, bottom)],
+                3 => &[(right, left)],
+                4 => &[(top, right)],
+                5 if center_filled() => &[(bottom, right), (top, left)],
+                5 => &[(bottom, left), (top, right)],
+                6 => &[(top, bottom)],
+                7 => &[(top, left)],
+                8 => &[(left, top)],
+                9 => &[(bottom, top)],
+                10 if center_filled() => &[(left, bottom), (right, top)],
+                10 => &[(right, bottom), (left, top)],
+                11 => &[(right, top)],
+                12 => &[(left, right)],
+                13 => &[(bottom, right)],
+                14 => &[(left, bottom)],
+                _ => &[],
+            };
+
+            for &(from, to) in segments {
+                next_edge.insert(from, to);
+            }
+        }
+    }
+
+    // Follow the segments around each closed loop
+    let mut outlines = Vec::new();
+    while let Some((&start, _)) = next_edge.iter().next() {
+        let mut points = alloc::vec![edge_point(start)];
+        let mut current = start;
+
+        while let Some(next) = next_edge.remove(&current) {
+            points.push(edge_point(next));
+            current = next;
+        }
+
+        outlines.push(Polyline::new(points.into_iter().map(Position::from)));
+    }
+
+    outlines
+}
@@ -0,0 +1,246 @@
+//! Rotate and mirror whole shapes on discrete grids, such as tetrominoes, prefab rooms or ship parts
+//!
+//! A [`SquareTransform`] is one of the eight symmetries of a square grid,
+//! while a [`HexTransform`] is one of the twelve symmetries of a hexagonal [`AxialHex`] grid.
+//! Each maps cells exactly onto other cells, so shapes never drift or distort, no matter how often they are turned.
+//! Iterate over [`SquareTransform::ALL`] or [`HexTransform::ALL`] to stamp out every copy needed for symmetric map generation.
+
+use crate::coordinate::Coordinate;
+use crate::discrete::AxialHex;
+use crate::position::Position;
+use alloc::vec::Vec;
+
+/// A rotation by a multiple of 90 degrees, optionally preceded by a mirror image, that maps a square grid onto itself
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::OrthogonalGrid;
+/// use leafwing_2d::grid_transform::SquareTransform;
+/// use leafwing_2d::position::Position;
+///
+/// let cell = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+///
+/// // An L-shaped tetromino
+/// let piece = [cell(0, 0), cell(0, 1), cell(0, 2), cell(1, 0)];
+///
+/// let turned = SquareTransform::rotation(1).apply_all(piece, cell(0, 0));
+/// assert_eq!(turned, vec![cell(0, 0), cell(1, 0), cell(2, 0), cell(0, -1)]);
+///
+/// // Mirroring turns it into a J-shaped tetromino
+/// let mirrored = SquareTransform::MIRROR.apply_all(piece, cell(0, 0));
+/// assert_eq!(mirrored, vec![cell(0, 0), cell(0, 1), cell(0, 2), cell(-1, 0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SquareTransform {
+    /// The number of clockwise quarter turns, from 0 to 3
+    quarter_turns: u8,
+    /// Is the shape mirrored across the vertical line through the pivot, before being rotated?
+    mirrored: bool,
+}
+
+impl SquareTransform {
+    /// Leaves every position unchanged
+    pub const IDENTITY: SquareTransform = SquareTransform {
+        quarter_turns: 0,
+        mirrored: false,
+    };
+
+    /// Mirrors positions across the vertical line through the pivot
+    pub const MIRROR: SquareTransform = SquareTransform {
+        quarter_turns: 0,
+        mirrored: true,
+    };
+
+    /// Every distinct symmetry of a square grid: the four rotations, followed by their mirror images
+    pub const ALL: [SquareTransform; 8] = [
+        SquareTransform::rotation(0),
+        SquareTransform::rotation(1),
+        SquareTransform::rotation(2),
+        SquareTransform::rotation(3),
+        SquareTransform::rotation(0).mirrored(),
+        SquareTransform::rotation(1).mirrored(),
+        SquareTransform::rotation(2).mirrored(),
+        SquareTransform::rotation(3).mirrored(),
+    ];
+
+    /// A clockwise rotation by `quarter_turns` multiples of 90 degrees
+    ///
+    /// Negative values rotate counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(quarter_turns: i32) -> SquareTransform {
+        SquareTransform {
+            quarter_turns: quarter_turns.rem_euclid(4) as u8,
+            mirrored: false,
+        }
+    }
+
+    /// This transform, mirrored across the vertical line through the pivot before rotating
+    #[inline]
+    #[must_use]
+    pub const fn mirrored(self) -> SquareTransform {
+        SquareTransform {
+            quarter_turns: self.quarter_turns,
+            mirrored: !self.mirrored,
+        }
+    }
+
+    /// The number of clockwise quarter turns, from 0 to 3
+    #[inline]
+    #[must_use]
+    pub const fn quarter_turns(&self) -> u8 {
+        self.quarter_turns
+    }
+
+    /// Is the shape mirrored before it is rotated?
+    #[inline]
+    #[must_use]
+    pub const fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Transforms a single `position` around the `pivot`
+    ///
+    /// This is computed exactly, without converting to [`f32`].
+    #[inline]
+    #[must_use]
+    pub fn apply<C: Coordinate>(&self, position: Position<C>, pivot: Position<C>) -> Position<C> {
+        let position = if self.mirrored {
+            position.mirror_x(pivot.x)
+        } else {
+            position
+        };
+
+        position.rotated_quarter_turns(pivot, self.quarter_turns as i32)
+    }
+
+    /// Transforms every position in `positions` around the `pivot`, preserving their order
+    #[must_use]
+    pub fn apply_all<C: Coordinate>(
+        &self,
+        positions: impl IntoIterator<Item = Position<C>>,
+        pivot: Position<C>,
+    ) -> Vec<Position<C>> {
+        positions
+            .into_iter()
+            .map(|position| self.apply(position, pivot))
+            .collect()
+    }
+}
+
+/// A rotation by a multiple of 60 degrees, optionally preceded by a mirror image, that maps a hexagonal grid onto itself
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::AxialHex;
+/// use leafwing_2d::grid_transform::HexTransform;
+///
+/// // A room with one hex sticking out to the north
+/// let room = [AxialHex::ORIGIN, AxialHex::NEIGHBOR_OFFSETS[0]];
+///
+/// // Six rotated copies of the room form a symmetric star
+/// let star: Vec<AxialHex> = (0..6)
+///     .flat_map(|turns| HexTransform::rotation(turns).apply_all(room, AxialHex::ORIGIN))
+///     .filter(|&hex| hex != AxialHex::ORIGIN)
+///     .collect();
+/// assert_eq!(star, AxialHex::NEIGHBOR_OFFSETS);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HexTransform {
+    /// The number of clockwise sixth turns, from 0 to 5
+    sixth_turns: u8,
+    /// Is the shape mirrored across the `q` axis through the pivot, before being rotated?
+    mirrored: bool,
+}
+
+impl HexTransform {
+    /// Leaves every hex unchanged
+    pub const IDENTITY: HexTransform = HexTransform {
+        sixth_turns: 0,
+        mirrored: false,
+    };
+
+    /// Mirrors hexes across the `q` axis through the pivot
+    pub const MIRROR: HexTransform = HexTransform {
+        sixth_turns: 0,
+        mirrored: true,
+    };
+
+    /// Every distinct symmetry of a hexagonal grid: the six rotations, followed by their mirror images
+    pub const ALL: [HexTransform; 12] = [
+        HexTransform::rotation(0),
+        HexTransform::rotation(1),
+        HexTransform::rotation(2),
+        HexTransform::rotation(3),
+        HexTransform::rotation(4),
+        HexTransform::rotation(5),
+        HexTransform::rotation(0).mirrored(),
+        HexTransform::rotation(1).mirrored(),
+        HexTransform::rotation(2).mirrored(),
+        HexTransform::rotation(3).mirrored(),
+        HexTransform::rotation(4).mirrored(),
+        HexTransform::rotation(5).mirrored(),
+    ];
+
+    /// A clockwise rotation by `sixth_turns` multiples of 60 degrees
+    ///
+    /// Negative values rotate counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(sixth_turns: i32) -> HexTransform {
+        HexTransform {
+            sixth_turns: sixth_turns.rem_euclid(6) as u8,
+            mirrored: false,
+        }
+    }
+
+    /// This transform, mirrored across the `q` axis through the pivot before rotating
+    #[inline]
+    #[must_use]
+    pub const fn mirrored(self) -> HexTransform {
+        HexTransform {
+            sixth_turns: self.sixth_turns,
+            mirrored: !self.mirrored,
+        }
+    }
+
+    /// The number of clockwise sixth turns, from 0 to 5
+    #[inline]
+    #[must_use]
+    pub const fn sixth_turns(&self) -> u8 {
+        self.sixth_turns
+    }
+
+    /// Is the shape mirrored before it is rotated?
+    #[inline]
+    #[must_use]
+    pub const fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Transforms a single `hex` around the `pivot`
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, hex: AxialHex, pivot: AxialHex) -> AxialHex {
+        let hex = if self.mirrored {
+            hex.mirrored_across(pivot)
+        } else {
+            hex
+        };
+
+        hex.rotated_around(pivot, self.sixth_turns as i32)
+    }
+
+    /// Transforms every hex in `hexes` around the `pivot`, preserving their order
+    #[must_use]
+    pub fn apply_all(
+        &self,
+        hexes: impl IntoIterator<Item = AxialHex>,
+        pivot: AxialHex,
+    ) -> Vec<AxialHex> {
+        hexes
+            .into_iter()
+            .map(|hex| self.apply(hex, pivot))
+            .collect()
+    }
+}
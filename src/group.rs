@@ -0,0 +1,176 @@
+//! Summarize the state of a group of entities
+//!
+//! Add a [`Group`] to an entity that represents the group as a whole (such as a squad or a flock),
+//! and a [`GroupMember`] to each of its members.
+//! The [`update_groups`] system then recomputes the aggregate state of each group every frame,
+//! ready for squad AI, camera framing and level-of-detail decisions.
+
+use crate::bounding::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::kinematics::Velocity;
+use crate::position::Position;
+use alloc::collections::BTreeMap;
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+
+/// Marks an entity as a member of the [`Group`] stored on another entity
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroupMember(pub Entity);
+
+/// The aggregate state of every [`GroupMember`] that belongs to this entity
+///
+/// This is recomputed by [`update_groups`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::group::{update_groups, Group, GroupMember};
+/// use leafwing_2d::position::Position;
+///
+/// fn spawn_squad(mut commands: Commands) {
+///     let squad = commands.spawn().insert(Group::<F32>::default()).id();
+///     for x in 0..4 {
+///         commands
+///             .spawn()
+///             .insert(Position::<F32>::new(x as f32, 0.0))
+///             .insert(GroupMember(squad));
+///     }
+/// }
+///
+/// fn frame_camera(squads: Query<&Group<F32>>) {
+///     for squad in squads.iter() {
+///         if let Some(bounds) = squad.bounds() {
+///             let _width: f32 = (bounds.right - bounds.left).into();
+///             // Zoom out until the whole squad fits on screen
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_startup_system(spawn_squad)
+///     .add_system(update_groups::<F32>.label("update_groups"))
+///     .add_system(frame_camera.after("update_groups"));
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Default)]
+pub struct Group<C: Coordinate> {
+    count: usize,
+    centroid: Position<C>,
+    average_velocity: Velocity<C>,
+    bounds: Option<AxisAlignedBoundingBox<C>>,
+}
+
+impl<C: Coordinate> Group<C> {
+    /// The number of members in this group
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Are there no members in this group?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The mean [`Position`] of the members of this group
+    ///
+    /// This is the origin if the group is empty.
+    #[inline]
+    #[must_use]
+    pub fn centroid(&self) -> Position<C> {
+        self.centroid
+    }
+
+    /// The mean [`Velocity`] of the members of this group
+    ///
+    /// Members without a [`Velocity`] count as stationary.
+    #[inline]
+    #[must_use]
+    pub fn average_velocity(&self) -> Velocity<C> {
+        self.average_velocity
+    }
+
+    /// The smallest [`AxisAlignedBoundingBox`] that contains every member of this group
+    ///
+    /// Members with an [`AxisAlignedBoundingBox`] contribute the whole box, while other members only contribute their [`Position`].
+    /// Returns [`None`] if the group is empty.
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.bounds.as_ref()
+    }
+}
+
+/// Running totals for a single group, used by [`update_groups`]
+#[derive(Default)]
+struct GroupTotals<C: Coordinate> {
+    count: usize,
+    position_sum: Vec2,
+    velocity_sum: Vec2,
+    bounds: Option<AxisAlignedBoundingBox<C>>,
+}
+
+/// Recomputes each [`Group`] from its [`GroupMember`]s
+///
+/// [`GroupMember`]s whose group entity has no [`Group`] component are ignored,
+/// and groups without any members are reset to their empty state.
+pub fn update_groups<C: Coordinate>(
+    mut groups: Query<(Entity, &mut Group<C>)>,
+    members: Query<(Entity, &GroupMember, &Position<C>)>,
+    velocities: Query<&Velocity<C>>,
+    member_bounds: Query<&AxisAlignedBoundingBox<C>>,
+) {
+    let mut totals: BTreeMap<Entity, GroupTotals<C>> = BTreeMap::new();
+
+    for (member, &GroupMember(group), &position) in members.iter() {
+        let totals = totals.entry(group).or_default();
+
+        totals.count += 1;
+        totals.position_sum += Vec2::from(position);
+        if let Ok(&velocity) = velocities.get(member) {
+            totals.velocity_sum += Vec2::new(velocity.x.into(), velocity.y.into());
+        }
+
+        let bounds = member_bounds
+            .get(member)
+            .cloned()
+            .unwrap_or(AxisAlignedBoundingBox {
+                left: position.x,
+                right: position.x,
+                bottom: position.y,
+                top: position.y,
+            });
+        totals.bounds = Some(match totals.bounds.take() {
+            Some(existing) => existing.union(&bounds),
+            None => bounds,
+        });
+    }
+
+    for (entity, mut group) in groups.iter_mut() {
+        let new_group = match totals.remove(&entity) {
+            Some(totals) => {
+                let count = totals.count as f32;
+                let average_velocity = totals.velocity_sum / count;
+
+                Group {
+                    count: totals.count,
+                    centroid: (totals.position_sum / count).into(),
+                    average_velocity: Velocity {
+                        x: C::from(average_velocity.x),
+                        y: C::from(average_velocity.y),
+                    },
+                    bounds: totals.bounds,
+                }
+            }
+            None => Group::default(),
+        };
+
+        // Avoid triggering change detection pointlessly
+        if *group != new_group {
+            *group = new_group;
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Track where entities spend their time
+//!
+//! The [`OccupancyHeatmap`] resource divides the world into square cells,
+//! and accumulates how long [`Occupant`] entities have spent in each of them.
+//! Old samples fade away over time, so the heatmap reflects recent activity.
+
+use crate::coordinate::Coordinate;
+use crate::discrete::OrthogonalGrid;
+use crate::grid_map::GridMap;
+use crate::kinematics::decay_factor;
+use crate::math;
+use crate::position::Position;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+
+/// Marks entities that should be counted by the [`OccupancyHeatmap`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Occupant;
+
+/// A record of how crowded each part of the world has been recently
+///
+/// Each cell stores the number of seconds that [`Occupants`](Occupant) have spent inside of it,
+/// which decays by half every `half_life` seconds.
+/// Use this to let AI find (or avoid) crowded areas, or to draw analytics overlays for designers.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::heatmap::OccupancyHeatmap;
+/// use leafwing_2d::position::Position;
+///
+/// // A 100 x 100 area, split into 10 x 10 cells
+/// let mut heatmap = OccupancyHeatmap::<F32>::new(10, 10, Position::new(-50.0, -50.0), 10.0, 5.0);
+/// heatmap.add(Position::new(3.0, 4.0), 2.0);
+/// heatmap.add(Position::new(7.0, 1.0), 2.0);
+/// assert_eq!(heatmap.heat_at(Position::new(5.0, 5.0)), 4.0);
+///
+/// heatmap.decay(Duration::from_secs(5));
+/// assert_eq!(heatmap.heat_at(Position::new(5.0, 5.0)), 2.0);
+/// assert_eq!(heatmap.hottest_cell_center(), Some(Position::new(5.0, 5.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OccupancyHeatmap<C: Coordinate> {
+    grid: GridMap<f32>,
+    /// The bottom left corner of the heatmap
+    pub origin: Position<C>,
+    /// The width and height of each cell, measured in the [`f32`] conversion of `C`
+    pub cell_size: f32,
+    /// How many seconds it takes for the heat of each cell to halve
+    ///
+    /// Use [`f32::INFINITY`] to never forget old samples.
+    pub half_life: f32,
+}
+
+impl<C: Coordinate> OccupancyHeatmap<C> {
+    /// Creates a new, empty [`OccupancyHeatmap`] with `width` by `height` cells, starting from `origin`
+    ///
+    /// # Panics
+    /// `cell_size` must be greater than zero.
+    #[must_use]
+    pub fn new(
+        width: usize,
+        height: usize,
+        origin: Position<C>,
+        cell_size: f32,
+        half_life: f32,
+    ) -> Self {
+        assert!(cell_size > 0.);
+
+        Self {
+            grid: GridMap::new(width, height, 0.),
+            origin,
+            cell_size,
+            half_life,
+        }
+    }
+
+    /// The heat of every cell
+    #[inline]
+    #[must_use]
+    pub fn grid(&self) -> &GridMap<f32> {
+        &self.grid
+    }
+
+    /// The cell that contains the `position`, if it is inside of the heatmap
+    #[must_use]
+    pub fn cell_at(&self, position: Position<C>) -> Option<Position<OrthogonalGrid>> {
+        let local = (Vec2::from(position) - Vec2::from(self.origin)) / self.cell_size;
+        let cell = Position {
+            x: OrthogonalGrid(math::floor(local.x) as isize),
+            y: OrthogonalGrid(math::floor(local.y) as isize),
+        };
+
+        self.grid.contains(cell).then_some(cell)
+    }
+
+    /// The center of the `cell`, in world space
+    #[must_use]
+    pub fn cell_center(&self, cell: Position<OrthogonalGrid>) -> Position<C> {
+        let local = Vec2::new(cell.x.0 as f32 + 0.5, cell.y.0 as f32 + 0.5) * self.cell_size;
+
+        Position::from(Vec2::from(self.origin) + local)
+    }
+
+    /// The heat of the cell containing the `position`, or zero if it is outside of the heatmap
+    #[must_use]
+    pub fn heat_at(&self, position: Position<C>) -> f32 {
+        self.cell_at(position).map_or(0., |cell| self.grid[cell])
+    }
+
+    /// Adds `amount` of heat to the cell containing the `position`
+    ///
+    /// Positions outside of the heatmap are ignored.
+    pub fn add(&mut self, position: Position<C>, amount: f32) {
+        if let Some(cell) = self.cell_at(position) {
+            self.grid[cell] += amount;
+        }
+    }
+
+    /// Cools down every cell, as `delta_time` passes
+    pub fn decay(&mut self, delta_time: core::time::Duration) {
+        let factor = decay_factor(self.half_life, delta_time);
+        if factor < 1. {
+            for (_, heat) in self.grid.iter_mut() {
+                *heat *= factor;
+            }
+        }
+    }
+
+    /// The center of the hottest cell, or `None` if the heatmap is completely cold
+    #[must_use]
+    pub fn hottest_cell_center(&self) -> Option<Position<C>> {
+        self.grid
+            .iter()
+            .filter(|(_, &heat)| heat > 0.)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(cell, _)| self.cell_center(cell))
+    }
+
+    /// Resets every cell to zero heat
+    pub fn clear(&mut self) {
+        for (_, heat) in self.grid.iter_mut() {
+            *heat = 0.;
+        }
+    }
+}
+
+/// Decays the [`OccupancyHeatmap`] resource, then adds the time that each [`Occupant`] spent in its current cell
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::heatmap::{update_occupancy_heatmap, OccupancyHeatmap};
+/// use leafwing_2d::position::Position;
+///
+/// App::new()
+///     .insert_resource(OccupancyHeatmap::<F32>::new(64, 64, Position::new(-320.0, -320.0), 10.0, 30.0))
+///     .add_system(update_occupancy_heatmap::<F32>);
+/// ```
+pub fn update_occupancy_heatmap<C: Coordinate>(
+    time: Res<Time>,
+    mut heatmap: ResMut<OccupancyHeatmap<C>>,
+    occupants: Query<&Position<C>, With<Occupant>>,
+) {
+    let delta_time = time.delta();
+    heatmap.decay(delta_time);
+
+    let seconds = delta_time.as_secs_f32();
+    for &position in occupants.iter() {
+        heatmap.add(position, seconds);
+    }
+}
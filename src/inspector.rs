@@ -0,0 +1,209 @@
+//! Integration with [`bevy-inspector-egui`](bevy_inspector_egui), enabled by the `inspector` feature
+//!
+//! All of the components in this crate implement [`Inspectable`],
+//! and are registered with the [`InspectableRegistry`](bevy_inspector_egui::InspectableRegistry) by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+//! Rather than showing up as opaque structs, they are displayed using widgets that match their meaning:
+//! - [`Position`], [`Velocity`] and [`Acceleration`] are edited as a pair of `x` and `y` values
+//! - [`Rotation`] and [`Direction`] are edited as a dial in degrees, measured clockwise from midnight
+//! - [`AngularVelocity`] and [`AngularAcceleration`] are edited in degrees per second (per second)
+
+use crate::bounding::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::kinematics::{Acceleration, AngularAcceleration, AngularVelocity, Velocity};
+use crate::orientation::{Direction, Rotation};
+use crate::position::Position;
+use crate::scale::Scale;
+
+use bevy_app::App;
+use bevy_inspector_egui::egui::Ui;
+use bevy_inspector_egui::options::{NumberAttributes, Vec2dAttributes};
+use bevy_inspector_egui::{Context, Inspectable, InspectableRegistry, RegisterInspectable};
+use glam::Vec2;
+
+/// Registers all of the [`Inspectable`] components in this crate that use the coordinate type `C`
+///
+/// This is called automatically by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+pub fn register_inspectables<C: Coordinate>(app: &mut App) {
+    app.init_resource::<InspectableRegistry>();
+
+    app.register_inspectable::<Position<C>>()
+        .register_inspectable::<Velocity<C>>()
+        .register_inspectable::<Acceleration<C>>()
+        .register_inspectable::<AxisAlignedBoundingBox<C>>()
+        .register_inspectable::<Scale<C>>()
+        .register_inspectable::<Rotation>()
+        .register_inspectable::<Direction>()
+        .register_inspectable::<AngularVelocity>()
+        .register_inspectable::<AngularAcceleration>();
+}
+
+/// Displays a pair of coordinates as a [`Vec2`], writing the values back if they were changed
+fn coordinate_pair_ui<C: Coordinate>(
+    x: &mut C,
+    y: &mut C,
+    ui: &mut Ui,
+    options: Vec2dAttributes,
+    context: &mut Context,
+) -> bool {
+    let mut vec2 = Vec2::new((*x).into(), (*y).into());
+    let changed = vec2.ui(ui, options, context);
+
+    if changed {
+        *x = C::from(vec2.x);
+        *y = C::from(vec2.y);
+    }
+
+    changed
+}
+
+/// Displays a [`Rotation`] as a dial in degrees
+fn degrees_ui(rotation: &mut Rotation, ui: &mut Ui, context: &mut Context) -> bool {
+    let mut degrees = rotation.into_degrees();
+    let options = NumberAttributes {
+        speed: 1.0,
+        suffix: "°".to_string(),
+        ..NumberAttributes::between(0.0, 360.0)
+    };
+
+    let changed = degrees.ui(ui, options, context);
+    if changed {
+        *rotation = Rotation::from_degrees(degrees);
+    }
+
+    changed
+}
+
+impl<C: Coordinate> Inspectable for Position<C> {
+    type Attributes = Vec2dAttributes;
+
+    fn ui(&mut self, ui: &mut Ui, options: Vec2dAttributes, context: &mut Context) -> bool {
+        coordinate_pair_ui(&mut self.x, &mut self.y, ui, options, context)
+    }
+}
+
+impl<C: Coordinate> Inspectable for Velocity<C> {
+    type Attributes = Vec2dAttributes;
+
+    fn ui(&mut self, ui: &mut Ui, options: Vec2dAttributes, context: &mut Context) -> bool {
+        coordinate_pair_ui(&mut self.x, &mut self.y, ui, options, context)
+    }
+}
+
+impl<C: Coordinate> Inspectable for Acceleration<C> {
+    type Attributes = Vec2dAttributes;
+
+    fn ui(&mut self, ui: &mut Ui, options: Vec2dAttributes, context: &mut Context) -> bool {
+        coordinate_pair_ui(&mut self.x, &mut self.y, ui, options, context)
+    }
+}
+
+impl<C: Coordinate> Inspectable for AxisAlignedBoundingBox<C> {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        let mut changed = false;
+
+        ui.label("Bottom left");
+        changed |= coordinate_pair_ui(
+            &mut self.left,
+            &mut self.bottom,
+            ui,
+            Vec2dAttributes::default(),
+            context,
+        );
+
+        ui.label("Top right");
+        changed |= coordinate_pair_ui(
+            &mut self.right,
+            &mut self.top,
+            ui,
+            Vec2dAttributes::default(),
+            context,
+        );
+
+        changed
+    }
+}
+
+impl<C: Coordinate> Inspectable for Scale<C> {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        let mut coordinate = C::from(self.0);
+        let mut scale: f32 = coordinate.into();
+
+        let changed = scale.ui(ui, NumberAttributes::min(0.0), context);
+        if changed {
+            coordinate = C::from(scale);
+            self.0 = coordinate.into();
+        }
+
+        changed
+    }
+}
+
+impl Inspectable for Rotation {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        degrees_ui(self, ui, context)
+    }
+}
+
+impl Inspectable for Direction {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        let mut rotation: Rotation = (*self).into();
+
+        let changed = degrees_ui(&mut rotation, ui, context);
+        if changed {
+            *self = rotation.into();
+        }
+
+        let unit_vector = self.unit_vector();
+        ui.label(format!("({:.3}, {:.3})", unit_vector.x, unit_vector.y));
+
+        changed
+    }
+}
+
+impl Inspectable for AngularVelocity {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        let mut degrees = self.deci_degrees / 10.;
+        let options = NumberAttributes {
+            speed: 1.0,
+            suffix: "°/s".to_string(),
+            ..Default::default()
+        };
+
+        let changed = degrees.ui(ui, options, context);
+        if changed {
+            self.deci_degrees = degrees * 10.;
+        }
+
+        changed
+    }
+}
+
+impl Inspectable for AngularAcceleration {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut Ui, _options: (), context: &mut Context) -> bool {
+        let mut degrees = self.deci_degrees / 10.;
+        let options = NumberAttributes {
+            speed: 1.0,
+            suffix: "°/s²".to_string(),
+            ..Default::default()
+        };
+
+        let changed = degrees.ui(ui, options, context);
+        if changed {
+            self.deci_degrees = degrees * 10.;
+        }
+
+        changed
+    }
+}
@@ -1,15 +1,23 @@
 //! Tools for working with [`Velocity`], [`Acceleration`] and their [`Rotation`]-based equivalents
 
+use crate::bounding::BoundingRegion;
 use crate::coordinate::Coordinate;
-use bevy_ecs::component::Component;
+use crate::math;
+use crate::orientation::{Rotation, RotationDirection};
+use crate::position::Position;
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, entity::Entity, world::EntityRef};
+use core::time::Duration;
 use derive_more::{Add, AddAssign, Sub, SubAssign};
+use glam::Vec2;
 
 pub use kinematic_trait::Kinematic;
 
 /// The rate of change of [`Position<C>`]
 ///
 /// When used with [`linear_kinematics`](systems::linear_kinematics), the units are `C` per second
-#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct Velocity<C: Coordinate> {
     /// Velocity along the x-axis of a [`Position<C>`]
     pub x: C,
@@ -20,7 +28,8 @@ pub struct Velocity<C: Coordinate> {
 /// The rate of change of [`Velocity<C>`]
 ///
 /// When used with [`linear_kinematics`](systems::linear_kinematics), the units are `C` per second per second
-#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct Acceleration<C: Coordinate> {
     /// Acceleration along the x-axis of a [`Position<C>`]
     pub x: C,
@@ -28,40 +37,774 @@ pub struct Acceleration<C: Coordinate> {
     pub y: C,
 }
 
+/// How [`linear_kinematics`](systems::linear_kinematics) steps [`Position`] and [`Velocity`] forward each frame
+///
+/// When used with [`TwoDPlugin`](crate::plugin::TwoDPlugin), this is stored as a resource.
+/// If the resource is missing, [`KinematicsIntegrator::SemiImplicitEuler`] is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KinematicsIntegrator {
+    /// Moves by the old [`Velocity`], then applies [`Acceleration`] to it
+    ///
+    /// This is the simplest method, but gains energy over time: orbits spiral outwards and springs swing ever wider.
+    Euler,
+    /// Applies [`Acceleration`] to the [`Velocity`], then moves by the new [`Velocity`]
+    ///
+    /// This costs the same as [`KinematicsIntegrator::Euler`], but keeps orbits and springs stable.
+    /// Under constant acceleration, such as gravity, the path taken depends slightly on the frame rate.
+    #[default]
+    SemiImplicitEuler,
+    /// Velocity Verlet integration, which moves by the old [`Velocity`] plus half of the change caused by [`Acceleration`]
+    ///
+    /// This keeps orbits and springs stable, and follows the exact path under constant acceleration at any frame rate,
+    /// making it the best choice for ropes, soft bodies and jumps that must always reach the same height.
+    /// Each frame's change in [`Velocity`] is corrected at the start of the next frame,
+    /// once the [`Acceleration`] at the new [`Position`] is known: see [`IntegratorState`].
+    Verlet,
+}
+
+/// What [`linear_kinematics`](systems::linear_kinematics) remembers about an entity between frames
+///
+/// This is inserted automatically the first time that an entity is moved, and is included in [`TwoDBundle`](crate::bundles::TwoDBundle).
+/// Under [`KinematicsIntegrator::Verlet`], it stores the [`Acceleration`] and length of the previous frame,
+/// so the change in [`Velocity`] that they caused can be corrected once the next frame's [`Acceleration`] is known.
+/// Entities without one, such as those that were just spawned, start afresh without any correction.
+///
+/// Short-lived pushes, such as those of a [`ForceField`], are collected here too,
+/// and added to the entity's own [`Acceleration`] for the next frame only.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct IntegratorState<C: Coordinate> {
+    previous_step: Option<(Acceleration<C>, Duration)>,
+    extra_acceleration: Acceleration<C>,
+}
+
+impl<C: Coordinate> IntegratorState<C> {
+    /// Adds an [`Acceleration`] that only lasts for the next frame
+    ///
+    /// Call this every frame to keep pushing the entity.
+    #[inline]
+    pub fn add_acceleration(&mut self, acceleration: Acceleration<C>) {
+        self.extra_acceleration += acceleration;
+    }
+
+    /// The total [`Acceleration`] added for the next frame using [`IntegratorState::add_acceleration`]
+    #[inline]
+    #[must_use]
+    pub fn extra_acceleration(&self) -> Acceleration<C> {
+        self.extra_acceleration
+    }
+}
+
+/// A constant [`Acceleration`] that pulls on every entity with the [`GravityAffected`] marker
+///
+/// When used with [`linear_kinematics`](systems::linear_kinematics), this is added to the [`Acceleration`] of those entities,
+/// so it is integrated in the same way as their own.
+/// The [`Default`] value is zero: set this to something like `Acceleration { x: 0.0, y: -9.8 }` for a platformer.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Gravity<C: Coordinate>(pub Acceleration<C>);
+
+/// Marks entities that are pulled by [`Gravity`]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct GravityAffected;
+
+/// A push, which changes the [`Acceleration`] of entities with a [`Mass`]
+///
+/// Forces accumulate: add to them from as many systems as you'd like,
+/// and [`linear_dynamics`](systems::linear_dynamics) will convert the total into [`Acceleration`] and reset it.
+///
+/// The units are [`Mass`] times `C` per second per second.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Force<C: Coordinate> {
+    /// Force along the x-axis of a [`Position<C>`]
+    pub x: C,
+    /// Force along the y-axis of a [`Position<C>`]
+    pub y: C,
+}
+
+/// How hard it is to change the [`Velocity`] of an entity using [`Force`]
+///
+/// The [`Default`] value is `1`, which converts [`Force`] directly into [`Acceleration`].
+/// Entities whose mass is zero or less cannot be pushed by [`Force`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Mass<C: Coordinate>(pub C);
+
+impl<C: Coordinate> Default for Mass<C> {
+    fn default() -> Self {
+        Mass(C::from(1.0))
+    }
+}
+
 /// The rate of change of [`Rotation`]
 ///
 /// When used with [`angular_kinematics`](systems::angular_kinematics), the units are tenth of a degree per second
-#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct AngularVelocity {
     /// Tenth of a degree
     ///
     /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
     /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
-    pub deci_degrees: isize,
+    pub deci_degrees: f32,
+}
+
+impl AngularVelocity {
+    /// Creates a new [`AngularVelocity`] from a number of degrees per second
+    ///
+    /// Positive values are clockwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::kinematics::AngularVelocity;
+    ///
+    /// let spin = AngularVelocity::from_degrees_per_second(-90.0);
+    /// assert_eq!(spin.deci_degrees, -900.0);
+    /// assert_eq!(spin.into_degrees_per_second(), -90.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_degrees_per_second(degrees: f32) -> AngularVelocity {
+        AngularVelocity {
+            deci_degrees: degrees * 10.,
+        }
+    }
+
+    /// Creates a new [`AngularVelocity`] from a number of radians per second
+    ///
+    /// Positive values are clockwise.
+    #[inline]
+    #[must_use]
+    pub fn from_radians_per_second(radians: f32) -> AngularVelocity {
+        AngularVelocity {
+            deci_degrees: radians * 1800. / core::f32::consts::PI,
+        }
+    }
+
+    /// Creates a new [`AngularVelocity`] that turns by `rotation` each second, in the provided `direction`
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::kinematics::AngularVelocity;
+    /// use leafwing_2d::orientation::{Rotation, RotationDirection};
+    ///
+    /// let spin = AngularVelocity::from_rotation_per_second(Rotation::EAST, RotationDirection::CounterClockwise);
+    /// assert_eq!(spin.into_degrees_per_second(), -90.0);
+    /// assert_eq!(
+    ///     spin.into_rotation_per_second(),
+    ///     (Rotation::EAST, Some(RotationDirection::CounterClockwise))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_per_second(
+        rotation: Rotation,
+        direction: RotationDirection,
+    ) -> AngularVelocity {
+        AngularVelocity::new(rotation.deci_degrees(), direction)
+    }
+
+    /// The number of degrees turned each second, where positive values are clockwise
+    #[inline]
+    #[must_use]
+    pub fn into_degrees_per_second(self) -> f32 {
+        self.deci_degrees / 10.
+    }
+
+    /// The number of radians turned each second, where positive values are clockwise
+    #[inline]
+    #[must_use]
+    pub fn into_radians_per_second(self) -> f32 {
+        self.deci_degrees * core::f32::consts::PI / 1800.
+    }
+
+    /// The [`Rotation`] turned each second, and the direction of that turn
+    ///
+    /// The direction is `None` if this velocity is zero.
+    /// Speeds of a full turn per second or more wrap around, as a [`Rotation`] is always less than a full turn.
+    /// The [`Rotation`] is rounded to the nearest tenth of a degree.
+    #[inline]
+    #[must_use]
+    pub fn into_rotation_per_second(self) -> (Rotation, Option<RotationDirection>) {
+        (
+            rotation_from_deci_degrees(self.magnitude()),
+            self.direction(),
+        )
+    }
+}
+
+/// The fraction of a tenth of a degree that an entity has been turned by, but that its [`Rotation`] can't store yet
+///
+/// A [`Rotation`] can only store whole tenths of a degree, so [`angular_kinematics`](systems::angular_kinematics)
+/// collects slow turns here over many short frames until they add up to a visible change.
+/// This is inserted automatically the first time that an entity is turned, and is included in [`TwoDBundle`](crate::bundles::TwoDBundle).
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct RotationRemainder {
+    /// Tenths of a degree, between -0.5 and 0.5
+    ///
+    /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
+    pub deci_degrees: f32,
 }
 
 /// The rate of change of [`AngularVelocity`]
 ///
 /// When used with [`angular_kinematics`](systems::angular_kinematics), the units are tenth of a degree per second per second
-#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct AngularAcceleration {
     /// Tenth of a degree
     ///
     /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
     /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
-    pub deci_degrees: isize,
+    pub deci_degrees: f32,
+}
+
+/// A twisting force, which changes the [`AngularAcceleration`] of entities with a [`MomentOfInertia`]
+///
+/// Torque accumulates: add to it from as many systems as you'd like,
+/// and [`angular_dynamics`](systems::angular_dynamics) will convert the total into [`AngularAcceleration`] and reset it.
+///
+/// The units are [`MomentOfInertia`] times tenths of a degree per second per second.
+/// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
+/// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Torque(pub f32);
+
+impl Torque {
+    /// Creates a new [`Torque`] with the provided `magnitude`, twisting in the provided `direction`
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::kinematics::Torque;
+    /// use leafwing_2d::orientation::RotationDirection;
+    ///
+    /// let mut torque = Torque::new(50.0, RotationDirection::Clockwise);
+    /// torque += Torque::new(80.0, RotationDirection::CounterClockwise);
+    /// assert_eq!(torque, Torque(-30.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(magnitude: f32, direction: RotationDirection) -> Torque {
+        Torque(magnitude * direction.sign() as f32)
+    }
+}
+
+/// How hard it is to change the [`AngularVelocity`] of an entity using [`Torque`]
+///
+/// The [`Default`] value is `1.0`, which converts [`Torque`] directly into [`AngularAcceleration`].
+/// Entities whose moment of inertia is zero or less cannot be spun by [`Torque`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct MomentOfInertia(pub f32);
+
+impl Default for MomentOfInertia {
+    fn default() -> Self {
+        MomentOfInertia(1.0)
+    }
+}
+
+impl MomentOfInertia {
+    /// The moment of inertia of a solid disc with the provided `mass` and `radius`, spinning around its center
+    ///
+    /// Good for wheels, coins and chunks of debris.
+    #[inline]
+    #[must_use]
+    pub fn disc(mass: f32, radius: f32) -> MomentOfInertia {
+        MomentOfInertia(0.5 * mass * radius * radius)
+    }
+
+    /// The moment of inertia of a thin rod with the provided `mass` and `length`, swinging around one of its ends
+    ///
+    /// Good for doors, levers and gates.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::kinematics::MomentOfInertia;
+    ///
+    /// // A door, and a disc that is as wide as the door is long
+    /// assert_eq!(MomentOfInertia::rod(3.0, 2.0), MomentOfInertia(4.0));
+    /// assert_eq!(MomentOfInertia::disc(3.0, 2.0), MomentOfInertia(6.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rod(mass: f32, length: f32) -> MomentOfInertia {
+        MomentOfInertia(mass * length * length / 3.)
+    }
+
+    /// The moment of inertia of a solid rectangle with the provided `mass`, `width` and `height`, spinning around its center
+    ///
+    /// Good for crates, blocky asteroids and spinning platforms.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::kinematics::MomentOfInertia;
+    ///
+    /// assert_eq!(MomentOfInertia::rectangle(12.0, 2.0, 4.0), MomentOfInertia(20.0));
+    /// // Long, thin rectangles spin around their middle more easily than rods swing from one end
+    /// assert!(MomentOfInertia::rectangle(3.0, 2.0, 0.0).0 < MomentOfInertia::rod(3.0, 2.0).0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rectangle(mass: f32, width: f32, height: f32) -> MomentOfInertia {
+        MomentOfInertia(mass * (width * width + height * height) / 12.)
+    }
+}
+
+/// A region, such as a water current or wind tunnel, that pushes everything with a [`Velocity`] inside of it
+///
+/// When used with [`force_fields`](systems::force_fields), the `acceleration` is integrated by [`linear_kinematics`](systems::linear_kinematics)
+/// on top of any [`Acceleration`] of the entities affected.
+/// Entities without an [`Acceleration`] are still pushed: their [`Velocity`] is changed directly according to elapsed time.
+/// The `region` is in world space: overlapping fields stack.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct ForceField<R: BoundingRegion> {
+    /// The area affected by this field
+    pub region: R,
+    /// How quickly entities in the `region` are accelerated
+    pub acceleration: Acceleration<R::C>,
+}
+
+impl<R: BoundingRegion> ForceField<R> {
+    /// Creates a new [`ForceField`] that applies `acceleration` to entities inside of `region`
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::kinematics::{Acceleration, ForceField};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let wind_tunnel = ForceField::new(
+    ///     AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 2.0),
+    ///     Acceleration { x: F32(5.0), y: F32(0.0) },
+    /// );
+    ///
+    /// assert!(wind_tunnel.affects(Position::new(3.0, 1.0)));
+    /// assert!(!wind_tunnel.affects(Position::new(3.0, 5.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(region: R, acceleration: Acceleration<R::C>) -> ForceField<R> {
+        ForceField {
+            region,
+            acceleration,
+        }
+    }
+
+    /// Is an entity at `position` pushed by this field?
+    #[inline]
+    #[must_use]
+    pub fn affects(&self, position: Position<R::C>) -> bool {
+        self.region.contains(position)
+    }
+}
+
+/// Knocks an entity around, separately from the [`Velocity`] that it controls itself
+///
+/// Impulses from hits and explosions are [`pushed`](Knockback::push) onto the knockback,
+/// which moves the entity and fades away according to its [`KnockbackDecay`].
+/// Keeping this apart from [`Velocity`] means that player-controlled movement stays crisp,
+/// and never has to fight against (or accidentally cancel out) being knocked back.
+///
+/// When used with [`apply_knockback`](systems::apply_knockback), the units are `C` per second.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Knockback, KnockbackDecay, Velocity};
+///
+/// let mut knockback = Knockback::<F32>::new(KnockbackDecay::Linear(10.0));
+/// knockback.push(Velocity { x: F32(3.0), y: F32(4.0) });
+/// assert!(knockback.is_active());
+///
+/// // Slows down by 10 units per second, every second
+/// knockback.decay(Duration::from_millis(100));
+/// assert_eq!(knockback.velocity(), Velocity { x: F32(2.4), y: F32(3.2) });
+///
+/// knockback.decay(Duration::from_secs(1));
+/// assert!(!knockback.is_active());
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Knockback<C: Coordinate> {
+    velocity: Velocity<C>,
+    /// How quickly the knockback fades away
+    pub decay: KnockbackDecay,
+}
+
+impl<C: Coordinate> Knockback<C> {
+    /// Knockback slower than this, in `C` per second, is stopped entirely
+    pub const STOPPING_SPEED: f32 = 0.01;
+
+    /// Creates a new [`Knockback`] that isn't moving, and fades away according to `decay`
+    #[inline]
+    #[must_use]
+    pub fn new(decay: KnockbackDecay) -> Knockback<C> {
+        Knockback {
+            velocity: Velocity::default(),
+            decay,
+        }
+    }
+
+    /// Adds an `impulse` to this knockback, on top of any knockback that is still fading away
+    #[inline]
+    pub fn push(&mut self, impulse: Velocity<C>) {
+        self.velocity += impulse;
+    }
+
+    /// The velocity that the entity is currently being knocked back with
+    #[inline]
+    #[must_use]
+    pub fn velocity(&self) -> Velocity<C> {
+        self.velocity
+    }
+
+    /// Is the entity currently being knocked back?
+    #[inline]
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.velocity != Velocity::default()
+    }
+
+    /// Stops the knockback immediately
+    #[inline]
+    pub fn clear(&mut self) {
+        self.velocity = Velocity::default();
+    }
+
+    /// Fades the knockback away, as `delta_time` passes
+    pub fn decay(&mut self, delta_time: Duration) {
+        let speed: f32 = self.velocity.magnitude().into();
+        if speed == 0. {
+            return;
+        }
+
+        let seconds = delta_time.as_secs_f32();
+        let new_speed = match self.decay {
+            KnockbackDecay::Linear(deceleration) => (speed - deceleration * seconds).max(0.),
+            KnockbackDecay::Exponential(half_life) => speed * decay_factor(half_life, delta_time),
+        };
+
+        if new_speed < Self::STOPPING_SPEED {
+            self.clear();
+        } else {
+            self.velocity = self.velocity * (new_speed / speed);
+        }
+    }
+}
+
+/// How quickly a [`Knockback`] fades away
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KnockbackDecay {
+    /// Loses this much speed each second, in `C` per second per second
+    ///
+    /// Knockback with linear decay comes to a stop after a predictable time, like sliding with friction.
+    Linear(f32),
+    /// Loses half of its speed every this many seconds
+    ///
+    /// Knockback with exponential decay starts off sharp, then lingers, like being hit by an explosion.
+    Exponential(f32),
+}
+
+impl Default for KnockbackDecay {
+    fn default() -> Self {
+        KnockbackDecay::Exponential(0.1)
+    }
+}
+
+/// The fraction of a quantity that remains after `delta_time`, if it halves every `half_life` seconds
+///
+/// This is the building block for frame-rate independent smoothing and damping:
+/// multiplying by this factor every frame gives the same result no matter how the elapsed time is split up.
+/// A `half_life` of zero (or less) decays instantly, while [`f32::INFINITY`] never decays.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::kinematics::decay_factor;
+///
+/// assert_eq!(decay_factor(0.5, Duration::from_secs(1)), 0.25);
+/// assert_eq!(decay_factor(0.0, Duration::from_secs(1)), 0.0);
+/// assert_eq!(decay_factor(f32::INFINITY, Duration::from_secs(1)), 1.0);
+/// ```
+#[inline]
+#[must_use]
+pub fn decay_factor(half_life: f32, delta_time: Duration) -> f32 {
+    if half_life > 0. {
+        math::powf(0.5, delta_time.as_secs_f32() / half_life)
+    } else {
+        0.
+    }
+}
+
+/// Smoothly moves `current` towards `target`, closing half of the remaining gap every `half_life` seconds
+///
+/// Like [`decay_factor`], this is independent of the frame rate.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::damp_towards;
+/// use leafwing_2d::position::Position;
+///
+/// let current = Position::<F32>::new(0.0, 0.0);
+/// let target = Position::<F32>::new(8.0, 0.0);
+///
+/// let damped = damp_towards(current, target, 1.0, Duration::from_secs(2));
+/// assert_eq!(damped, Position::new(6.0, 0.0));
+/// ```
+#[must_use]
+pub fn damp_towards<C: Coordinate>(
+    current: Position<C>,
+    target: Position<C>,
+    half_life: f32,
+    delta_time: Duration,
+) -> Position<C> {
+    let remaining = decay_factor(half_life, delta_time);
+
+    Vec2::from(target)
+        .lerp(Vec2::from(current), remaining)
+        .into()
+}
+
+/// Where an entity at `position` moving at `velocity` will be once `duration` has passed, and how fast it will be moving
+///
+/// The `acceleration` is assumed to stay constant, and the result is exact no matter how long the `duration` is.
+/// Nothing is mutated, so this is safe to call speculatively from AI or networking code.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{predict, Acceleration, Velocity};
+/// use leafwing_2d::position::Position;
+///
+/// let (position, velocity) = predict(
+///     Position::<F32>::new(0.0, 0.0),
+///     Velocity { x: F32(4.0), y: F32(0.0) },
+///     Acceleration { x: F32(0.0), y: F32(8.0) },
+///     Duration::from_millis(250),
+/// );
+///
+/// assert_eq!(position, Position::new(1.0, 0.25));
+/// assert_eq!(velocity, Velocity { x: F32(4.0), y: F32(2.0) });
+/// ```
+#[must_use]
+pub fn predict<C: Coordinate>(
+    position: Position<C>,
+    velocity: Velocity<C>,
+    acceleration: Acceleration<C>,
+    duration: Duration,
+) -> (Position<C>, Velocity<C>) {
+    let seconds = duration.as_secs_f32();
+    let velocity = Vec2::from(velocity);
+    let acceleration = Vec2::from(acceleration);
+
+    (
+        Position::from(
+            Vec2::from(position) + velocity * seconds + acceleration * (seconds * seconds / 2.),
+        ),
+        Velocity::from(velocity + acceleration * seconds),
+    )
+}
+
+/// A snapshot of an entity's motion, which can be projected forwards in time without touching its components
+///
+/// Build one from a [`TwoDBundle`](crate::bundles::TwoDBundle) with [`TwoDBundle::kinematic_state`](crate::bundles::TwoDBundle::kinematic_state),
+/// or from a spawned entity with [`KinematicState::from_entity`].
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{AngularVelocity, KinematicState, Velocity};
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::Position;
+///
+/// let now = KinematicState {
+///     velocity: Velocity { x: F32(2.0), y: F32(0.0) },
+///     angular_velocity: AngularVelocity::from_degrees_per_second(90.0),
+///     ..KinematicState::<F32>::default()
+/// };
+///
+/// let soon = now.predict(Duration::from_millis(500));
+/// assert_eq!(soon.position, Position::new(1.0, 0.0));
+/// assert_eq!(soon.rotation, Rotation::from_degrees(45.0));
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct KinematicState<C: Coordinate> {
+    /// Where the entity is
+    pub position: Position<C>,
+    /// How fast the entity is moving
+    pub velocity: Velocity<C>,
+    /// How quickly the entity's velocity is changing
+    pub acceleration: Acceleration<C>,
+    /// Which way the entity is facing
+    pub rotation: Rotation,
+    /// How fast the entity is turning
+    pub angular_velocity: AngularVelocity,
+    /// How quickly the entity's angular velocity is changing
+    pub angular_acceleration: AngularAcceleration,
+}
+
+impl<C: Coordinate> KinematicState<C> {
+    /// The state of the entity once `duration` has passed, assuming both accelerations stay constant
+    #[must_use]
+    pub fn predict(&self, duration: Duration) -> KinematicState<C> {
+        let seconds = duration.as_secs_f32();
+        let (position, velocity) =
+            predict(self.position, self.velocity, self.acceleration, duration);
+        let angular_velocity = self.angular_velocity.deci_degrees;
+        let angular_acceleration = self.angular_acceleration.deci_degrees;
+
+        KinematicState {
+            position,
+            velocity,
+            rotation: self.rotation
+                + rotation_from_deci_degrees(
+                    angular_velocity * seconds + angular_acceleration * (seconds * seconds / 2.),
+                ),
+            angular_velocity: AngularVelocity {
+                deci_degrees: angular_velocity + angular_acceleration * seconds,
+            },
+            ..*self
+        }
+    }
+
+    /// Reads the current state of a spawned entity
+    ///
+    /// Missing motion components are treated as their default values.
+    /// Returns `None` if the entity has no [`Position<C>`].
+    #[cfg(feature = "bevy")]
+    #[must_use]
+    pub fn from_entity(entity: &EntityRef) -> Option<KinematicState<C>> {
+        Some(KinematicState {
+            position: *entity.get::<Position<C>>()?,
+            velocity: entity.get::<Velocity<C>>().copied().unwrap_or_default(),
+            acceleration: entity.get::<Acceleration<C>>().copied().unwrap_or_default(),
+            rotation: entity.get::<Rotation>().copied().unwrap_or_default(),
+            angular_velocity: entity.get::<AngularVelocity>().copied().unwrap_or_default(),
+            angular_acceleration: entity
+                .get::<AngularAcceleration>()
+                .copied()
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Makes an entity trail behind a `target` entity, such as a pet, drone or UI marker
+///
+/// When used with [`follow_targets`](systems::follow_targets), the follower's [`Position`] is smoothly damped towards
+/// the target's [`Position`] plus `offset`, closing half of the gap every `smoothing` seconds.
+/// The `offset` is measured in world space, and does not turn with the target.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::kinematics::Follow;
+/// use leafwing_2d::prelude::*;
+///
+/// fn spawn_pet(mut commands: Commands) {
+///     let player = commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .id();
+///
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(Follow {
+///             target: player,
+///             offset: Position::<F32>::new(-1.0, 1.0),
+///             smoothing: 0.2,
+///             max_speed: Some(F32(10.0)),
+///         });
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_startup_system(spawn_pet);
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Follow<C: Coordinate> {
+    /// The entity to follow
+    pub target: Entity,
+    /// Where the follower should settle, relative to the target's [`Position`]
+    pub offset: Position<C>,
+    /// How many seconds it takes to close half of the distance to the target
+    ///
+    /// Zero snaps straight to the target.
+    pub smoothing: f32,
+    /// The fastest that the follower can move, in `C` per second
+    ///
+    /// If `None`, the follower's speed is unlimited.
+    pub max_speed: Option<C>,
+}
+
+/// Marks an entity as riding on top of another entity, such as a moving platform, boat or elevator
+///
+/// When used with [`carry_riders`](systems::carry_riders), riders move along with their carrier's [`Velocity`],
+/// and orbit its [`Position`] as it turns according to its [`AngularVelocity`].
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CarriedBy(pub Entity);
+
+/// Turns an entity to face the way that it is moving, such as a missile or a fish
+///
+/// When used with [`face_velocity`](systems::face_velocity), the entity's [`Rotation`] and [`Direction`](crate::orientation::Direction)
+/// are turned towards its [`Velocity`] each frame. Entities that aren't moving keep facing the same way.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::kinematics::{AngularVelocity, FaceVelocity};
+///
+/// // Missiles turn quickly, but can still be dodged
+/// let missile = FaceVelocity {
+///     max_turn_rate: Some(AngularVelocity::from_degrees_per_second(180.0)),
+/// };
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct FaceVelocity {
+    /// The fastest that the entity can turn to face its [`Velocity`]
+    ///
+    /// If `None`, the entity snaps to face its [`Velocity`] immediately.
+    pub max_turn_rate: Option<AngularVelocity>,
 }
 
 mod kinematic_trait {
     use super::*;
     use crate::coordinate::Coordinate;
+    use crate::math;
     use crate::orientation::{Direction, RotationDirection};
     use crate::position::Position;
     use core::ops::Mul;
-    use std::time::Duration;
 
     /// A vector type that can be used as a [`Velocity`], [`Acceleration`] or so on
-    pub trait Kinematic: Mul<f32> + Mul<Duration> {
+    ///
+    /// This is also implemented for [`Vec2`], so that your own vector math can be used alongside these types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::kinematics::{Kinematic, Velocity};
+    /// use leafwing_2d::orientation::Direction;
+    ///
+    /// let push = Vec2::new(0.0, 3.0);
+    /// assert_eq!(push.magnitude(), 3.0);
+    /// assert_eq!(push.direction(), Some(Direction::NORTH));
+    /// assert_eq!(<Vec2 as Kinematic>::new(2.0, Direction::EAST), Vec2::new(2.0, 0.0));
+    ///
+    /// let velocity = Velocity::<F32>::from(push);
+    /// assert_eq!(Vec2::from(velocity), push);
+    /// ```
+    pub trait Kinematic: Mul<f32> {
         /// A measure of magnitude
         type M: PartialEq + PartialOrd + Default;
         /// A measure of direction
@@ -99,7 +842,7 @@ mod kinematic_trait {
 
         fn magnitude(&self) -> C {
             let sum_of_squares: f32 = (self.x * self.x + self.y * self.y).into();
-            C::from(sum_of_squares.sqrt())
+            C::from(math::sqrt(sum_of_squares))
         }
 
         fn magnitude_squared(&self) -> C {
@@ -137,7 +880,7 @@ mod kinematic_trait {
 
         fn magnitude(&self) -> C {
             let sum_of_squares: f32 = (self.x * self.x + self.y * self.y).into();
-            C::from(sum_of_squares.sqrt())
+            C::from(math::sqrt(sum_of_squares))
         }
 
         fn magnitude_squared(&self) -> C {
@@ -158,68 +901,92 @@ mod kinematic_trait {
         }
     }
 
+    impl Kinematic for Vec2 {
+        type M = f32;
+        type D = Direction;
+
+        fn new<IM: Into<Self::M>, ID: Into<Self::D>>(magnitude: IM, direction: ID) -> Self {
+            let magnitude: f32 = magnitude.into();
+            let direction: Direction = direction.into();
+
+            direction.unit_vector() * magnitude
+        }
+
+        fn magnitude(&self) -> f32 {
+            self.length()
+        }
+
+        fn magnitude_squared(&self) -> f32 {
+            self.length_squared()
+        }
+
+        fn direction(&self) -> Option<Direction> {
+            Direction::try_from(*self).ok()
+        }
+    }
+
     impl Kinematic for AngularVelocity {
         /// Tenths of a degree
-        type M = isize;
+        type M = f32;
         type D = RotationDirection;
 
         fn new<IM: Into<Self::M>, ID: Into<Self::D>>(magnitude: IM, direction: ID) -> Self {
-            let magnitude: isize = magnitude.into();
+            let magnitude: f32 = magnitude.into();
             let direction: RotationDirection = direction.into();
 
             AngularVelocity {
-                deci_degrees: magnitude * direction.sign(),
+                deci_degrees: magnitude * direction.sign() as f32,
             }
         }
 
-        fn magnitude(&self) -> isize {
+        fn magnitude(&self) -> f32 {
             self.deci_degrees.abs()
         }
 
-        fn magnitude_squared(&self) -> isize {
+        fn magnitude_squared(&self) -> f32 {
             self.deci_degrees * self.deci_degrees
         }
 
         fn direction(&self) -> Option<RotationDirection> {
-            let degrees = self.deci_degrees;
-            match degrees {
-                _neg if degrees < 0 => Some(RotationDirection::CounterClockwise),
-                _zero if degrees == 0 => None,
-                _pos if degrees > 0 => Some(RotationDirection::Clockwise),
-                _ => unreachable!(),
+            if self.deci_degrees < 0. {
+                Some(RotationDirection::CounterClockwise)
+            } else if self.deci_degrees > 0. {
+                Some(RotationDirection::Clockwise)
+            } else {
+                None
             }
         }
     }
 
     impl Kinematic for AngularAcceleration {
         /// Tenths of a degree
-        type M = isize;
+        type M = f32;
         type D = RotationDirection;
 
         fn new<IM: Into<Self::M>, ID: Into<Self::D>>(magnitude: IM, direction: ID) -> Self {
-            let magnitude: isize = magnitude.into();
+            let magnitude: f32 = magnitude.into();
             let direction: RotationDirection = direction.into();
 
             AngularAcceleration {
-                deci_degrees: magnitude * direction.sign(),
+                deci_degrees: magnitude * direction.sign() as f32,
             }
         }
 
-        fn magnitude(&self) -> isize {
+        fn magnitude(&self) -> f32 {
             self.deci_degrees.abs()
         }
 
-        fn magnitude_squared(&self) -> isize {
+        fn magnitude_squared(&self) -> f32 {
             self.deci_degrees * self.deci_degrees
         }
 
         fn direction(&self) -> Option<RotationDirection> {
-            let degrees = self.deci_degrees;
-            match degrees {
-                _neg if degrees < 0 => Some(RotationDirection::CounterClockwise),
-                _zero if degrees == 0 => None,
-                _pos if degrees > 0 => Some(RotationDirection::Clockwise),
-                _ => unreachable!(),
+            if self.deci_degrees < 0. {
+                Some(RotationDirection::CounterClockwise)
+            } else if self.deci_degrees > 0. {
+                Some(RotationDirection::Clockwise)
+            } else {
+                None
             }
         }
     }
@@ -228,35 +995,504 @@ mod kinematic_trait {
 /// Systems that apply kinematics in your `bevy` game.
 ///
 /// These can be included as part of [`crate::plugin::TwoDPlugin`].
+#[cfg(feature = "bevy")]
 pub mod systems {
     use super::*;
 
-    use crate::orientation::Rotation;
-    use crate::position::Position;
+    use crate::orientation::{Direction, Orientation, Rotation};
+    use alloc::vec::Vec;
     use bevy_core::Time;
     use bevy_ecs::prelude::*;
+    use glam::Vec2;
 
-    /// Applies [`Acceleration`] and [`Velocity`] according to elapsed [`Time`]
+    /// Applies [`Acceleration`] and [`Velocity`] according to elapsed [`Time`], using the [`KinematicsIntegrator`] resource
+    ///
+    /// [`Gravity`] and the extra acceleration collected in each entity's [`IntegratorState`] are added to its own [`Acceleration`],
+    /// and the [`IntegratorState`] is inserted into each entity the first time that it is moved.
     pub fn linear_kinematics<C: Coordinate>(
+        mut commands: Commands,
         time: Res<Time>,
-        mut query: Query<(&mut Position<C>, &mut Velocity<C>, &Acceleration<C>)>,
+        integrator: Option<Res<KinematicsIntegrator>>,
+        gravity: Option<Res<Gravity<C>>>,
+        mut query: Query<(
+            Entity,
+            &mut Position<C>,
+            &mut Velocity<C>,
+            &Acceleration<C>,
+            Option<&mut IntegratorState<C>>,
+            Option<&GravityAffected>,
+        )>,
     ) {
         let delta_time = time.delta();
-        for (mut position, mut velocity, acceleration) in query.iter_mut() {
-            *velocity += *acceleration * delta_time;
-            *position += *velocity * delta_time;
+        let integrator =
+            integrator.map_or(KinematicsIntegrator::default(), |integrator| *integrator);
+        let gravity = gravity.map_or(Acceleration::default(), |gravity| gravity.0);
+
+        for (entity, mut position, mut velocity, &acceleration, state, gravity_affected) in
+            query.iter_mut()
+        {
+            let previous_step = state.as_ref().and_then(|state| state.previous_step);
+            let mut acceleration = acceleration;
+            if gravity_affected.is_some() {
+                acceleration += gravity;
+            }
+            if let Some(state) = &state {
+                acceleration += state.extra_acceleration;
+            }
+
+            let previous_step = match integrator {
+                KinematicsIntegrator::Euler => {
+                    *position += *velocity * delta_time;
+                    *velocity += acceleration * delta_time;
+                    None
+                }
+                KinematicsIntegrator::SemiImplicitEuler => {
+                    *velocity += acceleration * delta_time;
+                    *position += *velocity * delta_time;
+                    None
+                }
+                KinematicsIntegrator::Verlet => {
+                    // Correct the previous frame's change in velocity, now that the acceleration at this position is known
+                    if let Some((previous_acceleration, previous_delta_time)) = previous_step {
+                        *velocity +=
+                            (acceleration - previous_acceleration) * (previous_delta_time / 2);
+                    }
+                    *position +=
+                        *velocity * delta_time + (acceleration * (delta_time / 2)) * delta_time;
+                    *velocity += acceleration * delta_time;
+                    Some((acceleration, delta_time))
+                }
+            };
+
+            match state {
+                Some(mut state) => {
+                    // Avoid triggering change detection pointlessly
+                    if state.previous_step != previous_step {
+                        state.previous_step = previous_step;
+                    }
+                    if state.extra_acceleration != Acceleration::default() {
+                        state.extra_acceleration = Acceleration::default();
+                    }
+                }
+                None => {
+                    commands.entity(entity).insert(IntegratorState {
+                        previous_step,
+                        extra_acceleration: Acceleration::default(),
+                    });
+                }
+            }
         }
     }
 
+    /// Turns a [`Rotation`] by `velocity` over `delta_time`, returning the new rotation
+    ///
+    /// A [`Rotation`] can only store whole tenths of a degree,
+    /// so the fraction left over from previous frames is added in, and what's left over now is stored in `remainder`.
+    /// This lets slow turns add up over many short frames, rather than being rounded away each frame.
+    fn turn(
+        rotation: Rotation,
+        velocity: AngularVelocity,
+        delta_time: Duration,
+        remainder: &mut f32,
+    ) -> Rotation {
+        let deci_degrees = velocity.deci_degrees * delta_time.as_secs_f32() + *remainder;
+        let whole_deci_degrees = math::round(deci_degrees);
+        *remainder = deci_degrees - whole_deci_degrees;
+
+        rotation + rotation_from_deci_degrees(whole_deci_degrees)
+    }
+
     /// Applies [`AngularAcceleration`] and [`AngularVelocity`] according to elapsed [`Time`]
+    ///
+    /// Turns of less than a tenth of a degree per frame are accumulated in each entity's [`RotationRemainder`],
+    /// which is inserted the first time that it is needed, until they add up to a visible change in [`Rotation`].
     pub fn angular_kinematics(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut query: Query<(
+            Entity,
+            &mut Rotation,
+            &mut AngularVelocity,
+            &AngularAcceleration,
+            Option<&mut RotationRemainder>,
+        )>,
+    ) {
+        let delta_time = time.delta();
+        for (entity, mut rotation, mut velocity, acceleration, remainder) in query.iter_mut() {
+            *velocity += *acceleration * delta_time;
+
+            let mut new_remainder = remainder.as_deref().copied().unwrap_or_default();
+            let new_rotation = turn(
+                *rotation,
+                *velocity,
+                delta_time,
+                &mut new_remainder.deci_degrees,
+            );
+            // Avoid triggering change detection pointlessly
+            if *rotation != new_rotation {
+                *rotation = new_rotation;
+            }
+            store_remainder(&mut commands, entity, remainder, new_remainder);
+        }
+    }
+
+    /// Applies [`AngularAcceleration`] and [`AngularVelocity`] to entities that have a [`Direction`] but no [`Rotation`]
+    ///
+    /// Entities with both components are turned by [`angular_kinematics`] instead,
+    /// and their [`Direction`] is kept in sync by [`sync_direction_and_rotation`](crate::plugin::sync_direction_and_rotation).
+    pub fn direction_angular_kinematics(
+        mut commands: Commands,
         time: Res<Time>,
-        mut query: Query<(&mut Rotation, &mut AngularVelocity, &AngularAcceleration)>,
+        mut query: Query<
+            (
+                Entity,
+                &mut Direction,
+                &mut AngularVelocity,
+                &AngularAcceleration,
+                Option<&mut RotationRemainder>,
+            ),
+            Without<Rotation>,
+        >,
     ) {
         let delta_time = time.delta();
-        for (mut position, mut velocity, acceleration) in query.iter_mut() {
+        for (entity, mut direction, mut velocity, acceleration, remainder) in query.iter_mut() {
             *velocity += *acceleration * delta_time;
-            *position += *velocity * delta_time;
+
+            let mut new_remainder = remainder.as_deref().copied().unwrap_or_default();
+            let rotation = turn(
+                Rotation::from(*direction),
+                *velocity,
+                delta_time,
+                &mut new_remainder.deci_degrees,
+            );
+            let new_direction = Direction::from(rotation);
+            // Avoid triggering change detection pointlessly
+            if *direction != new_direction {
+                *direction = new_direction;
+            }
+            store_remainder(&mut commands, entity, remainder, new_remainder);
+        }
+    }
+
+    /// Saves the `new_remainder` of a turn, inserting a [`RotationRemainder`] into the entity if it doesn't have one yet
+    fn store_remainder(
+        commands: &mut Commands,
+        entity: Entity,
+        remainder: Option<Mut<RotationRemainder>>,
+        new_remainder: RotationRemainder,
+    ) {
+        match remainder {
+            // Avoid triggering change detection pointlessly
+            Some(mut remainder) => {
+                if *remainder != new_remainder {
+                    *remainder = new_remainder;
+                }
+            }
+            None => {
+                if new_remainder != RotationRemainder::default() {
+                    commands.entity(entity).insert(new_remainder);
+                }
+            }
+        }
+    }
+
+    /// Moves each entity that is [`CarriedBy`] another entity along with it, according to elapsed [`Time`]
+    ///
+    /// Riders are moved by their carrier's [`Velocity`],
+    /// and swung around their carrier's [`Position`] by its [`AngularVelocity`].
+    /// This is in addition to the rider's own movement, so characters can walk around on moving platforms.
+    /// Riders are not turned to face the carrier's new orientation.
+    ///
+    /// This should run before [`linear_kinematics`] and [`angular_kinematics`],
+    /// so riders are moved relative to where their carrier was at the start of the frame.
+    /// Carriers without a [`Velocity`] or [`AngularVelocity`] simply don't move their riders in that way.
+    pub fn carry_riders<C: Coordinate>(
+        time: Res<Time>,
+        riders: Query<(Entity, &CarriedBy)>,
+        carriers: Query<(Option<&Velocity<C>>, Option<&AngularVelocity>)>,
+        mut positions: Query<&mut Position<C>>,
+    ) {
+        let delta_time = time.delta();
+        let mut displacements = Vec::new();
+
+        for (rider, &CarriedBy(carrier)) in riders.iter() {
+            let (velocity, angular_velocity) = match carriers.get(carrier) {
+                Ok(motion) => motion,
+                Err(_) => continue,
+            };
+            let mut displacement =
+                velocity.map_or(Position::default(), |&velocity| velocity * delta_time);
+
+            if let Some(angular_velocity) = angular_velocity {
+                if let (Ok(&rider_position), Ok(&carrier_position)) =
+                    (positions.get(rider), positions.get(carrier))
+                {
+                    // Positive angular velocities are clockwise
+                    let angle =
+                        angular_velocity.into_radians_per_second() * delta_time.as_secs_f32();
+                    let (sin, cos) = (math::sin(angle), math::cos(angle));
+                    let offset = Vec2::from(rider_position - carrier_position);
+                    let rotated = Vec2::new(
+                        offset.x * cos + offset.y * sin,
+                        offset.y * cos - offset.x * sin,
+                    );
+
+                    displacement += Position::from(rotated - offset);
+                }
+            }
+
+            displacements.push((rider, displacement));
+        }
+
+        for (rider, displacement) in displacements {
+            if let Ok(mut position) = positions.get_mut(rider) {
+                *position += displacement;
+            }
+        }
+    }
+
+    /// Moves each entity with a [`Follow`] component towards its target, according to elapsed [`Time`]
+    ///
+    /// This should run after any other systems that move the target, so that followers chase its latest [`Position`].
+    /// Followers whose target no longer has a [`Position`] stay where they are.
+    pub fn follow_targets<C: Coordinate>(
+        time: Res<Time>,
+        followers: Query<(Entity, &Follow<C>)>,
+        mut positions: Query<&mut Position<C>>,
+    ) {
+        let delta_time = time.delta();
+
+        for (follower, follow) in followers.iter() {
+            let target = match positions.get(follow.target) {
+                Ok(&target_position) => target_position + follow.offset,
+                Err(_) => continue,
+            };
+            let mut position = match positions.get_mut(follower) {
+                Ok(position) => position,
+                Err(_) => continue,
+            };
+
+            let current = Vec2::from(*position);
+            let damped = Vec2::from(damp_towards(
+                *position,
+                target,
+                follow.smoothing,
+                delta_time,
+            ));
+            let mut step = damped - current;
+            if let Some(max_speed) = follow.max_speed {
+                let max_speed: f32 = max_speed.into();
+                step = step.clamp_length_max(max_speed * delta_time.as_secs_f32());
+            }
+
+            let new_position = Position::from(current + step);
+            // Avoid triggering change detection pointlessly
+            if *position != new_position {
+                *position = new_position;
+            }
+        }
+    }
+
+    /// Moves each entity by its [`Knockback`], then fades the knockback away according to elapsed [`Time`]
+    pub fn apply_knockback<C: Coordinate>(
+        time: Res<Time>,
+        mut query: Query<(&mut Position<C>, &mut Knockback<C>)>,
+    ) {
+        let delta_time = time.delta();
+        for (mut position, mut knockback) in query.iter_mut() {
+            if knockback.is_active() {
+                *position += knockback.velocity() * delta_time;
+                knockback.decay(delta_time);
+            }
+        }
+    }
+
+    /// Adds the [`Acceleration`] of every [`ForceField`] with a region of type `R` to the [`IntegratorState`] of the entities inside of it
+    ///
+    /// [`linear_kinematics`] then integrates it along with their own [`Acceleration`].
+    /// Entities that it will not integrate, because they have no [`Acceleration`] or have not been given an [`IntegratorState`] yet,
+    /// have the field applied to their [`Velocity`] according to elapsed [`Time`] instead.
+    /// Add one copy of this system for each type of region used by your force fields,
+    /// before [`TwoDSystem::Kinematics`](crate::plugin::TwoDSystem::Kinematics).
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Circle};
+    /// use leafwing_2d::kinematics::systems::force_fields;
+    /// use leafwing_2d::plugin::TwoDSystem;
+    /// use leafwing_2d::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugin(TwoDPlugin::default())
+    ///     .add_system(force_fields::<F32, AxisAlignedBoundingBox<F32>>.before(TwoDSystem::Kinematics))
+    ///     .add_system(force_fields::<F32, Circle<F32>>.before(TwoDSystem::Kinematics));
+    /// ```
+    pub fn force_fields<C: Coordinate, R: BoundingRegion<C = C> + Send + Sync + 'static>(
+        time: Res<Time>,
+        fields: Query<&ForceField<R>>,
+        mut query: Query<(
+            &Position<C>,
+            &mut Velocity<C>,
+            Option<&mut IntegratorState<C>>,
+            Option<&Acceleration<C>>,
+        )>,
+    ) {
+        let delta_time = time.delta();
+        for (&position, mut velocity, state, acceleration) in query.iter_mut() {
+            let fields = fields.iter().filter(|field| field.affects(position));
+            match (state, acceleration) {
+                (Some(mut state), Some(_)) => {
+                    for field in fields {
+                        state.add_acceleration(field.acceleration);
+                    }
+                }
+                _ => {
+                    for field in fields {
+                        *velocity += field.acceleration * delta_time;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Turns each entity with [`FaceVelocity`] towards its [`Velocity`], according to elapsed [`Time`]
+    ///
+    /// Both the [`Rotation`] and [`Direction`] of the entity are updated, if present.
+    /// This should run after any systems that change the [`Velocity`], [`Rotation`] or [`Direction`],
+    /// so any turning caused by an [`AngularVelocity`] is overridden.
+    pub fn face_velocity<C: Coordinate>(
+        time: Res<Time>,
+        mut query: Query<(
+            &Velocity<C>,
+            &FaceVelocity,
+            Option<&mut Rotation>,
+            Option<&mut Direction>,
+        )>,
+    ) {
+        let seconds = time.delta_seconds();
+        for (velocity, face_velocity, rotation, direction) in query.iter_mut() {
+            let target = match Rotation::from_vec2(Vec2::from(*velocity)) {
+                Ok(target) => target,
+                // Stationary entities keep facing the same way
+                Err(_) => continue,
+            };
+            let max_rotation = face_velocity.max_turn_rate.map(|max_turn_rate| {
+                Rotation::from_degrees(max_turn_rate.into_degrees_per_second().abs() * seconds)
+            });
+
+            if let Some(mut rotation) = rotation {
+                let mut new_rotation = *rotation;
+                new_rotation.rotate_towards(target, max_rotation);
+                // Avoid triggering change detection pointlessly
+                if *rotation != new_rotation {
+                    *rotation = new_rotation;
+                }
+            }
+
+            if let Some(mut direction) = direction {
+                let mut new_direction = *direction;
+                new_direction.rotate_towards(Direction::from(target), max_rotation);
+                if *direction != new_direction {
+                    *direction = new_direction;
+                }
+            }
+        }
+    }
+
+    /// Converts the accumulated [`Force`] of each entity into [`Acceleration`], according to its [`Mass`]
+    ///
+    /// [`Force`] is reset to zero afterwards, ready to be accumulated again next frame.
+    /// Run this before [`linear_kinematics`], so the new [`Acceleration`] is applied immediately.
+    pub fn linear_dynamics<C: Coordinate>(
+        mut query: Query<(&mut Force<C>, &Mass<C>, &mut Acceleration<C>)>,
+    ) {
+        for (mut force, mass, mut acceleration) in query.iter_mut() {
+            let mass: f32 = mass.0.into();
+            let new_acceleration = if mass > 0. {
+                let (x, y): (f32, f32) = (force.x.into(), force.y.into());
+                Acceleration {
+                    x: (x / mass).into(),
+                    y: (y / mass).into(),
+                }
+            } else {
+                Acceleration::default()
+            };
+
+            // Avoid triggering change detection when nothing has changed
+            if *acceleration != new_acceleration {
+                *acceleration = new_acceleration;
+            }
+            if *force != Force::default() {
+                *force = Force::default();
+            }
+        }
+    }
+
+    /// Converts the accumulated [`Torque`] of each entity into [`AngularAcceleration`], according to its [`MomentOfInertia`]
+    ///
+    /// [`Torque`] is reset to zero afterwards, ready to be accumulated again next frame.
+    /// Run this before [`angular_kinematics`], so the new [`AngularAcceleration`] is applied immediately.
+    pub fn angular_dynamics(
+        mut query: Query<(&mut Torque, &MomentOfInertia, &mut AngularAcceleration)>,
+    ) {
+        for (mut torque, moment_of_inertia, mut acceleration) in query.iter_mut() {
+            let deci_degrees = if moment_of_inertia.0 > 0. {
+                torque.0 / moment_of_inertia.0
+            } else {
+                0.
+            };
+
+            // Avoid triggering change detection when nothing has changed
+            if acceleration.deci_degrees != deci_degrees {
+                acceleration.deci_degrees = deci_degrees;
+            }
+            if *torque != Torque::default() {
+                *torque = Torque::default();
+            }
+        }
+    }
+}
+
+/// The [`Rotation`] reached by turning `deci_degrees` clockwise from [`Rotation::NORTH`], rounded to the nearest tenth of a degree
+///
+/// Negative values turn counterclockwise, and turns of more than a full circle wrap around.
+fn rotation_from_deci_degrees(deci_degrees: f32) -> Rotation {
+    let deci_degrees = (math::round(deci_degrees) as i64).rem_euclid(Rotation::FULL_CIRCLE as i64);
+
+    Rotation::new(deci_degrees as u16)
+}
+
+mod conversions {
+    use super::*;
+
+    impl<C: Coordinate> From<Vec2> for Velocity<C> {
+        fn from(vec2: Vec2) -> Velocity<C> {
+            Velocity {
+                x: C::from(vec2.x),
+                y: C::from(vec2.y),
+            }
+        }
+    }
+
+    impl<C: Coordinate> From<Velocity<C>> for Vec2 {
+        fn from(velocity: Velocity<C>) -> Vec2 {
+            Vec2::new(velocity.x.into(), velocity.y.into())
+        }
+    }
+
+    impl<C: Coordinate> From<Vec2> for Acceleration<C> {
+        fn from(vec2: Vec2) -> Acceleration<C> {
+            Acceleration {
+                x: C::from(vec2.x),
+                y: C::from(vec2.y),
+            }
+        }
+    }
+
+    impl<C: Coordinate> From<Acceleration<C>> for Vec2 {
+        fn from(acceleration: Acceleration<C>) -> Vec2 {
+            Vec2::new(acceleration.x.into(), acceleration.y.into())
         }
     }
 }
@@ -264,7 +1500,7 @@ pub mod systems {
 mod mul_f32 {
     use super::*;
     use crate::coordinate::Coordinate;
-    use std::ops::Mul;
+    use core::ops::Mul;
 
     impl<C: Coordinate> Mul<f32> for Velocity<C> {
         type Output = Velocity<C>;
@@ -322,10 +1558,8 @@ mod mul_f32 {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: f32) -> Self::Output {
-            let scale = rhs.round() as isize;
-
             Self {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs,
             }
         }
     }
@@ -334,10 +1568,8 @@ mod mul_f32 {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: AngularVelocity) -> AngularVelocity {
-            let scale = self.round() as isize;
-
             AngularVelocity {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self,
             }
         }
     }
@@ -346,10 +1578,8 @@ mod mul_f32 {
         type Output = AngularAcceleration;
 
         fn mul(self, rhs: f32) -> AngularAcceleration {
-            let scale = rhs.round() as isize;
-
             Self {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs,
             }
         }
     }
@@ -358,10 +1588,8 @@ mod mul_f32 {
         type Output = AngularAcceleration;
 
         fn mul(self, rhs: AngularAcceleration) -> AngularAcceleration {
-            let scale = self.round() as isize;
-
             AngularAcceleration {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self,
             }
         }
     }
@@ -371,8 +1599,8 @@ mod mul_duration {
     use super::*;
     use crate::orientation::Rotation;
     use crate::position::Position;
-    use std::ops::Mul;
-    use std::time::Duration;
+    use core::ops::Mul;
+    use core::time::Duration;
 
     impl<C: Coordinate> Mul<Duration> for Velocity<C> {
         type Output = Position<C>;
@@ -430,10 +1658,7 @@ mod mul_duration {
         type Output = Rotation;
 
         fn mul(self, rhs: Duration) -> Rotation {
-            let scale = rhs.as_secs_f32() as isize;
-            let new_deci_degrees = (self.deci_degrees * scale) % 3600;
-
-            Rotation::new(new_deci_degrees as u16)
+            rotation_from_deci_degrees(self.deci_degrees * rhs.as_secs_f32())
         }
     }
 
@@ -441,10 +1666,7 @@ mod mul_duration {
         type Output = Rotation;
 
         fn mul(self, rhs: AngularVelocity) -> Rotation {
-            let scale = self.as_secs_f32() as isize;
-            let new_deci_degrees = (rhs.deci_degrees * scale) % 3600;
-
-            Rotation::new(new_deci_degrees as u16)
+            rotation_from_deci_degrees(rhs.deci_degrees * self.as_secs_f32())
         }
     }
 
@@ -452,10 +1674,8 @@ mod mul_duration {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: Duration) -> AngularVelocity {
-            let scale = rhs.as_secs_f32() as isize;
-
             AngularVelocity {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs.as_secs_f32(),
             }
         }
     }
@@ -464,10 +1684,8 @@ mod mul_duration {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: AngularAcceleration) -> AngularVelocity {
-            let scale = self.as_secs_f32() as isize;
-
             AngularVelocity {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self.as_secs_f32(),
             }
         }
     }
@@ -1,32 +1,79 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(missing_docs)]
 #![forbid(unsafe_code)]
 #![warn(clippy::doc_markdown)]
 #![doc = include_str!("../README.md")]
 
+extern crate alloc;
+
+pub mod batch;
 pub mod bounding;
+#[cfg(feature = "bevy")]
 pub mod bundles;
+#[cfg(feature = "bevy")]
+pub mod collision;
 pub mod continuous;
 pub mod coordinate;
+#[cfg(feature = "bevy")]
+pub mod dash;
 pub mod discrete;
 pub mod errors;
+pub mod flood_fill;
+pub mod formation;
+pub mod fov;
+pub mod geometry;
+pub mod grid_map;
+pub mod grid_transform;
+#[cfg(feature = "bevy")]
+pub mod group;
+#[cfg(feature = "bevy")]
+pub mod heatmap;
+#[cfg(feature = "inspector")]
+pub mod inspector;
 pub mod kinematics;
+pub(crate) mod math;
 pub mod orientation;
+#[cfg(feature = "bevy")]
+pub mod parallax;
 pub mod partitioning;
+pub mod path;
+pub mod pathfinding;
+#[cfg(feature = "picking")]
+pub mod picking;
+#[cfg(feature = "bevy")]
 pub mod plugin;
+pub mod polygon;
 pub mod position;
+#[cfg(feature = "bevy")]
+pub mod remote;
+#[cfg(feature = "bevy")]
+pub mod rewind;
 pub mod scale;
+#[cfg(feature = "bevy")]
+pub mod spatial;
+pub mod steering;
+pub mod sweep;
+pub mod tessellation;
+#[cfg(feature = "bevy")]
+pub mod trail;
+pub mod trajectory;
+pub mod transform;
+#[cfg(feature = "bevy")]
+pub mod vision;
 
 /// The most commonly useful bits of the library
 pub mod prelude {
     pub use crate::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+    #[cfg(feature = "bevy")]
     pub use crate::bundles::TwoDBundle;
     pub use crate::continuous::F32;
     pub use crate::coordinate::Coordinate;
-    pub use crate::discrete::DiscreteCoordinate;
+    pub use crate::discrete::{DiscreteCoordinate, GridMetric};
     pub use crate::kinematics::{
         Acceleration, AngularAcceleration, AngularVelocity, Kinematic, Velocity,
     };
     pub use crate::orientation::{Direction, Orientation, OrientationPositionInterop, Rotation};
+    #[cfg(feature = "bevy")]
     pub use crate::plugin::TwoDPlugin;
     pub use crate::position::{Position, Positionlike};
 }
@@ -0,0 +1,252 @@
+//! Floating point functions that work with or without the standard library
+//!
+//! When the `std` feature is enabled, these forward to the inherent methods on [`f32`] and [`f64`].
+//! Otherwise, the `libm` feature must be enabled, and its software implementations are used instead.
+//!
+//! When the `deterministic` feature is enabled, the trigonometric functions are instead computed in software
+//! using only basic arithmetic, which is guaranteed to produce bit-identical results on every platform.
+//!
+//! Always use these functions (rather than the inherent float methods) for math that is not provided by `core`.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("`leafwing_2d` requires either the `std` or the `libm` feature to be enabled.");
+
+#[cfg(feature = "std")]
+mod backend {
+    #[inline]
+    pub(crate) fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    #[inline]
+    pub(crate) fn round(x: f32) -> f32 {
+        x.round()
+    }
+
+    #[inline]
+    pub(crate) fn round_f64(x: f64) -> f64 {
+        x.round()
+    }
+
+    #[cfg(feature = "bevy")]
+    #[inline]
+    pub(crate) fn floor(x: f32) -> f32 {
+        x.floor()
+    }
+
+    #[inline]
+    pub(crate) fn abs(x: f32) -> f32 {
+        x.abs()
+    }
+
+    #[inline]
+    pub(crate) fn powf(x: f32, n: f32) -> f32 {
+        x.powf(n)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod backend {
+    #[inline]
+    pub(crate) fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+
+    #[inline]
+    pub(crate) fn round(x: f32) -> f32 {
+        libm::roundf(x)
+    }
+
+    #[inline]
+    pub(crate) fn round_f64(x: f64) -> f64 {
+        libm::round(x)
+    }
+
+    #[cfg(feature = "bevy")]
+    #[inline]
+    pub(crate) fn floor(x: f32) -> f32 {
+        libm::floorf(x)
+    }
+
+    #[inline]
+    pub(crate) fn abs(x: f32) -> f32 {
+        libm::fabsf(x)
+    }
+
+    #[inline]
+    pub(crate) fn powf(x: f32, n: f32) -> f32 {
+        libm::powf(x, n)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) use backend::*;
+
+#[cfg(feature = "deterministic")]
+pub(crate) use deterministic::{atan2, cos, sin};
+
+/// Trigonometric functions that only use addition, subtraction, multiplication and division
+///
+/// These operations are exactly specified by IEEE 754, unlike the platform-provided implementations of `sin`, `cos` and `atan2`,
+/// so the same inputs always produce the same outputs, no matter the target or compiler.
+/// All intermediate work is done in [`f64`], and the results are accurate to within a few ulps of [`f32`].
+#[cfg(feature = "deterministic")]
+mod deterministic {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_6, PI, TAU};
+
+    /// `tan(π/12)`: inputs to [`atan_reduced`] above this are shifted by π/6
+    const TAN_PI_12: f64 = 0.267_949_192_431_122_7;
+    const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+    /// Taylor series for `sin(r)`, for `r` in `[-π/4, π/4]`
+    fn sin_reduced(r: f64) -> f64 {
+        let r2 = r * r;
+        let mut term = r;
+        let mut sum = r;
+        for n in 1..8 {
+            term *= -r2 / f64::from((2 * n) * (2 * n + 1));
+            sum += term;
+        }
+        sum
+    }
+
+    /// Taylor series for `cos(r)`, for `r` in `[-π/4, π/4]`
+    fn cos_reduced(r: f64) -> f64 {
+        let r2 = r * r;
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for n in 1..8 {
+            term *= -r2 / f64::from((2 * n - 1) * (2 * n));
+            sum += term;
+        }
+        sum
+    }
+
+    /// Splits `x` into a quadrant (0 through 3) and a remainder in `[-π/4, π/4]`
+    fn reduce(x: f32) -> (u8, f64) {
+        let x = f64::from(x) % TAU;
+        let x = if x < 0.0 { x + TAU } else { x };
+
+        // `x` is now in [0, τ), so this truncation rounds to the nearest quarter turn
+        let quarter_turns = (x / FRAC_PI_2 + 0.5) as u8;
+        let remainder = x - f64::from(quarter_turns) * FRAC_PI_2;
+
+        (quarter_turns % 4, remainder)
+    }
+
+    pub(crate) fn sin(x: f32) -> f32 {
+        let (quadrant, r) = reduce(x);
+        let result = match quadrant {
+            0 => sin_reduced(r),
+            1 => cos_reduced(r),
+            2 => -sin_reduced(r),
+            _ => -cos_reduced(r),
+        };
+        result as f32
+    }
+
+    pub(crate) fn cos(x: f32) -> f32 {
+        let (quadrant, r) = reduce(x);
+        let result = match quadrant {
+            0 => cos_reduced(r),
+            1 => -sin_reduced(r),
+            2 => -cos_reduced(r),
+            _ => sin_reduced(r),
+        };
+        result as f32
+    }
+
+    /// Taylor series for `atan(z)`, for `z` in `[-tan(π/12), tan(π/12)]`
+    fn atan_series(z: f64) -> f64 {
+        let z2 = z * z;
+        let mut term = z;
+        let mut sum = z;
+        for n in 1..9 {
+            term *= -z2;
+            sum += term / f64::from(2 * n + 1);
+        }
+        sum
+    }
+
+    /// `atan(a)` for `a` in `[0, 1]`
+    fn atan_reduced(a: f64) -> f64 {
+        if a > TAN_PI_12 {
+            FRAC_PI_6 + atan_series((a * SQRT_3 - 1.0) / (a + SQRT_3))
+        } else {
+            atan_series(a)
+        }
+    }
+
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        let (y, x) = (f64::from(y), f64::from(x));
+        let (abs_y, abs_x) = (if y < 0.0 { -y } else { y }, if x < 0.0 { -x } else { x });
+
+        if abs_x == 0.0 && abs_y == 0.0 {
+            return 0.0;
+        }
+
+        // Angle from the positive x-axis within the first quadrant
+        let mut angle = if abs_y <= abs_x {
+            atan_reduced(abs_y / abs_x)
+        } else {
+            FRAC_PI_2 - atan_reduced(abs_x / abs_y)
+        };
+
+        if x < 0.0 {
+            angle = PI - angle;
+        }
+        if y < 0.0 {
+            angle = -angle;
+        }
+
+        angle as f32
+    }
+}
+
+/// The least non-negative remainder of `x` divided by `rhs`
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub(crate) fn rem_euclid(x: f32, rhs: f32) -> f32 {
+    let remainder = x % rhs;
+    if remainder < 0.0 {
+        remainder + abs(rhs)
+    } else {
+        remainder
+    }
+}
@@ -1,16 +1,24 @@
 //! Direction and rotation for spinning around in 2 dimensions
 
+pub use angle::Angle;
+pub use angular_cone::AngularCone;
 pub use direction::Direction;
 pub use orientation_position_trait::OrientationPositionInterop;
 pub use orientation_trait::Orientation;
 pub use rotation::Rotation;
 pub use rotation_direction::RotationDirection;
 
+#[cfg(feature = "bevy")]
+pub(crate) use conversions::quat_heading;
+#[cfg(feature = "bevy")]
+pub(crate) use conversions::split_yaw;
+
 mod orientation_trait {
     use super::{Direction, Rotation, RotationDirection};
-    use bevy_math::Quat;
+    #[cfg(feature = "bevy")]
     use bevy_transform::components::{GlobalTransform, Transform};
     use core::fmt::Debug;
+    use glam::Quat;
 
     /// A type that can represent a orientation in 2D space
     pub trait Orientation: Sized + Debug + From<Rotation> + Into<Rotation> + Copy {
@@ -187,6 +195,7 @@ mod orientation_trait {
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl Orientation for Transform {
         fn distance(&self, other: Transform) -> Rotation {
             let self_rotation: Rotation = (*self).into();
@@ -195,6 +204,7 @@ mod orientation_trait {
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl Orientation for GlobalTransform {
         fn distance(&self, other: GlobalTransform) -> Rotation {
             let self_rotation: Rotation = (*self).into();
@@ -287,6 +297,129 @@ mod orientation_position_trait {
     }
 }
 
+mod angular_cone {
+    use super::Rotation;
+
+    /// A contiguous range of orientations, centered on `facing` and extending `half_width` to either side
+    ///
+    /// Cones with a `half_width` of 180 degrees or more cover the full circle.
+    ///
+    /// The cone is stored as its counterclockwise edge and its total width,
+    /// so cones built with [`AngularCone::from_bounds`] cover exactly the requested range,
+    /// even when it spans an odd number of tenths of a degree.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::{AngularCone, Direction, Rotation};
+    ///
+    /// let field_of_view = AngularCone::new(Rotation::NORTH, Rotation::from_degrees(45.));
+    ///
+    /// assert!(field_of_view.contains(Direction::NORTH));
+    /// assert!(field_of_view.contains(Rotation::from_degrees(-30.)));
+    /// assert!(field_of_view.contains(Direction::NORTHEAST));
+    /// assert!(!field_of_view.contains(Direction::EAST));
+    ///
+    /// assert_eq!(field_of_view.start(), Rotation::NORTHWEST);
+    /// assert_eq!(field_of_view.end(), Rotation::NORTHEAST);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+    pub struct AngularCone {
+        /// The most counterclockwise orientation in the cone
+        start: Rotation,
+        /// How far the cone extends clockwise from `start`, in tenths of a degree
+        ///
+        /// This ranges from 0 to [`Rotation::FULL_CIRCLE`] (for cones that cover the full circle).
+        width: u16,
+    }
+
+    impl AngularCone {
+        /// A cone that contains every orientation
+        pub const FULL_CIRCLE: AngularCone = AngularCone {
+            start: Rotation::SOUTH,
+            width: Rotation::FULL_CIRCLE,
+        };
+
+        /// Creates a new [`AngularCone`], centered on `facing` and extending `half_width` to either side
+        #[inline]
+        #[must_use]
+        pub fn new(facing: impl Into<Rotation>, half_width: Rotation) -> AngularCone {
+            AngularCone {
+                start: facing.into() - half_width,
+                width: (2 * half_width.deci_degrees()).min(Rotation::FULL_CIRCLE),
+            }
+        }
+
+        /// Creates a new [`AngularCone`] that sweeps clockwise from `start` to `end`
+        #[inline]
+        #[must_use]
+        pub fn from_bounds(start: impl Into<Rotation>, end: impl Into<Rotation>) -> AngularCone {
+            let start: Rotation = start.into();
+
+            AngularCone {
+                start,
+                width: (end.into() - start).deci_degrees(),
+            }
+        }
+
+        /// Does this cone cover every orientation?
+        #[inline]
+        #[must_use]
+        pub fn is_full_circle(&self) -> bool {
+            self.width >= Rotation::FULL_CIRCLE
+        }
+
+        /// The orientation at the center of the cone
+        ///
+        /// If the cone spans an odd number of tenths of a degree, this is rounded counterclockwise.
+        #[inline]
+        #[must_use]
+        pub fn facing(&self) -> Rotation {
+            self.start + Rotation::new(self.width / 2)
+        }
+
+        /// How far the cone extends to either side of [`facing`](AngularCone::facing)
+        ///
+        /// If the cone spans an odd number of tenths of a degree, this is rounded down.
+        #[inline]
+        #[must_use]
+        pub fn half_width(&self) -> Rotation {
+            Rotation::new(self.width / 2)
+        }
+
+        /// The most counterclockwise orientation in this cone
+        #[inline]
+        #[must_use]
+        pub fn start(&self) -> Rotation {
+            self.start
+        }
+
+        /// The most clockwise orientation in this cone
+        #[inline]
+        #[must_use]
+        pub fn end(&self) -> Rotation {
+            self.start + Rotation::new(self.width)
+        }
+
+        /// The angle spanned by this cone, in radians
+        ///
+        /// This ranges from 0 to τ (for cones that cover the full circle).
+        #[inline]
+        #[must_use]
+        pub fn width_radians(&self) -> f32 {
+            self.width as f32 * core::f32::consts::TAU / Rotation::FULL_CIRCLE as f32
+        }
+
+        /// Is the `orientation` within this cone?
+        ///
+        /// Orientations exactly on the edge of the cone are contained.
+        #[inline]
+        #[must_use]
+        pub fn contains(&self, orientation: impl Into<Rotation>) -> bool {
+            self.is_full_circle() || (orientation.into() - self.start).deci_degrees() <= self.width
+        }
+    }
+}
+
 mod rotation_direction {
     /// A direction that a [`Rotation`] can be applied in
     ///
@@ -341,11 +474,14 @@ mod rotation_direction {
 }
 
 mod rotation {
+    use super::{Orientation, RotationDirection};
     use crate::errors::NearlySingularConversion;
+    use crate::math;
+    #[cfg(feature = "bevy")]
     use bevy_ecs::prelude::Component;
-    use bevy_math::Vec2;
     use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
     use derive_more::Display;
+    use glam::Vec2;
 
     /// A discretized 2-dimensional rotation
     ///
@@ -374,7 +510,8 @@ mod rotation {
     ///
     /// Direction::from(nine_o_clock).assert_approx_eq(Direction::WEST);
     /// ```
-    #[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Default, Display)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Default, Display)]
+    #[cfg_attr(feature = "bevy", derive(Component))]
     pub struct Rotation {
         /// Tenths of a degree, measured clockwise from midnight (x=0, y=1)
         ///
@@ -404,6 +541,159 @@ mod rotation {
         pub const fn deci_degrees(&self) -> u16 {
             self.deci_degrees
         }
+
+        /// Rotates `vec` clockwise by this rotation, preserving its length
+        #[inline]
+        #[must_use]
+        pub(crate) fn rotate_vec2(self, vec: Vec2) -> Vec2 {
+            // The x and y components of a rotation (measured clockwise from midnight) are its sine and cosine
+            let unit_vector = self.into_vec2();
+            let (sin, cos) = (unit_vector.x, unit_vector.y);
+
+            Vec2::new(vec.x * cos + vec.y * sin, vec.y * cos - vec.x * sin)
+        }
+
+        /// Sweeps from `self` to `target` in `steps` equal steps, yielding `steps + 1` rotations
+        ///
+        /// Both `self` and `target` are included.
+        /// The sweep turns in `rotation_direction` if it is supplied.
+        /// Otherwise, the shorter arc is taken, preferring [`RotationDirection::Clockwise`] in the case of ties.
+        /// If `steps` is zero, only `self` is yielded.
+        ///
+        /// Each rotation is rounded to the nearest tenth of a degree, so errors do not accumulate along the sweep.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Rotation, RotationDirection};
+        ///
+        /// // A fan of five bullets, centered on north
+        /// let fan: Vec<Rotation> = Rotation::from_degrees(-30.0)
+        ///     .sweep_to(Rotation::from_degrees(30.0), None, 4)
+        ///     .collect();
+        /// assert_eq!(fan[1], Rotation::from_degrees(-15.0));
+        /// assert_eq!(fan[2], Rotation::NORTH);
+        /// assert_eq!(fan[4], Rotation::from_degrees(30.0));
+        ///
+        /// // Taking the long way around
+        /// let mut sweep = Rotation::NORTH.sweep_to(Rotation::EAST, Some(RotationDirection::CounterClockwise), 3);
+        /// assert_eq!(sweep.nth(1), Some(Rotation::WEST));
+        /// ```
+        pub fn sweep_to(
+            self,
+            target: Rotation,
+            rotation_direction: Option<RotationDirection>,
+            steps: usize,
+        ) -> impl DoubleEndedIterator<Item = Rotation> + ExactSizeIterator {
+            let rotation_direction =
+                rotation_direction.unwrap_or_else(|| self.rotation_direction(target));
+            let clockwise_distance = (target - self).deci_degrees as isize;
+
+            let sweep = match rotation_direction {
+                RotationDirection::Clockwise => clockwise_distance,
+                RotationDirection::CounterClockwise if clockwise_distance == 0 => 0,
+                RotationDirection::CounterClockwise => {
+                    clockwise_distance - Rotation::FULL_CIRCLE as isize
+                }
+            };
+
+            (0..steps + 1).map(move |i| {
+                let offset = if steps == 0 {
+                    0
+                } else {
+                    // Round to the nearest deci-degree, away from zero on ties
+                    let scaled = sweep * i as isize;
+                    let half = steps as isize / 2;
+                    if scaled >= 0 {
+                        (scaled + half) / steps as isize
+                    } else {
+                        (scaled - half) / steps as isize
+                    }
+                };
+
+                Rotation::new(
+                    (self.deci_degrees as isize + offset).rem_euclid(Rotation::FULL_CIRCLE as isize)
+                        as u16,
+                )
+            })
+        }
+
+        /// Endlessly sweeps clockwise from `self` by `step` at a time, starting with `self`
+        ///
+        /// To sweep counterclockwise, negate the `step`.
+        /// Use [`Iterator::take`] to limit the number of rotations.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// // A ring of eight bullets
+        /// let ring: Vec<Rotation> = Rotation::NORTH.sweep_by(Rotation::NORTHEAST).take(8).collect();
+        /// assert_eq!(ring[2], Rotation::EAST);
+        /// assert_eq!(ring[7], Rotation::NORTHWEST);
+        ///
+        /// let mut counterclockwise = Rotation::NORTH.sweep_by(-Rotation::EAST);
+        /// assert_eq!(counterclockwise.nth(1), Some(Rotation::WEST));
+        /// ```
+        pub fn sweep_by(self, step: Rotation) -> impl Iterator<Item = Rotation> {
+            core::iter::successors(Some(self), move |&rotation| Some(rotation + step))
+        }
+
+        /// Computes the average of the `rotations`, correctly handling the wrap-around at north
+        ///
+        /// Each rotation is treated as a unit vector, and the angle of their sum is returned.
+        /// Returns `None` if no rotations are supplied, or if they cancel each other out
+        /// (such as north and south), as the average is then undefined.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Orientation, Rotation};
+        ///
+        /// // Naively averaging 350° and 10° would give 180°
+        /// let heading = Rotation::circular_mean([Rotation::from_degrees(350.0), Rotation::from_degrees(10.0)]);
+        /// heading.unwrap().assert_approx_eq(Rotation::NORTH);
+        ///
+        /// assert_eq!(Rotation::circular_mean([Rotation::EAST, Rotation::WEST]), None);
+        /// assert_eq!(Rotation::circular_mean([]), None);
+        /// ```
+        #[must_use]
+        pub fn circular_mean(rotations: impl IntoIterator<Item = Rotation>) -> Option<Rotation> {
+            Rotation::weighted_circular_mean(rotations.into_iter().map(|rotation| (rotation, 1.0)))
+        }
+
+        /// Computes the weighted average of the `rotations`, correctly handling the wrap-around at north
+        ///
+        /// Each rotation is paired with its weight, and treated as a vector whose length is that weight.
+        /// Negative weights pull the average away from their rotation.
+        /// Returns `None` if the weighted vectors cancel each other out, or sum to zero.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Orientation, Rotation};
+        ///
+        /// // The leader of the group counts three times as much as the follower
+        /// let heading = Rotation::weighted_circular_mean([(Rotation::NORTH, 3.0), (Rotation::SOUTH, 1.0)]);
+        /// assert_eq!(heading, Some(Rotation::NORTH));
+        ///
+        /// let heading = Rotation::weighted_circular_mean([(Rotation::NORTH, 1.0), (Rotation::EAST, 1.0)]);
+        /// heading.unwrap().assert_approx_eq(Rotation::NORTHEAST);
+        /// ```
+        #[must_use]
+        pub fn weighted_circular_mean(
+            rotations: impl IntoIterator<Item = (Rotation, f32)>,
+        ) -> Option<Rotation> {
+            let sum = rotations
+                .into_iter()
+                .fold(Vec2::ZERO, |sum, (rotation, weight)| {
+                    sum + rotation.into_vec2() * weight
+                });
+
+            // Opposing rotations rarely cancel exactly, due to floating point error
+            if sum.length_squared() < 1e-10 {
+                None
+            } else {
+                Rotation::from_vec2(sum).ok()
+            }
+        }
     }
 
     // Constants
@@ -440,7 +730,7 @@ mod rotation {
         ///
         /// # Example
         /// ```rust
-        /// use bevy_math::Vec2;
+        /// use glam::Vec2;
         /// use leafwing_2d::orientation::Rotation;
         ///
         /// assert_eq!(Rotation::from_xy(Vec2::new(0.0, 1.0)), Ok(Rotation::NORTH));
@@ -450,7 +740,7 @@ mod rotation {
             if vec.length_squared() < f32::EPSILON * f32::EPSILON {
                 Err(NearlySingularConversion)
             } else {
-                let radians = f32::atan2(vec.x, vec.y);
+                let radians = math::atan2(vec.x, vec.y);
                 Ok(Rotation::from_radians(radians))
             }
         }
@@ -460,16 +750,16 @@ mod rotation {
         #[must_use]
         pub fn into_vec2(self) -> Vec2 {
             let radians = self.into_radians();
-            Vec2::new(radians.sin(), radians.cos())
+            Vec2::new(math::sin(radians), math::cos(radians))
         }
 
         /// Construct a [`Direction`](crate::orientation::Direction) from radians, measured clockwise from midnight
         #[must_use]
         #[inline]
         pub fn from_radians(radians: impl Into<f32>) -> Rotation {
-            use std::f32::consts::TAU;
+            use core::f32::consts::TAU;
 
-            let normalized_radians: f32 = radians.into().rem_euclid(TAU);
+            let normalized_radians: f32 = math::rem_euclid(radians.into(), TAU);
 
             Rotation {
                 deci_degrees: (normalized_radians * 3600. / TAU) as u16,
@@ -480,14 +770,14 @@ mod rotation {
         #[inline]
         #[must_use]
         pub fn into_radians(self) -> f32 {
-            self.deci_degrees as f32 * std::f32::consts::TAU / 3600.
+            self.deci_degrees as f32 * core::f32::consts::TAU / 3600.
         }
 
         /// Construct a [`Direction`](crate::orientation::Direction) from degrees, measured clockwise from midnight
         #[must_use]
         #[inline]
         pub fn from_degrees(degrees: impl Into<f32>) -> Rotation {
-            let normalized_degrees: f32 = degrees.into().rem_euclid(360.0);
+            let normalized_degrees: f32 = math::rem_euclid(degrees.into(), 360.0);
 
             Rotation {
                 deci_degrees: (normalized_degrees * 10.0) as u16,
@@ -575,12 +865,253 @@ mod rotation {
     }
 }
 
+mod angle {
+    use super::Rotation;
+    use crate::math;
+    #[cfg(feature = "bevy")]
+    use bevy_ecs::prelude::Component;
+    use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+    use derive_more::Display;
+
+    /// An unbounded 2-dimensional angle, which remembers how many times it has wound around the circle
+    ///
+    /// Unlike [`Rotation`], this does not wrap back to zero after a full turn:
+    /// two clockwise turns are 720 degrees, not 0 degrees.
+    /// This makes it suitable for tracking the total amount that something has turned,
+    /// such as a coiled cable, a spinning wheel or a trick in mid-air.
+    ///
+    /// Like [`Rotation`], angles are stored in tenths of a degree and are positive in the clockwise direction.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::{Angle, Rotation};
+    ///
+    /// let mut total_spin = Angle::default();
+    /// let mut previous = Rotation::NORTH;
+    ///
+    /// // Spin clockwise in quarter turns, one and a half times
+    /// for quarter_turns in 1..=6 {
+    ///     let rotation = Rotation::new(quarter_turns * 900);
+    ///     total_spin.accumulate(previous, rotation);
+    ///     previous = rotation;
+    /// }
+    ///
+    /// assert_eq!(total_spin, Angle::from_degrees(540.0));
+    /// assert_eq!(total_spin.full_turns(), 1);
+    /// assert_eq!(Rotation::from(total_spin), Rotation::SOUTH);
+    ///
+    /// // Turning back the other way unwinds the angle
+    /// total_spin.accumulate(Rotation::SOUTH, Rotation::EAST);
+    /// assert_eq!(total_spin, Angle::from_degrees(450.0));
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Display)]
+    #[cfg_attr(feature = "bevy", derive(Component))]
+    pub struct Angle {
+        /// Tenths of a degree, measured clockwise
+        ///
+        /// 3600 make up a full turn.
+        deci_degrees: i32,
+    }
+
+    impl Angle {
+        /// An angle of zero
+        pub const ZERO: Angle = Angle { deci_degrees: 0 };
+
+        /// A single clockwise turn, 360 degrees
+        pub const FULL_TURN: Angle = Angle {
+            deci_degrees: Rotation::FULL_CIRCLE as i32,
+        };
+
+        /// Creates a new [`Angle`] from a whole number of tenths of a degree
+        ///
+        /// Positive values are clockwise.
+        #[inline]
+        #[must_use]
+        pub const fn new(deci_degrees: i32) -> Angle {
+            Angle { deci_degrees }
+        }
+
+        /// Returns the exact internal mesaurement, stored in tenths of a degree
+        ///
+        /// Positive values are clockwise.
+        /// 3600 make up a full turn.
+        #[inline]
+        #[must_use]
+        pub const fn deci_degrees(&self) -> i32 {
+            self.deci_degrees
+        }
+
+        /// Constructs an [`Angle`] from degrees, rounding to the nearest tenth of a degree
+        #[inline]
+        #[must_use]
+        pub fn from_degrees(degrees: f32) -> Angle {
+            Angle {
+                deci_degrees: math::round(degrees * 10.) as i32,
+            }
+        }
+
+        /// Converts this angle into degrees
+        #[inline]
+        #[must_use]
+        pub fn into_degrees(self) -> f32 {
+            self.deci_degrees as f32 / 10.
+        }
+
+        /// Constructs an [`Angle`] from radians, rounding to the nearest tenth of a degree
+        #[inline]
+        #[must_use]
+        pub fn from_radians(radians: f32) -> Angle {
+            Angle::from_degrees(radians.to_degrees())
+        }
+
+        /// Converts this angle into radians
+        #[inline]
+        #[must_use]
+        pub fn into_radians(self) -> f32 {
+            self.into_degrees().to_radians()
+        }
+
+        /// The shortest signed [`Angle`] that turns `from` into `to`
+        ///
+        /// The result ranges from -180 degrees (exclusive) to 180 degrees (inclusive).
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Angle, Rotation};
+        ///
+        /// assert_eq!(Angle::between(Rotation::NORTH, Rotation::EAST), Angle::from_degrees(90.0));
+        /// assert_eq!(Angle::between(Rotation::NORTH, Rotation::WEST), Angle::from_degrees(-90.0));
+        /// assert_eq!(Angle::between(Rotation::NORTH, Rotation::SOUTH), Angle::from_degrees(180.0));
+        /// ```
+        #[must_use]
+        pub fn between(from: Rotation, to: Rotation) -> Angle {
+            let clockwise = i32::from((to - from).deci_degrees);
+            let half_circle = i32::from(Rotation::FULL_CIRCLE / 2);
+
+            if clockwise > half_circle {
+                Angle::new(clockwise - i32::from(Rotation::FULL_CIRCLE))
+            } else {
+                Angle::new(clockwise)
+            }
+        }
+
+        /// Adds the shortest turn from `previous` to `current` onto this angle
+        ///
+        /// Call this each time the tracked [`Rotation`] changes to keep a running total of how far it has turned.
+        /// Changes of more than 180 degrees between calls are assumed to have gone the short way around.
+        #[inline]
+        pub fn accumulate(&mut self, previous: Rotation, current: Rotation) {
+            *self += Angle::between(previous, current);
+        }
+
+        /// The number of complete turns that make up this angle
+        ///
+        /// This is rounded towards zero, and is negative for counterclockwise angles.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Angle;
+        ///
+        /// assert_eq!(Angle::from_degrees(359.9).full_turns(), 0);
+        /// assert_eq!(Angle::from_degrees(720.0).full_turns(), 2);
+        /// assert_eq!(Angle::from_degrees(-400.0).full_turns(), -1);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn full_turns(&self) -> i32 {
+            self.deci_degrees / i32::from(Rotation::FULL_CIRCLE)
+        }
+
+        /// The absolute magnitude of this angle
+        #[inline]
+        #[must_use]
+        pub fn abs(self) -> Angle {
+            Angle::new(self.deci_degrees.abs())
+        }
+
+        /// Rotates `rotation` by this angle
+        ///
+        /// Only the remainder after removing any [`full_turns`](Angle::full_turns) has an effect.
+        #[inline]
+        #[must_use]
+        pub fn rotate(self, rotation: Rotation) -> Rotation {
+            rotation + Rotation::from(self)
+        }
+    }
+
+    impl From<Rotation> for Angle {
+        /// Converts the `rotation` into an angle between 0 (inclusive) and 360 (exclusive) degrees clockwise
+        fn from(rotation: Rotation) -> Angle {
+            Angle::new(i32::from(rotation.deci_degrees))
+        }
+    }
+
+    impl From<Angle> for Rotation {
+        /// Wraps the `angle` back onto the circle, discarding any full turns
+        fn from(angle: Angle) -> Rotation {
+            let deci_degrees = angle
+                .deci_degrees
+                .rem_euclid(i32::from(Rotation::FULL_CIRCLE));
+
+            Rotation::new(deci_degrees as u16)
+        }
+    }
+
+    impl Add for Angle {
+        type Output = Angle;
+        fn add(self, rhs: Self) -> Angle {
+            Angle::new(self.deci_degrees + rhs.deci_degrees)
+        }
+    }
+
+    impl Sub for Angle {
+        type Output = Angle;
+        fn sub(self, rhs: Self) -> Angle {
+            Angle::new(self.deci_degrees - rhs.deci_degrees)
+        }
+    }
+
+    impl AddAssign for Angle {
+        fn add_assign(&mut self, rhs: Self) {
+            self.deci_degrees += rhs.deci_degrees;
+        }
+    }
+
+    impl SubAssign for Angle {
+        fn sub_assign(&mut self, rhs: Self) {
+            self.deci_degrees -= rhs.deci_degrees;
+        }
+    }
+
+    impl Neg for Angle {
+        type Output = Angle;
+        fn neg(self) -> Angle {
+            Angle::new(-self.deci_degrees)
+        }
+    }
+
+    impl Mul<f32> for Angle {
+        type Output = Angle;
+        fn mul(self, rhs: f32) -> Angle {
+            Angle::from_degrees(self.into_degrees() * rhs)
+        }
+    }
+
+    impl Mul<Angle> for f32 {
+        type Output = Angle;
+        fn mul(self, rhs: Angle) -> Angle {
+            Angle::from_degrees(rhs.into_degrees() * self)
+        }
+    }
+}
+
 mod direction {
+    #[cfg(feature = "bevy")]
     use bevy_ecs::prelude::Component;
-    use bevy_math::{const_vec2, Vec2, Vec3};
+    use core::f32::consts::SQRT_2;
     use core::ops::{Add, Div, Mul, Neg, Sub};
     use derive_more::Display;
-    use std::f32::consts::SQRT_2;
+    use glam::{const_vec2, Vec2, Vec3};
 
     /// A 2D unit vector that represents a direction
     ///
@@ -597,7 +1128,8 @@ mod direction {
     /// assert_eq!(Direction::SOUTH * 3.0, Vec2::new(0.0, -3.0));
     /// assert_eq!(Direction::EAST / 2.0, Vec2::new(0.5, 0.0));
     /// ```
-    #[derive(Component, Clone, Copy, Debug, PartialEq, Display)]
+    #[derive(Clone, Copy, Debug, PartialEq, Display)]
+    #[cfg_attr(feature = "bevy", derive(Component))]
     pub struct Direction {
         pub(crate) unit_vector: Vec2,
     }
@@ -743,8 +1275,10 @@ mod direction {
 mod conversions {
     use super::{Direction, Rotation};
     use crate::errors::NearlySingularConversion;
-    use bevy_math::{Quat, Vec2, Vec3};
+    use crate::math;
+    #[cfg(feature = "bevy")]
     use bevy_transform::components::{GlobalTransform, Transform};
+    use glam::{Quat, Vec2};
 
     impl From<Rotation> for Direction {
         fn from(rotation: Rotation) -> Direction {
@@ -756,7 +1290,7 @@ mod conversions {
 
     impl From<Direction> for Rotation {
         fn from(direction: Direction) -> Rotation {
-            let radians = f32::atan2(direction.unit_vector().x, direction.unit_vector().y);
+            let radians = math::atan2(direction.unit_vector().x, direction.unit_vector().y);
             Rotation::from_radians(radians)
         }
     }
@@ -795,10 +1329,43 @@ mod conversions {
         }
     }
 
+    /// The x and y components of the up vector, after it has been rotated by the `quaternion`
+    ///
+    /// This is equivalent to `quaternion.mul_vec3(Vec3::Y).truncate()`, but skips computing the unused z component.
+    #[inline]
+    pub(crate) fn quat_heading(quaternion: Quat) -> Vec2 {
+        let [x, y, z, w] = quaternion.to_array();
+
+        Vec2::new(2. * (x * y - w * z), 1. - 2. * (x * x + z * z))
+    }
+
+    /// Splits the `quaternion` into `(tilt, yaw)`, where `quaternion == tilt * yaw`
+    ///
+    /// The yaw only turns around the z-axis, while the tilt contains everything else, such as a skew or wobble effect.
+    /// If the quaternion is tilted by exactly half a turn, the yaw is ambiguous and the identity is returned.
+    #[cfg(feature = "bevy")]
+    #[inline]
+    pub(crate) fn split_yaw(quaternion: Quat) -> (Quat, Quat) {
+        let [_, _, z, w] = quaternion.to_array();
+        let length_squared = z * z + w * w;
+        if length_squared <= f32::EPSILON {
+            return (quaternion, Quat::IDENTITY);
+        }
+
+        let yaw = Quat::from_xyzw(0., 0., z, w) * (1. / math::sqrt(length_squared));
+        (quaternion * yaw.conjugate(), yaw)
+    }
+
     impl From<Quat> for Rotation {
         fn from(quaternion: Quat) -> Rotation {
-            let direction: Direction = quaternion.into();
-            direction.into()
+            let heading = quat_heading(quaternion);
+
+            if heading == Vec2::ZERO {
+                Rotation::default()
+            } else {
+                // atan2 is unaffected by the length of the heading, so there is no need to normalize it
+                Rotation::from_radians(math::atan2(heading.x, heading.y))
+            }
         }
     }
 
@@ -811,7 +1378,7 @@ mod conversions {
 
     impl From<Quat> for Direction {
         fn from(quaternion: Quat) -> Self {
-            let vec2 = quaternion.mul_vec3(Vec3::Y).truncate();
+            let vec2 = quat_heading(quaternion);
 
             if vec2 == Vec2::ZERO {
                 Direction::default()
@@ -824,54 +1391,72 @@ mod conversions {
     }
 
     impl From<Direction> for Quat {
+        /// Builds the quaternion directly from the unit vector, without converting to an angle and back
         fn from(direction: Direction) -> Quat {
-            let rotation: Rotation = direction.into();
-            rotation.into()
+            let unit_vector = direction.unit_vector();
+
+            // The rotation from north to the unit vector, using the half-angle formulas:
+            // a rotation by theta around z is (0, 0, sin(theta / 2), cos(theta / 2))
+            let (sin, cos) = (-unit_vector.x, unit_vector.y);
+            if 1. + cos <= f32::EPSILON {
+                // Pointing straight south, where the half-angle formulas break down
+                return Quat::from_rotation_z(-math::atan2(unit_vector.x, unit_vector.y));
+            }
+
+            Quat::from_xyzw(0., 0., sin, 1. + cos).normalize()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Transform> for Direction {
         fn from(transform: Transform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<GlobalTransform> for Direction {
         fn from(transform: GlobalTransform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Direction> for Transform {
         fn from(direction: Direction) -> Self {
             Transform::from_rotation(direction.into())
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Direction> for GlobalTransform {
         fn from(direction: Direction) -> Self {
             GlobalTransform::from_rotation(direction.into())
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Transform> for Rotation {
         fn from(transform: Transform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<GlobalTransform> for Rotation {
         fn from(transform: GlobalTransform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Rotation> for Transform {
         fn from(rotation: Rotation) -> Self {
             Transform::from_rotation(rotation.into())
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl From<Rotation> for GlobalTransform {
         fn from(rotation: Rotation) -> Self {
             GlobalTransform::from_rotation(rotation.into())
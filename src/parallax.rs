@@ -0,0 +1,79 @@
+//! Scroll backgrounds and foregrounds at different speeds, to give a sense of depth
+//!
+//! The [`Transform`] of each [`ParallaxLayer`] is shifted by a fraction of the [`ParallaxCamera`]'s [`Position`],
+//! while its own [`Position`] stays put in world space.
+//! These systems are added by [`TwoDPlugin`](crate::plugin::TwoDPlugin), in [`TwoDSystem::SyncParallax`](crate::plugin::TwoDSystem::SyncParallax).
+
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::prelude::*;
+use bevy_transform::components::Transform;
+use glam::Vec2;
+
+/// Draws the entity offset by a fraction of the [`ParallaxCamera`]'s [`Position`]
+///
+/// A `factor` of zero along an axis behaves like any other entity,
+/// while a `factor` of one keeps the entity fixed on screen as the camera moves.
+/// Values in between make the entity appear further away, while negative values make it appear closer than the rest of the scene.
+///
+/// Only the [`Transform`] is offset, so the logical [`Position`] of the layer can be used for gameplay as usual.
+/// As a result, changes to the [`Transform`] of a parallax layer are not copied back to its [`Position`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::parallax::{ParallaxCamera, ParallaxLayer};
+/// use leafwing_2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(ParallaxCamera);
+///
+///     // Distant mountains only scroll by at half of the camera's speed
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(Transform::from_xyz(0.0, 0.0, -10.0))
+///         .insert(ParallaxLayer {
+///             factor: Vec2::splat(0.5),
+///         });
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_startup_system(setup);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParallaxLayer {
+    /// How much of the camera's movement is applied to this layer, along each axis
+    pub factor: Vec2,
+}
+
+/// Marks the entity whose [`Position`] is used to offset each [`ParallaxLayer`]
+///
+/// There should only be one parallax camera at a time.
+/// If there are none (or several), parallax layers are drawn at their [`Position`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParallaxCamera;
+
+/// Sets the translation of each [`ParallaxLayer`]'s [`Transform`] to its [`Position`], offset by the [`ParallaxCamera`]'s [`Position`]
+///
+/// z-values of the [`Transform`] translation will not be modified.
+pub fn sync_parallax_layers<C: Coordinate>(
+    cameras: Query<&Position<C>, With<ParallaxCamera>>,
+    mut layers: Query<(&ParallaxLayer, &Position<C>, &mut Transform)>,
+) {
+    let camera_position = cameras
+        .get_single()
+        .map_or(Vec2::ZERO, |&position| position.into());
+
+    for (layer, &position, mut transform) in layers.iter_mut() {
+        let new_translation = Vec2::from(position) + camera_position * layer.factor;
+
+        // Avoid triggering change detection pointlessly
+        if transform.translation.truncate() != new_translation {
+            transform.translation.x = new_translation.x;
+            transform.translation.y = new_translation.y;
+        }
+    }
+}
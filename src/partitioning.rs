@@ -1,20 +1,26 @@
 //! Tools to partition [`Orientations`](Orientation) into discrete regions
 
 use crate::orientation::{Direction, Orientation, Rotation};
-use bevy_math::Vec2;
+use alloc::vec::Vec;
+use glam::Vec2;
 
 /// An exhaustive partitioning of the unit circle, snapping continuous directional input into one of a few possible options
 ///
-/// Only `partitions` should be manually defined when implementing this trait for new types.
-pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> + Copy {
-    /// Returns the vector of possible partitions that can be snapped to
-    #[must_use]
-    fn partitions() -> Vec<Self>;
+/// Only `PARTITIONS` should be manually defined when implementing this trait for new types.
+/// If the partitions are evenly spaced, consider overriding [`snap`](DirectionParitioning::snap)
+/// with [`snap_uniform`] to find the nearest partition arithmetically, rather than by comparing against each partition in turn.
+pub trait DirectionParitioning:
+    Into<Rotation> + Into<Direction> + Into<Vec2> + Copy + 'static
+{
+    /// The possible partitions that can be snapped to
+    ///
+    /// This must contain at least one element.
+    const PARTITIONS: &'static [Self];
 
     /// Returns a vector of the snappable rotations
     #[must_use]
     fn rotations() -> Vec<Rotation> {
-        Self::partitions()
+        Self::PARTITIONS
             .iter()
             .map(|&partition| partition.into())
             .collect()
@@ -23,7 +29,7 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
     /// Returns a vector of the snappable directions
     #[must_use]
     fn directions() -> Vec<Direction> {
-        Self::partitions()
+        Self::PARTITIONS
             .iter()
             .map(|&partition| partition.into())
             .collect()
@@ -32,18 +38,20 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
     /// Returns a vector of the snappable unit vectors
     #[must_use]
     fn unit_vectors() -> Vec<Vec2> {
-        Self::partitions()
+        Self::PARTITIONS
             .iter()
             .map(|&partition| partition.into())
             .collect()
     }
 
     /// Snaps to the nearest partition
+    ///
+    /// This does not allocate, but checks the distance to every partition.
     #[must_use]
     fn snap(rotationlike: impl Into<Rotation>) -> Self {
         let rotation = rotationlike.into();
 
-        Self::partitions()
+        Self::PARTITIONS
             .iter()
             .map(|&partition| (partition, rotation.distance(partition.into())))
             .reduce(|(paritition_1, distance_1), (partition_2, distance_2)| {
@@ -54,9 +62,7 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
                     (partition_2, distance_2)
                 }
             })
-            .expect(
-                "At least one element must be returned by `DirectionPartitioning::partitions()`",
-            )
+            .expect("At least one element must be contained in `DirectionPartitioning::PARTITIONS`")
             .0
     }
 
@@ -85,6 +91,40 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
     }
 }
 
+/// Snaps `rotation` to the nearest of the evenly spaced `partitions`, in constant time
+///
+/// The `partitions` must be listed in clockwise order, starting at the `first` rotation.
+/// Exact ties between two partitions are broken clockwise.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::partitioning::{snap_uniform, CardinalQuadrant};
+///
+/// use CardinalQuadrant::*;
+///
+/// let snap = |degrees| snap_uniform(Rotation::from_degrees(degrees), Rotation::NORTH, &[North, East, South, West]);
+///
+/// assert_eq!(snap(80.0), East);
+/// assert_eq!(snap(350.0), North);
+/// // Ties are broken clockwise
+/// assert_eq!(snap(135.0), South);
+/// ```
+///
+/// # Panics
+/// Panics if `partitions` is empty, or has more than 3600 elements.
+#[inline]
+#[must_use]
+pub fn snap_uniform<P: Copy>(rotation: Rotation, first: Rotation, partitions: &[P]) -> P {
+    let n_partitions = partitions.len() as u32;
+    let full_circle = u32::from(Rotation::FULL_CIRCLE);
+    // Measured in partition-widths, so that sectors with a fractional number of deci-degrees are still exact
+    let relative = u32::from((rotation - first).deci_degrees()) * n_partitions;
+    let index = (relative + full_circle / 2) / full_circle % n_partitions;
+
+    partitions[index as usize]
+}
+
 /// A 4-way [`DirectionParitioning`], corresponding to the four cardinal directions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CardinalQuadrant {
@@ -99,10 +139,14 @@ pub enum CardinalQuadrant {
 }
 
 impl DirectionParitioning for CardinalQuadrant {
-    fn partitions() -> Vec<Self> {
+    const PARTITIONS: &'static [Self] = {
         use CardinalQuadrant::*;
 
-        vec![North, East, South, West]
+        &[North, East, South, West]
+    };
+
+    fn snap(rotationlike: impl Into<Rotation>) -> Self {
+        snap_uniform(rotationlike.into(), Rotation::NORTH, Self::PARTITIONS)
     }
 }
 
@@ -120,10 +164,14 @@ pub enum OffsetQuadrant {
 }
 
 impl DirectionParitioning for OffsetQuadrant {
-    fn partitions() -> Vec<Self> {
+    const PARTITIONS: &'static [Self] = {
         use OffsetQuadrant::*;
 
-        vec![NorthEast, SouthEast, SouthWest, NorthWest]
+        &[NorthEast, SouthEast, SouthWest, NorthWest]
+    };
+
+    fn snap(rotationlike: impl Into<Rotation>) -> Self {
+        snap_uniform(rotationlike.into(), Rotation::new(450), Self::PARTITIONS)
     }
 }
 
@@ -149,12 +197,16 @@ pub enum CardinalOctant {
 }
 
 impl DirectionParitioning for CardinalOctant {
-    fn partitions() -> Vec<Self> {
+    const PARTITIONS: &'static [Self] = {
         use CardinalOctant::*;
 
-        vec![
+        &[
             North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
         ]
+    };
+
+    fn snap(rotationlike: impl Into<Rotation>) -> Self {
+        snap_uniform(rotationlike.into(), Rotation::NORTH, Self::PARTITIONS)
     }
 }
 
@@ -179,10 +231,14 @@ pub enum CardinalSextant {
 }
 
 impl DirectionParitioning for CardinalSextant {
-    fn partitions() -> Vec<Self> {
+    const PARTITIONS: &'static [Self] = {
         use CardinalSextant::*;
 
-        vec![North, NorthEast, SouthEast, South, SouthWest, NorthWest]
+        &[North, NorthEast, SouthEast, South, SouthWest, NorthWest]
+    };
+
+    fn snap(rotationlike: impl Into<Rotation>) -> Self {
+        snap_uniform(rotationlike.into(), Rotation::NORTH, Self::PARTITIONS)
     }
 }
 
@@ -207,10 +263,14 @@ pub enum OffsetSextant {
 }
 
 impl DirectionParitioning for OffsetSextant {
-    fn partitions() -> Vec<Self> {
+    const PARTITIONS: &'static [Self] = {
         use OffsetSextant::*;
 
-        vec![NorthEast, East, SouthEast, SouthWest, West, NorthWest]
+        &[NorthEast, East, SouthEast, SouthWest, West, NorthWest]
+    };
+
+    fn snap(rotationlike: impl Into<Rotation>) -> Self {
+        snap_uniform(rotationlike.into(), Rotation::new(300), Self::PARTITIONS)
     }
 }
 
@@ -0,0 +1,541 @@
+//! Paths through 2D space, made up of [`Positions`](crate::position::Position)
+//!
+//! All lengths and distances are measured in the same units as the [`f32`] conversion of the [`Coordinate`] type.
+
+pub use polyline::Polyline;
+pub use splines::{ArcLengthParameterized, CatmullRom, CubicBezier, Spline};
+
+mod polyline {
+    use crate::coordinate::Coordinate;
+    use crate::geometry::closest_point_on_segment;
+    use crate::position::Position;
+    use alloc::{vec, vec::Vec};
+    #[cfg(feature = "bevy")]
+    use bevy_ecs::prelude::Component;
+    use glam::Vec2;
+
+    /// An ordered list of [`Positions`](Position), connected by straight line segments
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::path::Polyline;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let road = Polyline::<F32>::new([
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(3.0, 0.0),
+    ///     Position::new(3.0, 4.0),
+    /// ]);
+    ///
+    /// assert_eq!(road.length(), 7.0);
+    /// assert_eq!(road.sample_at_distance(5.0), Some(Position::new(3.0, 2.0)));
+    /// assert_eq!(road.closest_point(Position::new(1.0, -2.0)), Some(Position::new(1.0, 0.0)));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "bevy", derive(Component))]
+    pub struct Polyline<C: Coordinate> {
+        points: Vec<Position<C>>,
+    }
+
+    impl<C: Coordinate> Polyline<C> {
+        /// Creates a new [`Polyline`] that visits each of the `points` in order
+        #[inline]
+        #[must_use]
+        pub fn new(points: impl IntoIterator<Item = Position<C>>) -> Polyline<C> {
+            Polyline {
+                points: points.into_iter().collect(),
+            }
+        }
+
+        /// The ordered list of points that make up this path
+        #[inline]
+        #[must_use]
+        pub fn points(&self) -> &[Position<C>] {
+            &self.points
+        }
+
+        /// Adds a new point to the end of this path
+        #[inline]
+        pub fn push(&mut self, point: Position<C>) {
+            self.points.push(point);
+        }
+
+        /// Removes the first `count` points from the start of this path
+        ///
+        /// If `count` is greater than the number of points, every point is removed.
+        #[inline]
+        pub fn remove_start(&mut self, count: usize) {
+            self.points.drain(..count.min(self.points.len()));
+        }
+
+        /// Iterates over each straight line segment in this path, as `(start, end)` pairs
+        #[inline]
+        pub fn segments(&self) -> impl Iterator<Item = (Position<C>, Position<C>)> + '_ {
+            self.points.windows(2).map(|pair| (pair[0], pair[1]))
+        }
+
+        /// The total length of this path
+        ///
+        /// Paths with less than two points have a length of 0.
+        #[must_use]
+        pub fn length(&self) -> f32 {
+            self.segments()
+                .map(|(start, end)| Vec2::from(start).distance(end.into()))
+                .sum()
+        }
+
+        /// Finds the point that is `distance` along this path, measured from its first point
+        ///
+        /// `distance` is clamped to the length of the path,
+        /// so negative values return the first point and values past the end return the last point.
+        /// Returns [`None`] if the path has no points.
+        #[must_use]
+        pub fn sample_at_distance(&self, distance: f32) -> Option<Position<C>> {
+            let mut remaining = distance.max(0.0);
+
+            for (start, end) in self.segments() {
+                let (start, end) = (Vec2::from(start), Vec2::from(end));
+                let segment_length = start.distance(end);
+
+                if remaining <= segment_length {
+                    if segment_length == 0.0 {
+                        return Some(start.into());
+                    }
+                    return Some(start.lerp(end, remaining / segment_length).into());
+                }
+
+                remaining -= segment_length;
+            }
+
+            self.points.last().copied()
+        }
+
+        /// Finds the point on this path that is closest to `position`
+        ///
+        /// Returns [`None`] if the path has no points.
+        #[must_use]
+        pub fn closest_point(&self, position: Position<C>) -> Option<Position<C>> {
+            if self.points.len() == 1 {
+                return self.points.first().copied();
+            }
+
+            let target = Vec2::from(position);
+
+            self.segments()
+                .map(|(start, end)| closest_point_on_segment(target, start.into(), end.into()))
+                .min_by(|a, b| {
+                    a.distance_squared(target)
+                        .total_cmp(&b.distance_squared(target))
+                })
+                .map(Position::from)
+        }
+
+        /// Simplifies this path using the [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm)
+        ///
+        /// Points are removed as long as the simplified path stays within `epsilon` of every removed point.
+        /// The first and last points are always kept.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::path::Polyline;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let wobbly = Polyline::<F32>::new([
+        ///     Position::new(0.0, 0.0),
+        ///     Position::new(1.0, 0.1),
+        ///     Position::new(2.0, -0.1),
+        ///     Position::new(3.0, 5.0),
+        /// ]);
+        ///
+        /// let simplified = wobbly.simplify(0.5);
+        /// assert_eq!(
+        ///     simplified.points(),
+        ///     &[Position::new(0.0, 0.0), Position::new(2.0, -0.1), Position::new(3.0, 5.0)]
+        /// );
+        /// ```
+        #[must_use]
+        pub fn simplify(&self, epsilon: f32) -> Polyline<C> {
+            if self.points.len() < 3 {
+                return self.clone();
+            }
+
+            let vectors: Vec<Vec2> = self.points.iter().map(|&point| point.into()).collect();
+            let mut keep = vec![false; vectors.len()];
+            keep[0] = true;
+            keep[vectors.len() - 1] = true;
+
+            // Each range is an inclusive (first, last) pair of indexes whose interior has not yet been checked
+            let mut ranges = vec![(0, vectors.len() - 1)];
+            while let Some((first, last)) = ranges.pop() {
+                let mut farthest_index = first;
+                let mut farthest_distance = 0.0;
+
+                for (index, &vector) in vectors.iter().enumerate().take(last).skip(first + 1) {
+                    let closest = closest_point_on_segment(vector, vectors[first], vectors[last]);
+                    let distance = closest.distance(vector);
+
+                    if distance > farthest_distance {
+                        farthest_index = index;
+                        farthest_distance = distance;
+                    }
+                }
+
+                if farthest_distance > epsilon {
+                    keep[farthest_index] = true;
+                    ranges.push((first, farthest_index));
+                    ranges.push((farthest_index, last));
+                }
+            }
+
+            Polyline::new(
+                self.points
+                    .iter()
+                    .zip(keep)
+                    .filter_map(|(&point, kept)| kept.then_some(point)),
+            )
+        }
+    }
+
+    impl<C: Coordinate> From<Vec<Position<C>>> for Polyline<C> {
+        fn from(points: Vec<Position<C>>) -> Polyline<C> {
+            Polyline { points }
+        }
+    }
+
+    impl<C: Coordinate> FromIterator<Position<C>> for Polyline<C> {
+        fn from_iter<I: IntoIterator<Item = Position<C>>>(iter: I) -> Polyline<C> {
+            Polyline::new(iter)
+        }
+    }
+}
+
+mod splines {
+    use super::Polyline;
+    use crate::coordinate::Coordinate;
+    use crate::errors::NearlySingularConversion;
+    use crate::orientation::Direction;
+    use crate::position::Position;
+    use alloc::vec::Vec;
+    use glam::Vec2;
+
+    /// A smooth curve through 2D space, parameterized by `t` from 0 to 1
+    ///
+    /// Equal steps in `t` do not generally cover equal distances along the curve:
+    /// use [`ArcLengthParameterized`] to move along a spline at a constant speed.
+    pub trait Spline {
+        /// The coordinate type of the positions on this spline
+        type C: Coordinate;
+
+        /// The position on the curve at `t`, which is clamped between 0 and 1
+        #[must_use]
+        fn position_at(&self, t: f32) -> Position<Self::C>;
+
+        /// The derivative of the curve's position with respect to `t`, which is clamped between 0 and 1
+        #[must_use]
+        fn velocity_at(&self, t: f32) -> Vec2;
+
+        /// The [`Direction`] that the curve is heading at `t`
+        ///
+        /// If the curve is stationary at this point, [`Err(NearlySingularConversion)`] will be returned instead.
+        fn tangent_at(&self, t: f32) -> Result<Direction, NearlySingularConversion> {
+            Direction::try_from(self.velocity_at(t))
+        }
+
+        /// Approximates this curve as a [`Polyline`] with `segments` evenly spaced (in `t`) segments
+        #[must_use]
+        fn to_polyline(&self, segments: usize) -> Polyline<Self::C> {
+            let segments = segments.max(1);
+            (0..=segments)
+                .map(|i| self.position_at(i as f32 / segments as f32))
+                .collect()
+        }
+    }
+
+    /// A cubic Bézier curve, defined by four control points
+    ///
+    /// The curve starts at the first control point and ends at the last,
+    /// leaving and arriving in the direction of the two middle control points.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::orientation::{Direction, Orientation};
+    /// use leafwing_2d::path::{CubicBezier, Spline};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let swoop = CubicBezier::<F32>::new(
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(0.0, 1.0),
+    ///     Position::new(1.0, 1.0),
+    ///     Position::new(1.0, 0.0),
+    /// );
+    ///
+    /// assert_eq!(swoop.position_at(0.0), Position::new(0.0, 0.0));
+    /// assert_eq!(swoop.position_at(0.5), Position::new(0.5, 0.75));
+    /// assert_eq!(swoop.position_at(1.0), Position::new(1.0, 0.0));
+    ///
+    /// swoop.tangent_at(0.0).unwrap().assert_approx_eq(Direction::NORTH);
+    /// swoop.tangent_at(0.5).unwrap().assert_approx_eq(Direction::EAST);
+    /// swoop.tangent_at(1.0).unwrap().assert_approx_eq(Direction::SOUTH);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct CubicBezier<C: Coordinate> {
+        /// The four control points: start, start handle, end handle and end
+        pub control_points: [Position<C>; 4],
+    }
+
+    impl<C: Coordinate> CubicBezier<C> {
+        /// Creates a new [`CubicBezier`] curve from `start` to `end`
+        ///
+        /// The curve leaves `start` heading towards `start_handle`, and arrives at `end` heading away from `end_handle`.
+        #[inline]
+        #[must_use]
+        pub fn new(
+            start: Position<C>,
+            start_handle: Position<C>,
+            end_handle: Position<C>,
+            end: Position<C>,
+        ) -> Self {
+            CubicBezier {
+                control_points: [start, start_handle, end_handle, end],
+            }
+        }
+
+        fn control_vectors(&self) -> [Vec2; 4] {
+            self.control_points.map(Vec2::from)
+        }
+    }
+
+    impl<C: Coordinate> Spline for CubicBezier<C> {
+        type C = C;
+
+        fn position_at(&self, t: f32) -> Position<C> {
+            let [p0, p1, p2, p3] = self.control_vectors();
+            let t = t.clamp(0.0, 1.0);
+            let s = 1.0 - t;
+
+            (p0 * (s * s * s) + p1 * (3.0 * s * s * t) + p2 * (3.0 * s * t * t) + p3 * (t * t * t))
+                .into()
+        }
+
+        fn velocity_at(&self, t: f32) -> Vec2 {
+            let [p0, p1, p2, p3] = self.control_vectors();
+            let t = t.clamp(0.0, 1.0);
+            let s = 1.0 - t;
+
+            (p1 - p0) * (3.0 * s * s) + (p2 - p1) * (6.0 * s * t) + (p3 - p2) * (3.0 * t * t)
+        }
+    }
+
+    /// A uniform Catmull-Rom spline, which smoothly passes through each of its points in order
+    ///
+    /// The whole spline is parameterized by `t` from 0 to 1, with each segment between two points taking an equal share.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::path::{CatmullRom, Spline};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let flight_path = CatmullRom::<F32>::new([
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(1.0, 2.0),
+    ///     Position::new(3.0, 2.0),
+    /// ]);
+    ///
+    /// // Each point is visited exactly
+    /// assert_eq!(flight_path.position_at(0.0), Position::new(0.0, 0.0));
+    /// assert_eq!(flight_path.position_at(0.5), Position::new(1.0, 2.0));
+    /// assert_eq!(flight_path.position_at(1.0), Position::new(3.0, 2.0));
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CatmullRom<C: Coordinate> {
+        points: Vec<Position<C>>,
+    }
+
+    impl<C: Coordinate> CatmullRom<C> {
+        /// Creates a new [`CatmullRom`] spline that passes through each of the `points` in order
+        ///
+        /// # Panics
+        /// At least one point must be provided.
+        #[must_use]
+        pub fn new(points: impl IntoIterator<Item = Position<C>>) -> Self {
+            let points: Vec<Position<C>> = points.into_iter().collect();
+            assert!(!points.is_empty());
+
+            CatmullRom { points }
+        }
+
+        /// The points that this spline passes through
+        #[inline]
+        #[must_use]
+        pub fn points(&self) -> &[Position<C>] {
+            &self.points
+        }
+
+        /// Finds the four points that control the segment containing `t`, and the local parameter within that segment
+        fn segment(&self, t: f32) -> ([Vec2; 4], f32) {
+            let n_segments = self.points.len() - 1;
+            let scaled = t.clamp(0.0, 1.0) * n_segments as f32;
+            // Scaled is non-negative, so truncation is equivalent to flooring
+            let index = (scaled as usize).min(n_segments.saturating_sub(1));
+
+            let point = |i: usize| Vec2::from(self.points[i.min(n_segments)]);
+            let controls = [
+                point(index.saturating_sub(1)),
+                point(index),
+                point(index + 1),
+                point(index + 2),
+            ];
+
+            (controls, scaled - index as f32)
+        }
+    }
+
+    impl<C: Coordinate> Spline for CatmullRom<C> {
+        type C = C;
+
+        fn position_at(&self, t: f32) -> Position<C> {
+            let ([p0, p1, p2, p3], u) = self.segment(t);
+
+            let a = p1 * 2.0;
+            let b = p2 - p0;
+            let c = p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3;
+            let d = p1 * 3.0 - p0 - p2 * 3.0 + p3;
+
+            ((a + b * u + c * (u * u) + d * (u * u * u)) * 0.5).into()
+        }
+
+        fn velocity_at(&self, t: f32) -> Vec2 {
+            let ([p0, p1, p2, p3], u) = self.segment(t);
+
+            let b = p2 - p0;
+            let c = p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3;
+            let d = p1 * 3.0 - p0 - p2 * 3.0 + p3;
+
+            // Chain rule: each segment only covers 1 / n_segments of `t`
+            let n_segments = (self.points.len() - 1) as f32;
+            (b + c * (2.0 * u) + d * (3.0 * u * u)) * (0.5 * n_segments)
+        }
+    }
+
+    /// A [`Spline`] that can be sampled by distance travelled along it, rather than by its raw parameter
+    ///
+    /// Distances are approximated using a lookup table of cumulative lengths,
+    /// which is computed once when this is created.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::path::{ArcLengthParameterized, CubicBezier, Spline};
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let straight = CubicBezier::<F32>::new(
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(10.0, 0.0),
+    /// );
+    ///
+    /// // The curve starts slowly, so equal steps in `t` are not equal steps in distance
+    /// assert!(straight.position_at(0.5).x.0 < 5.0);
+    ///
+    /// let rail = ArcLengthParameterized::new(straight, 64);
+    /// assert!((rail.length() - 10.0).abs() < 1e-3);
+    /// assert!((rail.sample_at_distance(5.0).x.0 - 5.0).abs() < 1e-2);
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct ArcLengthParameterized<S: Spline> {
+        spline: S,
+        /// The distance along the spline at each of the evenly spaced samples of `t`
+        cumulative_lengths: Vec<f32>,
+    }
+
+    impl<S: Spline> ArcLengthParameterized<S> {
+        /// Measures the length of `spline` by splitting it into `samples` straight segments
+        ///
+        /// More samples are more accurate, but take more memory and are slower to create.
+        #[must_use]
+        pub fn new(spline: S, samples: usize) -> Self {
+            let samples = samples.max(1);
+            let mut cumulative_lengths = Vec::with_capacity(samples + 1);
+            cumulative_lengths.push(0.0);
+
+            let mut total = 0.0;
+            let mut previous = Vec2::from(spline.position_at(0.0));
+            for i in 1..=samples {
+                let current = Vec2::from(spline.position_at(i as f32 / samples as f32));
+                total += previous.distance(current);
+                cumulative_lengths.push(total);
+                previous = current;
+            }
+
+            ArcLengthParameterized {
+                spline,
+                cumulative_lengths,
+            }
+        }
+
+        /// The underlying [`Spline`]
+        #[inline]
+        #[must_use]
+        pub fn spline(&self) -> &S {
+            &self.spline
+        }
+
+        /// The total length of the spline
+        #[inline]
+        #[must_use]
+        pub fn length(&self) -> f32 {
+            self.cumulative_lengths.last().copied().unwrap_or_default()
+        }
+
+        /// Converts a `distance` along the spline into the spline's raw parameter `t`
+        ///
+        /// `distance` is clamped between 0 and the [`length`](Self::length) of the spline.
+        #[must_use]
+        pub fn parameter_at_distance(&self, distance: f32) -> f32 {
+            let samples = self.cumulative_lengths.len() - 1;
+            let distance = distance.clamp(0.0, self.length());
+
+            // The index of the first sample that is at least `distance` along the spline
+            let upper = self
+                .cumulative_lengths
+                .partition_point(|&length| length < distance)
+                .clamp(1, samples);
+            let (low, high) = (
+                self.cumulative_lengths[upper - 1],
+                self.cumulative_lengths[upper],
+            );
+
+            let fraction = if high > low {
+                (distance - low) / (high - low)
+            } else {
+                0.0
+            };
+
+            ((upper - 1) as f32 + fraction) / samples as f32
+        }
+
+        /// The position that is `distance` along the spline
+        #[inline]
+        #[must_use]
+        pub fn sample_at_distance(&self, distance: f32) -> Position<S::C> {
+            self.spline
+                .position_at(self.parameter_at_distance(distance))
+        }
+
+        /// The [`Direction`] that the spline is heading when `distance` along it
+        ///
+        /// If the curve is stationary at this point, [`Err(NearlySingularConversion)`] will be returned instead.
+        #[inline]
+        pub fn tangent_at_distance(
+            &self,
+            distance: f32,
+        ) -> Result<Direction, NearlySingularConversion> {
+            self.spline.tangent_at(self.parameter_at_distance(distance))
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Find shortest paths across discrete grids
+//!
+//! [`jump_point_search`] finds optimal paths on square grids where every step costs the same,
+//! such as an [`OrthogonalGrid`] or an [`AdjacentGrid`].
+//! It is a drop-in replacement for A* on these grids: rather than adding every neighbor of a cell to the open list,
+//! it skips straight along runs of open cells, only stopping at "jump points" where a wall forces the path to turn.
+//! This prunes the many symmetric paths across open ground, which A* would otherwise explore one by one.
+//!
+//! Straight steps cost 1 and diagonal steps cost √2.
+//! Diagonal steps never cut the corner of an impassable cell.
+
+use crate::discrete::{AdjacentGrid, DiscreteCoordinate, OrthogonalGrid};
+use crate::position::Position;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::f32::consts::SQRT_2;
+
+/// A square [`DiscreteCoordinate`] grid that can be searched with [`jump_point_search`]
+pub trait JumpPointGrid: DiscreteCoordinate<Data = isize> {
+    /// Can paths step diagonally between cells?
+    const DIAGONAL: bool;
+}
+
+impl JumpPointGrid for OrthogonalGrid {
+    const DIAGONAL: bool = false;
+}
+
+impl JumpPointGrid for AdjacentGrid {
+    const DIAGONAL: bool = true;
+}
+
+/// The shortest path from `start` to `goal`, stepping only between `passable` neighbors
+///
+/// The path includes both the `start` and the `goal`, and lists every cell along the way.
+/// Returns `None` if the `goal` cannot be reached.
+///
+/// # Warning
+///
+/// The grid is unbounded, so `passable` must enclose the area being searched,
+/// typically by returning `false` outside of the map.
+/// If it returns `true` forever in some direction, this will never finish.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::discrete::AdjacentGrid;
+/// use leafwing_2d::pathfinding::jump_point_search;
+/// use leafwing_2d::position::Position;
+///
+/// let cell = |x, y| Position::new(AdjacentGrid(x), AdjacentGrid(y));
+///
+/// // A 10x10 field, with a wall blocking most of the fifth column
+/// let passable = |position: Position<AdjacentGrid>| {
+///     let (x, y) = (position.x.0, position.y.0);
+///     (0..10).contains(&x) && (0..10).contains(&y) && !(x == 5 && y < 8)
+/// };
+///
+/// let path = jump_point_search(cell(0, 0), cell(9, 0), passable).unwrap();
+/// assert_eq!(path.first(), Some(&cell(0, 0)));
+/// assert_eq!(path.last(), Some(&cell(9, 0)));
+/// // The path has to go around the top of the wall
+/// assert!(path.contains(&cell(5, 8)));
+///
+/// // Walled off entirely
+/// assert_eq!(jump_point_search(cell(0, 0), cell(9, 0), |position| passable(position) && position.x.0 != 5), None);
+/// ```
+#[must_use]
+pub fn jump_point_search<C: JumpPointGrid>(
+    start: Position<C>,
+    goal: Position<C>,
+    mut passable: impl FnMut(Position<C>) -> bool,
+) -> Option<Vec<Position<C>>> {
+    let start = (start.x.into(), start.y.into());
+    let goal = (goal.x.into(), goal.y.into());
+
+    let mut searcher = Searcher {
+        goal,
+        diagonal: C::DIAGONAL,
+        passable: |(x, y): Cell| passable(Position::new(C::from(x), C::from(y))),
+    };
+    if !searcher.open(goal) {
+        return None;
+    }
+
+    let jump_points = searcher.search(start)?;
+    let mut path = vec![start];
+    for &(x, y) in &jump_points[1..] {
+        let &(mut current_x, mut current_y) = path.last().expect("The path starts with `start`.");
+        let (dx, dy) = ((x - current_x).signum(), (y - current_y).signum());
+        while (current_x, current_y) != (x, y) {
+            current_x += dx;
+            current_y += dy;
+            path.push((current_x, current_y));
+        }
+    }
+
+    Some(
+        path.into_iter()
+            .map(|(x, y)| Position::new(C::from(x), C::from(y)))
+            .collect(),
+    )
+}
+
+/// A cell of the grid, as `(x, y)`
+type Cell = (isize, isize);
+
+/// A cell waiting to be expanded, ordered so that the [`BinaryHeap`] pops the lowest estimated total cost first
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OpenCell {
+    estimated_cost: f32,
+    cell: Cell,
+}
+
+impl Eq for OpenCell {}
+
+impl PartialOrd for OpenCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimated_cost
+            .total_cmp(&self.estimated_cost)
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+/// The state shared by each step of [`jump_point_search`]
+struct Searcher<F> {
+    goal: Cell,
+    diagonal: bool,
+    passable: F,
+}
+
+impl<F: FnMut(Cell) -> bool> Searcher<F> {
+    #[inline]
+    fn open(&mut self, cell: Cell) -> bool {
+        (self.passable)(cell)
+    }
+
+    /// The cost of moving in straight and diagonal lines from `a` to `b`, ignoring walls
+    fn distance(&self, a: Cell, b: Cell) -> f32 {
+        let (dx, dy) = (a.0.abs_diff(b.0) as f32, a.1.abs_diff(b.1) as f32);
+
+        if self.diagonal {
+            dx.max(dy) + (SQRT_2 - 1.) * dx.min(dy)
+        } else {
+            dx + dy
+        }
+    }
+
+    /// A* over jump points, returning the jump points along the shortest path from `start` to the goal
+    fn search(&mut self, start: Cell) -> Option<Vec<Cell>> {
+        let mut costs = BTreeMap::from([(start, 0.)]);
+        let mut parents: BTreeMap<Cell, Cell> = BTreeMap::new();
+        let mut closed = BTreeSet::new();
+        let mut open = BinaryHeap::from([OpenCell {
+            estimated_cost: self.distance(start, self.goal),
+            cell: start,
+        }]);
+
+        while let Some(OpenCell { cell, .. }) = open.pop() {
+            if !closed.insert(cell) {
+                continue;
+            }
+
+            if cell == self.goal {
+                let mut jump_points = vec![cell];
+                while let Some(&parent) = parents.get(jump_points.last().unwrap()) {
+                    jump_points.push(parent);
+                }
+                jump_points.reverse();
+                return Some(jump_points);
+            }
+
+            let cost = costs[&cell];
+            for direction in self.directions(cell, parents.get(&cell).copied()) {
+                let Some(jump_point) = self.jump(cell, direction) else {
+                    continue;
+                };
+                if closed.contains(&jump_point) {
+                    continue;
+                }
+
+                let new_cost = cost + self.distance(cell, jump_point);
+                if costs
+                    .get(&jump_point)
+                    .is_none_or(|&old_cost| new_cost < old_cost)
+                {
+                    costs.insert(jump_point, new_cost);
+                    parents.insert(jump_point, cell);
+                    open.push(OpenCell {
+                        estimated_cost: new_cost + self.distance(jump_point, self.goal),
+                        cell: jump_point,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The directions worth searching from `cell`, given the jump point it was reached from
+    ///
+    /// Directions that lead into walls are pruned later, by [`Searcher::jump`].
+    fn directions(&self, (x, y): Cell, parent: Option<Cell>) -> Vec<Cell> {
+        let Some((parent_x, parent_y)) = parent else {
+            return if self.diagonal {
+                vec![
+                    (0, 1),
+                    (1, 1),
+                    (1, 0),
+                    (1, -1),
+                    (0, -1),
+                    (-1, -1),
+                    (-1, 0),
+                    (-1, 1),
+                ]
+            } else {
+                vec![(0, 1), (1, 0), (0, -1), (-1, 0)]
+            };
+        };
+
+        let (dx, dy) = ((x - parent_x).signum(), (y - parent_y).signum());
+        match (self.diagonal, dx, dy) {
+            (true, _, 0) => vec![(dx, 0), (dx, 1), (dx, -1), (0, 1), (0, -1)],
+            (true, 0, _) => vec![(0, dy), (1, dy), (-1, dy), (1, 0), (-1, 0)],
+            (true, _, _) => vec![(dx, 0), (0, dy), (dx, dy)],
+            (false, _, 0) => vec![(dx, 0), (0, 1), (0, -1)],
+            (false, _, _) => vec![(0, dy), (1, 0), (-1, 0)],
+        }
+    }
+
+    /// Moves from `from` in the direction `(dx, dy)` until reaching a jump point, or hitting a wall
+    fn jump(&mut self, from: Cell, (dx, dy): Cell) -> Option<Cell> {
+        let (mut x, mut y) = from;
+
+        loop {
+            // Diagonal steps cannot cut corners
+            if dx != 0 && dy != 0 && !(self.open((x + dx, y)) && self.open((x, y + dy))) {
+                return None;
+            }
+
+            x += dx;
+            y += dy;
+            if !self.open((x, y)) {
+                return None;
+            }
+            if (x, y) == self.goal {
+                return Some((x, y));
+            }
+
+            if dx != 0 && dy != 0 {
+                // Moving diagonally, so stop wherever a straight line would find a jump point
+                if self.jump((x, y), (dx, 0)).is_some() || self.jump((x, y), (0, dy)).is_some() {
+                    return Some((x, y));
+                }
+            } else if dx != 0 {
+                // A wall that has just ended forces the path to consider turning around it
+                if (self.open((x, y - 1)) && !self.open((x - dx, y - 1)))
+                    || (self.open((x, y + 1)) && !self.open((x - dx, y + 1)))
+                {
+                    return Some((x, y));
+                }
+            } else {
+                if (self.open((x - 1, y)) && !self.open((x - 1, y - dy)))
+                    || (self.open((x + 1, y)) && !self.open((x + 1, y - dy)))
+                {
+                    return Some((x, y));
+                }
+
+                // Without diagonal steps, vertical moves must stop wherever a horizontal line would find a jump point
+                if !self.diagonal
+                    && (self.jump((x, y), (1, 0)).is_some() || self.jump((x, y), (-1, 0)).is_some())
+                {
+                    return Some((x, y));
+                }
+            }
+        }
+    }
+}
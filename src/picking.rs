@@ -0,0 +1,235 @@
+//! Click on and hover over entities with the mouse
+//!
+//! Add a [`Pickable`] marker to entities with a world-space [`AxisAlignedBoundingBox`] or [`Circle`] component.
+//! The [`PickingPlugin`] keeps the [`CursorPosition`] up to date, tracks which entity is under the cursor in [`HoveredEntity`],
+//! and sends [`HoverStarted`], [`HoverEnded`] and [`PickedEntity`] events.
+//!
+//! When several entities are under the cursor, the one drawn on top (with the greatest z-value in its [`GlobalTransform`]) is picked.
+
+use crate::bounding::{AxisAlignedBoundingBox, BoundingRegion, Circle};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{mouse::MouseButton, Input, InputSystem};
+use bevy_render::camera::{Camera, RenderTarget};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::Windows;
+use core::marker::PhantomData;
+use glam::Vec2;
+
+/// The world-space [`Position`] of the mouse cursor, as seen through the first camera rendering to the window that contains it
+///
+/// This is `None` when the cursor is outside of every window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CursorPosition<C: Coordinate>(pub Option<Position<C>>);
+
+impl<C: Coordinate> Default for CursorPosition<C> {
+    fn default() -> Self {
+        CursorPosition(None)
+    }
+}
+
+/// Marks entities that can be hovered over and clicked on with the mouse
+///
+/// Pickable entities must also have a world-space [`AxisAlignedBoundingBox`] or [`Circle`] component, which is used for hit-testing.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Pickable;
+
+/// The [`Pickable`] entity that is currently under the cursor, if any
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HoveredEntity(pub Option<Entity>);
+
+/// Sent when the cursor moves onto a [`Pickable`] entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoverStarted {
+    /// The entity under the cursor
+    pub entity: Entity,
+}
+
+/// Sent when the cursor moves off of a [`Pickable`] entity
+///
+/// Entities that are despawned or lose their [`Pickable`] component while hovered also end their hover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoverEnded {
+    /// The entity that was under the cursor
+    pub entity: Entity,
+}
+
+/// Sent when a mouse button is pressed while the cursor is over a [`Pickable`] entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PickedEntity {
+    /// The entity under the cursor
+    pub entity: Entity,
+    /// The mouse button that was pressed
+    pub button: MouseButton,
+}
+
+/// Converts the position of the cursor in each window into world space, and stores it in the [`CursorPosition`] resource
+///
+/// The cursor is seen through the first [`Camera`] that renders to the window containing it.
+pub fn update_cursor_position<C: Coordinate>(
+    windows: Res<Windows>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut cursor_position: ResMut<CursorPosition<C>>,
+) {
+    let new_position = windows.iter().find_map(|window| {
+        let cursor = window.cursor_position()?;
+        let (camera, camera_transform) = cameras
+            .iter()
+            .find(|(camera, _)| camera.target == RenderTarget::Window(window.id()))?;
+
+        // From screen space, through normalized device coordinates, into world space
+        let window_size = Vec2::new(window.width(), window.height());
+        let ndc = cursor / window_size * 2. - Vec2::ONE;
+        let ndc_to_world = camera_transform.compute_matrix() * camera.projection_matrix.inverse();
+        let world = ndc_to_world.project_point3(ndc.extend(-1.));
+
+        Some(Position::from(world.truncate()))
+    });
+
+    // Avoid triggering change detection pointlessly
+    if cursor_position.0 != new_position {
+        cursor_position.0 = new_position;
+    }
+}
+
+/// Finds the [`Pickable`] entity under the [`CursorPosition`], sending [`HoverStarted`], [`HoverEnded`] and [`PickedEntity`] events
+///
+/// When several entities are under the cursor, the one with the greatest z-value in its [`GlobalTransform`] is chosen;
+/// entities without a [`GlobalTransform`] are treated as having a z-value of zero.
+/// Remaining ties go to the entity that was spawned most recently.
+pub fn pick_entities<C: Coordinate>(
+    cursor_position: Res<CursorPosition<C>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    pickables: Query<
+        (
+            Entity,
+            Option<&AxisAlignedBoundingBox<C>>,
+            Option<&Circle<C>>,
+            Option<&GlobalTransform>,
+        ),
+        With<Pickable>,
+    >,
+    mut hovered: ResMut<HoveredEntity>,
+    mut hover_started: EventWriter<HoverStarted>,
+    mut hover_ended: EventWriter<HoverEnded>,
+    mut picked: EventWriter<PickedEntity>,
+) {
+    let topmost = cursor_position.0.and_then(|cursor| {
+        pickables
+            .iter()
+            .filter(|(_, aabb, circle, _)| {
+                aabb.is_some_and(|aabb| aabb.contains(cursor))
+                    || circle.is_some_and(|circle| circle.contains(cursor))
+            })
+            .map(|(entity, _, _, transform)| {
+                (
+                    transform.map_or(0., |transform| transform.translation.z),
+                    entity,
+                )
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+            .map(|(_, entity)| entity)
+    });
+
+    if hovered.0 != topmost {
+        if let Some(entity) = hovered.0 {
+            hover_ended.send(HoverEnded { entity });
+        }
+        if let Some(entity) = topmost {
+            hover_started.send(HoverStarted { entity });
+        }
+        hovered.0 = topmost;
+    }
+
+    if let Some(entity) = topmost {
+        for &button in mouse_buttons.get_just_pressed() {
+            picked.send(PickedEntity { entity, button });
+        }
+    }
+}
+
+/// [`SystemLabel`] for [`PickingPlugin`]
+#[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PickingSystem {
+    /// Updates the [`CursorPosition`]
+    ///
+    /// Contains [`update_cursor_position::<C>`].
+    Cursor,
+    /// Hit-tests [`Pickable`] entities against the [`CursorPosition`] and sends events
+    ///
+    /// Contains [`pick_entities::<C>`].
+    Pick,
+}
+
+/// Adds the [`CursorPosition`] and [`HoveredEntity`] resources, the [`HoverStarted`], [`HoverEnded`] and [`PickedEntity`] events,
+/// and the systems that keep them up to date
+///
+/// The systems run in [`CoreStage::PreUpdate`], after mouse input has been processed,
+/// so that gameplay systems can respond to picking in the same frame.
+/// They are labelled with [`PickingSystem`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::Circle;
+/// use leafwing_2d::picking::{Pickable, PickedEntity, PickingPlugin};
+/// use leafwing_2d::prelude::*;
+///
+/// fn spawn_button(mut commands: Commands) {
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(Circle::<F32>::new(Position::new(0.0, 0.0), 1.0))
+///         .insert(Pickable);
+/// }
+///
+/// fn on_click(mut events: EventReader<PickedEntity>) {
+///     for event in events.iter() {
+///         println!("Clicked on {:?} with {:?}", event.entity, event.button);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(PickingPlugin::<F32>::default())
+///     .add_startup_system(spawn_button)
+///     .add_system(on_click);
+/// ```
+#[derive(Debug)]
+pub struct PickingPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for PickingPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for PickingPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CursorPosition<C>>()
+            .init_resource::<HoveredEntity>()
+            .init_resource::<Input<MouseButton>>()
+            .init_resource::<Windows>()
+            .add_event::<HoverStarted>()
+            .add_event::<HoverEnded>()
+            .add_event::<PickedEntity>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                update_cursor_position::<C>
+                    .label(PickingSystem::Cursor)
+                    .after(InputSystem),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                pick_entities::<C>
+                    .label(PickingSystem::Pick)
+                    .after(PickingSystem::Cursor),
+            );
+    }
+}
@@ -2,19 +2,27 @@
 
 use crate::continuous::F32;
 use crate::coordinate::Coordinate;
-use crate::kinematics::systems::{angular_kinematics, linear_kinematics};
-use crate::orientation::{Direction, Rotation};
+use crate::discrete::GridLayout;
+use crate::kinematics::systems::{
+    angular_dynamics, angular_kinematics, apply_knockback, carry_riders,
+    direction_angular_kinematics, face_velocity, follow_targets, linear_dynamics,
+    linear_kinematics,
+};
+use crate::kinematics::{Gravity, KinematicsIntegrator};
+use crate::orientation::{quat_heading, split_yaw, Direction, Rotation};
+use crate::parallax::{sync_parallax_layers, ParallaxLayer};
 use crate::position::Position;
+use crate::transform::{Anchor, PreserveTilt};
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::ShouldRun;
 use bevy_ecs::system::Resource;
-use bevy_math::Quat;
 use bevy_transform::components::Transform;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use glam::{Quat, Vec2};
 
 /// Ensures that two-dimensional [`Position`], [`Direction`] and [`Rotation`] components are synchronized with the [`Transform`] equivalent
 ///
@@ -32,6 +40,7 @@ use core::marker::PhantomData;
 /// use leafwing_2d::prelude::*;
 /// use leafwing_2d::plugin::GameState;
 /// use leafwing_2d::discrete::FlatHex;
+/// use leafwing_2d::kinematics::KinematicsIntegrator;
 /// use core::marker::PhantomData;
 ///
 /// // This is a sensible starting point for a grid-based game
@@ -40,6 +49,7 @@ use core::marker::PhantomData;
 ///     .add_plugin(TwoDPlugin {
 ///       kinematics: false,
 ///       kinematics_state: None,
+///       integrator: KinematicsIntegrator::default(),
 ///       stage: CoreStage::PostUpdate,
 ///       // Hexagons are the bestagons
 ///       coordinate_type: PhantomData::<FlatHex>::default(),
@@ -63,6 +73,12 @@ pub struct TwoDPlugin<
     ///
     /// Default: [`None`]
     pub kinematics_state: Option<UserState>,
+    /// How should [`Velocity`](crate::kinematics::Velocity) and [`Acceleration`](crate::kinematics::Acceleration) be applied each frame?
+    ///
+    /// This is inserted as a resource, which can be changed while the game is running.
+    ///
+    /// Default: [`KinematicsIntegrator::SemiImplicitEuler`]
+    pub integrator: KinematicsIntegrator,
     /// Which stage should these systems run in?
     ///
     /// Default: [`CoreStage::PostUpdate`]
@@ -78,6 +94,7 @@ impl Default for TwoDPlugin<F32, GameState, CoreStage> {
         Self {
             kinematics: true,
             kinematics_state: None,
+            integrator: KinematicsIntegrator::default(),
             stage: CoreStage::PostUpdate,
             coordinate_type: PhantomData::<F32>::default(),
         }
@@ -98,9 +115,30 @@ pub enum GameState {
 /// These labels are executed in sequence.
 #[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TwoDSystem {
+    /// Moves riders along with the entities that they are carried by
+    ///
+    /// Contains [`carry_riders::<C>`], and is part of [`TwoDSystem::Kinematics`].
+    Carry,
+    /// Moves followers towards their targets, once the targets have finished moving
+    ///
+    /// Contains [`follow_targets::<C>`], and is part of [`TwoDSystem::Kinematics`].
+    Follow,
+    /// Moves and turns entities according to their velocity and acceleration
+    ///
+    /// Contains [`linear_kinematics::<C>`], [`angular_kinematics`], [`direction_angular_kinematics`] and [`apply_knockback::<C>`],
+    /// and is part of [`TwoDSystem::Kinematics`].
+    Motion,
+    /// Turns entities to face the way that they are moving, once they have moved
+    ///
+    /// Contains [`face_velocity::<C>`], and is part of [`TwoDSystem::Kinematics`].
+    FaceVelocity,
+    /// Converts accumulated forces and torque into acceleration
+    ///
+    /// Contains [`linear_dynamics::<C>`] and [`angular_dynamics`], and is part of [`TwoDSystem::Kinematics`].
+    Dynamics,
     /// Applies acceleration and velocity
     ///
-    /// Contains [`linear_kinematics::<C>`] and [`angular_kinematics`].
+    /// Contains [`linear_kinematics::<C>`], [`angular_kinematics`], [`direction_angular_kinematics`], [`face_velocity::<C>`], [`linear_dynamics::<C>`], [`angular_dynamics`], [`carry_riders::<C>`], [`apply_knockback::<C>`] and [`follow_targets::<C>`].
     /// Disable these by setting the `kinematics` field of [`TwoDPlugin`].
     Kinematics,
     /// Synchronizes the [`Direction`] and [`Rotation`] of all entities
@@ -116,6 +154,10 @@ pub enum TwoDSystem {
     ///
     /// Contains [`sync_transform_with_2d`].
     SyncTransform,
+    /// Offsets the [`Transform`] of each [`ParallaxLayer`] by the position of the camera
+    ///
+    /// Contains [`sync_parallax_layers::<C>`].
+    SyncParallax,
 }
 
 impl<
@@ -126,9 +168,45 @@ impl<
 {
     fn build(&self, app: &mut App) {
         if self.kinematics {
+            app.insert_resource(self.integrator)
+                .init_resource::<Gravity<C>>();
+
             let kinematics_systems = SystemSet::new()
-                .with_system(linear_kinematics::<C>)
-                .with_system(angular_kinematics)
+                .with_system(carry_riders::<C>.label(TwoDSystem::Carry))
+                .with_system(linear_dynamics::<C>.label(TwoDSystem::Dynamics))
+                .with_system(
+                    face_velocity::<C>
+                        .label(TwoDSystem::FaceVelocity)
+                        .after(TwoDSystem::Dynamics)
+                        .after(TwoDSystem::Motion),
+                )
+                .with_system(
+                    linear_kinematics::<C>
+                        .label(TwoDSystem::Motion)
+                        .after(TwoDSystem::Dynamics)
+                        .after(TwoDSystem::Carry)
+                        .before(TwoDSystem::Follow),
+                )
+                .with_system(
+                    apply_knockback::<C>
+                        .label(TwoDSystem::Motion)
+                        .after(TwoDSystem::Carry)
+                        .before(TwoDSystem::Follow),
+                )
+                .with_system(follow_targets::<C>.label(TwoDSystem::Follow))
+                .with_system(angular_dynamics.label(TwoDSystem::Dynamics))
+                .with_system(
+                    angular_kinematics
+                        .label(TwoDSystem::Motion)
+                        .after(TwoDSystem::Dynamics)
+                        .after(TwoDSystem::Carry),
+                )
+                .with_system(
+                    direction_angular_kinematics
+                        .label(TwoDSystem::Motion)
+                        .after(TwoDSystem::Dynamics)
+                        .after(TwoDSystem::Carry),
+                )
                 .label(TwoDSystem::Kinematics)
                 .before(TwoDSystem::SyncDirectionRotation);
 
@@ -159,9 +237,21 @@ impl<
 
         let sync_systems = SystemSet::new()
             .with_system(sync_direction_and_rotation.label(TwoDSystem::SyncDirectionRotation))
-            .with_system(sync_transform_with_2d::<C>.label(TwoDSystem::SyncTransform));
+            .with_system(
+                sync_transform_with_2d::<C>
+                    .label(TwoDSystem::SyncTransform)
+                    .after(TwoDSystem::SyncDirectionRotation),
+            )
+            .with_system(
+                sync_parallax_layers::<C>
+                    .label(TwoDSystem::SyncParallax)
+                    .after(TwoDSystem::SyncTransform),
+            );
 
         app.add_system_set_to_stage(self.stage.clone(), sync_systems);
+
+        #[cfg(feature = "inspector")]
+        crate::inspector::register_inspectables::<C>(app);
     }
 }
 
@@ -193,11 +283,24 @@ pub fn sync_direction_and_rotation(mut query: Query<(&mut Direction, &mut Rotati
 /// and its 2D analogue have been changed, the 2D version will take priority.
 ///
 /// z-values of the [`Transform`] translation will not be modified.
-/// Any off-axis rotation of the [`Transform`]'s rotation quaternion will be lost.
+/// Any off-axis rotation of the [`Transform`]'s rotation quaternion will be lost,
+/// unless the entity is marked with [`PreserveTilt`], in which case only the turn around the z-axis is synchronized.
+///
+/// This should run after [`sync_direction_and_rotation`], so that [`Direction`] and [`Rotation`] agree.
+/// If both have changed, the [`Transform`] is updated from the [`Direction`], which can be converted without any trigonometry.
+///
+/// Entities with an [`Anchor`] are rotated and scaled around their pivot: their [`Position`] is the location of the pivot,
+/// and the translation of their [`Transform`] is offset accordingly.
+///
+/// The translation of each [`ParallaxLayer`] is managed by [`sync_parallax_layers`] instead, and is never copied back to its [`Position`].
+///
+/// If a [`GridLayout<C>`] resource exists, it is used to convert between [`Position`] and the [`Transform`] translation,
+/// and every [`Transform`] is updated whenever it changes.
 // FIXME: also sync `Scale`.
 pub fn sync_transform_with_2d<C: Coordinate>(
     mut query: Query<
         (
+            Entity,
             &mut Transform,
             Option<&mut Rotation>,
             Option<&mut Direction>,
@@ -205,57 +308,112 @@ pub fn sync_transform_with_2d<C: Coordinate>(
         ),
         Or<(With<Rotation>, With<Position<C>>)>,
     >,
+    parallax_layers: Query<(), With<ParallaxLayer>>,
+    anchors: Query<(&Anchor<C>, ChangeTrackers<Anchor<C>>)>,
+    preserve_tilt: Query<(), With<PreserveTilt>>,
+    layout: Option<Res<GridLayout<C>>>,
 ) {
-    for (mut transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut() {
-        // Synchronize Rotation with Transform
-        if let Some(mut rotation) = maybe_rotation {
-            if rotation.is_changed() {
-                let new_quat: Quat = (*rotation).into();
-                if transform.rotation != new_quat {
-                    transform.rotation = new_quat;
-                }
-            } else if transform.is_changed() {
-                if let Ok(new_rotation) = transform.rotation.try_into() {
-                    if *rotation != new_rotation {
-                        *rotation = new_rotation;
-                    }
+    // Moving the grid moves every entity on it
+    let layout_changed = layout.as_ref().is_some_and(|layout| layout.is_changed());
+
+    for (entity, mut transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut()
+    {
+        // Synchronize Rotation and Direction with Transform
+        let changed_direction = maybe_direction
+            .as_ref()
+            .filter(|direction| direction.is_changed())
+            .map(|direction| **direction);
+        let changed_rotation = maybe_rotation
+            .as_ref()
+            .filter(|rotation| rotation.is_changed())
+            .map(|rotation| **rotation);
+
+        // Directions convert to quaternions without any trigonometry,
+        // so only one quaternion is built, even when both have changed
+        let new_quat: Option<Quat> = changed_direction
+            .map(Quat::from)
+            .or_else(|| changed_rotation.map(Quat::from));
+
+        // Only the yaw of tilted entities is synchronized, and the rest of their rotation is reapplied on top
+        let tilted = preserve_tilt.contains(entity);
+        let new_quat = if tilted {
+            new_quat.map(|yaw| split_yaw(transform.rotation).0 * yaw)
+        } else {
+            new_quat
+        };
+
+        let mut rotation_synced_from_2d = false;
+        if let Some(new_quat) = new_quat {
+            if transform.rotation != new_quat {
+                transform.rotation = new_quat;
+                rotation_synced_from_2d = true;
+            }
+        } else if transform.is_changed() && (maybe_rotation.is_some() || maybe_direction.is_some())
+        {
+            // The heading is shared between both conversions, rather than rotating a vector by the quaternion twice
+            let heading = if tilted {
+                quat_heading(split_yaw(transform.rotation).1)
+            } else {
+                quat_heading(transform.rotation)
+            };
+
+            if let Some(mut rotation) = maybe_rotation {
+                let new_rotation = Rotation::from_vec2(heading).unwrap_or_default();
+                if *rotation != new_rotation {
+                    *rotation = new_rotation;
                 }
             }
-        }
 
-        // Synchronize Direction with Transform
-        if let Some(mut direction) = maybe_direction {
-            if direction.is_changed() {
-                let new_quat = (*direction).into();
-                if transform.rotation != new_quat {
-                    transform.rotation = new_quat;
+            if let Some(mut direction) = maybe_direction {
+                let new_direction = Direction::try_from(heading).unwrap_or_default();
+                if *direction != new_direction {
+                    *direction = new_direction;
                 }
-            } else if transform.is_changed() && *direction != transform.rotation.into() {
-                *direction = transform.rotation.into();
             }
         }
 
         // Synchronize Position with Transform
+        if parallax_layers.contains(entity) {
+            continue;
+        }
+
         if let Some(mut position) = maybe_position {
-            if position.is_changed() {
-                let new_x: f32 = position.x.into();
-                if transform.translation.x != new_x {
-                    transform.translation.x = new_x;
+            // Where the pivot is, relative to the translation of the transform
+            let (anchor_offset, anchor_changed) = match anchors.get(entity) {
+                Ok((anchor, anchor_tracker)) => (
+                    anchor.world_offset(&transform),
+                    // Turning an anchored entity moves its center
+                    anchor_tracker.is_changed() || rotation_synced_from_2d,
+                ),
+                Err(_) => (Vec2::ZERO, false),
+            };
+
+            if position.is_changed() || anchor_changed || layout_changed {
+                let world = match &layout {
+                    Some(layout) => layout.cell_to_world(*position),
+                    None => Vec2::from(*position),
+                };
+                let new_translation = world - anchor_offset;
+                if transform.translation.x != new_translation.x {
+                    transform.translation.x = new_translation.x;
                 }
 
-                let new_y: f32 = position.y.into();
-                if transform.translation.y != new_y {
-                    transform.translation.y = new_y;
+                if transform.translation.y != new_translation.y {
+                    transform.translation.y = new_translation.y;
                 }
             } else if transform.is_changed() {
-                let new_x = C::from(transform.translation.x);
-                if position.x != new_x {
-                    position.x = new_x;
+                let world = transform.translation.truncate() + anchor_offset;
+                let new_position = match &layout {
+                    Some(layout) => layout.world_to_cell(world),
+                    None => Position::from(world),
+                };
+
+                if position.x != new_position.x {
+                    position.x = new_position.x;
                 }
 
-                let new_y = C::from(transform.translation.y);
-                if position.y != new_y {
-                    position.y = new_y;
+                if position.y != new_position.y {
+                    position.y = new_position.y;
                 }
             }
         }
@@ -0,0 +1,671 @@
+//! Polygonal shapes made up of [`Positions`](crate::position::Position)
+//!
+//! All lengths and areas are measured in the same units as the [`f32`] conversion of the [`Coordinate`](crate::coordinate::Coordinate) type.
+
+pub use polygon_struct::Polygon;
+pub use triangle::Triangle;
+
+mod triangle {
+    use crate::coordinate::Coordinate;
+    use crate::geometry::closest_point_on_segment;
+    use crate::position::Position;
+    use glam::Vec2;
+
+    /// A triangle, defined by its three corners
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::polygon::Triangle;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let triangle = Triangle::<F32>::new(
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(4.0, 0.0),
+    ///     Position::new(0.0, 3.0),
+    /// );
+    ///
+    /// assert_eq!(triangle.area(), 6.0);
+    /// assert!(triangle.contains(Position::new(1.0, 1.0)));
+    /// assert!(!triangle.contains(Position::new(3.0, 3.0)));
+    ///
+    /// assert_eq!(triangle.circumcenter(), Some(Position::new(2.0, 1.5)));
+    /// assert_eq!(triangle.closest_point(Position::new(-1.0, 1.0)), Position::new(0.0, 1.0));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Triangle<C: Coordinate> {
+        /// The first corner
+        pub a: Position<C>,
+        /// The second corner
+        pub b: Position<C>,
+        /// The third corner
+        pub c: Position<C>,
+    }
+
+    impl<C: Coordinate> Triangle<C> {
+        /// Creates a new [`Triangle`] from its three corners
+        #[inline]
+        #[must_use]
+        pub const fn new(a: Position<C>, b: Position<C>, c: Position<C>) -> Self {
+            Triangle { a, b, c }
+        }
+
+        /// The three corners of this triangle, in order
+        #[inline]
+        #[must_use]
+        pub const fn vertexes(&self) -> [Position<C>; 3] {
+            [self.a, self.b, self.c]
+        }
+
+        fn vectors(&self) -> [Vec2; 3] {
+            self.vertexes().map(Vec2::from)
+        }
+
+        /// The area of this triangle
+        #[inline]
+        #[must_use]
+        pub fn area(&self) -> f32 {
+            self.signed_area().abs()
+        }
+
+        /// The area of this triangle, which is positive if its corners wind counterclockwise and negative if they wind clockwise
+        #[must_use]
+        pub fn signed_area(&self) -> f32 {
+            let [a, b, c] = self.vectors();
+            (b - a).perp_dot(c - a) / 2.
+        }
+
+        /// Computes the barycentric coordinates of `position` with respect to this triangle
+        ///
+        /// These are the weights of `a`, `b` and `c` (in that order) that sum to 1 and reproduce `position`.
+        /// All three weights are between 0 and 1 if and only if `position` is inside of the triangle.
+        ///
+        /// Returns [`None`] if the triangle is degenerate (its corners are collinear), as these weights are not unique.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::polygon::Triangle;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let triangle = Triangle::<F32>::new(
+        ///     Position::new(0.0, 0.0),
+        ///     Position::new(2.0, 0.0),
+        ///     Position::new(0.0, 2.0),
+        /// );
+        ///
+        /// assert_eq!(triangle.barycentric(Position::new(0.0, 0.0)), Some([1.0, 0.0, 0.0]));
+        /// assert_eq!(triangle.barycentric(Position::new(1.0, 1.0)), Some([0.0, 0.5, 0.5]));
+        /// ```
+        #[must_use]
+        pub fn barycentric(&self, position: Position<C>) -> Option<[f32; 3]> {
+            let [a, b, c] = self.vectors();
+            let p = Vec2::from(position);
+
+            let double_area = (b - a).perp_dot(c - a);
+            if double_area == 0.0 {
+                return None;
+            }
+
+            let weight_b = (p - a).perp_dot(c - a) / double_area;
+            let weight_c = (b - a).perp_dot(p - a) / double_area;
+
+            Some([1. - weight_b - weight_c, weight_b, weight_c])
+        }
+
+        /// Does this triangle contain the `position`?
+        ///
+        /// Positions on the edges are contained.
+        /// Degenerate triangles (whose corners are collinear) contain nothing.
+        #[must_use]
+        pub fn contains(&self, position: Position<C>) -> bool {
+            match self.barycentric(position) {
+                Some(weights) => weights.iter().all(|&weight| weight >= 0.0),
+                None => false,
+            }
+        }
+
+        /// The average of the three corners, which is always inside of the triangle
+        #[must_use]
+        pub fn centroid(&self) -> Position<C> {
+            let [a, b, c] = self.vectors();
+            ((a + b + c) / 3.).into()
+        }
+
+        /// The center of the unique circle that passes through all three corners
+        ///
+        /// Returns [`None`] if the triangle is degenerate (its corners are collinear).
+        #[must_use]
+        pub fn circumcenter(&self) -> Option<Position<C>> {
+            let [a, b, c] = self.vectors();
+            let (ab, ac) = (b - a, c - a);
+
+            let denominator = 2. * ab.perp_dot(ac);
+            if denominator == 0.0 {
+                return None;
+            }
+
+            let offset =
+                (ac.length_squared() * ab.perp() - ab.length_squared() * ac.perp()) / denominator;
+            Some((a + offset).into())
+        }
+
+        /// Finds the point inside of (or on the edge of) this triangle that is closest to `position`
+        #[must_use]
+        pub fn closest_point(&self, position: Position<C>) -> Position<C> {
+            if self.contains(position) {
+                return position;
+            }
+
+            let [a, b, c] = self.vectors();
+            let p = Vec2::from(position);
+
+            [(a, b), (b, c), (c, a)]
+                .into_iter()
+                .map(|(start, end)| closest_point_on_segment(p, start, end))
+                .min_by(|x, y| x.distance_squared(p).total_cmp(&y.distance_squared(p)))
+                .expect("A triangle always has three edges.")
+                .into()
+        }
+    }
+}
+
+mod polygon_struct {
+    use super::Triangle;
+    use crate::bounding::AxisAlignedBoundingBox;
+    use crate::coordinate::Coordinate;
+    use crate::math;
+    use crate::position::Position;
+    use alloc::vec::Vec;
+    #[cfg(feature = "bevy")]
+    use bevy_ecs::prelude::Component;
+    use glam::Vec2;
+
+    /// A simple polygon: a closed shape whose edges connect each of its vertexes in order, and do not cross each other
+    ///
+    /// The last vertex is connected back to the first, so it should not be repeated.
+    /// Vertexes may be listed in either clockwise or counterclockwise order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::polygon::Polygon;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// // An L-shaped room
+    /// let room = Polygon::<F32>::new([
+    ///     Position::new(0.0, 0.0),
+    ///     Position::new(2.0, 0.0),
+    ///     Position::new(2.0, 1.0),
+    ///     Position::new(1.0, 1.0),
+    ///     Position::new(1.0, 2.0),
+    ///     Position::new(0.0, 2.0),
+    /// ]);
+    ///
+    /// assert_eq!(room.area(), 3.0);
+    /// assert!(!room.is_convex());
+    /// assert!(room.contains(Position::new(0.5, 1.5)));
+    /// assert!(!room.contains(Position::new(1.5, 1.5)));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "bevy", derive(Component))]
+    pub struct Polygon<C: Coordinate> {
+        vertexes: Vec<Position<C>>,
+    }
+
+    impl<C: Coordinate> Polygon<C> {
+        /// Creates a new [`Polygon`] with the provided `vertexes`, which are connected in order
+        #[inline]
+        #[must_use]
+        pub fn new(vertexes: impl IntoIterator<Item = Position<C>>) -> Polygon<C> {
+            Polygon {
+                vertexes: vertexes.into_iter().collect(),
+            }
+        }
+
+        /// The corners of this polygon, in order
+        #[inline]
+        #[must_use]
+        pub fn vertexes(&self) -> &[Position<C>] {
+            &self.vertexes
+        }
+
+        /// Iterates over each edge of this polygon, as `(start, end)` pairs
+        ///
+        /// The final edge connects the last vertex back to the first.
+        pub fn edges(&self) -> impl Iterator<Item = (Position<C>, Position<C>)> + '_ {
+            let n = self.vertexes.len();
+            (0..n).map(move |i| (self.vertexes[i], self.vertexes[(i + 1) % n]))
+        }
+
+        fn vectors(&self) -> Vec<Vec2> {
+            self.vertexes.iter().map(|&vertex| vertex.into()).collect()
+        }
+
+        /// The area of this polygon, which is positive if its vertexes wind counterclockwise and negative if they wind clockwise
+        #[must_use]
+        pub fn signed_area(&self) -> f32 {
+            self.edges()
+                .map(|(start, end)| Vec2::from(start).perp_dot(end.into()))
+                .sum::<f32>()
+                / 2.
+        }
+
+        /// The area enclosed by this polygon
+        #[inline]
+        #[must_use]
+        pub fn area(&self) -> f32 {
+            math::abs(self.signed_area())
+        }
+
+        /// Is every interior angle of this polygon 180 degrees or less?
+        ///
+        /// Polygons with less than three vertexes are trivially convex.
+        #[must_use]
+        pub fn is_convex(&self) -> bool {
+            is_convex(&self.vectors())
+        }
+
+        /// Does this polygon contain the `position`?
+        ///
+        /// Positions exactly on the edges of the polygon may or may not be contained.
+        #[must_use]
+        pub fn contains(&self, position: Position<C>) -> bool {
+            let point = Vec2::from(position);
+            let mut inside = false;
+
+            // Count how many edges a ray heading in the +x direction crosses
+            for (start, end) in self.edges() {
+                let (start, end) = (Vec2::from(start), Vec2::from(end));
+                if (start.y > point.y) != (end.y > point.y) {
+                    let crossing_x =
+                        start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x);
+                    if point.x < crossing_x {
+                        inside = !inside;
+                    }
+                }
+            }
+
+            inside
+        }
+
+        /// Splits this polygon into triangles using ear clipping
+        ///
+        /// The returned triangles always wind counterclockwise, and cover exactly the same area as the polygon.
+        /// Polygons with less than three vertexes produce no triangles.
+        #[must_use]
+        pub fn triangulate(&self) -> Vec<Triangle<C>> {
+            triangulate_indexes(&self.vectors())
+                .into_iter()
+                .map(|[a, b, c]| {
+                    Triangle::new(self.vertexes[a], self.vertexes[b], self.vertexes[c])
+                })
+                .collect()
+        }
+
+        /// Splits this polygon into convex pieces, using the Hertel-Mehlhorn algorithm
+        ///
+        /// The polygon is first triangulated, and then neighboring pieces are merged whenever the result would still be convex.
+        /// This produces at most four times the minimum possible number of pieces.
+        /// The returned polygons always wind counterclockwise.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::polygon::Polygon;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let room = Polygon::<F32>::new([
+        ///     Position::new(0.0, 0.0),
+        ///     Position::new(2.0, 0.0),
+        ///     Position::new(2.0, 1.0),
+        ///     Position::new(1.0, 1.0),
+        ///     Position::new(1.0, 2.0),
+        ///     Position::new(0.0, 2.0),
+        /// ]);
+        ///
+        /// let pieces = room.convex_decomposition();
+        /// assert_eq!(pieces.len(), 2);
+        /// assert!(pieces.iter().all(|piece| piece.is_convex()));
+        /// assert_eq!(pieces.iter().map(|piece| piece.area()).sum::<f32>(), room.area());
+        /// ```
+        #[must_use]
+        pub fn convex_decomposition(&self) -> Vec<Polygon<C>> {
+            let vectors = self.vectors();
+            let mut pieces: Vec<Vec<usize>> = triangulate_indexes(&vectors)
+                .into_iter()
+                .map(Vec::from)
+                .collect();
+
+            // Repeatedly remove diagonals between pieces, as long as the merged piece stays convex
+            let mut merged_any = true;
+            while merged_any {
+                merged_any = false;
+
+                'search: for i in 0..pieces.len() {
+                    for j in (i + 1)..pieces.len() {
+                        if let Some(merged) = merge_if_convex(&pieces[i], &pieces[j], &vectors) {
+                            pieces[i] = merged;
+                            pieces.swap_remove(j);
+                            merged_any = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+
+            pieces
+                .into_iter()
+                .map(|piece| Polygon::new(piece.into_iter().map(|index| self.vertexes[index])))
+                .collect()
+        }
+
+        /// Computes the region covered by both this polygon and `other`
+        ///
+        /// The result is returned as a collection of non-overlapping convex pieces, which wind counterclockwise.
+        /// Pieces with no area (such as where the polygons only touch along an edge) are discarded.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::polygon::Polygon;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let square = Polygon::<F32>::new([
+        ///     Position::new(0.0, 0.0),
+        ///     Position::new(2.0, 0.0),
+        ///     Position::new(2.0, 2.0),
+        ///     Position::new(0.0, 2.0),
+        /// ]);
+        /// let diamond = Polygon::<F32>::new([
+        ///     Position::new(2.0, 0.0),
+        ///     Position::new(4.0, 2.0),
+        ///     Position::new(2.0, 4.0),
+        ///     Position::new(0.0, 2.0),
+        /// ]);
+        ///
+        /// let overlap = square.intersection(&diamond);
+        /// assert_eq!(overlap.iter().map(|piece| piece.area()).sum::<f32>(), 2.0);
+        /// ```
+        #[must_use]
+        pub fn intersection(&self, other: &Polygon<C>) -> Vec<Polygon<C>> {
+            let minimum_area = sliver_area(self, other);
+            let others: Vec<Vec<Vec2>> = other
+                .convex_decomposition()
+                .iter()
+                .map(Polygon::vectors)
+                .collect();
+
+            self.convex_decomposition()
+                .iter()
+                .flat_map(|piece| {
+                    let piece = piece.vectors();
+                    others
+                        .iter()
+                        .map(move |other| convex_intersection(&piece, other))
+                })
+                .filter_map(|piece| to_polygon(piece, minimum_area))
+                .collect()
+        }
+
+        /// Computes the region covered by this polygon, but not by `other`
+        ///
+        /// The result is returned as a collection of non-overlapping convex pieces, which wind counterclockwise.
+        /// Pieces with no area are discarded.
+        /// Subtracting a polygon that lies entirely inside of this one leaves a hole,
+        /// which is represented by the pieces surrounding it.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::polygon::Polygon;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let floor = Polygon::<F32>::new([
+        ///     Position::new(0.0, 0.0),
+        ///     Position::new(10.0, 0.0),
+        ///     Position::new(10.0, 10.0),
+        ///     Position::new(0.0, 10.0),
+        /// ]);
+        /// let obstacle = Polygon::<F32>::new([
+        ///     Position::new(4.0, 4.0),
+        ///     Position::new(6.0, 4.0),
+        ///     Position::new(5.0, 6.0),
+        /// ]);
+        ///
+        /// let walkable = floor.difference(&obstacle);
+        /// assert_eq!(walkable.iter().map(|piece| piece.area()).sum::<f32>(), 98.0);
+        /// assert!(!walkable.iter().any(|piece| piece.contains(Position::new(5.0, 5.0))));
+        /// assert!(walkable.iter().any(|piece| piece.contains(Position::new(5.0, 3.0))));
+        /// ```
+        #[must_use]
+        pub fn difference(&self, other: &Polygon<C>) -> Vec<Polygon<C>> {
+            let minimum_area = sliver_area(self, other);
+            let mut pieces: Vec<Vec<Vec2>> = self
+                .convex_decomposition()
+                .iter()
+                .map(Polygon::vectors)
+                .collect();
+
+            for subtracted in other.convex_decomposition() {
+                let subtracted = subtracted.vectors();
+                pieces = pieces
+                    .iter()
+                    .flat_map(|piece| convex_difference(piece, &subtracted))
+                    .collect();
+            }
+
+            pieces
+                .into_iter()
+                .filter_map(|piece| to_polygon(piece, minimum_area))
+                .collect()
+        }
+    }
+
+    impl<C: Coordinate> From<AxisAlignedBoundingBox<C>> for Polygon<C> {
+        fn from(aabb: AxisAlignedBoundingBox<C>) -> Polygon<C> {
+            Polygon::new([
+                aabb.bottom_left(),
+                aabb.bottom_right(),
+                aabb.top_right(),
+                aabb.top_left(),
+            ])
+        }
+    }
+
+    impl<C: Coordinate> From<Vec<Position<C>>> for Polygon<C> {
+        fn from(vertexes: Vec<Position<C>>) -> Polygon<C> {
+            Polygon { vertexes }
+        }
+    }
+
+    impl<C: Coordinate> FromIterator<Position<C>> for Polygon<C> {
+        fn from_iter<I: IntoIterator<Item = Position<C>>>(iter: I) -> Polygon<C> {
+            Polygon::new(iter)
+        }
+    }
+
+    /// Twice the signed area of the triangle `a`, `b`, `c`: positive if they turn counterclockwise
+    fn turn(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+        (b - a).perp_dot(c - b)
+    }
+
+    fn is_convex(vectors: &[Vec2]) -> bool {
+        let n = vectors.len();
+        if n < 3 {
+            return true;
+        }
+
+        let mut has_left_turn = false;
+        let mut has_right_turn = false;
+        for i in 0..n {
+            let turn = turn(vectors[i], vectors[(i + 1) % n], vectors[(i + 2) % n]);
+            has_left_turn |= turn > 0.0;
+            has_right_turn |= turn < 0.0;
+        }
+
+        !(has_left_turn && has_right_turn)
+    }
+
+    /// Triangulates the simple polygon with the provided vertexes using ear clipping
+    ///
+    /// Returns the indexes of the corners of each triangle, in counterclockwise order.
+    fn triangulate_indexes(vectors: &[Vec2]) -> Vec<[usize; 3]> {
+        let n = vectors.len();
+        if n < 3 {
+            return Vec::new();
+        }
+
+        let signed_area: f32 = (0..n)
+            .map(|i| vectors[i].perp_dot(vectors[(i + 1) % n]))
+            .sum();
+        let mut remaining: Vec<usize> = if signed_area < 0.0 {
+            (0..n).rev().collect()
+        } else {
+            (0..n).collect()
+        };
+
+        let mut triangles = Vec::with_capacity(n - 2);
+        while remaining.len() > 3 {
+            let len = remaining.len();
+            let ear = (0..len).find(|&i| {
+                let [prev, current, next] = [
+                    remaining[(i + len - 1) % len],
+                    remaining[i],
+                    remaining[(i + 1) % len],
+                ];
+                let (a, b, c) = (vectors[prev], vectors[current], vectors[next]);
+
+                if turn(a, b, c) <= 0.0 {
+                    return false;
+                }
+
+                // No other vertex may be inside of the ear
+                let ear = Triangle::<crate::continuous::F32>::new(a.into(), b.into(), c.into());
+                remaining.iter().all(|&other| {
+                    let point = vectors[other];
+                    point == a || point == b || point == c || !ear.contains(point.into())
+                })
+            });
+
+            // Degenerate polygons (such as those with crossing edges) may have no ears left
+            let Some(ear) = ear else { break };
+
+            triangles.push([
+                remaining[(ear + len - 1) % len],
+                remaining[ear],
+                remaining[(ear + 1) % len],
+            ]);
+            remaining.remove(ear);
+        }
+
+        if remaining.len() == 3 {
+            triangles.push([remaining[0], remaining[1], remaining[2]]);
+        }
+
+        triangles
+    }
+
+    /// Pieces smaller than this are rounding error left over from clipping `a` and `b` against each other
+    fn sliver_area<C: Coordinate>(a: &Polygon<C>, b: &Polygon<C>) -> f32 {
+        a.area().max(b.area()) * 1e-6
+    }
+
+    /// Converts a clipped piece back into a [`Polygon`], unless it has (nearly) no area
+    fn to_polygon<C: Coordinate>(mut piece: Vec<Vec2>, minimum_area: f32) -> Option<Polygon<C>> {
+        piece.dedup();
+        if piece.len() > 1 && piece.first() == piece.last() {
+            piece.pop();
+        }
+
+        let polygon: Polygon<C> = piece.into_iter().map(Position::from).collect();
+        (polygon.vertexes.len() >= 3 && polygon.area() > minimum_area).then_some(polygon)
+    }
+
+    /// Clips the convex `polygon` to the left of the infinite line running from `start` to `end`
+    ///
+    /// If `keep_left` is false, the part to the right of the line is kept instead.
+    fn clip_to_half_plane(polygon: &[Vec2], start: Vec2, end: Vec2, keep_left: bool) -> Vec<Vec2> {
+        let direction = end - start;
+        let side = |point: Vec2| {
+            let side = direction.perp_dot(point - start);
+            if keep_left {
+                side
+            } else {
+                -side
+            }
+        };
+
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for (i, &current) in polygon.iter().enumerate() {
+            let next = polygon[(i + 1) % polygon.len()];
+            let (current_side, next_side) = (side(current), side(next));
+
+            if current_side >= 0.0 {
+                clipped.push(current);
+            }
+            if (current_side > 0.0 && next_side < 0.0) || (current_side < 0.0 && next_side > 0.0) {
+                let t = current_side / (current_side - next_side);
+                clipped.push(current + (next - current) * t);
+            }
+        }
+
+        clipped
+    }
+
+    /// The region covered by both of the counterclockwise convex polygons
+    fn convex_intersection(a: &[Vec2], b: &[Vec2]) -> Vec<Vec2> {
+        let mut clipped = a.to_vec();
+        for i in 0..b.len() {
+            if clipped.is_empty() {
+                break;
+            }
+            clipped = clip_to_half_plane(&clipped, b[i], b[(i + 1) % b.len()], true);
+        }
+
+        clipped
+    }
+
+    /// The region covered by the counterclockwise convex polygon `a` but not `b`, as non-overlapping convex pieces
+    fn convex_difference(a: &[Vec2], b: &[Vec2]) -> Vec<Vec<Vec2>> {
+        let mut pieces = Vec::new();
+        let mut remaining = a.to_vec();
+
+        // Peel off the part of `a` outside of each edge of `b` in turn
+        for i in 0..b.len() {
+            let (start, end) = (b[i], b[(i + 1) % b.len()]);
+
+            let outside = clip_to_half_plane(&remaining, start, end, false);
+            if outside.len() >= 3 {
+                pieces.push(outside);
+            }
+
+            remaining = clip_to_half_plane(&remaining, start, end, true);
+            if remaining.len() < 3 {
+                break;
+            }
+        }
+
+        pieces
+    }
+
+    /// Merges two counterclockwise pieces that share an edge, if the result is convex
+    fn merge_if_convex(first: &[usize], second: &[usize], vectors: &[Vec2]) -> Option<Vec<usize>> {
+        let (n, m) = (first.len(), second.len());
+
+        // Find an edge a -> b in `first` that is traversed as b -> a in `second`
+        let (i, j) = (0..n).find_map(|i| {
+            let (a, b) = (first[i], first[(i + 1) % n]);
+            (0..m)
+                .find(|&j| second[j] == b && second[(j + 1) % m] == a)
+                .map(|j| (i, j))
+        })?;
+
+        // Walk `first` from b around to a, then `second` from just after a to just before b
+        let mut merged: Vec<usize> = (1..=n).map(|k| first[(i + k) % n]).collect();
+        merged.extend((2..m).map(|k| second[(j + k) % m]));
+
+        let merged_vectors: Vec<Vec2> = merged.iter().map(|&index| vectors[index]).collect();
+        is_convex(&merged_vectors).then_some(merged)
+    }
+}
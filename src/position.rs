@@ -1,15 +1,20 @@
 //! 2-dimensional coordinates
 
 // Re-exporting the derive macro
+pub use batch_conversions::{positions_to_vec2, vec2_to_positions};
 pub use position_struct::Position;
 pub use positionlike::Positionlike;
+pub use quantized_position::QuantizedPosition;
 
 mod position_struct {
     use crate::coordinate::Coordinate;
     use crate::errors::NearlySingularConversion;
-    use crate::orientation::OrientationPositionInterop;
+    use crate::orientation::{Direction, OrientationPositionInterop, Rotation};
+    #[cfg(feature = "bevy")]
     use bevy_ecs::prelude::Component;
+    use core::cmp::Ordering;
     use derive_more::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+    use glam::Vec2;
 
     /// A 2-dimensional coordinate
     ///
@@ -27,7 +32,6 @@ mod position_struct {
     /// assert_eq!(player_position - origin, player_position);
     /// ```
     #[derive(
-        Component,
         Default,
         Clone,
         Copy,
@@ -38,7 +42,10 @@ mod position_struct {
         DivAssign,
         RemAssign,
         PartialEq,
+        Eq,
+        Hash,
     )]
+    #[cfg_attr(feature = "bevy", derive(Component))]
     pub struct Position<C: Coordinate> {
         /// The first coordinate of the position, typically the x-axis
         pub x: C,
@@ -58,6 +65,22 @@ mod position_struct {
         }
     }
 
+    /// Positions are ordered by their `x` coordinate, then their `y` coordinate,
+    /// so positions on totally ordered coordinates can be stored in a [`BTreeSet`](alloc::collections::BTreeSet)
+    impl<C: Coordinate + Ord> PartialOrd for Position<C> {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<C: Coordinate + Ord> Ord for Position<C> {
+        #[inline]
+        fn cmp(&self, other: &Self) -> Ordering {
+            (self.x, self.y).cmp(&(other.x, other.y))
+        }
+    }
+
     impl<C: Coordinate> Position<C> {
         /// Gets the [`Orientation`](crate::orientation::Orientation) that points away from this position towards `other_position`
         ///
@@ -102,14 +125,207 @@ mod position_struct {
             O::orientation_between_positions(other_position, *self)
         }
     }
+
+    impl<C: Coordinate> Position<C> {
+        /// Creates the position that is `distance` away from the origin, in the direction of `rotation`
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::orientation::Rotation;
+        /// use leafwing_2d::position::{Position, Positionlike};
+        ///
+        /// Position::<F32>::from_polar(Rotation::EAST, 2.0).assert_approx_eq(Position::<F32>::new(2.0, 0.0));
+        /// Position::<F32>::from_polar(Rotation::SOUTH, 3.0).assert_approx_eq(Position::<F32>::new(0.0, -3.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn from_polar(rotation: Rotation, distance: impl Into<C>) -> Position<C> {
+            let distance: f32 = distance.into().into();
+
+            (rotation.into_vec2() * distance).into()
+        }
+
+        /// Returns the position that is `distance` away from this position, in the provided `direction`
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::orientation::Direction;
+        /// use leafwing_2d::position::{Position, Positionlike};
+        ///
+        /// let ship = Position::<F32>::new(10.0, 5.0);
+        /// let facing = Direction::WEST;
+        ///
+        /// let bullet_spawn = ship.offset_in_direction(facing, 20.0);
+        /// bullet_spawn.assert_approx_eq(Position::<F32>::new(-10.0, 5.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn offset_in_direction(
+            &self,
+            direction: Direction,
+            distance: impl Into<C>,
+        ) -> Position<C> {
+            let distance: f32 = distance.into().into();
+
+            (Vec2::from(*self) + direction.unit_vector() * distance).into()
+        }
+
+        /// Returns this position after rotating it clockwise around the `pivot` by `rotation`
+        ///
+        /// The distance to the `pivot` is unchanged.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::orientation::Rotation;
+        /// use leafwing_2d::position::{Position, Positionlike};
+        ///
+        /// let pivot = Position::<F32>::new(1.0, 1.0);
+        /// let hardpoint = Position::<F32>::new(1.0, 3.0);
+        ///
+        /// hardpoint.rotated_around(pivot, Rotation::EAST).assert_approx_eq(Position::<F32>::new(3.0, 1.0));
+        /// hardpoint.rotated_around(pivot, Rotation::SOUTH).assert_approx_eq(Position::<F32>::new(1.0, -1.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn rotated_around(&self, pivot: Position<C>, rotation: Rotation) -> Position<C> {
+            let pivot_vec = Vec2::from(pivot);
+            let offset = Vec2::from(*self) - pivot_vec;
+
+            (pivot_vec + rotation.rotate_vec2(offset)).into()
+        }
+
+        /// Rotates this position clockwise around the `pivot` by `rotation`, in place
+        ///
+        /// See [`Position::rotated_around`] for more details.
+        #[inline]
+        pub fn rotate_around(&mut self, pivot: Position<C>, rotation: Rotation) {
+            *self = self.rotated_around(pivot, rotation);
+        }
+
+        /// Returns this position after rotating it clockwise around the `pivot` by `quarter_turns` multiples of 90 degrees
+        ///
+        /// Negative values rotate counterclockwise.
+        /// Unlike [`Position::rotated_around`], this is computed exactly, without converting to [`f32`],
+        /// and so maps cells of a square grid onto other cells.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::discrete::OrthogonalGrid;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let cell = |x, y| Position::<OrthogonalGrid>::new(OrthogonalGrid(x), OrthogonalGrid(y));
+        ///
+        /// assert_eq!(cell(1, 3).rotated_quarter_turns(cell(1, 1), 1), cell(3, 1));
+        /// assert_eq!(cell(1, 3).rotated_quarter_turns(cell(1, 1), 2), cell(1, -1));
+        /// assert_eq!(cell(1, 3).rotated_quarter_turns(cell(1, 1), -1), cell(-1, 1));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn rotated_quarter_turns(&self, pivot: Position<C>, quarter_turns: i32) -> Position<C> {
+            match quarter_turns.rem_euclid(4) {
+                0 => *self,
+                1 => Position {
+                    x: pivot.x + self.y - pivot.y,
+                    y: pivot.y + pivot.x - self.x,
+                },
+                2 => Position {
+                    x: pivot.x + pivot.x - self.x,
+                    y: pivot.y + pivot.y - self.y,
+                },
+                _ => Position {
+                    x: pivot.x + pivot.y - self.y,
+                    y: pivot.y + self.x - pivot.x,
+                },
+            }
+        }
+
+        /// Returns this position after reflecting it across the line that passes through `origin` in the direction of `axis`
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::orientation::Direction;
+        /// use leafwing_2d::position::{Position, Positionlike};
+        ///
+        /// let origin = Position::<F32>::new(0.0, 0.0);
+        /// let ball = Position::<F32>::new(3.0, 1.0);
+        ///
+        /// ball.reflected_across(origin, Direction::NORTH).assert_approx_eq(Position::<F32>::new(-3.0, 1.0));
+        /// ball.reflected_across(origin, Direction::NORTHEAST).assert_approx_eq(Position::<F32>::new(1.0, 3.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn reflected_across(&self, origin: Position<C>, axis: Direction) -> Position<C> {
+            let origin_vec = Vec2::from(origin);
+            let offset = Vec2::from(*self) - origin_vec;
+            let axis = axis.unit_vector();
+
+            let reflected = 2. * offset.dot(axis) * axis - offset;
+            (origin_vec + reflected).into()
+        }
+
+        /// Returns this position after mirroring it across the vertical line at `x`
+        ///
+        /// This is computed exactly, without converting to [`f32`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let position = Position::<F32>::new(1.0, 2.0);
+        ///
+        /// assert_eq!(position.mirror_x(0.0), Position::new(-1.0, 2.0));
+        /// assert_eq!(position.mirror_x(5.0), Position::new(9.0, 2.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn mirror_x(&self, x: impl Into<C>) -> Position<C> {
+            let x = x.into();
+
+            Position {
+                x: x + x - self.x,
+                y: self.y,
+            }
+        }
+
+        /// Returns this position after mirroring it across the horizontal line at `y`
+        ///
+        /// This is computed exactly, without converting to [`f32`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::continuous::F32;
+        /// use leafwing_2d::position::Position;
+        ///
+        /// let position = Position::<F32>::new(1.0, 2.0);
+        ///
+        /// assert_eq!(position.mirror_y(0.0), Position::new(1.0, -2.0));
+        /// assert_eq!(position.mirror_y(5.0), Position::new(1.0, 8.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn mirror_y(&self, y: impl Into<C>) -> Position<C> {
+            let y = y.into();
+
+            Position {
+                x: self.x,
+                y: y + y - self.y,
+            }
+        }
+    }
 }
 
 mod positionlike {
     use super::Position;
     use crate::coordinate::Coordinate;
-    use bevy_math::{Vec2, Vec3};
+    #[cfg(feature = "bevy")]
     use bevy_transform::components::{GlobalTransform, Transform};
     use core::fmt::Debug;
+    use glam::{DVec2, DVec3, Vec2, Vec3};
 
     /// A type that can be treated like a 2D (x,y) [`Position`]
     pub trait Positionlike: Sized + Copy + Debug + 'static {
@@ -138,12 +354,14 @@ mod positionlike {
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl Positionlike for Transform {
         fn into_vec2(self) -> Vec2 {
             self.translation.truncate()
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl Positionlike for GlobalTransform {
         fn into_vec2(self) -> Vec2 {
             self.translation.truncate()
@@ -161,12 +379,24 @@ mod positionlike {
             self.truncate()
         }
     }
+
+    impl Positionlike for DVec2 {
+        fn into_vec2(self) -> Vec2 {
+            self.as_vec2()
+        }
+    }
+
+    impl Positionlike for DVec3 {
+        fn into_vec2(self) -> Vec2 {
+            self.truncate().as_vec2()
+        }
+    }
 }
 
 mod basic_operations {
     use super::Position;
     use crate::coordinate::Coordinate;
-    use std::ops::*;
+    use core::ops::*;
 
     impl<C: Coordinate> Add<Position<C>> for Position<C> {
         type Output = Self;
@@ -238,47 +468,39 @@ mod basic_operations {
 // When relevant, z-values are simply ignored
 mod conversions {
     use super::*;
+    use crate::continuous::F64;
     use crate::coordinate::Coordinate;
     use crate::errors::NearlySingularConversion;
     use crate::orientation::{Direction, Rotation};
-    use bevy_math::{Quat, Vec2, Vec3};
+    #[cfg(feature = "bevy")]
     use bevy_transform::components::{GlobalTransform, Transform};
+    use glam::{DVec2, DVec3, Quat, Vec2, Vec3};
 
     // Transform-like to Coordinate
 
     impl<C: Coordinate> From<Vec2> for Position<C> {
         fn from(vec: Vec2) -> Position<C> {
-            let x = C::from(vec.x);
-            let y = C::from(vec.y);
-
-            Position { x, y }
+            C::vec2_to_position(vec)
         }
     }
 
     impl<C: Coordinate> From<Vec3> for Position<C> {
         fn from(vec: Vec3) -> Position<C> {
-            let x = C::from(vec.x);
-            let y = C::from(vec.y);
-
-            Position { x, y }
+            C::vec2_to_position(vec.truncate())
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl<C: Coordinate> From<Transform> for Position<C> {
         fn from(transform: Transform) -> Position<C> {
-            let x = C::from(transform.translation.x);
-            let y = C::from(transform.translation.y);
-
-            Position { x, y }
+            C::vec2_to_position(transform.translation.truncate())
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl<C: Coordinate> From<GlobalTransform> for Position<C> {
         fn from(transform: GlobalTransform) -> Position<C> {
-            let x = C::from(transform.translation.x);
-            let y = C::from(transform.translation.y);
-
-            Position { x, y }
+            C::vec2_to_position(transform.translation.truncate())
         }
     }
 
@@ -286,37 +508,59 @@ mod conversions {
 
     impl<C: Coordinate> From<Position<C>> for Vec2 {
         fn from(position: Position<C>) -> Vec2 {
-            let x = position.x.into();
-            let y = position.y.into();
-
-            Vec2::new(x, y)
+            C::position_to_vec2(position)
         }
     }
 
     impl<C: Coordinate> From<Position<C>> for Vec3 {
         fn from(position: Position<C>) -> Vec3 {
-            let x = position.x.into();
-            let y = position.y.into();
-
-            Vec3::new(x, y, 0.0)
+            C::position_to_vec2(position).extend(0.0)
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl<C: Coordinate> From<Position<C>> for Transform {
         fn from(position: Position<C>) -> Transform {
-            let x = position.x.into();
-            let y = position.y.into();
-
-            Transform::from_xyz(x, y, 0.0)
+            Transform::from_translation(C::position_to_vec2(position).extend(0.0))
         }
     }
 
+    #[cfg(feature = "bevy")]
     impl<C: Coordinate> From<Position<C>> for GlobalTransform {
         fn from(position: Position<C>) -> GlobalTransform {
-            let x = position.x.into();
-            let y = position.y.into();
+            GlobalTransform::from_translation(C::position_to_vec2(position).extend(0.0))
+        }
+    }
+
+    // Double-precision
 
-            GlobalTransform::from_xyz(x, y, 0.0)
+    impl From<DVec2> for Position<F64> {
+        fn from(vec: DVec2) -> Position<F64> {
+            Position {
+                x: F64(vec.x),
+                y: F64(vec.y),
+            }
+        }
+    }
+
+    impl From<DVec3> for Position<F64> {
+        fn from(vec: DVec3) -> Position<F64> {
+            Position {
+                x: F64(vec.x),
+                y: F64(vec.y),
+            }
+        }
+    }
+
+    impl From<Position<F64>> for DVec2 {
+        fn from(position: Position<F64>) -> DVec2 {
+            DVec2::new(position.x.0, position.y.0)
+        }
+    }
+
+    impl From<Position<F64>> for DVec3 {
+        fn from(position: Position<F64>) -> DVec3 {
+            DVec3::new(position.x.0, position.y.0, 0.0)
         }
     }
 
@@ -326,8 +570,7 @@ mod conversions {
         type Error = NearlySingularConversion;
 
         fn try_from(position: Position<C>) -> Result<Direction, NearlySingularConversion> {
-            // We can bypass scaling here, since the magnitude is normalized anyways
-            let vec2: Vec2 = Vec2::new(position.x.into(), position.y.into());
+            let vec2 = C::position_to_vec2(position);
 
             vec2.try_into()
         }
@@ -337,8 +580,7 @@ mod conversions {
         type Error = NearlySingularConversion;
 
         fn try_from(position: Position<C>) -> Result<Rotation, NearlySingularConversion> {
-            // We can bypass scaling here, since the magnitude is normalized anyways
-            let vec2: Vec2 = Vec2::new(position.x.into(), position.y.into());
+            let vec2 = C::position_to_vec2(position);
 
             vec2.try_into()
         }
@@ -354,3 +596,242 @@ mod conversions {
         }
     }
 }
+
+mod batch_conversions {
+    use super::Position;
+    use crate::coordinate::Coordinate;
+    use alloc::vec::Vec;
+    use glam::Vec2;
+
+    /// Converts a slice of [`Positions`](Position) into [`Vec2`], storing the results in `output`
+    ///
+    /// `output` is cleared first, but its allocation is reused.
+    /// Prefer this over collecting into a new [`Vec`] when filling instance buffers
+    /// or particle systems every frame, as no memory needs to be allocated once `output` is large enough.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::{positions_to_vec2, Position};
+    ///
+    /// let positions: Vec<Position<F32>> = (0..100).map(|i| Position::new(i as f32, -1.0)).collect();
+    /// let mut buffer = Vec::new();
+    ///
+    /// positions_to_vec2(&positions, &mut buffer);
+    /// assert_eq!(buffer.len(), 100);
+    /// assert_eq!(buffer[42], Vec2::new(42.0, -1.0));
+    /// ```
+    pub fn positions_to_vec2<C: Coordinate>(positions: &[Position<C>], output: &mut Vec<Vec2>) {
+        output.clear();
+        output.extend(
+            positions
+                .iter()
+                .map(|&position| C::position_to_vec2(position)),
+        );
+    }
+
+    /// Converts a slice of [`Vec2`] into [`Positions`](Position), storing the results in `output`
+    ///
+    /// `output` is cleared first, but its allocation is reused.
+    /// This is the inverse of [`positions_to_vec2`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_2d::discrete::OrthogonalGrid;
+    /// use leafwing_2d::position::{vec2_to_positions, Position};
+    ///
+    /// let buffer = vec![Vec2::new(0.2, 0.8), Vec2::new(-3.0, 4.4)];
+    /// let mut positions = Vec::new();
+    ///
+    /// vec2_to_positions(&buffer, &mut positions);
+    /// assert_eq!(positions, vec![Position::<OrthogonalGrid>::new(0, 1), Position::new(-3, 4)]);
+    /// ```
+    pub fn vec2_to_positions<C: Coordinate>(vectors: &[Vec2], output: &mut Vec<Position<C>>) {
+        output.clear();
+        output.extend(vectors.iter().map(|&vec| C::vec2_to_position(vec)));
+    }
+}
+
+mod quantized_position {
+    use super::Position;
+    use crate::bounding::AxisAlignedBoundingBox;
+    use crate::coordinate::Coordinate;
+    use crate::errors::PositionOutOfBounds;
+    use crate::math;
+    use glam::Vec2;
+
+    /// A [`Position`] compressed into a fixed number of bits per axis, designed to be sent over the network
+    ///
+    /// Positions are mapped linearly from a bounding [`AxisAlignedBoundingBox`] onto the integers `0..=MAX_STEP`.
+    /// This conversion is lossy, but the error is bounded:
+    /// the round-trip error along each axis is at most half of a step, as reported by [`QuantizedPosition::max_error`].
+    ///
+    /// `BITS_PER_AXIS` must be between 1 and 32 (inclusive).
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::position::{Position, QuantizedPosition};
+    ///
+    /// let world = AxisAlignedBoundingBox::<F32>::new(-100.0, 100.0, -100.0, 100.0);
+    /// let position = Position::<F32>::new(12.34, -56.78);
+    ///
+    /// // 16 bits per axis fits the whole position into a single `u32`
+    /// let quantized = QuantizedPosition::<16>::quantize(position, &world).unwrap();
+    /// let round_trip: Position<F32> = quantized.dequantize(&world);
+    ///
+    /// let max_error = QuantizedPosition::<16>::max_error(&world);
+    /// assert!((round_trip.x.0 - position.x.0).abs() <= max_error.x);
+    /// assert!((round_trip.y.0 - position.y.0).abs() <= max_error.y);
+    ///
+    /// // Positions outside of the bounds cannot be quantized directly
+    /// let outlier = Position::<F32>::new(9001.0, 0.0);
+    /// assert!(QuantizedPosition::<16>::quantize(outlier, &world).is_err());
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+    pub struct QuantizedPosition<const BITS_PER_AXIS: u32> {
+        x: u32,
+        y: u32,
+    }
+
+    impl<const BITS_PER_AXIS: u32> QuantizedPosition<BITS_PER_AXIS> {
+        /// The largest value that can be stored along each axis
+        pub const MAX_STEP: u32 = {
+            assert!(
+                BITS_PER_AXIS >= 1 && BITS_PER_AXIS <= 32,
+                "`BITS_PER_AXIS` must be between 1 and 32."
+            );
+
+            if BITS_PER_AXIS == 32 {
+                u32::MAX
+            } else {
+                (1 << BITS_PER_AXIS) - 1
+            }
+        };
+
+        /// Creates a new [`QuantizedPosition`] directly from its raw per-axis steps
+        ///
+        /// Returns `None` if either value is greater than [`QuantizedPosition::MAX_STEP`].
+        #[inline]
+        #[must_use]
+        pub const fn from_steps(x: u32, y: u32) -> Option<Self> {
+            if x <= Self::MAX_STEP && y <= Self::MAX_STEP {
+                Some(QuantizedPosition { x, y })
+            } else {
+                None
+            }
+        }
+
+        /// The raw step along the x-axis
+        #[inline]
+        #[must_use]
+        pub const fn x(&self) -> u32 {
+            self.x
+        }
+
+        /// The raw step along the y-axis
+        #[inline]
+        #[must_use]
+        pub const fn y(&self) -> u32 {
+            self.y
+        }
+
+        /// Packs both axes into a single integer, with `x` stored in the higher bits
+        ///
+        /// Only the lowest `2 * BITS_PER_AXIS` bits can be set.
+        #[inline]
+        #[must_use]
+        pub const fn to_bits(self) -> u64 {
+            ((self.x as u64) << BITS_PER_AXIS) | self.y as u64
+        }
+
+        /// Unpacks a value created by [`QuantizedPosition::to_bits`]
+        ///
+        /// Any bits above the lowest `2 * BITS_PER_AXIS` are ignored.
+        #[inline]
+        #[must_use]
+        pub const fn from_bits(bits: u64) -> Self {
+            let mask = Self::MAX_STEP as u64;
+
+            QuantizedPosition {
+                x: ((bits >> BITS_PER_AXIS) & mask) as u32,
+                y: (bits & mask) as u32,
+            }
+        }
+
+        /// Quantizes `position` relative to the provided `bounds`
+        ///
+        /// # Errors
+        /// Returns [`PositionOutOfBounds`] if `position` does not lie within `bounds`.
+        pub fn quantize<C: Coordinate>(
+            position: Position<C>,
+            bounds: &AxisAlignedBoundingBox<C>,
+        ) -> Result<Self, PositionOutOfBounds> {
+            if position.x < bounds.left
+                || position.x > bounds.right
+                || position.y < bounds.bottom
+                || position.y > bounds.top
+            {
+                Err(PositionOutOfBounds)
+            } else {
+                Ok(Self::quantize_clamped(position, bounds))
+            }
+        }
+
+        /// Quantizes `position` relative to the provided `bounds`, clamping it to the nearest representable value
+        #[must_use]
+        pub fn quantize_clamped<C: Coordinate>(
+            position: Position<C>,
+            bounds: &AxisAlignedBoundingBox<C>,
+        ) -> Self {
+            QuantizedPosition {
+                x: Self::quantize_axis(position.x.into(), bounds.left.into(), bounds.right.into()),
+                y: Self::quantize_axis(position.y.into(), bounds.bottom.into(), bounds.top.into()),
+            }
+        }
+
+        /// Converts this value back into a [`Position`], using the same `bounds` that it was quantized with
+        #[must_use]
+        pub fn dequantize<C: Coordinate>(self, bounds: &AxisAlignedBoundingBox<C>) -> Position<C> {
+            let x = Self::dequantize_axis(self.x, bounds.left.into(), bounds.right.into());
+            let y = Self::dequantize_axis(self.y, bounds.bottom.into(), bounds.top.into());
+
+            Position::new(x, y)
+        }
+
+        /// The largest possible round-trip error along each axis when quantizing positions within `bounds`
+        ///
+        /// This is half of the width of a single step plus the precision of [`f32`] at the edges of `bounds`,
+        /// measured in the same units as the [`f32`] conversion of `C`.
+        /// Coordinate types that round when converted from [`f32`] may add up to their own rounding error on top of this.
+        #[must_use]
+        pub fn max_error<C: Coordinate>(bounds: &AxisAlignedBoundingBox<C>) -> Vec2 {
+            let low = Vec2::new(bounds.left.into(), bounds.bottom.into());
+            let high = Vec2::new(bounds.right.into(), bounds.top.into());
+
+            let half_step = (high - low) / (2. * Self::MAX_STEP as f32);
+            let float_precision = low.abs().max(high.abs()) * f32::EPSILON;
+
+            half_step + float_precision
+        }
+
+        fn quantize_axis(value: f32, low: f32, high: f32) -> u32 {
+            let range = high as f64 - low as f64;
+            if range <= 0.0 {
+                return 0;
+            }
+
+            // f64 is needed to represent all 2^32 steps without collisions
+            let fraction = ((value as f64 - low as f64) / range).clamp(0.0, 1.0);
+            math::round_f64(fraction * Self::MAX_STEP as f64) as u32
+        }
+
+        fn dequantize_axis(step: u32, low: f32, high: f32) -> f32 {
+            let fraction = step as f64 / Self::MAX_STEP as f64;
+            (low as f64 + fraction * (high as f64 - low as f64)) as f32
+        }
+    }
+}
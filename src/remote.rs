@@ -0,0 +1,241 @@
+//! Smoothly display entities that are simulated somewhere else, such as on a game server
+//!
+//! Each time an authoritative [`RemoteSnapshot`] arrives, pass it to the entity's [`RemoteKinematics`].
+//! Between snapshots, the [`RemoteKinematicsPlugin`] extrapolates where the entity should be by now (dead reckoning),
+//! and blends its displayed [`Position`] and [`Rotation`] towards that estimate, rather than teleporting it whenever a correction arrives.
+
+use crate::coordinate::Coordinate;
+use crate::kinematics::{damp_towards, decay_factor, Velocity};
+use crate::math;
+use crate::orientation::{Orientation, Rotation};
+use crate::plugin::TwoDSystem;
+use crate::position::Position;
+use bevy_app::prelude::*;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use core::time::Duration;
+use glam::Vec2;
+
+/// The authoritative kinematic state of an entity, as of `timestamp`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoteSnapshot<C: Coordinate> {
+    /// The [`Position`] of the entity
+    pub position: Position<C>,
+    /// The [`Velocity`] of the entity
+    pub velocity: Velocity<C>,
+    /// The [`Rotation`] of the entity
+    pub rotation: Rotation,
+    /// When this snapshot was taken, in seconds since startup
+    ///
+    /// This should be measured on the same clock as [`Time::seconds_since_startup`].
+    pub timestamp: f64,
+}
+
+/// Predicts and smooths the motion of an entity that is simulated remotely
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Velocity;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::remote::{RemoteKinematics, RemoteSnapshot};
+///
+/// let mut remote = RemoteKinematics::<F32>::new(0.1);
+/// let snapshot = RemoteSnapshot {
+///     position: Position::new(0.0, 0.0),
+///     velocity: Velocity { x: F32(2.0), y: F32(0.0) },
+///     rotation: Rotation::EAST,
+///     timestamp: 1.0,
+/// };
+/// assert!(remote.receive(snapshot));
+///
+/// // Snapshots that arrive out of order are ignored
+/// assert!(!remote.receive(RemoteSnapshot { timestamp: 0.5, ..snapshot }));
+///
+/// // A tenth of a second after the snapshot was taken, the entity should have moved a little further
+/// let (position, rotation) = remote.extrapolate(1.1).unwrap();
+/// assert!((position.x.0 - 0.2).abs() < 1e-5);
+/// assert_eq!(rotation, Rotation::EAST);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct RemoteKinematics<C: Coordinate> {
+    latest: Option<RemoteSnapshot<C>>,
+    /// How many seconds it takes to correct half of the error between the displayed and predicted states
+    ///
+    /// Smaller values are more accurate, while larger values hide more jitter.
+    pub correction_half_life: f32,
+    /// The longest that a snapshot will be extrapolated for, in seconds
+    ///
+    /// This stops entities from sliding off into the distance when snapshots stop arriving.
+    ///
+    /// Default: 0.25 seconds
+    pub max_extrapolation: f32,
+    /// Errors larger than this are corrected instantly, rather than smoothly
+    ///
+    /// Use this to avoid visibly sliding entities across the map after they teleport.
+    ///
+    /// Default: [`f32::INFINITY`]
+    pub snap_distance: f32,
+}
+
+impl<C: Coordinate> RemoteKinematics<C> {
+    /// Creates a new [`RemoteKinematics`] without any snapshots, using the default `max_extrapolation` and `snap_distance`
+    #[must_use]
+    pub fn new(correction_half_life: f32) -> Self {
+        Self {
+            latest: None,
+            correction_half_life,
+            max_extrapolation: 0.25,
+            snap_distance: f32::INFINITY,
+        }
+    }
+
+    /// Stores a new authoritative `snapshot`
+    ///
+    /// Returns `false` (and ignores the snapshot) if it is older than the latest snapshot.
+    pub fn receive(&mut self, snapshot: RemoteSnapshot<C>) -> bool {
+        if self
+            .latest
+            .is_some_and(|latest| snapshot.timestamp < latest.timestamp)
+        {
+            return false;
+        }
+
+        self.latest = Some(snapshot);
+        true
+    }
+
+    /// The most recent snapshot, if any have been received
+    #[inline]
+    #[must_use]
+    pub fn latest(&self) -> Option<&RemoteSnapshot<C>> {
+        self.latest.as_ref()
+    }
+
+    /// Predicts the position and rotation of the entity at the time `now`, in seconds since startup
+    ///
+    /// The position is moved along by the snapshot's velocity, for at most `max_extrapolation` seconds.
+    /// Returns `None` if no snapshots have been received.
+    #[must_use]
+    pub fn extrapolate(&self, now: f64) -> Option<(Position<C>, Rotation)> {
+        self.latest.map(|snapshot| {
+            let elapsed = ((now - snapshot.timestamp) as f32).clamp(0., self.max_extrapolation);
+            let position = snapshot.position + snapshot.velocity * Duration::from_secs_f32(elapsed);
+
+            (position, snapshot.rotation)
+        })
+    }
+}
+
+/// Blends each entity with [`RemoteKinematics`] towards its predicted [`Position`] and [`Rotation`]
+///
+/// The entity's [`Velocity`] is set to the velocity of the latest snapshot,
+/// so that the local kinematics keep the entity moving smoothly between corrections.
+/// This should run after [`TwoDSystem::Kinematics`].
+pub fn reconcile_remote_kinematics<C: Coordinate>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &RemoteKinematics<C>, &mut Position<C>)>,
+    mut rotations: Query<&mut Rotation>,
+    mut velocities: Query<&mut Velocity<C>>,
+) {
+    let now = time.seconds_since_startup();
+    let delta_time = time.delta();
+
+    for (entity, remote, mut position) in query.iter_mut() {
+        let (predicted_position, predicted_rotation) = match remote.extrapolate(now) {
+            Some(prediction) => prediction,
+            None => continue,
+        };
+
+        let error = Vec2::from(predicted_position).distance(Vec2::from(*position));
+        let new_position = if error > remote.snap_distance {
+            predicted_position
+        } else {
+            damp_towards(
+                *position,
+                predicted_position,
+                remote.correction_half_life,
+                delta_time,
+            )
+        };
+        // Avoid triggering change detection pointlessly
+        if *position != new_position {
+            *position = new_position;
+        }
+
+        if let Ok(mut rotation) = rotations.get_mut(entity) {
+            let remaining = decay_factor(remote.correction_half_life, delta_time);
+            let error = f32::from(rotation.distance(predicted_rotation).deci_degrees());
+            let correction = Rotation::new(math::round(error * (1. - remaining)) as u16);
+
+            let mut new_rotation = *rotation;
+            new_rotation.rotate_towards(predicted_rotation, Some(correction));
+            if *rotation != new_rotation {
+                *rotation = new_rotation;
+            }
+        }
+
+        if let (Ok(mut velocity), Some(snapshot)) = (velocities.get_mut(entity), remote.latest()) {
+            if *velocity != snapshot.velocity {
+                *velocity = snapshot.velocity;
+            }
+        }
+    }
+}
+
+/// Adds the [`reconcile_remote_kinematics`] system, which smooths out the motion of remotely simulated entities
+///
+/// The system runs in [`CoreStage::PostUpdate`], after [`TwoDSystem::Kinematics`] and before [`TwoDSystem::SyncDirectionRotation`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::remote::{RemoteKinematics, RemoteKinematicsPlugin, RemoteSnapshot};
+///
+/// /// Stand-in for your networking library
+/// struct IncomingSnapshots(Vec<(Entity, RemoteSnapshot<F32>)>);
+///
+/// fn receive_snapshots(
+///     mut incoming: ResMut<IncomingSnapshots>,
+///     mut query: Query<&mut RemoteKinematics<F32>>,
+/// ) {
+///     for (entity, snapshot) in incoming.0.drain(..) {
+///         if let Ok(mut remote) = query.get_mut(entity) {
+///             remote.receive(snapshot);
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .insert_resource(IncomingSnapshots(Vec::new()))
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(RemoteKinematicsPlugin::<F32>::default())
+///     .add_system(receive_snapshots);
+/// ```
+#[derive(Debug)]
+pub struct RemoteKinematicsPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for RemoteKinematicsPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for RemoteKinematicsPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            reconcile_remote_kinematics::<C>
+                .after(TwoDSystem::Kinematics)
+                .before(TwoDSystem::SyncDirectionRotation),
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Turn back time
+//!
+//! Each entity with a [`KinematicHistory`] records its [`Position`], [`Rotation`] and [`Velocity`] once per frame.
+//! While the [`RewindActive`] resource exists, the [`RewindPlugin`] instead plays these snapshots back in reverse,
+//! one per frame. Once the resource is removed, recording and normal integration resume from the rewound state.
+
+use crate::coordinate::Coordinate;
+use crate::kinematics::Velocity;
+use crate::orientation::Rotation;
+use crate::plugin::TwoDSystem;
+use crate::position::Position;
+use alloc::collections::VecDeque;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+/// The kinematic state of an entity at the end of a single frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KinematicSnapshot<C: Coordinate> {
+    /// The [`Position`] of the entity
+    pub position: Position<C>,
+    /// The [`Rotation`] of the entity, if it has one
+    pub rotation: Option<Rotation>,
+    /// The [`Velocity`] of the entity, if it has one
+    pub velocity: Option<Velocity<C>>,
+}
+
+/// A bounded record of the recent [`KinematicSnapshots`](KinematicSnapshot) of an entity, from oldest to newest
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::rewind::{KinematicHistory, KinematicSnapshot};
+///
+/// let snapshot = |x: f32| KinematicSnapshot {
+///     position: Position::<F32>::new(x, 0.0),
+///     rotation: None,
+///     velocity: None,
+/// };
+///
+/// let mut history = KinematicHistory::new(2);
+/// history.record(snapshot(1.0));
+/// history.record(snapshot(2.0));
+/// // The oldest snapshot is forgotten, to stay within the capacity
+/// history.record(snapshot(3.0));
+/// assert_eq!(history.len(), 2);
+///
+/// assert_eq!(history.rewind(), Some(snapshot(3.0)));
+/// assert_eq!(history.rewind(), Some(snapshot(2.0)));
+/// assert_eq!(history.rewind(), None);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct KinematicHistory<C: Coordinate> {
+    capacity: usize,
+    snapshots: VecDeque<KinematicSnapshot<C>>,
+}
+
+impl<C: Coordinate> KinematicHistory<C> {
+    /// Creates a new, empty [`KinematicHistory`] that remembers up to `capacity` frames
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            snapshots: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// The maximum number of snapshots that are remembered
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds the `snapshot` as the newest entry, forgetting the oldest entry if the history is full
+    pub fn record(&mut self, snapshot: KinematicSnapshot<C>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    /// Removes and returns the newest snapshot, if any remain
+    pub fn rewind(&mut self) -> Option<KinematicSnapshot<C>> {
+        self.snapshots.pop_back()
+    }
+
+    /// The newest snapshot, if any
+    #[inline]
+    #[must_use]
+    pub fn latest(&self) -> Option<&KinematicSnapshot<C>> {
+        self.snapshots.back()
+    }
+
+    /// Iterates over the recorded snapshots, from oldest to newest
+    pub fn snapshots(&self) -> impl Iterator<Item = &KinematicSnapshot<C>> + '_ {
+        self.snapshots.iter()
+    }
+
+    /// The number of recorded snapshots
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Are there no recorded snapshots?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Forgets every recorded snapshot
+    #[inline]
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+/// While this resource exists, entities with a [`KinematicHistory`] play back their history instead of recording it
+///
+/// Insert it to start rewinding, and remove it to resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RewindActive;
+
+/// Records the state of each entity with a [`KinematicHistory`], or rewinds it by one frame while [`RewindActive`] exists
+///
+/// This should run after [`TwoDSystem::Kinematics`], so that the rewound state overrides the integrated state.
+/// Once an entity's history runs out, it stays at its oldest recorded state until rewinding stops.
+pub fn update_kinematic_history<C: Coordinate>(
+    rewind_active: Option<Res<RewindActive>>,
+    mut query: Query<(Entity, &mut KinematicHistory<C>, &mut Position<C>)>,
+    mut rotations: Query<&mut Rotation>,
+    mut velocities: Query<&mut Velocity<C>>,
+) {
+    for (entity, mut history, mut position) in query.iter_mut() {
+        if rewind_active.is_none() {
+            history.record(KinematicSnapshot {
+                position: *position,
+                rotation: rotations.get(entity).ok().copied(),
+                velocity: velocities.get(entity).ok().copied(),
+            });
+            continue;
+        }
+
+        let snapshot = match history.rewind() {
+            Some(snapshot) => snapshot,
+            None => continue,
+        };
+
+        // These checks avoid triggering change detection pointlessly
+        if *position != snapshot.position {
+            *position = snapshot.position;
+        }
+        if let (Ok(mut rotation), Some(new_rotation)) =
+            (rotations.get_mut(entity), snapshot.rotation)
+        {
+            if *rotation != new_rotation {
+                *rotation = new_rotation;
+            }
+        }
+        if let (Ok(mut velocity), Some(new_velocity)) =
+            (velocities.get_mut(entity), snapshot.velocity)
+        {
+            if *velocity != new_velocity {
+                *velocity = new_velocity;
+            }
+        }
+    }
+}
+
+/// Adds the [`update_kinematic_history`] system, which records and rewinds each [`KinematicHistory`]
+///
+/// The system runs in [`CoreStage::PostUpdate`], after [`TwoDSystem::Kinematics`] and before [`TwoDSystem::SyncDirectionRotation`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::rewind::{KinematicHistory, RewindActive, RewindPlugin};
+///
+/// fn setup(mut commands: Commands) {
+///     // Remember the last ten seconds, at sixty frames per second
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(KinematicHistory::<F32>::new(600));
+/// }
+///
+/// fn rewind_while_held(keyboard: Res<Input<KeyCode>>, mut commands: Commands) {
+///     if keyboard.pressed(KeyCode::R) {
+///         commands.insert_resource(RewindActive);
+///     } else {
+///         commands.remove_resource::<RewindActive>();
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(RewindPlugin::<F32>::default())
+///     .add_startup_system(setup)
+///     .add_system(rewind_while_held);
+/// ```
+#[derive(Debug)]
+pub struct RewindPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for RewindPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for RewindPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            update_kinematic_history::<C>
+                .after(TwoDSystem::Kinematics)
+                .before(TwoDSystem::SyncDirectionRotation),
+        );
+    }
+}
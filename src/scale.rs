@@ -1,12 +1,14 @@
 //! Tools to change the size / scale of 2D objects
 
 use crate::coordinate::Coordinate;
+#[cfg(feature = "bevy")]
 use bevy_ecs::prelude::Component;
 
 /// The relative size of a 2D object
 ///
 /// Use a [`AxisAlignedBoundingBox`] as a component for a measure of absolute size
-#[derive(Component, Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
 pub struct Scale<C: Coordinate>(pub C::Data);
 
 impl<C: Coordinate> Default for Scale<C> {
@@ -0,0 +1,755 @@
+//! Look up entities by where they are
+//!
+//! [`SpatialQuery`] checks every entity with a [`Position`] against the region that you're interested in,
+//! using [`BoundingRegion::contains_batch`] to keep this scan fast.
+//! For very large numbers of entities, [`SpatialHash`] sorts entities into a grid of cells,
+//! so that each query only checks the entities nearby, while [`QuadTree`] subdivides only the crowded parts of large, sparse worlds.
+
+use crate::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+use crate::coordinate::Coordinate;
+use crate::math;
+use crate::position::Position;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use glam::Vec2;
+
+/// A [`SystemParam`] for finding the entities whose [`Position`] lies inside of a [`BoundingRegion`]
+///
+/// This is handy for unit selection boxes, area-of-effect damage and other region-based gameplay.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Circle};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::spatial::SpatialQuery;
+///
+/// fn explode(spatial_query: SpatialQuery<F32>, mut commands: Commands) {
+///     let blast = Circle::new(Position::new(0.0, 0.0), 5.0);
+///     for entity in spatial_query.entities_in(&blast) {
+///         commands.entity(entity).despawn();
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Selected;
+///
+/// fn select(spatial_query: SpatialQuery<F32>, mut commands: Commands) {
+///     let selection_box = AxisAlignedBoundingBox::new(-10.0, 10.0, -10.0, 10.0);
+///     for entity in spatial_query.entities_in(&selection_box) {
+///         commands.entity(entity).insert(Selected);
+///     }
+/// }
+///
+/// App::new().add_system(explode).add_system(select);
+/// ```
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w, 's, C: Coordinate> {
+    positions: Query<'w, 's, (Entity, &'static Position<C>)>,
+}
+
+impl<'w, 's, C: Coordinate> SpatialQuery<'w, 's, C> {
+    /// Returns every entity whose [`Position`] is inside of the `region`
+    ///
+    /// Entities are returned in an arbitrary order.
+    #[must_use]
+    pub fn entities_in<R: BoundingRegion<C = C> + ?Sized>(&self, region: &R) -> Vec<Entity> {
+        let (entities, positions): (Vec<Entity>, Vec<Position<C>>) = self
+            .positions
+            .iter()
+            .map(|(entity, &position)| (entity, position))
+            .unzip();
+
+        let mut inside = Vec::with_capacity(positions.len());
+        region.contains_batch(&positions, &mut inside);
+
+        entities
+            .into_iter()
+            .zip(inside)
+            .filter_map(|(entity, inside)| inside.then_some(entity))
+            .collect()
+    }
+
+    /// Is there at least one entity whose [`Position`] is inside of the `region`?
+    #[must_use]
+    pub fn any_in<R: BoundingRegion<C = C> + ?Sized>(&self, region: &R) -> bool {
+        self.positions
+            .iter()
+            .any(|(_, &position)| region.contains(position))
+    }
+}
+
+/// A grid of buckets that entities are sorted into by their [`Position`], for fast proximity queries
+///
+/// Each query only visits the cells that overlap the area of interest,
+/// so the cost depends on how many entities are nearby, rather than how many exist.
+/// Use this in place of [`SpatialQuery`] when there are many thousands of entities.
+///
+/// Keep this up to date with the [`update_spatial_hash`] system,
+/// or call [`insert`](SpatialHash::insert) and [`remove`](SpatialHash::remove) yourself.
+///
+/// Cells should be a few times larger than the typical query radius:
+/// smaller cells waste time visiting empty buckets, while larger cells check more entities that are too far away.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::spatial::SpatialHash;
+///
+/// let (player, bullet, enemy) = (Entity::from_raw(0), Entity::from_raw(1), Entity::from_raw(2));
+///
+/// let mut spatial_hash = SpatialHash::<F32>::new(4.0);
+/// spatial_hash.insert(player, Position::new(0.0, 0.0));
+/// spatial_hash.insert(bullet, Position::new(1.0, 1.0));
+/// spatial_hash.insert(enemy, Position::new(20.0, 5.0));
+///
+/// assert_eq!(spatial_hash.entities_in_radius(Position::new(0.0, 0.0), 2.0), vec![player, bullet]);
+/// assert_eq!(
+///     spatial_hash.entities_in_aabb(&AxisAlignedBoundingBox::new(10.0, 30.0, 0.0, 10.0)),
+///     vec![enemy]
+/// );
+/// assert_eq!(spatial_hash.nearest(Position::new(15.0, 0.0)), Some(enemy));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialHash<C: Coordinate> {
+    cell_size: f32,
+    cells: BTreeMap<(i32, i32), Vec<Entity>>,
+    positions: BTreeMap<Entity, Position<C>>,
+}
+
+impl<C: Coordinate> Default for SpatialHash<C> {
+    /// Creates an empty [`SpatialHash`] with cells that are [`SpatialHash::DEFAULT_CELL_SIZE`] wide
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CELL_SIZE)
+    }
+}
+
+impl<C: Coordinate> SpatialHash<C> {
+    /// The width and height of each cell used by [`SpatialHash::default`], in the [`f32`] units of the coordinate type
+    pub const DEFAULT_CELL_SIZE: f32 = 10.0;
+
+    /// Creates an empty [`SpatialHash`], with square cells that are `cell_size` wide
+    ///
+    /// # Panics
+    /// `cell_size` must be greater than zero.
+    #[must_use]
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0);
+
+        Self {
+            cell_size,
+            cells: BTreeMap::new(),
+            positions: BTreeMap::new(),
+        }
+    }
+
+    /// The width and height of each cell, in the [`f32`] units of the coordinate type
+    #[inline]
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The cell that contains the `point`
+    fn cell(&self, point: Vec2) -> (i32, i32) {
+        (
+            math::floor(point.x / self.cell_size) as i32,
+            math::floor(point.y / self.cell_size) as i32,
+        )
+    }
+
+    /// Adds the `entity` at the provided `position`, or moves it if it was already present
+    ///
+    /// Entities that stay within the same cell are cheap to move.
+    pub fn insert(&mut self, entity: Entity, position: Position<C>) {
+        let cell = self.cell(position.into());
+        if let Some(old_position) = self.positions.insert(entity, position) {
+            let old_cell = self.cell(old_position.into());
+            if old_cell == cell {
+                return;
+            }
+            self.remove_from_cell(entity, old_cell);
+        }
+
+        self.cells.entry(cell).or_default().push(entity);
+    }
+
+    /// Removes the `entity`, returning its position if it was present
+    pub fn remove(&mut self, entity: Entity) -> Option<Position<C>> {
+        let position = self.positions.remove(&entity)?;
+        self.remove_from_cell(entity, self.cell(position.into()));
+        Some(position)
+    }
+
+    fn remove_from_cell(&mut self, entity: Entity, cell: (i32, i32)) {
+        if let Some(entities) = self.cells.get_mut(&cell) {
+            entities.retain(|&other| other != entity);
+            if entities.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    /// The position of the `entity`, if it is present
+    #[inline]
+    #[must_use]
+    pub fn position(&self, entity: Entity) -> Option<Position<C>> {
+        self.positions.get(&entity).copied()
+    }
+
+    /// The number of entities being tracked
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Are no entities being tracked?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Removes every entity
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.positions.clear();
+    }
+
+    /// Every entity whose position is in one of the cells between `min` and `max` (inclusive), along with its position
+    fn candidates(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let (min_cell, max_cell) = (self.cell(min), self.cell(max));
+
+        (min_cell.0..=max_cell.0).flat_map(move |x| {
+            self.cells
+                .range((x, min_cell.1)..=(x, max_cell.1))
+                .flat_map(|(_, entities)| entities)
+                .map(|&entity| (entity, Vec2::from(self.positions[&entity])))
+        })
+    }
+
+    /// Every entity whose position is inside of the `aabb`, sorted by [`Entity`]
+    #[must_use]
+    pub fn entities_in_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = self
+            .candidates(aabb.bottom_left().into(), aabb.top_right().into())
+            .filter(|&(_, point)| aabb.contains(point.into()))
+            .map(|(entity, _)| entity)
+            .collect();
+
+        entities.sort_unstable();
+        entities
+    }
+
+    /// Every entity whose position is within `radius` of `center`, sorted by [`Entity`]
+    #[must_use]
+    pub fn entities_in_radius(&self, center: Position<C>, radius: impl Into<C>) -> Vec<Entity> {
+        let center = Vec2::from(center);
+        let radius: f32 = radius.into().into();
+
+        let mut entities: Vec<Entity> = self
+            .candidates(center - radius, center + radius)
+            .filter(|&(_, point)| point.distance_squared(center) <= radius * radius)
+            .map(|(entity, _)| entity)
+            .collect();
+
+        entities.sort_unstable();
+        entities
+    }
+
+    /// The entity whose position is closest to `position`, if there are any
+    ///
+    /// Ties are broken in favor of the lowest [`Entity`].
+    ///
+    /// This searches outwards from the cell containing `position`, one ring of cells at a time,
+    /// so it is fastest when there is an entity nearby.
+    #[must_use]
+    pub fn nearest(&self, position: Position<C>) -> Option<Entity> {
+        let target = Vec2::from(position);
+        let closer = |best: Option<(f32, Entity)>, (entity, point): (Entity, Vec2)| {
+            let candidate = (point.distance_squared(target), entity);
+            match best {
+                Some(best)
+                    if best
+                        .0
+                        .total_cmp(&candidate.0)
+                        .then(best.1.cmp(&candidate.1))
+                        .is_le() =>
+                {
+                    Some(best)
+                }
+                _ => Some(candidate),
+            }
+        };
+
+        let center = self.cell(target);
+        let mut best = None;
+        for ring in 0_i32.. {
+            // Once the ring holds more cells than are occupied, it is cheaper to check every entity
+            if (8 * ring as usize).max(1) > self.cells.len() {
+                return self
+                    .positions
+                    .iter()
+                    .map(|(&entity, &position)| (entity, Vec2::from(position)))
+                    .fold(None, closer)
+                    .map(|(_, entity)| entity);
+            }
+
+            let (min, max) = (
+                (center.0 - ring, center.1 - ring),
+                (center.0 + ring, center.1 + ring),
+            );
+            for x in min.0..=max.0 {
+                // Only the edges of the ring are new
+                let ys: Vec<i32> = if x == min.0 || x == max.0 {
+                    (min.1..=max.1).collect()
+                } else {
+                    vec![min.1, max.1]
+                };
+
+                for y in ys {
+                    for &entity in self.cells.get(&(x, y)).into_iter().flatten() {
+                        best = closer(best, (entity, Vec2::from(self.positions[&entity])));
+                    }
+                }
+            }
+
+            // Every cell in the next ring is at least this far away
+            let reach = ring as f32 * self.cell_size;
+            if best.is_some_and(|(distance_squared, _)| distance_squared <= reach * reach) {
+                break;
+            }
+        }
+
+        best.map(|(_, entity)| entity)
+    }
+}
+
+/// Updates the [`SpatialHash`] resource from entities whose [`Position`] has changed
+///
+/// Entities that are despawned or lose their [`Position`] are removed.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::spatial::{update_spatial_hash, SpatialHash};
+///
+/// App::new()
+///     .insert_resource(SpatialHash::<F32>::new(5.0))
+///     .add_system_to_stage(CoreStage::PostUpdate, update_spatial_hash::<F32>);
+/// ```
+pub fn update_spatial_hash<C: Coordinate>(
+    mut spatial_hash: ResMut<SpatialHash<C>>,
+    positions: Query<(Entity, &Position<C>), Changed<Position<C>>>,
+    removed: RemovedComponents<Position<C>>,
+) {
+    for entity in removed.iter() {
+        spatial_hash.remove(entity);
+    }
+
+    for (entity, &position) in positions.iter() {
+        spatial_hash.insert(entity, position);
+    }
+}
+
+/// A tree of nested quadrants that entities are sorted into by their [`Position`], for range and nearest-neighbor queries
+///
+/// Each node covers a rectangle, which is split into four equal quadrants once it holds more than
+/// [`QuadTree::NODE_CAPACITY`] entities. Crowded areas are subdivided finely while empty space costs nothing,
+/// which makes this a better fit than [`SpatialHash`] for large, sparse worlds with uneven clusters of entities.
+///
+/// Build a tree from `(Entity, Position)` pairs with [`FromIterator`], which covers exactly those positions,
+/// or use [`QuadTree::new`] with the bounds of your world and [`insert`](QuadTree::insert) entities one at a time.
+/// Entities outside of the [`bounds`](QuadTree::bounds) are still tracked, but are checked by every query,
+/// so the bounds should cover most entities.
+///
+/// Keep this up to date with the [`update_quad_tree`] system,
+/// or call [`insert`](QuadTree::insert) and [`remove`](QuadTree::remove) yourself.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::spatial::QuadTree;
+///
+/// let (castle, village, tower) = (Entity::from_raw(0), Entity::from_raw(1), Entity::from_raw(2));
+///
+/// let quad_tree: QuadTree<F32> = [
+///     (castle, Position::new(0.0, 0.0)),
+///     (village, Position::new(40.0, 30.0)),
+///     (tower, Position::new(-500.0, 800.0)),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(quad_tree.k_nearest(Position::new(30.0, 20.0), 2), vec![village, castle]);
+/// assert_eq!(
+///     quad_tree.entities_in_aabb(&AxisAlignedBoundingBox::new(-1000.0, 0.0, 0.0, 1000.0)),
+///     vec![castle, tower]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadTree<C: Coordinate> {
+    bounds: AxisAlignedBoundingBox<C>,
+    root: QuadNode,
+    outside: Vec<(Entity, Vec2)>,
+    positions: BTreeMap<Entity, Position<C>>,
+}
+
+impl<C: Coordinate> QuadTree<C> {
+    /// The most entities that a node can hold before it is split into quadrants
+    pub const NODE_CAPACITY: usize = NODE_CAPACITY;
+
+    /// The deepest that nodes can be nested
+    ///
+    /// Nodes at this depth are never split, so that many entities at the same position can't recurse forever.
+    pub const MAX_DEPTH: usize = MAX_DEPTH;
+
+    /// Creates an empty [`QuadTree`] that covers the provided `bounds`
+    #[must_use]
+    pub fn new(bounds: AxisAlignedBoundingBox<C>) -> Self {
+        Self {
+            root: QuadNode::new(bounds.bottom_left().into(), bounds.top_right().into()),
+            bounds,
+            outside: Vec::new(),
+            positions: BTreeMap::new(),
+        }
+    }
+
+    /// The area covered by the root of this tree
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> &AxisAlignedBoundingBox<C> {
+        &self.bounds
+    }
+
+    /// Adds the `entity` at the provided `position`, or moves it if it was already present
+    pub fn insert(&mut self, entity: Entity, position: Position<C>) {
+        if let Some(old_position) = self.positions.insert(entity, position) {
+            if old_position == position {
+                return;
+            }
+            self.remove_point(entity, old_position.into());
+        }
+
+        let point = Vec2::from(position);
+        if self.root.contains(point) {
+            self.root.insert(entity, point, 0);
+        } else {
+            self.outside.push((entity, point));
+        }
+    }
+
+    /// Removes the `entity`, returning its position if it was present
+    pub fn remove(&mut self, entity: Entity) -> Option<Position<C>> {
+        let position = self.positions.remove(&entity)?;
+        self.remove_point(entity, position.into());
+        Some(position)
+    }
+
+    fn remove_point(&mut self, entity: Entity, point: Vec2) {
+        if self.root.contains(point) {
+            self.root.remove(entity, point);
+        } else {
+            self.outside.retain(|&(other, _)| other != entity);
+        }
+    }
+
+    /// The position of the `entity`, if it is present
+    #[inline]
+    #[must_use]
+    pub fn position(&self, entity: Entity) -> Option<Position<C>> {
+        self.positions.get(&entity).copied()
+    }
+
+    /// The number of entities being tracked
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Are no entities being tracked?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Removes every entity, keeping the same bounds
+    pub fn clear(&mut self) {
+        *self = Self::new(self.bounds.clone());
+    }
+
+    /// Calls `visit` on every entity in a node that overlaps the box between `min` and `max`, along with its position
+    fn for_each_candidate(&self, min: Vec2, max: Vec2, mut visit: impl FnMut(Entity, Vec2)) {
+        self.root.for_each_in(min, max, &mut visit);
+        for &(entity, point) in &self.outside {
+            visit(entity, point);
+        }
+    }
+
+    /// Every entity whose position is inside of the `aabb`, sorted by [`Entity`]
+    #[must_use]
+    pub fn entities_in_aabb(&self, aabb: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        let mut entities = Vec::new();
+        self.for_each_candidate(
+            aabb.bottom_left().into(),
+            aabb.top_right().into(),
+            |entity, point| {
+                if aabb.contains(point.into()) {
+                    entities.push(entity);
+                }
+            },
+        );
+
+        entities.sort_unstable();
+        entities
+    }
+
+    /// Every entity whose position is within `radius` of `center`, sorted by [`Entity`]
+    #[must_use]
+    pub fn entities_in_radius(&self, center: Position<C>, radius: impl Into<C>) -> Vec<Entity> {
+        let center = Vec2::from(center);
+        let radius: f32 = radius.into().into();
+
+        let mut entities = Vec::new();
+        self.for_each_candidate(center - radius, center + radius, |entity, point| {
+            if point.distance_squared(center) <= radius * radius {
+                entities.push(entity);
+            }
+        });
+
+        entities.sort_unstable();
+        entities
+    }
+
+    /// The `k` entities closest to `position`, ordered from nearest to furthest
+    ///
+    /// Ties are broken in favor of the lowest [`Entity`].
+    /// Fewer than `k` entities are returned if fewer than `k` are being tracked.
+    #[must_use]
+    pub fn k_nearest(&self, position: Position<C>, k: usize) -> Vec<Entity> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let target = Vec2::from(position);
+        let mut best = Vec::with_capacity(k + 1);
+        for &(entity, point) in &self.outside {
+            push_candidate(&mut best, k, (point.distance_squared(target), entity));
+        }
+        self.root.k_nearest(target, k, &mut best);
+
+        best.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    /// The entity whose position is closest to `position`, if there are any
+    ///
+    /// Ties are broken in favor of the lowest [`Entity`].
+    #[must_use]
+    pub fn nearest(&self, position: Position<C>) -> Option<Entity> {
+        self.k_nearest(position, 1).pop()
+    }
+}
+
+impl<C: Coordinate> FromIterator<(Entity, Position<C>)> for QuadTree<C> {
+    /// Builds a [`QuadTree`] whose bounds are tightly drawn around all of the provided positions
+    ///
+    /// If an entity is provided more than once, its last position is used.
+    fn from_iter<I: IntoIterator<Item = (Entity, Position<C>)>>(iter: I) -> Self {
+        let entities: Vec<(Entity, Position<C>)> = iter.into_iter().collect();
+        let mut quad_tree = QuadTree::new(AxisAlignedBoundingBox::draw_around(
+            entities.iter().map(|&(_, position)| position),
+        ));
+
+        for (entity, position) in entities {
+            quad_tree.insert(entity, position);
+        }
+
+        quad_tree
+    }
+}
+
+const NODE_CAPACITY: usize = 8;
+const MAX_DEPTH: usize = 16;
+
+/// A single rectangle of a [`QuadTree`], covering the points between `min` and `max` (inclusive)
+///
+/// Entities are only stored in leaves: nodes with children pass everything down to them.
+#[derive(Debug, Clone, PartialEq)]
+struct QuadNode {
+    min: Vec2,
+    max: Vec2,
+    entities: Vec<(Entity, Vec2)>,
+    children: Option<Box<[QuadNode; 4]>>,
+}
+
+impl QuadNode {
+    fn new(min: Vec2, max: Vec2) -> Self {
+        Self {
+            min,
+            max,
+            entities: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn contains(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// The index of the child that `point` belongs in: bottom-left, bottom-right, top-left, then top-right
+    fn quadrant(&self, point: Vec2) -> usize {
+        let center = (self.min + self.max) / 2.;
+        usize::from(point.x >= center.x) + 2 * usize::from(point.y >= center.y)
+    }
+
+    /// The squared distance from `point` to the closest part of this node
+    fn distance_squared(&self, point: Vec2) -> f32 {
+        point.clamp(self.min, self.max).distance_squared(point)
+    }
+
+    fn insert(&mut self, entity: Entity, point: Vec2, depth: usize) {
+        let quadrant = self.quadrant(point);
+        if let Some(children) = &mut self.children {
+            children[quadrant].insert(entity, point, depth + 1);
+            return;
+        }
+
+        self.entities.push((entity, point));
+        if self.entities.len() > NODE_CAPACITY && depth < MAX_DEPTH {
+            let (min, max, center) = (self.min, self.max, (self.min + self.max) / 2.);
+            self.children = Some(Box::new([
+                QuadNode::new(min, center),
+                QuadNode::new(Vec2::new(center.x, min.y), Vec2::new(max.x, center.y)),
+                QuadNode::new(Vec2::new(min.x, center.y), Vec2::new(center.x, max.y)),
+                QuadNode::new(center, max),
+            ]));
+
+            for (entity, point) in core::mem::take(&mut self.entities) {
+                self.insert(entity, point, depth);
+            }
+        }
+    }
+
+    /// Removes the `entity` from the leaf containing `point`, merging leaves back together once they are sparse enough
+    fn remove(&mut self, entity: Entity, point: Vec2) {
+        let quadrant = self.quadrant(point);
+        match &mut self.children {
+            Some(children) => {
+                children[quadrant].remove(entity, point);
+
+                let sparse = children.iter().all(|child| child.children.is_none())
+                    && children
+                        .iter()
+                        .map(|child| child.entities.len())
+                        .sum::<usize>()
+                        <= NODE_CAPACITY;
+                if sparse {
+                    self.entities = children
+                        .iter_mut()
+                        .flat_map(|child| core::mem::take(&mut child.entities))
+                        .collect();
+                    self.children = None;
+                }
+            }
+            None => self.entities.retain(|&(other, _)| other != entity),
+        }
+    }
+
+    fn for_each_in(&self, min: Vec2, max: Vec2, visit: &mut impl FnMut(Entity, Vec2)) {
+        if self.max.cmplt(min).any() || self.min.cmpgt(max).any() {
+            return;
+        }
+
+        for &(entity, point) in &self.entities {
+            visit(entity, point);
+        }
+
+        for child in self.children.iter().flat_map(|children| children.iter()) {
+            child.for_each_in(min, max, visit);
+        }
+    }
+
+    /// Adds the entities in this node to `best`, skipping any nodes that are too far away to improve on it
+    fn k_nearest(&self, target: Vec2, k: usize, best: &mut Vec<(f32, Entity)>) {
+        if best.len() == k && self.distance_squared(target) > best[k - 1].0 {
+            return;
+        }
+
+        for &(entity, point) in &self.entities {
+            push_candidate(best, k, (point.distance_squared(target), entity));
+        }
+
+        if let Some(children) = &self.children {
+            // Visiting the closest quadrants first lets the rest be skipped sooner
+            let mut order = [0, 1, 2, 3];
+            order.sort_by(|&a, &b| {
+                children[a]
+                    .distance_squared(target)
+                    .total_cmp(&children[b].distance_squared(target))
+            });
+
+            for index in order {
+                children[index].k_nearest(target, k, best);
+            }
+        }
+    }
+}
+
+/// Inserts the `(squared distance, entity)` candidate into the sorted list of the `k` best candidates found so far
+fn push_candidate(best: &mut Vec<(f32, Entity)>, k: usize, candidate: (f32, Entity)) {
+    let index = best.partition_point(|&(distance_squared, entity)| {
+        distance_squared
+            .total_cmp(&candidate.0)
+            .then(entity.cmp(&candidate.1))
+            .is_lt()
+    });
+
+    if index < k {
+        best.insert(index, candidate);
+        best.truncate(k);
+    }
+}
+
+/// Updates the [`QuadTree`] resource from entities whose [`Position`] has changed
+///
+/// Entities that are despawned or lose their [`Position`] are removed.
+/// There is no sensible default area for the tree to cover, so the resource must be inserted with [`QuadTree::new`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::spatial::{update_quad_tree, QuadTree};
+///
+/// App::new()
+///     .insert_resource(QuadTree::<F32>::new(AxisAlignedBoundingBox::new(-1000.0, 1000.0, -1000.0, 1000.0)))
+///     .add_system_to_stage(CoreStage::PostUpdate, update_quad_tree::<F32>);
+/// ```
+pub fn update_quad_tree<C: Coordinate>(
+    mut quad_tree: ResMut<QuadTree<C>>,
+    positions: Query<(Entity, &Position<C>), Changed<Position<C>>>,
+    removed: RemovedComponents<Position<C>>,
+) {
+    for entity in removed.iter() {
+        quad_tree.remove(entity);
+    }
+
+    for (entity, &position) in positions.iter() {
+        quad_tree.insert(entity, position);
+    }
+}
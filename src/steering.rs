@@ -0,0 +1,775 @@
+//! Steer agents around the world by choosing their [`Acceleration`]
+//!
+//! Each steering behavior compares how an agent is moving with how it would like to move,
+//! and returns the [`Acceleration`] that closes the gap, limited by the agent's [`SteeringAgent`] settings.
+//! These can be called directly, or combined by giving entities behavior components such as `Seek`, `Flee`, `Arrive` and `Pursue`
+//! and adding the `SteeringPlugin`.
+//!
+//! Calculations are performed using the [`f32`] conversion of the [`Coordinate`] type.
+
+use crate::bounding::Capsule;
+use crate::coordinate::Coordinate;
+use crate::kinematics::{Acceleration, Velocity};
+use crate::position::Position;
+use glam::Vec2;
+#[cfg(feature = "bevy")]
+use {
+    crate::bounding::{AxisAlignedBoundingBox, Circle},
+    crate::plugin::TwoDSystem,
+    crate::spatial::SpatialHash,
+    alloc::vec::Vec,
+    bevy_app::prelude::*,
+    bevy_ecs::prelude::*,
+    core::marker::PhantomData,
+};
+
+/// How fast an agent would like to move, and how quickly steering can change its motion
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Acceleration, Velocity};
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::steering::SteeringAgent;
+///
+/// let agent = SteeringAgent::new(5.0, 100.0);
+/// let position = Position::<F32>::new(0.0, 0.0);
+/// let velocity = Velocity::<F32>::default();
+///
+/// // Speed up towards the target, up to the agent's top speed
+/// let acceleration = agent.seek(position, velocity, Position::new(10.0, 0.0));
+/// assert_eq!(acceleration, Acceleration { x: F32(5.0), y: F32(0.0) });
+///
+/// // Only coming up to half speed, since the target is inside of the slowing radius
+/// let acceleration = agent.arrive(position, velocity, Position::new(10.0, 0.0), 20.0);
+/// assert_eq!(acceleration, Acceleration { x: F32(2.5), y: F32(0.0) });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct SteeringAgent {
+    /// The fastest that this agent would like to move, in the [`f32`] conversion of `C` per second
+    pub max_speed: f32,
+    /// The strongest [`Acceleration`] that steering can apply, in the [`f32`] conversion of `C` per second per second
+    pub max_acceleration: f32,
+}
+
+impl SteeringAgent {
+    /// Creates a new [`SteeringAgent`] with the provided limits
+    #[inline]
+    #[must_use]
+    pub fn new(max_speed: f32, max_acceleration: f32) -> Self {
+        Self {
+            max_speed,
+            max_acceleration,
+        }
+    }
+
+    /// Accelerates straight towards the `target`, at up to [`max_speed`](Self::max_speed)
+    ///
+    /// Seeking agents overshoot their target and turn back around; use [`arrive`](Self::arrive) to come to a stop instead.
+    #[must_use]
+    pub fn seek<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        target: Position<C>,
+    ) -> Acceleration<C> {
+        let to_target = Vec2::from(target) - Vec2::from(position);
+
+        self.steer_towards(velocity, to_target.normalize_or_zero() * self.max_speed)
+    }
+
+    /// Accelerates straight away from the `target`, at up to [`max_speed`](Self::max_speed)
+    ///
+    /// If the agent is exactly on the `target`, there is no way to tell which way is away, and no acceleration is returned.
+    #[must_use]
+    pub fn flee<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        target: Position<C>,
+    ) -> Acceleration<C> {
+        let from_target = Vec2::from(position) - Vec2::from(target);
+        if from_target == Vec2::ZERO {
+            return Acceleration::default();
+        }
+
+        self.steer_towards(velocity, from_target.normalize() * self.max_speed)
+    }
+
+    /// Accelerates towards the `target` like [`seek`](Self::seek), but slows down to stop on it
+    ///
+    /// Once the agent is within `slowing_radius` of the target, the speed it would like to move at shrinks
+    /// in proportion to the remaining distance.
+    #[must_use]
+    pub fn arrive<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        target: Position<C>,
+        slowing_radius: f32,
+    ) -> Acceleration<C> {
+        let to_target = Vec2::from(target) - Vec2::from(position);
+        let distance = to_target.length();
+
+        let speed = if distance < slowing_radius {
+            self.max_speed * distance / slowing_radius
+        } else {
+            self.max_speed
+        };
+
+        self.steer_towards(velocity, to_target.normalize_or_zero() * speed)
+    }
+
+    /// Accelerates towards where the `target` is going to be, assuming that it keeps moving at `target_velocity`
+    ///
+    /// The agent looks ahead by the time it would take to cover the current distance to the `target` at [`max_speed`](Self::max_speed),
+    /// and [`seek`](Self::seek)s that predicted position, cutting off targets rather than trailing behind them.
+    #[must_use]
+    pub fn pursue<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        target: Position<C>,
+        target_velocity: Velocity<C>,
+    ) -> Acceleration<C> {
+        self.seek(
+            position,
+            velocity,
+            self.predict(position, target, target_velocity),
+        )
+    }
+
+    /// Accelerates away from where the `target` is going to be, assuming that it keeps moving at `target_velocity`
+    ///
+    /// This looks ahead in the same way as [`pursue`](Self::pursue), then [`flee`](Self::flee)s the predicted position.
+    #[must_use]
+    pub fn evade<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        target: Position<C>,
+        target_velocity: Velocity<C>,
+    ) -> Acceleration<C> {
+        self.flee(
+            position,
+            velocity,
+            self.predict(position, target, target_velocity),
+        )
+    }
+
+    /// Where the `target` will be by the time this agent could cover the distance to it at [`max_speed`](Self::max_speed)
+    fn predict<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        target: Position<C>,
+        target_velocity: Velocity<C>,
+    ) -> Position<C> {
+        let distance = Vec2::from(target).distance(Vec2::from(position));
+        let seconds = if self.max_speed > 0. {
+            distance / self.max_speed
+        } else {
+            0.
+        };
+        let target_velocity = Vec2::from(target_velocity);
+
+        Position::from(Vec2::from(target) + target_velocity * seconds)
+    }
+
+    /// Accelerates away from crowded `neighbors`, to avoid bumping into them
+    ///
+    /// Closer neighbors push harder, in proportion to how close they are.
+    /// Neighbors at exactly the same position as the agent are ignored, as there is no way to tell which way is away.
+    #[must_use]
+    pub fn separation<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        neighbors: impl IntoIterator<Item = Position<C>>,
+    ) -> Acceleration<C> {
+        let position = Vec2::from(position);
+        let push = neighbors
+            .into_iter()
+            .map(|neighbor| position - Vec2::from(neighbor))
+            .filter(|&away| away != Vec2::ZERO)
+            .map(|away| away / away.length_squared())
+            .fold(Vec2::ZERO, |total, vector| total + vector);
+
+        if push == Vec2::ZERO {
+            return Acceleration::default();
+        }
+
+        self.steer_towards(velocity, push.normalize_or_zero() * self.max_speed)
+    }
+
+    /// Accelerates to travel in the same direction as the average velocity of the `neighbors`
+    ///
+    /// If there are no neighbors, or they are not going anywhere on average, no acceleration is returned.
+    #[must_use]
+    pub fn alignment<C: Coordinate>(
+        &self,
+        velocity: Velocity<C>,
+        neighbors: impl IntoIterator<Item = Velocity<C>>,
+    ) -> Acceleration<C> {
+        let heading = neighbors
+            .into_iter()
+            .map(Vec2::from)
+            .fold(Vec2::ZERO, |total, vector| total + vector);
+
+        if heading == Vec2::ZERO {
+            return Acceleration::default();
+        }
+
+        self.steer_towards(velocity, heading.normalize_or_zero() * self.max_speed)
+    }
+
+    /// Accelerates towards the average position of the `neighbors`, to keep the group together
+    ///
+    /// If there are no neighbors, no acceleration is returned.
+    #[must_use]
+    pub fn cohesion<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        neighbors: impl IntoIterator<Item = Position<C>>,
+    ) -> Acceleration<C> {
+        let (sum, count) = neighbors
+            .into_iter()
+            .fold((Vec2::ZERO, 0), |(sum, count), neighbor| {
+                (sum + Vec2::from(neighbor), count + 1)
+            });
+
+        if count == 0 {
+            return Acceleration::default();
+        }
+
+        self.seek(position, velocity, Position::from(sum / count as f32))
+    }
+
+    /// Accelerates sideways, away from an `obstacle` centered in the agent's path
+    ///
+    /// The agent swerves at full [`max_acceleration`](Self::max_acceleration) to whichever side of its heading is further from the `obstacle`;
+    /// obstacles dead ahead are passed on the right.
+    /// Agents that aren't moving have no heading to swerve from, and no acceleration is returned.
+    /// Use [`AvoidObstacles::probe`] to check whether an obstacle is in the way.
+    #[must_use]
+    pub fn avoid<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        obstacle: Position<C>,
+    ) -> Acceleration<C> {
+        let heading = Vec2::from(velocity).normalize_or_zero();
+        let left = heading.perp();
+        let to_obstacle = Vec2::from(obstacle) - Vec2::from(position);
+
+        let away = if to_obstacle.dot(left) >= 0. {
+            -left
+        } else {
+            left
+        };
+
+        Acceleration::from(away * self.max_acceleration.max(0.))
+    }
+
+    /// Shortens the `acceleration` so it is no stronger than [`max_acceleration`](Self::max_acceleration)
+    #[must_use]
+    pub fn limit<C: Coordinate>(&self, acceleration: Acceleration<C>) -> Acceleration<C> {
+        let acceleration = Vec2::from(acceleration);
+
+        Acceleration::from(acceleration.clamp_length_max(self.max_acceleration.max(0.)))
+    }
+
+    /// The limited [`Acceleration`] that changes `velocity` into the `desired` velocity
+    fn steer_towards<C: Coordinate>(
+        &self,
+        velocity: Velocity<C>,
+        desired: Vec2,
+    ) -> Acceleration<C> {
+        let velocity = Vec2::from(velocity);
+
+        self.limit(Acceleration::from(desired - velocity))
+    }
+}
+
+/// Steers this [`SteeringAgent`] to move as part of a flock, along with nearby agents that are also [`Flocking`]
+///
+/// Flocking combines three behaviors, each scaled by its own weight:
+/// [`separation`](SteeringAgent::separation) from crowded neighbors,
+/// [`alignment`](SteeringAgent::alignment) with the heading of the flock,
+/// and [`cohesion`](SteeringAgent::cohesion) towards its center.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Velocity;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::steering::{Flocking, SteeringAgent};
+///
+/// let agent = SteeringAgent::new(5.0, 10.0);
+/// let flocking = Flocking::new(4.0);
+/// let neighbors = [
+///     (Position::<F32>::new(3.0, 0.0), Velocity { x: F32(5.0), y: F32(0.0) }),
+///     (Position::<F32>::new(3.0, 2.0), Velocity { x: F32(5.0), y: F32(0.0) }),
+/// ];
+///
+/// // Swept along with the rest of the flock, which is heading east
+/// let acceleration = flocking.acceleration(&agent, Position::new(0.0, 0.0), Velocity::default(), &neighbors);
+/// assert!(acceleration.x > F32(0.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Flocking {
+    /// How close other flocking agents must be to count as neighbors
+    pub neighborhood_radius: f32,
+    /// How strongly agents avoid crowding their neighbors
+    ///
+    /// Default: 1.5
+    pub separation_weight: f32,
+    /// How strongly agents match the heading of their neighbors
+    ///
+    /// Default: 1.0
+    pub alignment_weight: f32,
+    /// How strongly agents are drawn towards the center of their neighbors
+    ///
+    /// Default: 1.0
+    pub cohesion_weight: f32,
+}
+
+impl Flocking {
+    /// Creates a new [`Flocking`] behavior that considers agents within `neighborhood_radius`, using the default weights
+    #[inline]
+    #[must_use]
+    pub fn new(neighborhood_radius: f32) -> Self {
+        Self {
+            neighborhood_radius,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+        }
+    }
+
+    /// The weighted total of the separation, alignment and cohesion behaviors, for an `agent` surrounded by `neighbors`
+    ///
+    /// Each neighbor is described by its position and velocity.
+    /// The total is limited by the agent's [`max_acceleration`](SteeringAgent::max_acceleration).
+    #[must_use]
+    pub fn acceleration<C: Coordinate>(
+        &self,
+        agent: &SteeringAgent,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        neighbors: &[(Position<C>, Velocity<C>)],
+    ) -> Acceleration<C> {
+        let positions = || neighbors.iter().map(|&(position, _)| position);
+
+        let separation = agent.separation(position, velocity, positions());
+        let alignment = agent.alignment(velocity, neighbors.iter().map(|&(_, velocity)| velocity));
+        let cohesion = agent.cohesion(position, velocity, positions());
+
+        agent.limit(
+            separation * self.separation_weight
+                + alignment * self.alignment_weight
+                + cohesion * self.cohesion_weight,
+        )
+    }
+}
+
+/// Steers this [`SteeringAgent`] around obstacles in its path, by probing ahead of it with a [`Capsule`]
+///
+/// The probe runs `look_ahead` units from the agent's [`Position`] in the direction of its [`Velocity`],
+/// and is `clearance` units wide on each side.
+/// When an obstacle overlaps the probe, the agent swerves away from it using [`SteeringAgent::avoid`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::Circle;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Velocity;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::steering::{AvoidObstacles, SteeringAgent};
+///
+/// let agent = SteeringAgent::new(5.0, 10.0);
+/// let avoidance = AvoidObstacles::new(6.0, 0.5);
+/// let (position, velocity) = (Position::<F32>::new(0.0, 0.0), Velocity { x: F32(0.0), y: F32(5.0) });
+///
+/// // A rock just to the right of the path ahead
+/// let rock = Circle::new(Position::<F32>::new(0.5, 4.0), 1.0);
+/// let probe = avoidance.probe(position, velocity).unwrap();
+/// assert!(probe.intersects_circle(&rock));
+///
+/// // Swerve to the left
+/// let acceleration = agent.avoid(position, velocity, rock.center);
+/// assert!(acceleration.x < F32(0.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct AvoidObstacles {
+    /// How far ahead of the agent to look for obstacles
+    pub look_ahead: f32,
+    /// How much room to leave on either side of the agent's path
+    pub clearance: f32,
+}
+
+impl AvoidObstacles {
+    /// Creates a new [`AvoidObstacles`] behavior, which probes `look_ahead` units ahead and `clearance` units to each side
+    #[inline]
+    #[must_use]
+    pub fn new(look_ahead: f32, clearance: f32) -> Self {
+        Self {
+            look_ahead,
+            clearance,
+        }
+    }
+
+    /// The region ahead of an agent at `position` moving with `velocity` that should be kept clear of obstacles
+    ///
+    /// Returns `None` if the agent isn't moving, as it has no path to check.
+    #[must_use]
+    pub fn probe<C: Coordinate>(
+        &self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+    ) -> Option<Capsule<C>> {
+        let heading = Vec2::from(velocity).try_normalize()?;
+        let end = Vec2::from(position) + heading * self.look_ahead;
+
+        Some(Capsule::new(
+            position,
+            Position::from(end),
+            self.clearance.max(0.),
+        ))
+    }
+}
+
+/// The total [`Acceleration`] requested by an agent's steering behaviors this frame
+///
+/// Steering behaviors add to this, and [`apply_steering`] limits the total by the agent's [`SteeringAgent::max_acceleration`],
+/// copies it into the agent's [`Acceleration`] and resets it.
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Default)]
+pub struct Steering<C: Coordinate>(pub Acceleration<C>);
+
+/// Steers this [`SteeringAgent`] towards the `target`, using [`SteeringAgent::seek`]
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Seek<C: Coordinate> {
+    /// Where to go
+    pub target: Position<C>,
+}
+
+/// Steers this [`SteeringAgent`] away from the `target`, using [`SteeringAgent::flee`]
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Flee<C: Coordinate> {
+    /// What to run away from
+    pub target: Position<C>,
+}
+
+/// Steers this [`SteeringAgent`] to stop at the `target`, using [`SteeringAgent::arrive`]
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Arrive<C: Coordinate> {
+    /// Where to stop
+    pub target: Position<C>,
+    /// How close to the `target` the agent starts slowing down
+    pub slowing_radius: f32,
+}
+
+/// Steers this [`SteeringAgent`] to intercept the `target` entity, using [`SteeringAgent::pursue`]
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pursue {
+    /// The entity to chase
+    ///
+    /// It must have a [`Position`], and its [`Velocity`] (if any) is used to predict where it is going.
+    pub target: Entity,
+}
+
+/// Steers this [`SteeringAgent`] away from where the `target` entity is going, using [`SteeringAgent::evade`]
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Evade {
+    /// The entity to escape from
+    ///
+    /// It must have a [`Position`], and its [`Velocity`] (if any) is used to predict where it is going.
+    pub target: Entity,
+}
+
+/// Marks entities that steering agents with [`AvoidObstacles`] should swerve around
+///
+/// Obstacles must also have a world-space [`AxisAlignedBoundingBox`] or [`Circle`] component, which is checked against each agent's probe.
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Obstacle;
+
+/// Adds the [`Acceleration`] requested by each agent's [`Seek`], [`Flee`] and [`Arrive`] behaviors to its [`Steering`]
+#[cfg(feature = "bevy")]
+pub fn seek_flee_and_arrive<C: Coordinate>(
+    mut query: Query<(
+        &SteeringAgent,
+        &Position<C>,
+        &Velocity<C>,
+        &mut Steering<C>,
+        Option<&Seek<C>>,
+        Option<&Flee<C>>,
+        Option<&Arrive<C>>,
+    )>,
+) {
+    for (agent, &position, &velocity, mut steering, seek, flee, arrive) in query.iter_mut() {
+        if let Some(seek) = seek {
+            steering.0 += agent.seek(position, velocity, seek.target);
+        }
+        if let Some(flee) = flee {
+            steering.0 += agent.flee(position, velocity, flee.target);
+        }
+        if let Some(arrive) = arrive {
+            steering.0 += agent.arrive(position, velocity, arrive.target, arrive.slowing_radius);
+        }
+    }
+}
+
+/// Adds the [`Acceleration`] requested by each agent's [`Pursue`] and [`Evade`] behaviors to its [`Steering`]
+///
+/// Targets without a [`Velocity`] are treated as standing still, and targets without a [`Position`] are ignored.
+#[cfg(feature = "bevy")]
+pub fn pursue_and_evade<C: Coordinate>(
+    mut agents: Query<(
+        &SteeringAgent,
+        &Position<C>,
+        &Velocity<C>,
+        &mut Steering<C>,
+        Option<&Pursue>,
+        Option<&Evade>,
+    )>,
+    targets: Query<(&Position<C>, Option<&Velocity<C>>)>,
+) {
+    let target_motion = |target: Entity| {
+        targets
+            .get(target)
+            .ok()
+            .map(|(&position, velocity)| (position, velocity.copied().unwrap_or_default()))
+    };
+
+    for (agent, &position, &velocity, mut steering, pursue, evade) in agents.iter_mut() {
+        if let Some((target, target_velocity)) =
+            pursue.and_then(|pursue| target_motion(pursue.target))
+        {
+            steering.0 += agent.pursue(position, velocity, target, target_velocity);
+        }
+        if let Some((target, target_velocity)) = evade.and_then(|evade| target_motion(evade.target))
+        {
+            steering.0 += agent.evade(position, velocity, target, target_velocity);
+        }
+    }
+}
+
+/// Adds the [`Acceleration`] requested by each agent's [`Flocking`] behavior to its [`Steering`]
+///
+/// Neighbors are found using the [`SpatialHash`] resource, which should be kept up to date with [`update_spatial_hash`](crate::spatial::update_spatial_hash).
+/// Only other [`Flocking`] entities with a [`Position`] and [`Velocity`] count as neighbors.
+/// If there is no [`SpatialHash`], agents have no neighbors and do not flock.
+#[cfg(feature = "bevy")]
+pub fn flock<C: Coordinate>(
+    spatial_hash: Option<Res<SpatialHash<C>>>,
+    flockmates: Query<(&Position<C>, &Velocity<C>), With<Flocking>>,
+    mut agents: Query<(
+        Entity,
+        &SteeringAgent,
+        &Flocking,
+        &Position<C>,
+        &Velocity<C>,
+        &mut Steering<C>,
+    )>,
+) {
+    let spatial_hash = match spatial_hash {
+        Some(spatial_hash) => spatial_hash,
+        None => return,
+    };
+
+    let mut neighbors = Vec::new();
+    for (entity, agent, flocking, &position, &velocity, mut steering) in agents.iter_mut() {
+        neighbors.clear();
+        neighbors.extend(
+            spatial_hash
+                .entities_in_radius(position, flocking.neighborhood_radius)
+                .into_iter()
+                .filter(|&neighbor| neighbor != entity)
+                .filter_map(|neighbor| flockmates.get(neighbor).ok())
+                .map(|(&position, &velocity)| (position, velocity)),
+        );
+
+        steering.0 += flocking.acceleration(agent, position, velocity, &neighbors);
+    }
+}
+
+/// Adds the [`Acceleration`] requested by each agent's [`AvoidObstacles`] behavior to its [`Steering`]
+///
+/// Each agent only swerves around the closest [`Obstacle`] that overlaps its [`probe`](AvoidObstacles::probe),
+/// measured to the center of the obstacle's [`Circle`] or [`AxisAlignedBoundingBox`].
+/// Agents are never obstacles to themselves.
+#[cfg(feature = "bevy")]
+pub fn avoid_obstacles<C: Coordinate>(
+    obstacles: Query<
+        (
+            Entity,
+            Option<&AxisAlignedBoundingBox<C>>,
+            Option<&Circle<C>>,
+        ),
+        With<Obstacle>,
+    >,
+    mut agents: Query<(
+        Entity,
+        &SteeringAgent,
+        &AvoidObstacles,
+        &Position<C>,
+        &Velocity<C>,
+        &mut Steering<C>,
+    )>,
+) {
+    for (entity, agent, avoidance, &position, &velocity, mut steering) in agents.iter_mut() {
+        let probe = match avoidance.probe(position, velocity) {
+            Some(probe) => probe,
+            None => continue,
+        };
+
+        let closest = obstacles
+            .iter()
+            .filter(|&(obstacle, ..)| obstacle != entity)
+            .filter_map(|(obstacle, aabb, circle)| {
+                if let Some(circle) = circle.filter(|circle| probe.intersects_circle(circle)) {
+                    Some((Vec2::from(circle.center), obstacle))
+                } else {
+                    aabb.filter(|aabb| probe.intersects_aabb(aabb)).map(|aabb| {
+                        let center =
+                            (Vec2::from(aabb.bottom_left()) + Vec2::from(aabb.top_right())) / 2.;
+                        (center, obstacle)
+                    })
+                }
+            })
+            .min_by(|a, b| {
+                let distance = |center: Vec2| center.distance_squared(Vec2::from(position));
+                distance(a.0).total_cmp(&distance(b.0)).then(a.1.cmp(&b.1))
+            });
+
+        if let Some((center, _)) = closest {
+            steering.0 += agent.avoid(position, velocity, Position::from(center));
+        }
+    }
+}
+
+/// Limits the total [`Steering`] of each agent by its [`SteeringAgent::max_acceleration`], and uses it as the agent's [`Acceleration`]
+///
+/// [`Steering`] is reset to zero afterwards, ready to be accumulated again next frame.
+/// Agents should not also have a [`Force`](crate::kinematics::Force),
+/// as [`linear_dynamics`](crate::kinematics::systems::linear_dynamics) would overwrite the steering [`Acceleration`].
+#[cfg(feature = "bevy")]
+pub fn apply_steering<C: Coordinate>(
+    mut query: Query<(&SteeringAgent, &mut Steering<C>, &mut Acceleration<C>)>,
+) {
+    for (agent, mut steering, mut acceleration) in query.iter_mut() {
+        let new_acceleration = agent.limit(steering.0);
+
+        // Avoid triggering change detection when nothing has changed
+        if *acceleration != new_acceleration {
+            *acceleration = new_acceleration;
+        }
+        if *steering != Steering::default() {
+            *steering = Steering::default();
+        }
+    }
+}
+
+/// [`SystemLabel`] for [`SteeringPlugin`]
+#[cfg(feature = "bevy")]
+#[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SteeringSystem {
+    /// Adds the [`Acceleration`] requested by each behavior to the [`Steering`] of each agent
+    ///
+    /// Contains [`seek_flee_and_arrive::<C>`], [`pursue_and_evade::<C>`], [`flock::<C>`] and [`avoid_obstacles::<C>`].
+    Behaviors,
+    /// Converts the total [`Steering`] of each agent into [`Acceleration`]
+    ///
+    /// Contains [`apply_steering::<C>`].
+    Apply,
+}
+
+/// Adds the systems that steer each [`SteeringAgent`] according to its behavior components
+///
+/// Agents need a [`SteeringAgent`], [`Steering`], [`Position`], [`Velocity`] and [`Acceleration`],
+/// along with any number of behaviors such as [`Seek`], [`Flee`], [`Arrive`], [`Pursue`], [`Evade`], [`Flocking`] and [`AvoidObstacles`].
+/// The systems run in [`CoreStage::PostUpdate`], before [`TwoDSystem::Kinematics`], and are labelled with [`SteeringSystem`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::steering::{Arrive, Steering, SteeringAgent, SteeringPlugin};
+///
+/// fn spawn_drone(mut commands: Commands) {
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(SteeringAgent::new(5.0, 10.0))
+///         .insert(Steering::<F32>::default())
+///         .insert(Arrive {
+///             target: Position::<F32>::new(20.0, 10.0),
+///             slowing_radius: 4.0,
+///         });
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(SteeringPlugin::<F32>::default())
+///     .add_startup_system(spawn_drone);
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Debug)]
+pub struct SteeringPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> Default for SteeringPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> Plugin for SteeringPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            seek_flee_and_arrive::<C>
+                .label(SteeringSystem::Behaviors)
+                .before(SteeringSystem::Apply),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            pursue_and_evade::<C>
+                .label(SteeringSystem::Behaviors)
+                .before(SteeringSystem::Apply),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            flock::<C>
+                .label(SteeringSystem::Behaviors)
+                .before(SteeringSystem::Apply),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            avoid_obstacles::<C>
+                .label(SteeringSystem::Behaviors)
+                .before(SteeringSystem::Apply),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            apply_steering::<C>
+                .label(SteeringSystem::Apply)
+                .before(TwoDSystem::Kinematics),
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Delaunay triangulations and Voronoi diagrams of collections of [`Positions`](Position)
+//!
+//! These are useful for territory maps, procedural region generation and seeding navigation meshes.
+//! Calculations are performed in [`f64`], starting from the [`f32`] conversion of the [`Coordinate`] type.
+
+use crate::bounding::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::polygon::{Polygon, Triangle};
+use crate::position::Position;
+use alloc::{vec, vec::Vec};
+use glam::{DVec2, Vec2};
+
+/// Computes the Delaunay triangulation of the provided `positions`
+///
+/// No position lies strictly inside of the circumcircle of any of the returned triangles,
+/// which avoids long, thin triangles wherever possible.
+/// Together, the triangles cover the convex hull of the `positions`.
+///
+/// The returned triangles always wind counterclockwise.
+/// Duplicate positions are ignored, and collections with less than three positions,
+/// or whose positions are all collinear, produce no triangles.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::tessellation::delaunay;
+///
+/// let square = [
+///     Position::<F32>::new(0.0, 0.0),
+///     Position::new(1.0, 0.0),
+///     Position::new(1.0, 1.0),
+///     Position::new(0.0, 1.0),
+///     Position::new(0.5, 0.5),
+/// ];
+///
+/// let triangles = delaunay(square);
+/// assert_eq!(triangles.len(), 4);
+/// assert_eq!(triangles.iter().map(|triangle| triangle.area()).sum::<f32>(), 1.0);
+/// ```
+#[must_use]
+pub fn delaunay<C: Coordinate>(
+    positions: impl IntoIterator<Item = Position<C>>,
+) -> Vec<Triangle<C>> {
+    let positions: Vec<Position<C>> = positions.into_iter().collect();
+    let points = to_points(&positions);
+
+    triangulate_indexes(&points)
+        .into_iter()
+        .map(|[a, b, c]| Triangle::new(positions[a], positions[b], positions[c]))
+        .collect()
+}
+
+/// Computes the Voronoi diagram of the provided `positions`, clipped to `bounds`
+///
+/// Each cell contains every point in `bounds` that is at least as close to its position as to any other.
+/// Cells are returned in the same order as `positions`, and are always convex and wound counterclockwise.
+///
+/// Duplicate positions share the same cell.
+/// Positions outside of `bounds` may have a cell with no vertexes.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::tessellation::voronoi;
+///
+/// let capitals = [Position::<F32>::new(-5.0, 0.0), Position::new(5.0, 0.0)];
+/// let map = AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -10.0, 10.0);
+///
+/// let territories = voronoi(capitals, &map);
+/// assert_eq!(territories[0].area(), 200.0);
+/// assert!(territories[0].contains(Position::new(-1.0, 9.0)));
+/// assert!(territories[1].contains(Position::new(1.0, -9.0)));
+/// ```
+#[must_use]
+pub fn voronoi<C: Coordinate>(
+    positions: impl IntoIterator<Item = Position<C>>,
+    bounds: &AxisAlignedBoundingBox<C>,
+) -> Vec<Polygon<C>> {
+    let positions: Vec<Position<C>> = positions.into_iter().collect();
+    let points = to_points(&positions);
+    let n = points.len();
+
+    // Only Delaunay neighbors can share an edge of a Voronoi cell
+    let triangles = triangulate_indexes(&points);
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for [a, b, c] in triangles.iter().copied() {
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            if !neighbors[from].contains(&to) {
+                neighbors[from].push(to);
+                neighbors[to].push(from);
+            }
+        }
+    }
+
+    let (left, right): (f32, f32) = (bounds.left.into(), bounds.right.into());
+    let (bottom, top): (f32, f32) = (bounds.bottom.into(), bounds.top.into());
+    let rectangle = vec![
+        DVec2::new(left.into(), bottom.into()),
+        DVec2::new(right.into(), bottom.into()),
+        DVec2::new(right.into(), top.into()),
+        DVec2::new(left.into(), top.into()),
+    ];
+
+    (0..n)
+        .map(|i| {
+            let site = points[i];
+
+            // Duplicates were skipped when triangulating, so share the neighbors of the first copy
+            let canonical = points.iter().position(|&point| point == site).unwrap_or(i);
+            let others: Vec<usize> = if triangles.is_empty() {
+                (0..n).collect()
+            } else {
+                neighbors[canonical].clone()
+            };
+
+            let cell = others
+                .into_iter()
+                .filter(|&other| points[other] != site)
+                .fold(rectangle.clone(), |cell, other| {
+                    clip_to_closer_half(&cell, site, points[other])
+                });
+
+            Polygon::new(
+                cell.into_iter()
+                    .map(|vertex| Position::from(vertex.as_vec2())),
+            )
+        })
+        .collect()
+}
+
+fn to_points<C: Coordinate>(positions: &[Position<C>]) -> Vec<DVec2> {
+    positions
+        .iter()
+        .map(|&position| Vec2::from(position).as_dvec2())
+        .collect()
+}
+
+/// Clips the convex `polygon` to the half-plane of points at least as close to `site` as to `other`
+fn clip_to_closer_half(polygon: &[DVec2], site: DVec2, other: DVec2) -> Vec<DVec2> {
+    let midpoint = (site + other) / 2.;
+    let normal = other - site;
+    // Negative when closer to `site`
+    let signed_distance = |point: DVec2| (point - midpoint).dot(normal);
+
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (current_distance, next_distance) = (signed_distance(current), signed_distance(next));
+
+        if current_distance <= 0.0 {
+            clipped.push(current);
+        }
+        if (current_distance < 0.0) != (next_distance < 0.0)
+            && current_distance != 0.0
+            && next_distance != 0.0
+        {
+            let t = current_distance / (current_distance - next_distance);
+            clipped.push(current + (next - current) * t);
+        }
+    }
+
+    clipped
+}
+
+/// A triangle in the Bowyer-Watson algorithm, with its circumcircle cached
+struct Candidate {
+    corners: [usize; 3],
+    center: DVec2,
+    radius_squared: f64,
+}
+
+impl Candidate {
+    /// Creates a candidate from corners that wind counterclockwise
+    fn new(corners: [usize; 3], points: &[DVec2]) -> Candidate {
+        let [a, b, c] = corners.map(|corner| points[corner]);
+        let (ab, ac) = (b - a, c - a);
+        let denominator = 2. * ab.perp_dot(ac);
+
+        let offset = DVec2::new(
+            ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+            ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+        ) / denominator;
+
+        Candidate {
+            corners,
+            center: a + offset,
+            radius_squared: offset.length_squared(),
+        }
+    }
+
+    fn circumcircle_contains(&self, point: DVec2) -> bool {
+        point.distance_squared(self.center) < self.radius_squared
+    }
+}
+
+/// Computes the Delaunay triangulation of `points` using the Bowyer-Watson algorithm
+///
+/// Returns the indexes of the corners of each triangle, in counterclockwise order.
+fn triangulate_indexes(points: &[DVec2]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    let (min, max) = points
+        .iter()
+        .fold((points[0], points[0]), |(min, max), &point| {
+            (min.min(point), max.max(point))
+        });
+    let middle = (min + max) / 2.;
+    let size = (max - min).max_element().max(1.0);
+
+    // Start with a triangle that is much larger than all of the points,
+    // whose corners are stored after the real points
+    let mut vertexes = points.to_vec();
+    vertexes.extend([
+        middle + DVec2::new(-100. * size, -100. * size),
+        middle + DVec2::new(100. * size, -100. * size),
+        middle + DVec2::new(0., 100. * size),
+    ]);
+    let mut candidates = vec![Candidate::new([n, n + 1, n + 2], &vertexes)];
+
+    for (i, &point) in points.iter().enumerate() {
+        if points[..i].contains(&point) {
+            continue;
+        }
+
+        let (bad, good): (Vec<Candidate>, Vec<Candidate>) = candidates
+            .into_iter()
+            .partition(|candidate| candidate.circumcircle_contains(point));
+        candidates = good;
+
+        // The edges of the hole that are not shared between two removed triangles
+        let edges = bad.iter().flat_map(|candidate| {
+            let [a, b, c] = candidate.corners;
+            [(a, b), (b, c), (c, a)]
+        });
+        let boundary: Vec<(usize, usize)> = edges
+            .clone()
+            .filter(|&(a, b)| !edges.clone().any(|edge| edge == (b, a)))
+            .collect();
+
+        candidates.extend(
+            boundary
+                .into_iter()
+                .map(|(a, b)| Candidate::new([a, b, i], &vertexes)),
+        );
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| candidate.corners)
+        .filter(|corners| corners.iter().all(|&corner| corner < n))
+        .collect()
+}
@@ -0,0 +1,184 @@
+//! Record where entities have been
+//!
+//! Add a [`Trail`] to an entity with a [`Position`], and the [`TrailPlugin`] will record its recent path as a [`Polyline`].
+//! Use these for debugging motion, drawing smoke trails behind missiles, or visualizing replays.
+//!
+//! With the `debug_render` feature enabled, add a `TrailMesh` to draw the trail as a line strip.
+
+use crate::coordinate::Coordinate;
+use crate::path::Polyline;
+use crate::plugin::TwoDSystem;
+use crate::position::Position;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use glam::Vec2;
+#[cfg(feature = "debug_render")]
+use {
+    alloc::vec::Vec,
+    bevy_asset::{Assets, Handle},
+    bevy_render::mesh::{Mesh, PrimitiveTopology},
+};
+
+/// The recent path of an entity, recorded from its [`Position`]
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::trail::Trail;
+///
+/// let mut trail = Trail::<F32>::new(3, 1.0);
+/// assert!(trail.record(Position::new(0.0, 0.0)));
+/// // Too close to the previous point
+/// assert!(!trail.record(Position::new(0.5, 0.0)));
+/// assert!(trail.record(Position::new(1.0, 0.0)));
+/// assert!(trail.record(Position::new(2.0, 0.0)));
+/// // The oldest point is dropped, to stay within `max_points`
+/// assert!(trail.record(Position::new(3.0, 0.0)));
+///
+/// assert_eq!(trail.polyline().points().len(), 3);
+/// assert_eq!(trail.polyline().length(), 2.0);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Trail<C: Coordinate> {
+    /// The maximum number of points to keep, after which the oldest points are forgotten
+    pub max_points: usize,
+    /// How far apart recorded points must be, measured in the [`f32`] conversion of `C`
+    ///
+    /// Larger spacing makes for longer, coarser trails.
+    pub min_spacing: f32,
+    polyline: Polyline<C>,
+}
+
+impl<C: Coordinate> Trail<C> {
+    /// Creates a new, empty [`Trail`]
+    #[inline]
+    #[must_use]
+    pub fn new(max_points: usize, min_spacing: f32) -> Self {
+        Self {
+            max_points,
+            min_spacing,
+            polyline: Polyline::default(),
+        }
+    }
+
+    /// Adds the `position` to the end of the trail, if it is at least `min_spacing` away from the last point
+    ///
+    /// Returns `true` if the position was recorded.
+    pub fn record(&mut self, position: Position<C>) -> bool {
+        if !self.far_enough(position) {
+            return false;
+        }
+
+        self.polyline.push(position);
+        let excess = self.polyline.points().len().saturating_sub(self.max_points);
+        self.polyline.remove_start(excess);
+        true
+    }
+
+    /// The recorded path, from oldest to newest
+    #[inline]
+    #[must_use]
+    pub fn polyline(&self) -> &Polyline<C> {
+        &self.polyline
+    }
+
+    /// Forgets every recorded point
+    #[inline]
+    pub fn clear(&mut self) {
+        self.polyline = Polyline::default();
+    }
+
+    /// Is the `position` at least `min_spacing` away from the last recorded point?
+    fn far_enough(&self, position: Position<C>) -> bool {
+        self.polyline
+            .points()
+            .last()
+            .is_none_or(|&last| Vec2::from(last).distance(Vec2::from(position)) >= self.min_spacing)
+    }
+}
+
+/// Records the [`Position`] of each entity with a [`Trail`], whenever it has moved far enough
+pub fn record_trails<C: Coordinate>(mut query: Query<(&Position<C>, &mut Trail<C>)>) {
+    for (&position, mut trail) in query.iter_mut() {
+        // Avoid triggering change detection when nothing would be recorded
+        if trail.far_enough(position) {
+            trail.record(position);
+        }
+    }
+}
+
+/// A [`Mesh`] that [`render_trails`] keeps up to date with the [`Trail`] of the same entity
+///
+/// The trail is drawn as a line strip, in world space.
+/// Display the mesh using an entity at the origin, with any material that supports line strips.
+#[cfg(feature = "debug_render")]
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct TrailMesh(pub Handle<Mesh>);
+
+/// Rewrites each [`TrailMesh`] whenever its [`Trail`] changes
+#[cfg(feature = "debug_render")]
+pub fn render_trails<C: Coordinate>(
+    trails: Query<(&Trail<C>, &TrailMesh), Changed<Trail<C>>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (trail, TrailMesh(handle)) in trails.iter() {
+        let positions: Vec<[f32; 3]> = trail
+            .polyline()
+            .points()
+            .iter()
+            .map(|&point| Vec2::from(point).extend(0.).to_array())
+            .collect();
+        let n_points = positions.len();
+
+        let mut mesh = Mesh::new(PrimitiveTopology::LineStrip);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, alloc::vec![[0., 0., 1.]; n_points]);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, alloc::vec![[0., 0.]; n_points]);
+        meshes.set_untracked(handle, mesh);
+    }
+}
+
+/// Adds the [`record_trails`] system, as well as the `render_trails` system if the `debug_render` feature is enabled
+///
+/// Trails are recorded in [`CoreStage::PostUpdate`], after [`TwoDSystem::Kinematics`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::trail::TrailPlugin;
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(TrailPlugin::<F32>::default());
+/// ```
+#[derive(Debug)]
+pub struct TrailPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for TrailPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for TrailPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            record_trails::<C>.after(TwoDSystem::Kinematics),
+        );
+
+        #[cfg(feature = "debug_render")]
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            render_trails::<C>.after(TwoDSystem::Kinematics),
+        );
+    }
+}
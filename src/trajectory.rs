@@ -0,0 +1,157 @@
+//! Predict the flight of projectiles under constant acceleration, and aim them at targets
+//!
+//! A [`Trajectory`] follows the exact path of a projectile pulled by a constant [`Acceleration`] such as gravity,
+//! so aim previews can be drawn without stepping a simulation forward frame by frame.
+//! [`launch_velocities`] works backwards, finding the [`Velocity`] needed to hit a target at a given launch speed.
+//!
+//! Calculations are performed using the [`f32`] conversion of the [`Coordinate`] type.
+
+use crate::coordinate::Coordinate;
+use crate::kinematics::{predict, Acceleration, Velocity};
+use crate::math;
+use crate::position::Position;
+use alloc::{vec, vec::Vec};
+use core::time::Duration;
+use glam::Vec2;
+
+/// The path of a projectile launched from `start` at `velocity`, under a constant `acceleration`
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Acceleration, Velocity};
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::trajectory::Trajectory;
+///
+/// let cannonball = Trajectory::new(
+///     Position::<F32>::new(0.0, 0.0),
+///     Velocity { x: F32(3.0), y: F32(10.0) },
+///     Acceleration { x: F32(0.0), y: F32(-10.0) },
+/// );
+///
+/// // Highest after one second, and back on the ground after two
+/// assert_eq!(cannonball.position_at(Duration::from_secs(1)), Position::new(3.0, 5.0));
+/// assert_eq!(cannonball.velocity_at(Duration::from_secs(1)), Velocity { x: F32(3.0), y: F32(0.0) });
+/// assert_eq!(cannonball.position_at(Duration::from_secs(2)), Position::new(6.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trajectory<C: Coordinate> {
+    /// Where the projectile is launched from
+    pub start: Position<C>,
+    /// The velocity of the projectile when it is launched
+    pub velocity: Velocity<C>,
+    /// The constant acceleration pulling on the projectile, such as gravity
+    pub acceleration: Acceleration<C>,
+}
+
+impl<C: Coordinate> Trajectory<C> {
+    /// Creates a new [`Trajectory`]
+    #[inline]
+    #[must_use]
+    pub fn new(start: Position<C>, velocity: Velocity<C>, acceleration: Acceleration<C>) -> Self {
+        Self {
+            start,
+            velocity,
+            acceleration,
+        }
+    }
+
+    /// Where the projectile is once `time` has passed since launch
+    #[must_use]
+    pub fn position_at(&self, time: Duration) -> Position<C> {
+        predict(self.start, self.velocity, self.acceleration, time).0
+    }
+
+    /// How fast the projectile is moving once `time` has passed since launch
+    #[must_use]
+    pub fn velocity_at(&self, time: Duration) -> Velocity<C> {
+        predict(self.start, self.velocity, self.acceleration, time).1
+    }
+
+    /// `n` positions, evenly spaced in time from the launch until `duration` has passed
+    ///
+    /// The first position is the `start`, and the last is where the projectile is after `duration`.
+    /// Connect these with lines to draw an aim preview.
+    #[must_use]
+    pub fn positions(&self, duration: Duration, n: usize) -> Vec<Position<C>> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.start],
+            _ => (0..n)
+                .map(|i| self.position_at(duration.mul_f32(i as f32 / (n - 1) as f32)))
+                .collect(),
+        }
+    }
+}
+
+/// The launch velocities with a speed of `speed` that carry a projectile from `start` to `target`, under a constant `acceleration`
+///
+/// There are usually two ways to hit a target: a flatter, faster low arc, and a slower high arc that lobs over obstacles.
+/// These are returned in that order.
+/// When the target is at the very edge of the projectile's range, or there is no `acceleration`, both are the same.
+///
+/// Returns `None` if the target is out of range at this speed, or the `target` is the `start`.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Acceleration;
+/// use leafwing_2d::position::{Position, Positionlike};
+/// use leafwing_2d::trajectory::{launch_velocities, Trajectory};
+///
+/// let gravity = Acceleration { x: F32(0.0), y: F32(-10.0) };
+/// let start = Position::<F32>::new(0.0, 0.0);
+/// let target = Position::<F32>::new(8.0, 1.0);
+///
+/// let [low, high] = launch_velocities(start, target, 10.0, gravity).unwrap();
+/// assert!(low.y < high.y);
+///
+/// // The low arc reaches the target after about one second
+/// let landing = Trajectory::new(start, low, gravity).position_at(Duration::from_secs_f32(8.0 / low.x.0));
+/// landing.assert_approx_eq(target);
+///
+/// // Too far to reach at this speed
+/// assert_eq!(launch_velocities(start, Position::new(100.0, 0.0), 10.0, gravity), None);
+/// ```
+#[must_use]
+pub fn launch_velocities<C: Coordinate>(
+    start: Position<C>,
+    target: Position<C>,
+    speed: f32,
+    acceleration: Acceleration<C>,
+) -> Option<[Velocity<C>; 2]> {
+    let offset = Vec2::from(target) - Vec2::from(start);
+    let acceleration = Vec2::from(acceleration);
+    if offset == Vec2::ZERO {
+        return None;
+    }
+
+    let strength = acceleration.length();
+    if strength == 0. {
+        let velocity = Velocity::from(offset.normalize() * speed);
+        return Some([velocity, velocity]);
+    }
+
+    // Work in a frame where the acceleration points straight down
+    let up = -acceleration / strength;
+    let right = Vec2::new(up.y, -up.x);
+    let (x, y) = (offset.dot(right), offset.dot(up));
+
+    let speed_squared = speed * speed;
+    let discriminant =
+        speed_squared * speed_squared - strength * (strength * x * x + 2. * y * speed_squared);
+    if discriminant < 0. {
+        return None;
+    }
+
+    let root = math::sqrt(discriminant);
+    let launch = |rise: f32| -> Option<Velocity<C>> {
+        // The launch direction has a slope of `rise / (strength * x)` in the rotated frame
+        let direction = (right * (strength * x) + up * rise).try_normalize()?;
+        Some(Velocity::from(direction * speed))
+    };
+
+    Some([launch(speed_squared - root)?, launch(speed_squared + root)?])
+}
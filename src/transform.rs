@@ -0,0 +1,291 @@
+//! A lightweight 2D transformation, for describing relative offsets and local coordinate spaces
+
+use crate::coordinate::Coordinate;
+use crate::orientation::Rotation;
+use crate::position::Position;
+use crate::scale::Scale;
+#[cfg(feature = "bevy")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "bevy")]
+use bevy_transform::components::{GlobalTransform, Transform};
+use core::ops::Mul;
+use glam::Vec2;
+#[cfg(feature = "bevy")]
+use glam::{Quat, Vec3};
+
+/// A 2-dimensional transformation, made up of a translation, rotation and uniform scale
+///
+/// Transforming a [`Position`] first scales it (relative to the origin),
+/// then rotates it clockwise around the origin, and finally translates it by `position`.
+///
+/// Transformations can be composed by multiplying them together:
+/// `(parent * child) * point` is the same as `parent * (child * point)`.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::position::{Position, Positionlike};
+/// use leafwing_2d::scale::Scale;
+/// use leafwing_2d::transform::Transform2D;
+///
+/// let ship = Transform2D::<F32>::new(Position::new(10.0, 0.0), Rotation::EAST, Scale(2.0));
+/// let turret_offset = Transform2D::<F32>::from_position(Position::new(0.0, 1.0));
+///
+/// // The turret is one unit in front of the ship's nose, scaled up along with the ship
+/// let turret = ship * turret_offset;
+/// turret.position.assert_approx_eq(Position::<F32>::new(12.0, 0.0));
+///
+/// // Converting from world space back into the ship's local space
+/// let local = ship.inverse() * turret.position;
+/// local.assert_approx_eq(Position::<F32>::new(0.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<C: Coordinate> {
+    /// The translation, applied last
+    pub position: Position<C>,
+    /// The clockwise rotation around the origin, applied after scaling
+    pub rotation: Rotation,
+    /// The uniform scale relative to the origin, applied first
+    pub scale: Scale<C>,
+}
+
+impl<C: Coordinate> Transform2D<C> {
+    /// The transformation that leaves every position unchanged
+    pub const IDENTITY: Transform2D<C> = Transform2D {
+        position: C::ORIGIN,
+        rotation: Rotation::NORTH,
+        scale: Scale(C::DATA_ONE),
+    };
+
+    /// Creates a new [`Transform2D`] from its parts
+    #[inline]
+    #[must_use]
+    pub const fn new(position: Position<C>, rotation: Rotation, scale: Scale<C>) -> Self {
+        Transform2D {
+            position,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Creates a new [`Transform2D`] that only translates by `position`
+    #[inline]
+    #[must_use]
+    pub fn from_position(position: Position<C>) -> Self {
+        Transform2D {
+            position,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Transform2D`] that only rotates by `rotation`
+    #[inline]
+    #[must_use]
+    pub fn from_rotation(rotation: Rotation) -> Self {
+        Transform2D {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Transform2D`] that only scales by `scale`
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: Scale<C>) -> Self {
+        Transform2D {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// The scale of this transformation, as an [`f32`]
+    #[inline]
+    #[must_use]
+    fn scale_factor(&self) -> f32 {
+        C::from(self.scale.0).into()
+    }
+
+    /// Applies this transformation to a `position`
+    ///
+    /// This is equivalent to `self * position`.
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, position: Position<C>) -> Position<C> {
+        let scaled = Vec2::from(position) * self.scale_factor();
+
+        (Vec2::from(self.position) + self.rotation.rotate_vec2(scaled)).into()
+    }
+
+    /// Computes the transformation that undoes this one
+    ///
+    /// # Panics
+    /// May panic if the scale is zero and `C::Data` is an integer type.
+    /// Floating point data types will produce infinite or NaN values instead.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let inverse_scale = C::DATA_ONE / self.scale.0;
+        let inverse_rotation = -self.rotation;
+
+        let unscaled_origin =
+            inverse_rotation.rotate_vec2(-Vec2::from(self.position)) / self.scale_factor();
+
+        Transform2D {
+            position: unscaled_origin.into(),
+            rotation: inverse_rotation,
+            scale: Scale(inverse_scale),
+        }
+    }
+}
+
+impl<C: Coordinate> Default for Transform2D<C> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<C: Coordinate> Mul<Transform2D<C>> for Transform2D<C> {
+    type Output = Transform2D<C>;
+
+    fn mul(self, rhs: Transform2D<C>) -> Transform2D<C> {
+        Transform2D {
+            position: self.transform_point(rhs.position),
+            rotation: self.rotation + rhs.rotation,
+            scale: self.scale * rhs.scale.0,
+        }
+    }
+}
+
+impl<C: Coordinate> Mul<Position<C>> for Transform2D<C> {
+    type Output = Position<C>;
+
+    fn mul(self, rhs: Position<C>) -> Position<C> {
+        self.transform_point(rhs)
+    }
+}
+
+/// The pivot point of an entity, which it rotates and scales around
+///
+/// The `offset` is measured from the center of the entity's [`Transform`] (such as the middle of a sprite),
+/// in the entity's local, unrotated and unscaled space.
+/// When an entity has an [`Anchor`], its [`Position`] describes where the pivot is, rather than where the center of the [`Transform`] is.
+/// [`sync_transform_with_2d`](crate::plugin::sync_transform_with_2d) accounts for this in both directions,
+/// so that changes to the [`Rotation`](crate::orientation::Rotation) swing the entity around the pivot,
+/// and the [`Transform`]'s scale stretches the entity away from the pivot.
+///
+/// Editing the rotation or scale of the [`Transform`] directly leaves its translation in place,
+/// so the pivot moves instead: prefer changing the [`Rotation`](crate::orientation::Rotation) of anchored entities.
+///
+/// Use this to plant a character's feet, or to hinge a door at one edge.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::transform::Anchor;
+///
+/// fn spawn_door(mut commands: Commands) {
+///     // A door 2 units wide, hinged at its left edge
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         .insert(Transform::default())
+///         .insert(Anchor {
+///             offset: Position::<F32>::new(-1.0, 0.0),
+///         });
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_startup_system(spawn_door);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct Anchor<C: Coordinate> {
+    /// Where the pivot is, relative to the center of the entity
+    pub offset: Position<C>,
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> Anchor<C> {
+    /// Where the pivot is relative to the center of the [`Transform`], once it has been rotated and scaled
+    #[must_use]
+    pub fn world_offset(&self, transform: &Transform) -> Vec2 {
+        let local = Vec2::from(self.offset).extend(0.) * transform.scale;
+
+        (transform.rotation * local).truncate()
+    }
+}
+
+/// Keeps any tilt of an entity's [`Transform`] when it is synchronized with its [`Rotation`](crate::orientation::Rotation) or [`Direction`](crate::orientation::Direction)
+///
+/// By default, [`sync_transform_with_2d`](crate::plugin::sync_transform_with_2d) overwrites the whole rotation quaternion of the [`Transform`].
+/// For entities with this marker, only the turn around the z-axis (the yaw) is replaced,
+/// so rotations around the x and y axes, such as those added by skew, wobble or card-flip effects, are left in place.
+/// Similarly, only the yaw of the [`Transform`] is read when copying changes back into the 2D components.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::transform::PreserveTilt;
+///
+/// fn spawn_card(mut commands: Commands) {
+///     commands
+///         .spawn_bundle(TwoDBundle::<F32>::default())
+///         // Half-flipped over, showing its edge
+///         .insert(Transform::from_rotation(Quat::from_rotation_y(core::f32::consts::FRAC_PI_2)))
+///         .insert(PreserveTilt);
+/// }
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_startup_system(spawn_card);
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PreserveTilt;
+
+// z-values are dropped, and only the x-component of the scale is used
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> From<Transform> for Transform2D<C> {
+    fn from(transform: Transform) -> Self {
+        Transform2D {
+            position: transform.translation.into(),
+            rotation: transform.rotation.into(),
+            scale: Scale(C::from(transform.scale.x).into()),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> From<GlobalTransform> for Transform2D<C> {
+    fn from(transform: GlobalTransform) -> Self {
+        Transform2D {
+            position: transform.translation.into(),
+            rotation: transform.rotation.into(),
+            scale: Scale(C::from(transform.scale.x).into()),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> From<Transform2D<C>> for Transform {
+    fn from(transform: Transform2D<C>) -> Self {
+        Transform {
+            translation: transform.position.into(),
+            rotation: Quat::from(transform.rotation),
+            scale: Vec3::new(transform.scale_factor(), transform.scale_factor(), 1.0),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<C: Coordinate> From<Transform2D<C>> for GlobalTransform {
+    fn from(transform: Transform2D<C>) -> Self {
+        GlobalTransform {
+            translation: transform.position.into(),
+            rotation: Quat::from(transform.rotation),
+            scale: Vec3::new(transform.scale_factor(), transform.scale_factor(), 1.0),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Detect when entities enter or leave the field of view of an observer
+//!
+//! Add a [`VisionCone`] to observers with a [`Position`] and [`Direction`],
+//! and a [`Detectable`] marker to the entities that they should look for.
+//! The [`VisionPlugin`] then sends a [`TargetSpotted`] or [`TargetLost`] event
+//! whenever a target enters or leaves the cone.
+//!
+//! Observers check every target each frame, which is fast enough for a few hundred observers and targets.
+
+use crate::bounding::Sector;
+use crate::coordinate::Coordinate;
+use crate::geometry::{segment_segment_intersection, LineIntersection};
+use crate::orientation::{AngularCone, Direction, Rotation};
+use crate::plugin::TwoDSystem;
+use crate::polygon::Polygon;
+use crate::position::Position;
+use alloc::vec::Vec;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+/// The field of view of an observer: a cone centered on its facing [`Direction`]
+///
+/// The entities that are currently visible are tracked by the [`vision_cones`] system.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::{Direction, Rotation};
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::vision::VisionCone;
+///
+/// let guard = VisionCone::<F32>::new(Rotation::from_degrees(30.0), 10.0);
+/// let (guard_position, guard_direction) = (Position::new(0.0, 0.0), Direction::NORTH);
+///
+/// assert!(guard.can_see(guard_position, guard_direction, Position::new(1.0, 5.0)));
+/// // Behind the guard
+/// assert!(!guard.can_see(guard_position, guard_direction, Position::new(0.0, -5.0)));
+/// // Too far away
+/// assert!(!guard.can_see(guard_position, guard_direction, Position::new(0.0, 15.0)));
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct VisionCone<C: Coordinate> {
+    /// How far the cone extends to either side of the facing direction
+    pub half_angle: Rotation,
+    /// How far away targets can be seen
+    pub range: C,
+    /// Should [`Occluders`](Occluder) block this cone's line of sight?
+    pub line_of_sight: bool,
+    visible: Vec<Entity>,
+}
+
+impl<C: Coordinate> VisionCone<C> {
+    /// Creates a new [`VisionCone`], which sees through [`Occluders`](Occluder)
+    ///
+    /// # Panics
+    /// `range` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new(half_angle: Rotation, range: impl Into<C>) -> Self {
+        let range = range.into();
+        assert!(range >= C::ZERO);
+
+        Self {
+            half_angle,
+            range,
+            line_of_sight: false,
+            visible: Vec::new(),
+        }
+    }
+
+    /// Makes this cone's line of sight blocked by [`Occluders`](Occluder)
+    #[inline]
+    #[must_use]
+    pub fn with_line_of_sight(mut self) -> Self {
+        self.line_of_sight = true;
+        self
+    }
+
+    /// The targets that were visible the last time [`vision_cones`] ran
+    #[inline]
+    #[must_use]
+    pub fn visible(&self) -> &[Entity] {
+        &self.visible
+    }
+
+    /// Is the `target` within this cone, for an observer at `position` facing `direction`?
+    ///
+    /// [`Occluders`](Occluder) are not considered.
+    #[must_use]
+    pub fn can_see(
+        &self,
+        position: Position<C>,
+        direction: Direction,
+        target: Position<C>,
+    ) -> bool {
+        let cone = AngularCone::new(direction, self.half_angle);
+        Sector::new(position, self.range, cone).contains(target)
+    }
+}
+
+/// Marks entities that can be seen by a [`VisionCone`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Detectable;
+
+/// A shape that blocks the line of sight of [`VisionCones`](VisionCone)
+///
+/// The polygon is stored in world space.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct Occluder<C: Coordinate>(pub Polygon<C>);
+
+impl<C: Coordinate> Occluder<C> {
+    /// Does this occluder block the line of sight from `from` to `to`?
+    ///
+    /// Lines of sight that only graze a corner or edge are blocked.
+    #[must_use]
+    pub fn blocks(&self, from: Position<C>, to: Position<C>) -> bool {
+        self.0.edges().any(|(start, end)| {
+            !matches!(
+                segment_segment_intersection(from, to, start, end),
+                LineIntersection::None
+            )
+        })
+    }
+}
+
+/// Sent when a [`Detectable`] entity enters the [`VisionCone`] of an `observer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetSpotted {
+    /// The entity with the [`VisionCone`]
+    pub observer: Entity,
+    /// The entity that was spotted
+    pub target: Entity,
+}
+
+/// Sent when a [`Detectable`] entity leaves the [`VisionCone`] of an `observer`
+///
+/// Targets that are despawned or lose their [`Detectable`] component are also lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetLost {
+    /// The entity with the [`VisionCone`]
+    pub observer: Entity,
+    /// The entity that was lost
+    pub target: Entity,
+}
+
+/// Updates the visible targets of every [`VisionCone`], sending [`TargetSpotted`] and [`TargetLost`] events as they change
+///
+/// Observers never see themselves.
+pub fn vision_cones<C: Coordinate>(
+    mut observers: Query<(Entity, &Position<C>, &Direction, &mut VisionCone<C>)>,
+    targets: Query<(Entity, &Position<C>), With<Detectable>>,
+    occluders: Query<&Occluder<C>>,
+    mut spotted: EventWriter<TargetSpotted>,
+    mut lost: EventWriter<TargetLost>,
+) {
+    for (observer, &position, &direction, mut cone) in observers.iter_mut() {
+        let visible: Vec<Entity> = targets
+            .iter()
+            .filter(|&(target, &target_position)| {
+                target != observer
+                    && cone.can_see(position, direction, target_position)
+                    && !(cone.line_of_sight
+                        && occluders
+                            .iter()
+                            .any(|occluder| occluder.blocks(position, target_position)))
+            })
+            .map(|(target, _)| target)
+            .collect();
+
+        for &target in visible
+            .iter()
+            .filter(|target| !cone.visible.contains(target))
+        {
+            spotted.send(TargetSpotted { observer, target });
+        }
+        for &target in cone
+            .visible
+            .iter()
+            .filter(|target| !visible.contains(target))
+        {
+            lost.send(TargetLost { observer, target });
+        }
+
+        // Avoid triggering change detection when nothing has changed
+        if cone.visible != visible {
+            cone.visible = visible;
+        }
+    }
+}
+
+/// Adds the [`TargetSpotted`] and [`TargetLost`] events, and the [`vision_cones`] system
+///
+/// The system runs in [`CoreStage::PostUpdate`], after [`TwoDSystem::SyncDirectionRotation`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::vision::VisionPlugin;
+///
+/// App::new()
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(VisionPlugin::<F32>::default());
+/// ```
+#[derive(Debug)]
+pub struct VisionPlugin<C: Coordinate> {
+    /// What [`Coordinate`] should be used?
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl<C: Coordinate> Default for VisionPlugin<C> {
+    fn default() -> Self {
+        Self {
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate> Plugin for VisionPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TargetSpotted>()
+            .add_event::<TargetLost>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                vision_cones::<C>.after(TwoDSystem::SyncDirectionRotation),
+            );
+    }
+}
@@ -0,0 +1,64 @@
+use leafwing_2d::orientation::{Angle, Rotation};
+
+#[test]
+fn angle_rotation_round_trip() {
+    for deci_degrees in (0..3600).step_by(7) {
+        let rotation = Rotation::new(deci_degrees);
+        assert_eq!(Rotation::from(Angle::from(rotation)), rotation);
+    }
+}
+
+#[test]
+fn angles_wrap_when_converted_to_rotations() {
+    assert_eq!(Rotation::from(Angle::from_degrees(450.0)), Rotation::EAST);
+    assert_eq!(Rotation::from(Angle::from_degrees(-90.0)), Rotation::WEST);
+    assert_eq!(Rotation::from(Angle::from_degrees(-720.0)), Rotation::NORTH);
+    assert_eq!(Rotation::from(-Angle::FULL_TURN), Rotation::NORTH);
+}
+
+#[test]
+fn shortest_turn_between_rotations() {
+    assert_eq!(
+        Angle::between(Rotation::from_degrees(350.0), Rotation::from_degrees(10.0)),
+        Angle::from_degrees(20.0)
+    );
+    assert_eq!(
+        Angle::between(Rotation::from_degrees(10.0), Rotation::from_degrees(350.0)),
+        Angle::from_degrees(-20.0)
+    );
+    assert_eq!(Angle::between(Rotation::EAST, Rotation::EAST), Angle::ZERO);
+    assert_eq!(
+        Angle::between(Rotation::EAST, Rotation::WEST),
+        Angle::from_degrees(180.0)
+    );
+}
+
+#[test]
+fn counterclockwise_spins_accumulate_negative_turns() {
+    let mut total = Angle::ZERO;
+    let mut previous = Rotation::NORTH;
+
+    // Three counterclockwise turns, in steps of 30 degrees
+    for _ in 0..36 {
+        let current = previous - Rotation::from_degrees(30.0);
+        total.accumulate(previous, current);
+        previous = current;
+    }
+
+    assert_eq!(total, Angle::from_degrees(-1080.0));
+    assert_eq!(total.full_turns(), -3);
+    assert_eq!(total.abs(), 3.0 * Angle::FULL_TURN);
+    assert_eq!(Rotation::from(total), Rotation::NORTH);
+}
+
+#[test]
+fn angle_arithmetic() {
+    let a = Angle::from_degrees(400.0);
+    let b = Angle::from_degrees(-50.0);
+
+    assert_eq!(a + b, Angle::from_degrees(350.0));
+    assert_eq!(a - b, Angle::from_degrees(450.0));
+    assert_eq!(a * 0.5, Angle::from_degrees(200.0));
+    assert!((Angle::FULL_TURN.into_radians() - core::f32::consts::TAU).abs() < 1e-5);
+    assert_eq!(b.rotate(Rotation::NORTH), Rotation::from_degrees(310.0));
+}
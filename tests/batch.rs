@@ -0,0 +1,148 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use core::time::Duration;
+use leafwing_2d::batch::KinematicBatch;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::position::Position;
+
+fn velocity(x: f32, y: f32) -> Velocity<F32> {
+    Velocity {
+        x: F32(x),
+        y: F32(y),
+    }
+}
+
+fn acceleration(x: f32, y: f32) -> Acceleration<F32> {
+    Acceleration {
+        x: F32(x),
+        y: F32(y),
+    }
+}
+
+#[test]
+fn integration_matches_per_component_kinematics() {
+    let objects: Vec<_> = (0..10)
+        .map(|i| {
+            let i = i as f32;
+            (
+                Position::<F32>::new(i, -i),
+                velocity(1.0, i),
+                acceleration(-i, 2.0),
+            )
+        })
+        .collect();
+
+    let mut batch: KinematicBatch<F32> = objects.iter().copied().collect();
+    let delta_time = Duration::from_millis(250);
+    batch.integrate(delta_time);
+
+    for (index, &(position, velocity, acceleration)) in objects.iter().enumerate() {
+        let expected_velocity = velocity + acceleration * delta_time;
+        let expected_position = position + expected_velocity * delta_time;
+
+        assert_eq!(batch.velocity(index), Some(expected_velocity));
+        assert_eq!(batch.position(index), Some(expected_position));
+        assert_eq!(batch.acceleration(index), Some(acceleration));
+    }
+}
+
+#[test]
+fn batch_bookkeeping() {
+    let mut batch = KinematicBatch::<F32>::with_capacity(4);
+    assert!(batch.is_empty());
+
+    let first = batch.push(
+        Position::new(1.0, 1.0),
+        velocity(0.0, 0.0),
+        acceleration(0.0, 0.0),
+    );
+    let second = batch.push(
+        Position::new(2.0, 2.0),
+        velocity(1.0, 0.0),
+        acceleration(0.0, 0.0),
+    );
+    assert_eq!((first, second), (0, 1));
+    assert_eq!(batch.len(), 2);
+
+    batch.set_velocity(first, velocity(0.0, -1.0));
+    batch.set_acceleration(first, acceleration(3.0, 0.0));
+    batch.set_position(second, Position::new(5.0, 5.0));
+    assert_eq!(batch.velocity(first), Some(velocity(0.0, -1.0)));
+    assert_eq!(batch.acceleration(first), Some(acceleration(3.0, 0.0)));
+    assert_eq!(batch.position(second), Some(Position::new(5.0, 5.0)));
+    assert_eq!(batch.position(2), None);
+
+    let (removed_position, _, _) = batch.swap_remove(first);
+    assert_eq!(removed_position, Position::new(1.0, 1.0));
+    assert_eq!(
+        batch.positions().collect::<Vec<_>>(),
+        vec![Position::new(5.0, 5.0)]
+    );
+
+    batch.clear();
+    assert!(batch.is_empty());
+}
+
+#[derive(Component)]
+struct Bullet;
+
+fn move_bullets_in_batch(
+    mut batch: Local<KinematicBatch<F32>>,
+    mut query: Query<(&mut Position<F32>, &mut Velocity<F32>, &Acceleration<F32>), With<Bullet>>,
+) {
+    batch.clear();
+    batch.extend(query.iter().map(|(&p, &v, &a)| (p, v, a)));
+    batch.integrate(Duration::from_secs(1));
+    batch.scatter_into(
+        query
+            .iter_mut()
+            .map(|(position, velocity, _)| (position, velocity)),
+    );
+}
+
+#[test]
+fn scatter_results_back_to_components() {
+    let mut world = World::new();
+    let moving = world
+        .spawn()
+        .insert_bundle((
+            Bullet,
+            Position::<F32>::new(0.0, 0.0),
+            velocity(1.0, 2.0),
+            acceleration(1.0, 0.0),
+        ))
+        .id();
+    let resting = world
+        .spawn()
+        .insert_bundle((
+            Bullet,
+            Position::<F32>::new(7.0, 7.0),
+            velocity(0.0, 0.0),
+            acceleration(0.0, 0.0),
+        ))
+        .id();
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(move_bullets_in_batch);
+    stage.run(&mut world);
+    world.clear_trackers();
+    stage.run(&mut world);
+
+    assert_eq!(
+        *world.get::<Position<F32>>(moving).unwrap(),
+        Position::new(5.0, 4.0)
+    );
+    assert_eq!(
+        *world.get::<Velocity<F32>>(moving).unwrap(),
+        velocity(3.0, 2.0)
+    );
+    assert_eq!(
+        *world.get::<Position<F32>>(resting).unwrap(),
+        Position::new(7.0, 7.0)
+    );
+
+    // Entities that did not move are not marked as changed
+    let mut changed = world.query_filtered::<Entity, Changed<Position<F32>>>();
+    assert_eq!(changed.iter(&world).collect::<Vec<_>>(), vec![moving]);
+}
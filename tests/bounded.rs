@@ -0,0 +1,116 @@
+use bevy::math::Vec2;
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::{
+    AdjacentGrid, Bounded, DiscreteCoordinate, GridMetric, OrthogonalGrid,
+};
+use leafwing_2d::errors::CoordinateOutOfBounds;
+use leafwing_2d::position::Position;
+
+type Board = Bounded<OrthogonalGrid, 0, 7>;
+type KingBoard = Bounded<AdjacentGrid, -3, 3>;
+
+#[test]
+fn construction_respects_the_range() {
+    assert_eq!(Board::new(7).unwrap().value(), 7);
+    assert_eq!(Board::new(8), Err(CoordinateOutOfBounds));
+    assert_eq!(Board::new(-1), Err(CoordinateOutOfBounds));
+    assert_eq!(Board::saturating(100).value(), 7);
+    assert_eq!(Board::from(-100).value(), 0);
+    assert_eq!(Board::from(3.4_f32).value(), 3);
+    assert_eq!(Board::from(1e30_f32).value(), 7);
+    assert_eq!(Board::default().value(), 0);
+
+    assert_eq!(Board::MIN.value(), 0);
+    assert_eq!(Board::MAX.value(), 7);
+    Board::assert_values_distinct();
+}
+
+#[test]
+fn arithmetic_saturates() {
+    let six = Board::saturating(6);
+    let three = Board::saturating(3);
+
+    assert_eq!((six + three).value(), 7);
+    assert_eq!((three - six).value(), 0);
+    assert_eq!((six * three).value(), 7);
+    assert_eq!((six / three).value(), 2);
+    assert_eq!((six % Board::saturating(4)).value(), 2);
+    assert_eq!((three * 2).value(), 6);
+
+    let mut value = six;
+    value += three;
+    assert_eq!(value.value(), 7);
+    value -= Board::MAX;
+    assert_eq!(value.value(), 0);
+
+    assert_eq!(Board::MAX.next(), Board::MAX);
+    assert_eq!(Board::MIN.prev(), Board::MIN);
+    assert_eq!(three.next().value(), 4);
+}
+
+#[test]
+fn checked_arithmetic_reports_overflow() {
+    let six = Board::saturating(6);
+
+    assert_eq!(six.checked_add(Board::saturating(1)).unwrap().value(), 7);
+    assert_eq!(
+        six.checked_add(Board::saturating(2)),
+        Err(CoordinateOutOfBounds)
+    );
+    assert_eq!(
+        Board::MIN.checked_sub(Board::saturating(1)),
+        Err(CoordinateOutOfBounds)
+    );
+
+    let knight = Position::<Board>::new(6, 1);
+    let jump = Position::<Board>::new(1, 2);
+    assert_eq!(knight.checked_add(jump), Ok(Position::new(7, 3)));
+    assert_eq!(
+        knight.checked_add(jump).unwrap().checked_add(jump),
+        Err(CoordinateOutOfBounds)
+    );
+    assert_eq!(knight.checked_sub(jump), Err(CoordinateOutOfBounds));
+
+    // Ordinary position math stops at the edge of the board
+    assert_eq!(knight + jump + jump, Position::new(7, 5));
+}
+
+#[test]
+fn neighbors_stop_at_the_edges() {
+    assert_eq!(Board::neighbors(Position::new(3, 3)).len(), 4);
+    assert_eq!(
+        Board::neighbors(Position::new(0, 3)),
+        vec![
+            Position::new(0, 4),
+            Position::new(1, 3),
+            Position::new(0, 2)
+        ]
+    );
+    assert_eq!(KingBoard::neighbors(Position::new(3, -3)).len(), 3);
+    assert_eq!(KingBoard::neighbors(Position::new(0, 0)).len(), 8);
+
+    // Directions are unaffected by the edges
+    assert_eq!(
+        Board::neighbor_directions(),
+        OrthogonalGrid::neighbor_directions()
+    );
+}
+
+#[test]
+fn distances_match_the_wrapped_grid() {
+    let a = Position::<KingBoard>::new(-3, -3);
+    let b = Position::<KingBoard>::new(3, 1);
+
+    assert_eq!(KingBoard::grid_distance(a, b), 6);
+    assert_eq!(KingBoard::manhattan_distance(a, b), 10);
+    assert_eq!(Vec2::from(b), Vec2::new(3.0, 1.0));
+}
+
+#[test]
+fn ranges_stop_at_the_edges() {
+    let corner = Position::<Board>::new(0, 0);
+
+    assert_eq!(Board::within_range(corner, 1).count(), 3);
+    assert_eq!(Board::within_range(corner, 2).count(), 6);
+    assert_eq!(Board::within_range(corner, 100).count(), 64);
+}
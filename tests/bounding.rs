@@ -0,0 +1,1023 @@
+use bevy::math::Vec2;
+use core::time::Duration;
+use leafwing_2d::bounding::{
+    Annulus, Arc, AxisAlignedBoundingBox, BoundingRegion, Capsule, Circle, CollisionInfo,
+    CompositeMode, CompositeRegion, Contact, Ellipse, HalfPlane, Intersects, Sector, SweptRegion,
+};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::kinematics::Velocity;
+use leafwing_2d::orientation::{AngularCone, Direction, Orientation, Rotation};
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::scale::Scale;
+use leafwing_2d::transform::Transform2D;
+
+#[test]
+fn cone_wrapping() {
+    let cone = AngularCone::from_bounds(Rotation::from_degrees(300.), Rotation::from_degrees(60.));
+
+    assert_eq!(cone.facing(), Rotation::NORTH);
+    assert!(cone.contains(Rotation::from_degrees(330.)));
+    assert!(cone.contains(Rotation::from_degrees(30.)));
+    assert!(!cone.contains(Rotation::SOUTH));
+
+    assert!(AngularCone::FULL_CIRCLE.contains(Rotation::SOUTH));
+    assert!(AngularCone::new(Rotation::EAST, Rotation::from_degrees(270.)).is_full_circle());
+}
+
+#[test]
+fn cone_with_odd_width() {
+    // 4.5 degrees, which cannot be split into two whole halves of tenths of a degree
+    let start = Rotation::new(3590);
+    let end = Rotation::new(35);
+    let cone = AngularCone::from_bounds(start, end);
+
+    assert_eq!(cone.start(), start);
+    assert_eq!(cone.end(), end);
+    assert!(cone.contains(end));
+    assert!(cone.contains(Rotation::new(0)));
+    assert!(!cone.contains(Rotation::new(36)));
+    assert!(!cone.contains(Rotation::new(3589)));
+
+    assert_eq!(cone.facing(), Rotation::new(12));
+    assert_eq!(cone.half_width(), Rotation::new(22));
+    assert!((cone.width_radians() - 4.5f32.to_radians()).abs() < 1e-6);
+}
+
+#[test]
+fn sector_vertexes() {
+    let quarter = Sector::<F32>::new(
+        Position::new(1., 1.),
+        2.,
+        AngularCone::from_bounds(Rotation::NORTH, Rotation::EAST),
+    );
+
+    let vertexes = quarter.vertexes(2);
+    assert_eq!(vertexes.len(), 4);
+    vertexes[0].assert_approx_eq(Position::<F32>::new(1., 1.));
+    vertexes[1].assert_approx_eq(Position::<F32>::new(1., 3.));
+    vertexes[3].assert_approx_eq(Position::<F32>::new(3., 1.));
+
+    for vertex in vertexes {
+        // Rotations are only precise to a tenth of a degree, so nudge the vertex inwards
+        let nudged =
+            Position::<F32>::new(0.99 * vertex.x.0 + 0.01 * 1., 0.99 * vertex.y.0 + 0.01 * 1.);
+        assert!(quarter.contains(nudged), "{nudged:?}");
+    }
+
+    let full_circle = Sector::<F32>::new(Position::new(0., 0.), 1., AngularCone::FULL_CIRCLE);
+    assert_eq!(full_circle.vertexes(8).len(), 9);
+    assert!(full_circle.contains(Position::new(0., -1.)));
+}
+
+#[test]
+fn sector_vision_cone() {
+    let guard = Position::<F32>::new(2., 2.);
+    let vision = Sector::from_facing(guard, Direction::EAST, Rotation::from_degrees(30.), 5.);
+    vision.facing().assert_approx_eq(Direction::EAST);
+    assert_eq!(vision.radius, F32(5.));
+
+    assert!(vision.contains(guard));
+    assert!(vision.contains(Position::new(6., 3.)));
+    // Just outside of the field of view
+    assert!(!vision.contains(Position::new(4., 4.)));
+    // In the field of view, but too far away
+    assert!(!vision.contains(Position::new(8., 2.)));
+    // Behind the guard
+    assert!(!vision.contains(Position::new(0., 2.)));
+}
+
+#[test]
+fn arc_vertexes() {
+    let band = Arc::<F32>::new(
+        Position::new(0., 0.),
+        1.,
+        2.,
+        AngularCone::new(Rotation::SOUTH, Rotation::from_degrees(45.)),
+    );
+
+    let vertexes = band.vertexes(4);
+    assert_eq!(vertexes.len(), 10);
+    // The outer and inner edges are walked in opposite directions
+    vertexes[0].assert_approx_eq(Position::<F32>::new(1.414, -1.414));
+    vertexes[4].assert_approx_eq(Position::<F32>::new(-1.414, -1.414));
+    vertexes[5].assert_approx_eq(Position::<F32>::new(-0.707, -0.707));
+    vertexes[9].assert_approx_eq(Position::<F32>::new(0.707, -0.707));
+
+    assert!(band.contains(Position::new(0., -1.)));
+    assert!(band.contains(Position::new(0., -2.)));
+    assert!(!band.contains(Position::new(0., 0.)));
+}
+
+/// A deterministic scattering of points, generated with a simple linear congruential generator
+fn scattered(count: usize) -> Vec<Position<F32>> {
+    let mut state: u32 = 777;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (state >> 8) as f32 / (1 << 24) as f32 * 10. - 5.
+    };
+
+    (0..count)
+        .map(|_| Position::new(next(), 0.5 * next() + 1.))
+        .collect()
+}
+
+#[test]
+fn enclosing_circle_is_tight() {
+    let positions = scattered(100);
+    let circle = Circle::draw_around(positions.iter().copied());
+    let radius: f32 = circle.radius.into();
+
+    for &position in &positions {
+        assert!(circle.contains(position));
+    }
+
+    // The smallest enclosing circle touches at least two of the positions
+    let touching = positions
+        .iter()
+        .filter(|&&position| {
+            let distance = Vec2::from(position).distance(circle.center.into());
+            (distance - radius).abs() < 1e-4
+        })
+        .count();
+    assert!(touching >= 2);
+}
+
+#[test]
+fn enclosing_circle_degenerate_inputs() {
+    assert_eq!(
+        Circle::<F32>::draw_around([]),
+        Circle::new(Position::new(0., 0.), 0.)
+    );
+
+    let single = Position::<F32>::new(3., -2.);
+    assert_eq!(Circle::draw_around([single]), Circle::new(single, 0.));
+
+    let collinear = (0..5).map(|i| Position::<F32>::new(i as f32, 0.));
+    let circle = Circle::draw_around(collinear);
+    circle.center.assert_approx_eq(Position::<F32>::new(2., 0.));
+    assert!((circle.radius.0 - 2.).abs() < 1e-5);
+}
+
+#[test]
+fn circle_intersection() {
+    let circle = Circle::<F32>::new(Position::new(0., 0.), 2.);
+
+    assert!(matches!(
+        circle.intersects(Circle::new(Position::new(3., 0.), 1.5)),
+        Intersects::Yes
+    ));
+    assert!(matches!(
+        circle.intersects(Circle::new(Position::new(3., 3.), 1.)),
+        Intersects::No
+    ));
+}
+
+#[test]
+fn fitted_ellipse_contains_positions() {
+    // A long, thin, tilted cloud of points
+    let positions: Vec<Position<F32>> = scattered(60)
+        .into_iter()
+        .map(|position| Position::new(position.x.0 + position.y.0, position.x.0 - position.y.0))
+        .collect();
+    let ellipse = Ellipse::fit(positions.iter().copied());
+
+    for &position in &positions {
+        assert!(ellipse.contains(position));
+    }
+
+    // The major axis runs along the diagonal
+    assert!(ellipse.half_width > ellipse.half_height);
+    let tilt = ellipse.rotation.distance(Rotation::from_degrees(315.));
+    let flipped_tilt = ellipse.rotation.distance(Rotation::from_degrees(135.));
+    assert!(tilt.into_degrees().min(flipped_tilt.into_degrees()) < 5.);
+}
+
+#[test]
+fn degenerate_ellipses() {
+    let empty = Ellipse::<F32>::fit([]);
+    assert_eq!(empty.half_width, F32(0.));
+    assert_eq!(empty.half_height, F32(0.));
+
+    let segment = Ellipse::<F32>::fit([Position::new(-1., 0.), Position::new(1., 0.)]);
+    assert!(segment.contains(Position::new(0.5, 0.)));
+    assert!(!segment.contains(Position::new(0., 0.5)));
+}
+
+#[test]
+fn aabb_corners_and_clamping() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-1., 3., 0., 17.);
+
+    assert_eq!(aabb.bottom_left(), Position::new(-1., 0.));
+    assert_eq!(aabb.bottom_right(), Position::new(3., 0.));
+    assert_eq!(aabb.top_left(), Position::new(-1., 17.));
+    assert_eq!(aabb.top_right(), Position::new(3., 17.));
+
+    assert!(aabb.contains(Position::new(2., 16.)));
+    assert!(!aabb.contains(Position::new(16., 2.)));
+    assert_eq!(aabb.clamp(Position::new(10., -5.)), aabb.bottom_right());
+
+    let drawn = AxisAlignedBoundingBox::draw_around([
+        Position::<F32>::new(5., 6.),
+        Position::new(7., 10.),
+        Position::new(6., 8.),
+    ]);
+    assert_eq!(drawn, AxisAlignedBoundingBox::new(5., 7., 6., 10.));
+}
+
+#[test]
+fn aabb_intersection() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0., 4., 0., 2.);
+
+    assert_eq!(
+        aabb.intersection(&AxisAlignedBoundingBox::new(3., 5., -1., 1.)),
+        Some(AxisAlignedBoundingBox::new(3., 4., 0., 1.))
+    );
+    assert_eq!(
+        aabb.intersection(&AxisAlignedBoundingBox::new(4., 5., 0., 2.)),
+        Some(AxisAlignedBoundingBox::new(4., 4., 0., 2.))
+    );
+    assert_eq!(
+        aabb.intersection(&AxisAlignedBoundingBox::new(5., 6., 0., 2.)),
+        None
+    );
+}
+
+#[test]
+fn aabb_difference() {
+    let floor = AxisAlignedBoundingBox::<F32>::new(0., 10., 0., 10.);
+    let area = |aabb: &AxisAlignedBoundingBox<F32>| {
+        (aabb.right.0 - aabb.left.0) * (aabb.top.0 - aabb.bottom.0)
+    };
+
+    // Cutting off a corner
+    let corner = AxisAlignedBoundingBox::new(8., 12., 8., 12.);
+    let pieces = floor.difference(&corner);
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces.iter().map(area).sum::<f32>(), 96.);
+
+    // Cutting the floor in two
+    let wall = AxisAlignedBoundingBox::new(4., 5., -1., 11.);
+    let pieces = floor.difference(&wall);
+    assert_eq!(
+        pieces,
+        vec![
+            AxisAlignedBoundingBox::new(0., 4., 0., 10.),
+            AxisAlignedBoundingBox::new(5., 10., 0., 10.),
+        ]
+    );
+
+    // Subtracting everything
+    assert!(floor.difference(&floor).is_empty());
+
+    // Subtracting nothing
+    let elsewhere = AxisAlignedBoundingBox::new(20., 30., 20., 30.);
+    assert_eq!(floor.difference(&elsewhere), vec![floor.clone()]);
+}
+
+#[test]
+fn aabb_expand_translate_and_scale() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0., 2., 0., 4.);
+
+    assert_eq!(
+        aabb.expanded_by(1.),
+        AxisAlignedBoundingBox::new(-1., 3., -1., 5.)
+    );
+    // Shrinking the narrow side past nothing collapses it onto the center
+    assert_eq!(
+        aabb.expanded_by(-1.5),
+        AxisAlignedBoundingBox::new(1., 1., 1.5, 2.5)
+    );
+
+    assert_eq!(
+        aabb.translated_by(Position::new(-2., 1.)),
+        AxisAlignedBoundingBox::new(-2., 0., 1., 5.)
+    );
+
+    assert_eq!(
+        aabb.scaled_by(0.5),
+        AxisAlignedBoundingBox::new(0.5, 1.5, 1., 3.)
+    );
+    assert_eq!(aabb.scaled_by(-1.), aabb);
+
+    let cells = AxisAlignedBoundingBox::<OrthogonalGrid>::new(2., 5., 2., 6.);
+    assert_eq!(
+        cells.scaled_by(3),
+        AxisAlignedBoundingBox::new(-1., 8., -2., 10.)
+    );
+}
+
+#[test]
+fn minkowski_sum_and_difference() {
+    let wall = AxisAlignedBoundingBox::<F32>::new(10., 11., 0., 5.);
+    let player = AxisAlignedBoundingBox::<F32>::new(-0.5, 0.5, -1., 1.);
+
+    // Growing and then shrinking by the same box gets back to where we started
+    let grown = wall.minkowski_sum(&player);
+    assert_eq!(grown, AxisAlignedBoundingBox::new(9.5, 11.5, -1., 6.));
+    assert_eq!(grown.minkowski_difference(&player), Some(wall.clone()));
+
+    // Too wide to fit inside of the wall
+    assert_eq!(
+        wall.minkowski_difference(&AxisAlignedBoundingBox::new(-1., 1., -1., 1.)),
+        None
+    );
+
+    // Boxes overlap exactly when one position is in the sum of one box and the other reflected through the origin
+    let reflected = |aabb: &AxisAlignedBoundingBox<F32>| {
+        AxisAlignedBoundingBox::new(-aabb.right.0, -aabb.left.0, -aabb.top.0, -aabb.bottom.0)
+    };
+    for (x, y) in [(9., 0.), (9.6, 2.), (11.4, 5.5), (12., 7.)] {
+        let moved = AxisAlignedBoundingBox::from_size(Position::new(x, y), 0.5, 1.);
+        assert_eq!(
+            matches!(wall.intersects(moved.clone()), Intersects::Yes),
+            wall.minkowski_sum(&reflected(&moved))
+                .contains(Position::new(0., 0.))
+        );
+    }
+
+    let arena = Circle::<F32>::new(Position::new(1., 1.), 10.);
+    let ball = Circle::<F32>::new(Position::new(0., 0.), 0.5);
+    assert_eq!(
+        arena.minkowski_sum(&ball),
+        Circle::new(Position::new(1., 1.), 10.5)
+    );
+    assert_eq!(
+        arena.minkowski_difference(&ball),
+        Some(Circle::new(Position::new(1., 1.), 9.5))
+    );
+    assert_eq!(ball.minkowski_difference(&arena), None);
+}
+
+#[test]
+fn aabb_sweep_time_of_impact() {
+    let bullet = AxisAlignedBoundingBox::<F32>::new(0., 1., 0., 1.);
+    let wall = AxisAlignedBoundingBox::<F32>::new(10., 10.5, -5., 5.);
+    let velocity = Velocity {
+        x: F32(100.),
+        y: F32(0.),
+    };
+    let frame = Duration::from_millis(200);
+
+    // Tunnels straight through the wall between frames
+    let swept = bullet.sweep(velocity, frame);
+    assert!(matches!(
+        swept.end().intersects(wall.clone()),
+        Intersects::No
+    ));
+    assert!(matches!(
+        swept.intersects(SweptRegion::draw_around(wall.vertexes())),
+        Intersects::Yes
+    ));
+
+    let impact = bullet.sweep_intersects(velocity, &wall, frame).unwrap();
+    assert!((impact.as_secs_f32() - 0.09).abs() < 1e-6);
+
+    // Too short a frame to reach the wall
+    assert_eq!(
+        bullet.sweep_intersects(velocity, &wall, Duration::from_millis(50)),
+        None
+    );
+    // Already touching
+    assert_eq!(
+        bullet.sweep_intersects(velocity, &bullet, frame),
+        Some(Duration::ZERO)
+    );
+    // Moving diagonally past the end of the wall
+    let diagonal = Velocity {
+        x: F32(100.),
+        y: F32(100.),
+    };
+    assert_eq!(bullet.sweep_intersects(diagonal, &wall, frame), None);
+}
+
+#[test]
+fn swept_region_outline() {
+    let player = AxisAlignedBoundingBox::<F32>::new(0., 1., 0., 1.);
+    let swept = player.sweep(
+        Velocity {
+            x: F32(4.),
+            y: F32(4.),
+        },
+        Duration::from_secs(1),
+    );
+
+    assert_eq!(swept.aabb(), AxisAlignedBoundingBox::new(0., 5., 0., 5.));
+    assert_eq!(
+        swept.vertexes(),
+        vec![
+            Position::new(0., 1.),
+            Position::new(4., 5.),
+            Position::new(5., 5.),
+            Position::new(5., 4.),
+            Position::new(1., 0.),
+            Position::new(0., 0.),
+        ]
+    );
+
+    assert!(swept.contains(Position::new(2.5, 2.5)));
+    assert!(!swept.contains(Position::new(4., 0.)));
+    swept
+        .clamp(Position::new(4., 0.))
+        .assert_approx_eq(Position::<F32>::new(2.5, 1.5));
+}
+
+#[test]
+fn annulus_sampling_stays_in_ring() {
+    let ring = Annulus::<F32>::new(Position::new(3., -2.), 1., 2.5);
+
+    for i in 0..=10 {
+        for j in 0..=10 {
+            let sample = ring.sample(i as f32 / 10., j as f32 / 10.);
+            let distance = Vec2::from(sample).distance(ring.center.into());
+            assert!((1. - 1e-5..=2.5 + 1e-5).contains(&distance));
+        }
+    }
+
+    // Half of the area is closer to the inside edge than this
+    let halfway = ring.sample(0.5, 0.25);
+    let distance = Vec2::from(halfway).distance(ring.center.into());
+    assert!((distance - ((1. + 2.5 * 2.5) / 2.0f32).sqrt()).abs() < 1e-5);
+}
+
+#[test]
+fn annulus_clamping() {
+    let ring = Annulus::<F32>::new(Position::new(0., 0.), 2., 3.);
+
+    // The center is pushed out in some direction
+    let clamped = ring.clamp(Position::new(0., 0.));
+    assert!(ring.contains(clamped));
+
+    let inside = Position::new(0., 2.5);
+    assert_eq!(ring.clamp(inside), inside);
+
+    ring.clamp(Position::new(-1., -1.))
+        .assert_approx_eq(Position::<F32>::new(-2. / 2f32.sqrt(), -2. / 2f32.sqrt()));
+}
+
+#[test]
+fn annulus_intersection() {
+    let ring = Annulus::<F32>::new(Position::new(0., 0.), 2., 3.);
+    let intersects = |other: Annulus<F32>| matches!(ring.intersects(other), Intersects::Yes);
+
+    // A small ring sitting entirely in the hole
+    assert!(!intersects(Annulus::new(Position::new(0.5, 0.), 0., 1.)));
+    // A big ring whose hole contains this whole ring
+    assert!(!intersects(Annulus::new(Position::new(0., 0.), 4., 5.)));
+    // Too far away
+    assert!(!intersects(Annulus::new(Position::new(10., 0.), 1., 2.)));
+
+    assert!(intersects(Annulus::new(Position::new(4., 0.), 0., 1.5)));
+    assert!(intersects(Annulus::new(Position::new(0., 0.), 1., 2.)));
+}
+
+#[test]
+fn annulus_drawn_around_positions() {
+    let positions = [
+        Position::<F32>::new(-3., 0.),
+        Position::new(3., 0.),
+        Position::new(0., 1.),
+    ];
+    let ring = Annulus::draw_around(positions);
+
+    assert_eq!(ring, Annulus::new(Position::new(0., 0.), 1., 3.));
+    for position in positions {
+        assert!(ring.contains(position));
+    }
+}
+
+#[test]
+fn capsule_clamping() {
+    let capsule = Capsule::<F32>::new(Position::new(-2., 0.), Position::new(2., 0.), 1.);
+
+    let inside = Position::new(1.5, 0.5);
+    assert_eq!(capsule.clamp(inside), inside);
+
+    // Along the straight sides, and around the round ends
+    assert_eq!(capsule.clamp(Position::new(0., 5.)), Position::new(0., 1.));
+    capsule
+        .clamp(Position::new(5., 0.))
+        .assert_approx_eq(Position::<F32>::new(3., 0.));
+    assert!(!capsule.contains(Position::new(2.9, 0.9)));
+
+    for position in capsule.sample_perimeter(12) {
+        assert!((Vec2::from(capsule.clamp(position)) - Vec2::from(position)).length() < 1e-4);
+    }
+}
+
+#[test]
+fn capsule_intersection() {
+    let capsule = Capsule::<F32>::new(Position::new(0., 0.), Position::new(4., 4.), 0.5);
+    let intersects = |other: Capsule<F32>| matches!(capsule.intersects(other), Intersects::Yes);
+
+    // Crossing in an X, without either end near the other capsule
+    assert!(intersects(Capsule::new(
+        Position::new(0., 4.),
+        Position::new(4., 0.),
+        0.1
+    )));
+    // Parallel, but just out of reach
+    assert!(!intersects(Capsule::new(
+        Position::new(2., 0.),
+        Position::new(6., 4.),
+        0.9
+    )));
+    assert!(intersects(Capsule::new(
+        Position::new(2., 0.),
+        Position::new(6., 4.),
+        1.
+    )));
+
+    // A box that the segment passes straight through
+    assert!(capsule.intersects_aabb(&AxisAlignedBoundingBox::new(1., 3., 1.5, 2.5)));
+    // A box that is only touched by the rounded end
+    assert!(capsule.intersects_aabb(&AxisAlignedBoundingBox::new(4.4, 5., 3., 5.)));
+    // A box that sits in the corner beside the segment
+    assert!(!capsule.intersects_aabb(&AxisAlignedBoundingBox::new(3., 5., 0., 1.)));
+
+    assert!(capsule.intersects_circle(&Circle::new(Position::new(4., 0.), 2.4)));
+    assert!(!capsule.intersects_circle(&Circle::new(Position::new(4., 0.), 2.)));
+}
+
+#[test]
+fn capsule_drawn_around_positions() {
+    // A long, thin, tilted cloud of points
+    let positions: Vec<Position<F32>> = scattered(60)
+        .into_iter()
+        .map(|position| Position::new(position.x.0 + position.y.0, position.x.0 - position.y.0))
+        .collect();
+    let capsule = Capsule::draw_around(positions.iter().copied());
+
+    for &position in &positions {
+        assert!(capsule.contains(position));
+    }
+
+    let single = Capsule::<F32>::draw_around([Position::new(1., 2.)]);
+    assert_eq!(single.start, single.end);
+    assert!(single.contains(Position::new(1., 2.)));
+
+    let empty = Capsule::<F32>::draw_around([]);
+    assert_eq!(empty.radius, F32(0.));
+}
+
+#[test]
+fn circle_contacts() {
+    let circle = Circle::<F32>::new(Position::new(0., 0.), 2.);
+
+    let contact = circle
+        .collision_info(&Circle::new(Position::new(3., 0.), 1.5))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::EAST);
+    assert_eq!(contact.depth, 0.5);
+    assert_eq!(contact.point, Position::new(1.75, 0.));
+
+    assert_eq!(
+        circle.collision_info(&Circle::new(Position::new(3., 3.), 1.)),
+        None
+    );
+
+    // A circle whose center is inside of the box is pushed out through the closest edge
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-1., 5., -1., 1.);
+    let contact: Contact<F32> = circle.collision_info(&aabb).unwrap();
+    assert_eq!(contact.normal, Direction::EAST);
+    assert_eq!(contact.depth, 3.);
+    assert_eq!(aabb.collision_info(&circle), Some(contact.reversed()));
+}
+
+#[test]
+fn aabb_contacts() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0., 4., 0., 4.);
+
+    // Overlapping a little from above, and a lot from the side
+    let contact = aabb
+        .collision_info(&AxisAlignedBoundingBox::new(1., 5., 3., 6.))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::NORTH);
+    assert_eq!(contact.depth, 1.);
+    assert_eq!(contact.point, Position::new(2.5, 3.5));
+
+    let contact = aabb
+        .collision_info(&AxisAlignedBoundingBox::new(-1., 0.5, 1., 3.))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::WEST);
+    assert_eq!(contact.depth, 0.5);
+
+    assert_eq!(
+        aabb.collision_info(&AxisAlignedBoundingBox::new(5., 6., 0., 4.)),
+        None
+    );
+}
+
+#[test]
+fn capsule_contacts() {
+    let capsule = Capsule::<F32>::new(Position::new(-2., 0.), Position::new(2., 0.), 1.);
+
+    // Side by side
+    let contact = capsule
+        .collision_info(&Capsule::new(
+            Position::new(-1., 1.5),
+            Position::new(1., 1.5),
+            1.,
+        ))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::NORTH);
+    assert_eq!(contact.depth, 0.5);
+
+    // Crossing, with the other capsule mostly above this one
+    let contact = capsule
+        .collision_info(&Capsule::new(
+            Position::new(0., -1.),
+            Position::new(0., 3.),
+            0.5,
+        ))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::NORTH);
+    assert_eq!(contact.depth, 2.5);
+
+    // Touching a circle with the round end
+    let contact = capsule
+        .collision_info(&Circle::new(Position::new(4., 0.), 1.5))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::EAST);
+    assert_eq!(contact.depth, 0.5);
+
+    // Resting on a box
+    let floor = AxisAlignedBoundingBox::<F32>::new(-10., 10., -2., -0.75);
+    let contact = floor.collision_info(&capsule).unwrap();
+    assert_eq!(contact.normal, Direction::NORTH);
+    assert_eq!(contact.depth, 0.25);
+
+    // Sunk into a box
+    let contact = capsule
+        .collision_info(&AxisAlignedBoundingBox::new(-10., 10., -0.5, 10.))
+        .unwrap();
+    assert_eq!(contact.normal, Direction::NORTH);
+    assert_eq!(contact.depth, 1.5);
+
+    assert_eq!(
+        capsule.collision_info(&AxisAlignedBoundingBox::new(3.5, 5., -1., 1.)),
+        None
+    );
+}
+
+#[test]
+fn half_plane_with_diagonal_normal() {
+    let wall = HalfPlane::<F32>::new(Position::new(1., 1.), Direction::NORTHEAST);
+
+    assert!(wall.contains(Position::new(0., 0.)));
+    assert!(wall.contains(Position::new(2., 0.)));
+    assert!(!wall.contains(Position::new(2., 2.)));
+    assert!((wall.signed_distance(Position::new(2., 2.)) - 2f32.sqrt()).abs() < 1e-5);
+
+    let clamped = wall.clamp(Position::new(3., 2.));
+    clamped.assert_approx_eq(Position::<F32>::new(1.5, 0.5));
+    assert!(wall.signed_distance(clamped).abs() < 1e-5);
+}
+
+#[test]
+fn half_plane_intersection() {
+    let floor = HalfPlane::<F32>::new(Position::new(0., 0.), Direction::SOUTH);
+    let intersects = |other: HalfPlane<F32>| matches!(floor.intersects(other), Intersects::Yes);
+
+    // Opposite normals, with a gap in between
+    let ceiling = HalfPlane::new(Position::new(0., -1.), Direction::NORTH);
+    assert!(!intersects(ceiling));
+    // Opposite normals, with an overlapping strip
+    let ceiling = HalfPlane::new(Position::new(0., 1.), Direction::NORTH);
+    assert!(intersects(ceiling));
+    // Not parallel, so they must cross somewhere
+    assert!(intersects(HalfPlane::new(
+        Position::new(0., 100.),
+        Direction::EAST
+    )));
+    assert!(intersects(HalfPlane::new(
+        Position::new(0., 100.),
+        Direction::SOUTH
+    )));
+}
+
+#[test]
+fn half_plane_drawn_around_positions() {
+    let positions = [
+        Position::<F32>::new(1., 2.),
+        Position::new(-4., 7.),
+        Position::new(3., -1.),
+    ];
+    let region = HalfPlane::draw_around(positions);
+
+    assert_eq!(region.point, Position::new(-4., 7.));
+    for position in positions {
+        assert!(region.contains(position));
+    }
+}
+
+/// An L-shaped level, made of two overlapping boxes
+fn l_shape() -> CompositeRegion<F32> {
+    CompositeRegion::new(CompositeMode::Union)
+        .with(AxisAlignedBoundingBox::new(0., 2., 0., 6.))
+        .with(AxisAlignedBoundingBox::new(0., 6., 0., 2.))
+}
+
+#[test]
+fn composite_union() {
+    let level = l_shape();
+
+    assert_eq!(level.children().len(), 2);
+    assert!(level.contains(Position::new(1., 5.)));
+    assert!(level.contains(Position::new(5., 1.)));
+    assert!(!level.contains(Position::new(4., 4.)));
+
+    // Clamped to whichever arm of the L is closest
+    assert_eq!(level.clamp(Position::new(5., 3.)), Position::new(5., 2.));
+    assert_eq!(level.clamp(Position::new(3., 5.)), Position::new(2., 5.));
+
+    // An empty union contains nothing
+    let empty = CompositeRegion::<F32>::new(CompositeMode::Union);
+    assert!(!empty.contains(Position::new(0., 0.)));
+}
+
+#[test]
+fn composite_intersection() {
+    // A circular arena, with everything east of the river cut off
+    let arena = CompositeRegion::<F32>::new(CompositeMode::Intersection)
+        .with(Circle::new(Position::new(0., 0.), 5.))
+        .with(HalfPlane::new(Position::new(2., 0.), Direction::EAST));
+
+    assert!(arena.contains(Position::new(-4., 0.)));
+    assert!(!arena.contains(Position::new(3., 0.)));
+    assert!(!arena.contains(Position::new(-6., 0.)));
+
+    arena
+        .clamp(Position::new(3., 0.))
+        .assert_approx_eq(Position::<F32>::new(2., 0.));
+    arena
+        .clamp(Position::new(-10., 0.))
+        .assert_approx_eq(Position::<F32>::new(-5., 0.));
+
+    // Beyond both the river and the edge of the arena, the nearest corner is chosen
+    let corner = arena.clamp(Position::new(10., 10.));
+    let expected = Position::<F32>::new(2., 21f32.sqrt());
+    assert!(Vec2::from(corner).distance(expected.into()) < 1e-2);
+}
+
+#[test]
+fn composite_intersects() {
+    let level = l_shape();
+    let intersects =
+        |other: CompositeRegion<F32>| matches!(level.intersects(other), Intersects::Yes);
+
+    // Inside the crook of the L, but touching neither arm
+    let inside_crook =
+        CompositeRegion::new(CompositeMode::Union).with(Circle::new(Position::new(4.5, 4.5), 1.));
+    assert!(!intersects(inside_crook));
+
+    let overlapping_arm = CompositeRegion::new(CompositeMode::Union)
+        .with(Circle::new(Position::new(4.5, 4.5), 1.))
+        .with(Circle::new(Position::new(5., 3.), 1.5));
+    assert!(intersects(overlapping_arm));
+
+    let drawn = CompositeRegion::draw_around([Position::new(1., 1.), Position::new(3., 3.)]);
+    assert!(intersects(drawn));
+}
+
+#[test]
+fn ellipse_closest_point_is_on_edge() {
+    let ellipse = Ellipse::<F32>::new(Position::new(1., -2.), 5., 2., Rotation::from_degrees(30.));
+
+    for i in 0..36 {
+        let radians = i as f32 * 10f32.to_radians();
+        let outside = Position::<F32>::new(1. + 9. * radians.sin(), -2. + 9. * radians.cos());
+        let closest = ellipse.closest_point(outside);
+
+        // On the edge of the ellipse
+        let scaled =
+            |scale: f32| Ellipse::new(ellipse.center, 5. * scale, 2. * scale, ellipse.rotation);
+        assert!(scaled(1. + 1e-4).contains(closest));
+        assert!(!scaled(1. - 1e-4).contains(closest));
+
+        // No sampled point on the edge is closer
+        let distance = Vec2::from(closest).distance(outside.into());
+        for j in 0..360 {
+            let radians = (j as f32).to_radians();
+            let local = Vec2::new(5. * radians.cos(), 2. * radians.sin());
+            let edge = Transform2D::<F32>::new(ellipse.center, ellipse.rotation, Scale(1.))
+                * Position::from(local);
+            assert!(Vec2::from(edge).distance(outside.into()) >= distance - 1e-3);
+        }
+    }
+
+    let inside = Position::new(1.5, -2.5);
+    assert_eq!(ellipse.clamp(inside), inside);
+}
+
+#[test]
+fn ellipse_intersections() {
+    let ellipse = Ellipse::<F32>::new(Position::new(0., 0.), 4., 1., Rotation::from_degrees(90.));
+
+    // Rotated a quarter turn, so the long axis points north
+    assert!(ellipse.contains(Position::new(0., 3.5)));
+    assert!(!ellipse.contains(Position::new(3.5, 0.)));
+
+    assert!(ellipse.intersects_circle(&Circle::new(Position::new(0., 5.), 1.5)));
+    assert!(!ellipse.intersects_circle(&Circle::new(Position::new(2., 2.), 1.)));
+
+    assert!(ellipse.intersects_aabb(&AxisAlignedBoundingBox::new(0.5, 3., -0.5, 0.5)));
+    assert!(ellipse.intersects_aabb(&AxisAlignedBoundingBox::new(-10., 10., -10., 10.)));
+    assert!(!ellipse.intersects_aabb(&AxisAlignedBoundingBox::new(0.9, 3., 2.5, 5.)));
+
+    let other = |center: Position<F32>| Ellipse::new(center, 2., 1., Rotation::NORTH);
+    assert!(matches!(
+        ellipse.intersects(other(Position::new(2.5, 0.))),
+        Intersects::Yes
+    ));
+    assert!(matches!(
+        ellipse.intersects(other(Position::new(3.5, 3.))),
+        Intersects::No
+    ));
+}
+
+#[test]
+fn degenerate_ellipse_intersections() {
+    let segment = Ellipse::<F32>::new(Position::new(0., 0.), 2., 0., Rotation::NORTH);
+
+    assert!(segment.intersects_aabb(&AxisAlignedBoundingBox::new(1., 3., -1., 1.)));
+    assert!(!segment.intersects_aabb(&AxisAlignedBoundingBox::new(-1., 1., -3., -1.)));
+    segment
+        .closest_point(Position::new(5., 3.))
+        .assert_approx_eq(Position::<F32>::new(2., 0.));
+}
+
+#[test]
+fn aabb_perimeter_samples() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 2.0);
+
+    // Starts at the first vertex, and walks clockwise
+    assert_eq!(
+        aabb.sample_perimeter(6),
+        vec![
+            Position::new(4.0, 2.0),
+            Position::new(4.0, 0.0),
+            Position::new(2.0, 0.0),
+            Position::new(0.0, 0.0),
+            Position::new(0.0, 2.0),
+            Position::new(2.0, 2.0),
+        ]
+    );
+    assert!(aabb.sample_perimeter(0).is_empty());
+}
+
+#[test]
+fn curved_perimeter_samples() {
+    let circle = Circle::<F32>::new(Position::new(1.0, 1.0), 2.0);
+    let samples = circle.sample_perimeter(4);
+    assert_eq!(samples.len(), 4);
+    samples[0].assert_approx_eq(Position::<F32>::new(1.0, 3.0));
+    samples[1].assert_approx_eq(Position::<F32>::new(3.0, 1.0));
+
+    let ellipse = Ellipse::<F32>::new(Position::new(0.0, 0.0), 3.0, 1.0, Rotation::EAST);
+    let samples = ellipse.sample_perimeter(16);
+    assert_eq!(samples.len(), 16);
+    for sample in &samples {
+        let distance = Vec2::from(*sample).distance(ellipse.closest_point(*sample).into());
+        assert!(distance < 1e-3);
+    }
+    // Rotated clockwise, so the first sample is on the positive x axis
+    samples[0].assert_approx_eq(Position::<F32>::new(1.0, 0.0));
+
+    // Evenly spaced along the edge
+    let gaps: Vec<f32> = (0..samples.len())
+        .map(|i| Vec2::from(samples[i]).distance(samples[(i + 1) % samples.len()].into()))
+        .collect();
+    let (shortest, longest) = gaps.iter().fold((f32::MAX, 0.0_f32), |(low, high), &gap| {
+        (low.min(gap), high.max(gap))
+    });
+    assert!(longest - shortest < 0.05 * longest);
+
+    // Twice as much of the edge is on the outside, so it gets twice as many positions
+    let ring = Annulus::<F32>::new(Position::new(0.0, 0.0), 1.0, 2.0);
+    let samples = ring.sample_perimeter(9);
+    let outer = samples
+        .iter()
+        .filter(|&&sample| (Vec2::from(sample).length() - 2.0).abs() < 1e-5)
+        .count();
+    assert_eq!(samples.len(), 9);
+    assert_eq!(outer, 6);
+
+    let wall = HalfPlane::<F32>::new(Position::new(0.0, 0.0), Direction::NORTH);
+    assert!(wall.sample_perimeter(10).is_empty());
+}
+
+#[test]
+fn composite_perimeter_samples() {
+    let level = CompositeRegion::<F32>::new(CompositeMode::Union)
+        .with(AxisAlignedBoundingBox::new(0.0, 10.0, 0.0, 10.0))
+        .with(AxisAlignedBoundingBox::new(10.0, 20.0, 4.0, 6.0));
+
+    let samples = level.sample_perimeter(50);
+    assert_eq!(samples.len(), 50);
+
+    for sample in samples {
+        assert!(level.contains(sample));
+        // Positions on the edge have positions just outside of the level nearby
+        let nearby = [Vec2::X, Vec2::Y, -Vec2::X, -Vec2::Y]
+            .map(|offset| Position::from(Vec2::from(sample) + offset * 0.01));
+        assert!(nearby.iter().any(|&position| !level.contains(position)));
+    }
+}
+
+#[test]
+fn batch_containment_matches_contains() {
+    let positions: Vec<Position<F32>> = (-10..=10)
+        .flat_map(|x| (-10..=10).map(move |y| Position::new(x as f32 * 0.7, y as f32 * 0.7)))
+        .collect();
+
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-2.0, 3.0, -1.0, 4.0);
+    let circle = Circle::<F32>::new(Position::new(1.0, -1.0), 3.5);
+    let ellipse = Ellipse::<F32>::new(
+        Position::new(0.0, 0.0),
+        4.0,
+        2.0,
+        Rotation::from_degrees(30.0),
+    );
+    let union = CompositeRegion::new(CompositeMode::Union)
+        .with(aabb.clone())
+        .with(circle);
+    let intersection = CompositeRegion::new(CompositeMode::Intersection)
+        .with(aabb.clone())
+        .with(circle)
+        .with(ellipse);
+
+    let regions: [&dyn BoundingRegion<C = F32>; 5] =
+        [&aabb, &circle, &ellipse, &union, &intersection];
+
+    // Stale results are cleared
+    let mut results = vec![true; 3];
+    for region in regions {
+        region.contains_batch(&positions, &mut results);
+
+        let expected: Vec<bool> = positions
+            .iter()
+            .map(|&position| region.contains(position))
+            .collect();
+        assert_eq!(results, expected);
+    }
+
+    aabb.contains_batch(&[], &mut results);
+    assert!(results.is_empty());
+}
+
+#[test]
+fn aabb_cells() {
+    let aabb = AxisAlignedBoundingBox::<OrthogonalGrid>::new(-2., 1., 3., 4.);
+    let cells: Vec<_> = aabb.cells().collect();
+
+    assert_eq!(cells.len(), 8);
+    for cell in &cells {
+        assert!(aabb.contains(*cell));
+    }
+    assert_eq!(
+        cells[..4],
+        [
+            Position::<OrthogonalGrid>::new(OrthogonalGrid(-2), OrthogonalGrid(3)),
+            Position::<OrthogonalGrid>::new(OrthogonalGrid(-1), OrthogonalGrid(3)),
+            Position::<OrthogonalGrid>::new(OrthogonalGrid(0), OrthogonalGrid(3)),
+            Position::<OrthogonalGrid>::new(OrthogonalGrid(1), OrthogonalGrid(3)),
+        ]
+    );
+
+    // A single cell
+    let point = AxisAlignedBoundingBox::<OrthogonalGrid>::new(7., 7., 7., 7.);
+    assert_eq!(point.cells().count(), 1);
+
+    // Boxes touching the edge of the coordinate range do not overflow
+    let edge = AxisAlignedBoundingBox::<OrthogonalGrid>::new(
+        OrthogonalGrid(isize::MAX - 1),
+        OrthogonalGrid(isize::MAX),
+        OrthogonalGrid(0),
+        OrthogonalGrid(0),
+    );
+    assert_eq!(edge.cells().count(), 2);
+}
+
+#[test]
+fn circle_cells() {
+    let center = Position::<OrthogonalGrid>::new(OrthogonalGrid(-4), OrthogonalGrid(10));
+
+    let circle = Circle::new(center, OrthogonalGrid(0));
+    assert_eq!(circle.cells().collect::<Vec<_>>(), vec![center]);
+
+    // Radius 2 includes the 3x3 square around the center, and two cells straight out along each axis
+    let circle = Circle::new(center, OrthogonalGrid(2));
+    let cells: Vec<_> = circle.cells().collect();
+    assert_eq!(cells.len(), 13);
+
+    let circle = Circle::new(center, OrthogonalGrid(5));
+    let cells: Vec<_> = circle.cells().collect();
+    for x in -6..=6 {
+        for y in -6..=6 {
+            let cell =
+                Position::<OrthogonalGrid>::new(OrthogonalGrid(-4 + x), OrthogonalGrid(10 + y));
+            assert_eq!(cells.contains(&cell), x * x + y * y <= 25, "{:?}", (x, y));
+        }
+    }
+}
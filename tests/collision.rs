@@ -0,0 +1,520 @@
+use bevy::ecs::event::Events;
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use leafwing_2d::bounding::{AxisAlignedBoundingBox, Capsule, Circle};
+use leafwing_2d::collision::{
+    move_and_slide, update_broad_phase, BroadPhase, BroadphaseStrategy, CharacterController,
+    CollisionEvent, CollisionPlugin, RegionEntered, RegionExited, Sensor, SensorOccupants,
+};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Gravity, GravityAffected, Velocity};
+use leafwing_2d::orientation::Direction;
+use leafwing_2d::position::Position;
+
+fn pairs(world: &World) -> Vec<(Entity, Entity)> {
+    world.resource::<BroadPhase<F32>>().pairs().collect()
+}
+
+#[test]
+fn broad_phase_tracks_changes() {
+    let mut world = World::new();
+    world.init_resource::<BroadPhase<F32>>();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_broad_phase::<F32>);
+
+    let floor = world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -1.0, 0.0))
+        .id();
+    let ball = world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(0.0, 2.0), 1.0))
+        .id();
+    let crate_ = world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(3.0, 4.0, 0.0, 1.0))
+        .id();
+
+    stage.run(&mut world);
+    assert_eq!(world.resource::<BroadPhase<F32>>().len(), 3);
+    assert_eq!(pairs(&world), vec![(floor, crate_)]);
+
+    // The ball falls onto the floor
+    world.get_mut::<Circle<F32>>(ball).unwrap().center = Position::new(0.0, 0.5);
+    stage.run(&mut world);
+    assert_eq!(pairs(&world), vec![(floor, ball), (floor, crate_)]);
+    let broad_phase = world.resource::<BroadPhase<F32>>();
+    assert!(broad_phase.overlap(ball, floor));
+    assert!(!broad_phase.overlap(ball, crate_));
+    assert_eq!(
+        broad_phase.overlapping(floor).collect::<Vec<_>>(),
+        vec![ball, crate_]
+    );
+
+    // Despawned entities are forgotten
+    world.despawn(floor);
+    stage.run(&mut world);
+    assert!(pairs(&world).is_empty());
+    assert_eq!(world.resource::<BroadPhase<F32>>().len(), 2);
+}
+
+#[test]
+fn broad_phase_removal() {
+    let mut broad_phase = BroadPhase::<F32>::default();
+    let (a, b, c) = (
+        Entity::from_raw(0),
+        Entity::from_raw(1),
+        Entity::from_raw(2),
+    );
+    let bounds = AxisAlignedBoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    for entity in [a, b, c] {
+        broad_phase.insert(entity, bounds.clone());
+    }
+    assert_eq!(broad_phase.pairs().count(), 3);
+
+    assert_eq!(broad_phase.remove(b), Some(bounds));
+    assert_eq!(broad_phase.remove(b), None);
+    assert_eq!(broad_phase.pairs().collect::<Vec<_>>(), vec![(a, c)]);
+
+    broad_phase.clear();
+    assert!(broad_phase.is_empty());
+    assert_eq!(broad_phase.pairs().count(), 0);
+}
+
+#[test]
+fn sweep_and_prune_matches_brute_force() {
+    let mut brute_force = BroadPhase::<F32>::new(BroadphaseStrategy::BruteForce);
+    let mut sweep_and_prune = BroadPhase::<F32>::new(BroadphaseStrategy::SweepAndPrune);
+    assert_eq!(
+        sweep_and_prune.strategy(),
+        BroadphaseStrategy::SweepAndPrune
+    );
+
+    // Entities spread along a long horizontal level, with one very wide floor
+    let bounds = |i: u32, offset: f32| {
+        let left = ((i * 37) % 101) as f32 * 3.0 + offset;
+        let bottom = ((i * 53) % 7) as f32;
+        AxisAlignedBoundingBox::new(left, left + 1.0 + (i % 4) as f32, bottom, bottom + 2.0)
+    };
+    let floor = Entity::from_raw(1000);
+    for broad_phase in [&mut brute_force, &mut sweep_and_prune] {
+        broad_phase.insert(floor, AxisAlignedBoundingBox::new(-10.0, 100.0, -1.0, 0.0));
+        for i in 0..100 {
+            broad_phase.insert(Entity::from_raw(i), bounds(i, 0.0));
+        }
+    }
+    assert_eq!(
+        sweep_and_prune.pairs().collect::<Vec<_>>(),
+        brute_force.pairs().collect::<Vec<_>>()
+    );
+
+    // Move and remove some of them
+    for broad_phase in [&mut brute_force, &mut sweep_and_prune] {
+        for i in (0..100).step_by(3) {
+            broad_phase.insert(Entity::from_raw(i), bounds(i, -50.0));
+        }
+        for i in (0..100).step_by(7) {
+            broad_phase.remove(Entity::from_raw(i));
+        }
+    }
+    assert_eq!(sweep_and_prune.len(), brute_force.len());
+    assert_eq!(
+        sweep_and_prune.pairs().collect::<Vec<_>>(),
+        brute_force.pairs().collect::<Vec<_>>()
+    );
+
+    sweep_and_prune.clear();
+    sweep_and_prune.insert(floor, AxisAlignedBoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    assert_eq!(sweep_and_prune.pairs().count(), 0);
+}
+
+fn drain<E: Send + Sync + 'static>(world: &mut World) -> Vec<E> {
+    world.resource_mut::<Events<E>>().drain().collect()
+}
+
+#[test]
+fn collision_events_report_touching_shapes() {
+    let mut app = App::new();
+    app.add_plugin(CollisionPlugin::<F32>::default());
+
+    let floor = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -1.0, 0.0))
+        .id();
+    let ball = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(0.0, 0.75), 1.0))
+        .id();
+    // Its bounding box overlaps the floor's corner, but the ball itself does not
+    app.world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(10.9, 0.9), 1.0));
+    let sword = app
+        .world
+        .spawn()
+        .insert(Capsule::<F32>::new(
+            Position::new(0.5, 0.05),
+            Position::new(5.0, 5.0),
+            0.1,
+        ))
+        .id();
+
+    app.update();
+    let events = drain::<CollisionEvent<F32>>(&mut app.world);
+    assert_eq!(events.len(), 3);
+
+    let ball_on_floor = events
+        .iter()
+        .find(|event| (event.entity_a, event.entity_b) == (floor, ball))
+        .unwrap();
+    assert_eq!(ball_on_floor.contact.normal, Direction::NORTH);
+    assert_eq!(ball_on_floor.contact.depth, 0.25);
+
+    assert!(events
+        .iter()
+        .any(|event| (event.entity_a, event.entity_b) == (ball, sword)));
+    assert!(events
+        .iter()
+        .any(|event| (event.entity_a, event.entity_b) == (floor, sword)));
+
+    // Events keep being sent while the shapes touch, and stop once they don't
+    app.world.entity_mut(sword).despawn();
+    app.update();
+    assert_eq!(drain::<CollisionEvent<F32>>(&mut app.world).len(), 1);
+
+    app.world.get_mut::<Circle<F32>>(ball).unwrap().center = Position::new(0.0, 3.0);
+    app.update();
+    assert!(drain::<CollisionEvent<F32>>(&mut app.world).is_empty());
+}
+
+#[test]
+fn collision_plugin_uses_chosen_strategy() {
+    let mut app = App::new();
+    app.add_plugin(CollisionPlugin::<F32> {
+        strategy: BroadphaseStrategy::SweepAndPrune,
+        ..Default::default()
+    });
+
+    let left = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(0.0, 0.0), 1.0))
+        .id();
+    let right = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(1.5, 0.0), 1.0))
+        .id();
+    app.world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(100.0, 0.0), 1.0));
+
+    app.update();
+    assert_eq!(
+        app.world.resource::<BroadPhase<F32>>().strategy(),
+        BroadphaseStrategy::SweepAndPrune
+    );
+    let events = drain::<CollisionEvent<F32>>(&mut app.world);
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].entity_a, events[0].entity_b), (left, right));
+}
+
+#[test]
+fn sensors_report_entering_and_exiting() {
+    let mut app = App::new();
+    app.add_plugin(CollisionPlugin::<F32>::default());
+
+    let checkpoint = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 2.0))
+        .insert(Sensor)
+        .id();
+    // Sensors don't detect each other
+    app.world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(2.8, 1.0), 1.0))
+        .insert(Sensor);
+    let player = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(-5.0, 1.0), 0.5))
+        .id();
+
+    app.update();
+    assert!(drain::<RegionEntered>(&mut app.world).is_empty());
+
+    app.world.get_mut::<Circle<F32>>(player).unwrap().center = Position::new(1.0, 1.0);
+    app.update();
+    assert_eq!(
+        drain::<RegionEntered>(&mut app.world),
+        vec![RegionEntered {
+            sensor: checkpoint,
+            entity: player
+        }]
+    );
+    // Sensors never send collision events
+    assert!(drain::<CollisionEvent<F32>>(&mut app.world).is_empty());
+    let occupants = app.world.resource::<SensorOccupants>();
+    assert!(occupants.is_inside(checkpoint, player));
+    assert_eq!(
+        occupants.occupants(checkpoint).collect::<Vec<_>>(),
+        vec![player]
+    );
+
+    // Staying inside doesn't send more events
+    app.update();
+    assert!(drain::<RegionEntered>(&mut app.world).is_empty());
+    assert!(drain::<RegionExited>(&mut app.world).is_empty());
+
+    // Despawning the player makes it exit
+    app.world.despawn(player);
+    app.update();
+    assert_eq!(
+        drain::<RegionExited>(&mut app.world),
+        vec![RegionExited {
+            sensor: checkpoint,
+            entity: player
+        }]
+    );
+    assert_eq!(app.world.resource::<SensorOccupants>().pairs().count(), 0);
+}
+
+#[test]
+fn resolution_pushes_kinematic_entities_apart() {
+    let mut app = App::new();
+    app.add_plugin(CollisionPlugin::<F32> {
+        resolve: true,
+        ..Default::default()
+    });
+
+    let floor = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -1.0, 0.0))
+        .id();
+    // Falling and running to the right, sunk a little into the floor
+    let player = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, -0.25, 0.75))
+        .insert(Position::<F32>::new(0.5, 0.25))
+        .insert(Velocity::<F32> {
+            x: F32(1.0),
+            y: F32(-5.0),
+        })
+        .id();
+    // Two kinematic balls high above the floor
+    let left_ball = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(0.0, 5.0), 1.0))
+        .insert(Velocity::<F32>::default())
+        .id();
+    let right_ball = app
+        .world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(1.0, 5.0), 1.0))
+        .insert(Velocity::<F32>::default())
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        app.world.get::<AxisAlignedBoundingBox<F32>>(player),
+        Some(&AxisAlignedBoundingBox::new(0.0, 1.0, 0.0, 1.0))
+    );
+    assert_eq!(
+        app.world.get::<Position<F32>>(player),
+        Some(&Position::new(0.5, 0.5))
+    );
+    // Still running along the floor, but no longer falling into it
+    assert_eq!(
+        app.world.get::<Velocity<F32>>(player),
+        Some(&Velocity {
+            x: F32(1.0),
+            y: F32(0.0)
+        })
+    );
+    // Walls don't move
+    assert_eq!(
+        app.world.get::<AxisAlignedBoundingBox<F32>>(floor),
+        Some(&AxisAlignedBoundingBox::new(-10.0, 10.0, -1.0, 0.0))
+    );
+
+    // Each ball moves half of the way
+    assert_eq!(
+        app.world.get::<Circle<F32>>(left_ball).unwrap().center,
+        Position::new(-0.5, 5.0)
+    );
+    assert_eq!(
+        app.world.get::<Circle<F32>>(right_ball).unwrap().center,
+        Position::new(1.5, 5.0)
+    );
+}
+
+/// Advances the [`Time`] in the `world` by a little, returning how long passed
+fn tick(world: &mut World) -> f32 {
+    std::thread::sleep(core::time::Duration::from_millis(10));
+    let mut time = world.resource_mut::<Time>();
+    time.update();
+    time.delta_seconds()
+}
+
+#[test]
+fn characters_slide_along_floors_and_walls() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(move_and_slide::<F32>);
+
+    world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -1.0, 0.0));
+    // A thin wall, far to the right
+    world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(5.0, 5.1, 0.0, 10.0));
+    // Sensors don't block characters
+    world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(1.0, 2.0, 0.0, 2.0))
+        .insert(Sensor);
+
+    // Standing on the floor, running right and falling
+    let player = world
+        .spawn()
+        .insert(CharacterController::<F32>::default())
+        .insert(Position::<F32>::new(0.5, 0.5))
+        .insert(Velocity::<F32> {
+            x: F32(2.0),
+            y: F32(-3.0),
+        })
+        .insert(AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 1.0))
+        .id();
+
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    let position = *world.get::<Position<F32>>(player).unwrap();
+    assert!((position.x.0 - (0.5 + 2.0 * dt)).abs() < 1e-4);
+    assert!((position.y.0 - 0.5).abs() < 1e-4);
+    let aabb = world.get::<AxisAlignedBoundingBox<F32>>(player).unwrap();
+    assert!(aabb.bottom.0.abs() < 1e-4);
+    assert_eq!(
+        *world.get::<Velocity<F32>>(player).unwrap(),
+        Velocity {
+            x: F32(2.0),
+            y: F32(0.0)
+        }
+    );
+    let controller = world.get::<CharacterController<F32>>(player).unwrap();
+    assert!(controller.on_floor);
+    assert!(!controller.on_wall);
+    assert!(!controller.on_ceiling);
+
+    // Far too fast to stop in a single frame, but the wall still can't be skipped
+    world.get_mut::<Velocity<F32>>(player).unwrap().x = F32(10_000.0);
+    tick(&mut world);
+    stage.run(&mut world);
+
+    let aabb = world.get::<AxisAlignedBoundingBox<F32>>(player).unwrap();
+    assert!((aabb.right.0 - 5.0).abs() < 1e-3, "{aabb:?}");
+    assert_eq!(world.get::<Velocity<F32>>(player).unwrap().x, F32(0.0));
+    let controller = world.get::<CharacterController<F32>>(player).unwrap();
+    assert!(controller.on_floor);
+    assert!(controller.on_wall);
+}
+
+#[test]
+fn gravity_pulls_characters() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    world.insert_resource(Gravity(Acceleration::<F32> {
+        x: F32(0.0),
+        y: F32(-10.0),
+    }));
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(move_and_slide::<F32>);
+
+    let spawn_character = |world: &mut World, x: f32| {
+        world
+            .spawn()
+            .insert(CharacterController::<F32>::default())
+            .insert(Position::<F32>::new(x, 0.5))
+            .insert(Velocity::<F32>::default())
+            .insert(AxisAlignedBoundingBox::<F32>::new(
+                x - 0.5,
+                x + 0.5,
+                0.0,
+                1.0,
+            ))
+            .id()
+    };
+    let falling = spawn_character(&mut world, 0.0);
+    world.entity_mut(falling).insert(GravityAffected);
+    let floating = spawn_character(&mut world, 5.0);
+
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    let velocity = *world.get::<Velocity<F32>>(falling).unwrap();
+    assert!((velocity.y.0 + 10.0 * dt).abs() < 1e-6);
+    let position = *world.get::<Position<F32>>(falling).unwrap();
+    assert!((position.y.0 - (0.5 - 10.0 * dt * dt)).abs() < 1e-5);
+    assert_eq!(
+        *world.get::<Velocity<F32>>(floating).unwrap(),
+        Velocity::default()
+    );
+}
+
+#[test]
+fn degenerate_characters_take_a_limited_number_of_steps() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(move_and_slide::<F32>);
+
+    // Without any width, every step would be a tiny fraction of a unit long
+    let point = world
+        .spawn()
+        .insert(CharacterController::<F32>::default())
+        .insert(Position::<F32>::default())
+        .insert(Velocity::<F32> {
+            x: F32(1_000.0),
+            y: F32(0.0),
+        })
+        .insert(AxisAlignedBoundingBox::<F32>::new(0.0, 0.0, 0.0, 0.0))
+        .id();
+    let dot = world
+        .spawn()
+        .insert(CharacterController::<F32>::default())
+        .insert(Position::<F32>::new(0.0, 10.0))
+        .insert(Velocity::<F32> {
+            x: F32(0.0),
+            y: F32(1_000.0),
+        })
+        .insert(Circle::<F32>::new(Position::new(0.0, 10.0), 0.0))
+        .id();
+
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    // Nothing is in the way, so they still move the whole distance
+    let position = *world.get::<Position<F32>>(point).unwrap();
+    assert!((position.x.0 - 1_000.0 * dt).abs() < 1e-2);
+    let position = *world.get::<Position<F32>>(dot).unwrap();
+    assert!((position.y.0 - (10.0 + 1_000.0 * dt)).abs() < 1e-2);
+}
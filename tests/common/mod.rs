@@ -0,0 +1,19 @@
+//! Helpers shared between the integration tests
+//!
+//! Each test file only uses some of these, so unused helpers are allowed.
+#![allow(dead_code)]
+
+use bevy::ecs::event::Events;
+use bevy::prelude::World;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::position::Position;
+
+/// The [`Position`] of a cell on an [`OrthogonalGrid`]
+pub fn cell(x: isize, y: isize) -> Position<OrthogonalGrid> {
+    Position::new(OrthogonalGrid(x), OrthogonalGrid(y))
+}
+
+/// Removes and returns every event of type `E` that has not yet been read
+pub fn drain<E: Send + Sync + 'static>(world: &mut World) -> Vec<E> {
+    world.resource_mut::<Events<E>>().drain().collect()
+}
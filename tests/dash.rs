@@ -0,0 +1,108 @@
+mod common;
+
+use bevy::prelude::*;
+use common::drain;
+use core::time::Duration;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::dash::{dashes, Dash, DashEnded, DashStarted};
+use leafwing_2d::kinematics::Velocity;
+use leafwing_2d::orientation::Direction;
+
+/// An app where no time passes between frames
+fn test_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_event::<DashStarted>()
+        .add_event::<DashEnded>()
+        .add_system(dashes::<F32>);
+
+    app
+}
+
+const WALKING: Velocity<F32> = Velocity {
+    x: F32(1.0),
+    y: F32(0.0),
+};
+
+#[test]
+fn dashing_overrides_velocity_until_it_ends() {
+    let mut app = test_app();
+
+    // This dash lasts for a single frame, and has no cooldown
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Dash::<F32>::new(None, 10.0, Duration::ZERO, Duration::ZERO),
+            WALKING,
+            Direction::WEST,
+        ))
+        .id();
+
+    // Nothing happens until the dash is started
+    app.update();
+    assert!(drain::<DashStarted>(&mut app.world).is_empty());
+    assert_eq!(*app.world.get::<Velocity<F32>>(player).unwrap(), WALKING);
+
+    assert!(app.world.get_mut::<Dash<F32>>(player).unwrap().start());
+    app.update();
+    assert_eq!(
+        drain::<DashStarted>(&mut app.world),
+        vec![DashStarted {
+            entity: player,
+            direction: Direction::WEST
+        }]
+    );
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(player).unwrap(),
+        Velocity {
+            x: F32(-10.0),
+            y: F32(0.0)
+        }
+    );
+    assert!(app.world.get::<Dash<F32>>(player).unwrap().is_dashing());
+
+    // The original velocity is restored afterwards
+    app.update();
+    assert_eq!(
+        drain::<DashEnded>(&mut app.world),
+        vec![DashEnded { entity: player }]
+    );
+    assert_eq!(*app.world.get::<Velocity<F32>>(player).unwrap(), WALKING);
+
+    // Even without a cooldown, dashes can't be chained on the same frame that they end
+    let dash = app.world.get::<Dash<F32>>(player).unwrap();
+    assert!(!dash.is_dashing());
+    assert!(!dash.is_ready());
+    app.update();
+    assert!(app.world.get::<Dash<F32>>(player).unwrap().is_ready());
+}
+
+#[test]
+fn dashes_ignore_other_changes_to_velocity() {
+    let mut app = test_app();
+
+    let mut dash = Dash::<F32>::new(
+        Some(Direction::NORTH),
+        5.0,
+        Duration::from_secs(60),
+        Duration::from_secs(60),
+    );
+    assert!(dash.start());
+    assert!(!dash.start());
+
+    let player = app.world.spawn().insert_bundle((dash, WALKING)).id();
+    app.update();
+
+    let dashing = Velocity {
+        x: F32(0.0),
+        y: F32(5.0),
+    };
+    assert_eq!(*app.world.get::<Velocity<F32>>(player).unwrap(), dashing);
+
+    // Player input is overruled while dashing
+    *app.world.get_mut::<Velocity<F32>>(player).unwrap() = WALKING;
+    app.update();
+    assert_eq!(*app.world.get::<Velocity<F32>>(player).unwrap(), dashing);
+    assert!(drain::<DashEnded>(&mut app.world).is_empty());
+}
@@ -0,0 +1,121 @@
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::DiscreteCoordinate;
+use leafwing_2d::flood_fill::flood_fill;
+use leafwing_2d::position::Position;
+use std::collections::{BTreeSet, HashSet};
+
+/// A tile on a grid of 32 pixel tiles
+#[derive(Coordinate)]
+#[coordinate(data = i32, scale = 32.0, min = -1000, max = 1000)]
+struct Tile(i32);
+
+/// A small grid, using the bounds of its wrapped type
+#[derive(Coordinate)]
+struct Cell(i8);
+
+/// Continuous coordinates measured in meters, with 100 pixels per meter
+#[derive(Coordinate)]
+#[coordinate(scale = 100.0)]
+struct Meters(f64);
+
+#[derive(Coordinate)]
+#[coordinate(min = -1.0, max = 1.0)]
+struct Unit(f32);
+
+impl DiscreteCoordinate for Tile {
+    type Parititions = leafwing_2d::partitioning::CardinalQuadrant;
+    const N_NEIGHBORS: usize = 4;
+
+    fn next(&self) -> Self {
+        if *self < Tile::MAX {
+            Tile(self.0 + 1)
+        } else {
+            Tile::MAX
+        }
+    }
+
+    fn prev(&self) -> Self {
+        if *self > Tile::MIN {
+            Tile(self.0 - 1)
+        } else {
+            Tile::MIN
+        }
+    }
+
+    fn neighbors(position: Position<Self>) -> Vec<Position<Self>> {
+        vec![
+            Position::new(position.x.0, position.y.0 + 1),
+            Position::new(position.x.0 + 1, position.y.0),
+            Position::new(position.x.0, position.y.0 - 1),
+            Position::new(position.x.0 - 1, position.y.0),
+        ]
+    }
+}
+
+#[test]
+fn attributes_set_constants() {
+    assert_eq!(Tile::COORD_TO_TRANSFORM, 32.0);
+    assert_eq!(Tile::MIN, Tile(-1000));
+    assert_eq!(Tile::MAX, Tile(1000));
+    assert_eq!(Tile::ZERO, Tile(0));
+    assert_eq!(Tile::DATA_ZERO, 0);
+    assert_eq!(Tile::DATA_ONE, 1);
+}
+
+#[test]
+fn defaults_use_wrapped_type() {
+    assert_eq!(Cell::COORD_TO_TRANSFORM, 1.0);
+    assert_eq!(Cell::MIN, Cell(i8::MIN));
+    assert_eq!(Cell::MAX, Cell(i8::MAX));
+    assert_eq!(Meters::DATA_ONE, 1.0);
+    assert_eq!(Unit::MAX, Unit(1.0));
+    assert_eq!(Unit::COORD_TO_TRANSFORM, 1.0);
+}
+
+#[test]
+fn conversions_are_scaled() {
+    assert_eq!(f32::from(Tile(3)), 96.0);
+    assert_eq!(Tile::from(96.0), Tile(3));
+    assert_eq!(f32::from(Meters(1.5)), 150.0);
+    assert_eq!(Meters::from(150.0f32), Meters(1.5));
+    assert_eq!(Meters::from(-1e9f32), Meters(-1e7));
+    assert_eq!(f32::from(Unit(0.5)), 0.5);
+}
+
+#[test]
+fn integer_conversions_round_and_clamp() {
+    assert_eq!(Tile::from(47.0), Tile(1));
+    assert_eq!(Tile::from(49.0), Tile(2));
+    assert_eq!(Tile::from(-49.0), Tile(-2));
+    assert_eq!(Tile::from(1e9), Tile::MAX);
+    assert_eq!(Tile::from(-1e9), Tile::MIN);
+    assert_eq!(Cell::from(1000.0), Cell::MAX);
+}
+
+#[test]
+fn derived_coordinates_work_with_positions() {
+    let position = Position::<Tile>::new(2, -3);
+    assert_eq!(position * Tile(2), Position::new(4, -6));
+
+    let world: bevy::math::Vec2 = position.into();
+    assert_eq!(world, bevy::math::Vec2::new(64.0, -96.0));
+    assert_eq!(Position::<Tile>::from(world), position);
+
+    Tile::assert_values_distinct();
+    assert_eq!(Tile::neighbors(position).len(), 4);
+}
+
+#[test]
+fn integer_coordinates_can_be_set_members() {
+    let tiles = BTreeSet::from([Tile(3), Tile(-1), Tile(3)]);
+    assert_eq!(
+        tiles.into_iter().collect::<Vec<_>>(),
+        vec![Tile(-1), Tile(3)]
+    );
+    assert_eq!(HashSet::from([Cell(1), Cell(1)]).len(), 1);
+
+    let room = flood_fill(Position::<Tile>::new(0, 0), |position| {
+        position.x.0.abs() <= 1 && position.y.0.abs() <= 1
+    });
+    assert_eq!(room.len(), 9);
+}
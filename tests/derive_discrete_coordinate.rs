@@ -0,0 +1,134 @@
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::{AdjacentGrid, DiscreteCoordinate, FlatHex, OrthogonalGrid, PointyHex};
+use leafwing_2d::position::Position;
+
+#[derive(Coordinate, DiscreteCoordinate)]
+#[coordinate(min = -100, max = 100)]
+#[discrete(grid = "orthogonal")]
+struct Orthogonal(i32);
+
+#[derive(Coordinate, DiscreteCoordinate)]
+#[coordinate(min = -100, max = 100)]
+#[discrete(grid = "adjacent")]
+struct Adjacent(i32);
+
+#[derive(Coordinate, DiscreteCoordinate)]
+#[coordinate(min = -100, max = 100)]
+#[discrete(grid = "hex_flat")]
+struct Flat(i32);
+
+#[derive(Coordinate, DiscreteCoordinate)]
+#[coordinate(min = -100, max = 100)]
+#[discrete(grid = "hex_pointy")]
+struct Pointy(i32);
+
+/// Uses the full range of its wrapped unsigned type
+#[derive(Coordinate, DiscreteCoordinate)]
+#[discrete(grid = "adjacent")]
+struct Byte(u8);
+
+/// Converts the neighbors of a derived coordinate into comparable `(x, y)` pairs
+fn derived_offsets<C: DiscreteCoordinate<Data = i32> + Into<i32>>(
+    x: i32,
+    y: i32,
+) -> Vec<(i32, i32)> {
+    C::neighbors(Position::new(x, y))
+        .into_iter()
+        .map(|neighbor| (neighbor.x.into(), neighbor.y.into()))
+        .collect()
+}
+
+/// Converts the neighbors of a built-in grid into comparable `(x, y)` pairs
+fn builtin_offsets<C: DiscreteCoordinate<Data = isize> + Into<isize>>(
+    x: isize,
+    y: isize,
+) -> Vec<(i32, i32)> {
+    C::neighbors(Position::new(x, y))
+        .into_iter()
+        .map(|neighbor| {
+            let (x, y): (isize, isize) = (neighbor.x.into(), neighbor.y.into());
+            (x as i32, y as i32)
+        })
+        .collect()
+}
+
+#[test]
+fn neighbors_match_builtin_grids() {
+    for (x, y) in [(0, 0), (3, -7), (-12, 5)] {
+        let (big_x, big_y) = (x as isize, y as isize);
+
+        assert_eq!(
+            derived_offsets::<Orthogonal>(x, y),
+            builtin_offsets::<OrthogonalGrid>(big_x, big_y)
+        );
+        assert_eq!(
+            derived_offsets::<Adjacent>(x, y),
+            builtin_offsets::<AdjacentGrid>(big_x, big_y)
+        );
+        assert_eq!(
+            derived_offsets::<Flat>(x, y),
+            builtin_offsets::<FlatHex>(big_x, big_y)
+        );
+        assert_eq!(
+            derived_offsets::<Pointy>(x, y),
+            builtin_offsets::<PointyHex>(big_x, big_y)
+        );
+    }
+}
+
+#[test]
+fn constants_match_builtin_grids() {
+    assert_eq!(Orthogonal::N_NEIGHBORS, OrthogonalGrid::N_NEIGHBORS);
+    assert_eq!(Adjacent::N_NEIGHBORS, AdjacentGrid::N_NEIGHBORS);
+    assert_eq!(Flat::N_NEIGHBORS, FlatHex::N_NEIGHBORS);
+    assert_eq!(Pointy::N_NEIGHBORS, PointyHex::N_NEIGHBORS);
+
+    assert_eq!(
+        Orthogonal::neighbor_directions(),
+        OrthogonalGrid::neighbor_directions()
+    );
+    assert_eq!(
+        Adjacent::neighbor_directions(),
+        AdjacentGrid::neighbor_directions()
+    );
+    assert_eq!(Flat::neighbor_directions(), FlatHex::neighbor_directions());
+    assert_eq!(
+        Pointy::neighbor_directions(),
+        PointyHex::neighbor_directions()
+    );
+}
+
+#[test]
+fn next_and_prev_saturate() {
+    assert_eq!(Orthogonal(3).next(), Orthogonal(4));
+    assert_eq!(Orthogonal(3).prev(), Orthogonal(2));
+    assert_eq!(Orthogonal::MAX.next(), Orthogonal::MAX);
+    assert_eq!(Orthogonal::MIN.prev(), Orthogonal::MIN);
+
+    assert_eq!(Byte(0).prev(), Byte(0));
+    assert_eq!(Byte(255).next(), Byte(255));
+}
+
+#[test]
+fn neighbors_outside_range_are_skipped() {
+    let corner = Position::<Adjacent>::new(100, -100);
+    let neighbors = Adjacent::neighbors(corner);
+    assert_eq!(neighbors.len(), 3);
+    assert!(neighbors
+        .iter()
+        .all(|neighbor| neighbor.x <= Adjacent::MAX && neighbor.y >= Adjacent::MIN));
+
+    let edge = Position::<Orthogonal>::new(0, 100);
+    assert_eq!(Orthogonal::neighbors(edge).len(), 3);
+
+    // Unsigned coordinates must not underflow at zero
+    let origin = Position::<Byte>::new(0, 0);
+    assert_eq!(
+        Byte::neighbors(origin),
+        vec![
+            Position::new(0, 1),
+            Position::new(1, 1),
+            Position::new(1, 0)
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "deterministic")]
+
+use bevy::math::Vec2;
+use leafwing_2d::orientation::*;
+
+#[test]
+fn deterministic_trig_is_accurate() {
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let rotation = Rotation::new(deci_degrees);
+        let radians = rotation.into_radians();
+        let expected = Vec2::new(radians.sin(), radians.cos());
+
+        let actual = rotation.into_vec2();
+        assert!(
+            (actual - expected).abs().max_element() <= 1e-6,
+            "{actual} != {expected} at {rotation}"
+        );
+    }
+}
+
+#[test]
+fn deterministic_round_trip() {
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let rotation = Rotation::new(deci_degrees);
+        let round_trip = Rotation::from(Direction::from(rotation));
+
+        let error = (round_trip - rotation).deci_degrees();
+        assert!(
+            error <= 1 || error == Rotation::FULL_CIRCLE - 1,
+            "{round_trip} != {rotation}"
+        );
+    }
+}
+
+#[test]
+fn deterministic_golden_values() {
+    // These exact bits must be produced on every platform: if they change, lockstep simulations will desync
+    let mut checksum: u64 = 0;
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let vec2 = Rotation::new(deci_degrees).into_vec2();
+        let round_trip = Rotation::from_vec2(vec2 * 3.7).unwrap();
+
+        checksum = checksum
+            .wrapping_mul(31)
+            .wrapping_add(u64::from(vec2.x.to_bits()));
+        checksum = checksum
+            .wrapping_mul(31)
+            .wrapping_add(u64::from(vec2.y.to_bits()));
+        checksum = checksum
+            .wrapping_mul(31)
+            .wrapping_add(u64::from(round_trip.deci_degrees()));
+    }
+
+    assert_eq!(checksum, 9_498_159_351_019_222_058);
+}
@@ -0,0 +1,74 @@
+use bevy::math::Vec2;
+use leafwing_2d::continuous::FiniteF32;
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::errors::NonFiniteCoordinate;
+use leafwing_2d::position::Position;
+use std::collections::{BTreeSet, HashSet};
+
+#[test]
+fn new_rejects_non_finite_values() {
+    assert_eq!(FiniteF32::new(f32::NAN), Err(NonFiniteCoordinate));
+    assert_eq!(FiniteF32::new(f32::INFINITY), Err(NonFiniteCoordinate));
+    assert_eq!(FiniteF32::new(f32::NEG_INFINITY), Err(NonFiniteCoordinate));
+    assert_eq!(FiniteF32::new(1.5).map(|finite| finite.value()), Ok(1.5));
+}
+
+#[test]
+fn conversions_clamp_non_finite_values() {
+    assert_eq!(FiniteF32::from(f32::NAN), FiniteF32::ZERO);
+    assert_eq!(FiniteF32::from(f32::INFINITY), FiniteF32::MAX);
+    assert_eq!(FiniteF32::from(f32::NEG_INFINITY), FiniteF32::MIN);
+
+    let position = Position::<FiniteF32>::from(Vec2::new(f32::NAN, f32::INFINITY));
+    assert_eq!(position, Position::new(FiniteF32::ZERO, FiniteF32::MAX));
+}
+
+#[test]
+fn arithmetic_stays_finite() {
+    let max = FiniteF32::MAX;
+    let zero = FiniteF32::ZERO;
+
+    assert_eq!(max + max, max);
+    assert_eq!(FiniteF32::MIN - max, FiniteF32::MIN);
+    assert_eq!(max * 2.0, max);
+    assert_eq!(zero / zero, zero);
+    assert_eq!(FiniteF32::from(1.0) % zero, zero);
+
+    let mut value = FiniteF32::from(-1.0);
+    value /= zero;
+    assert_eq!(value, FiniteF32::MIN);
+
+    for value in [max + max, zero / zero, max * 2.0] {
+        assert!(f32::from(value).is_finite());
+    }
+}
+
+#[test]
+fn negative_zero_equals_zero() {
+    let negative_zero = FiniteF32::from(-0.0);
+
+    assert_eq!(negative_zero, FiniteF32::ZERO);
+    assert_eq!(negative_zero.value().to_bits(), 0.0f32.to_bits());
+    assert_eq!(FiniteF32::from(-1.0) * 0.0, FiniteF32::ZERO);
+}
+
+#[test]
+fn values_are_totally_ordered() {
+    let sorted: BTreeSet<FiniteF32> = [3.0, -1.0, f32::NAN, 0.0, -0.0, f32::INFINITY]
+        .into_iter()
+        .map(FiniteF32::from)
+        .collect();
+
+    let values: Vec<f32> = sorted.into_iter().map(f32::from).collect();
+    assert_eq!(values, vec![-1.0, 0.0, 3.0, f32::MAX]);
+}
+
+#[test]
+fn positions_can_be_hashed() {
+    let positions: HashSet<Position<FiniteF32>> = [(1.0, 2.0), (1.0, 2.0), (-0.0, 0.0), (0.0, 0.0)]
+        .into_iter()
+        .map(|(x, y)| Position::new(x, y))
+        .collect();
+
+    assert_eq!(positions.len(), 2);
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::cell;
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::{AdjacentGrid, OrthogonalGrid, I8};
+use leafwing_2d::flood_fill::{connected_regions, flood_fill};
+use leafwing_2d::position::Position;
+use std::collections::BTreeSet;
+
+/// A 7x5 map, where `#` is a wall
+const MAP: [&str; 5] = [
+    "..#....", //
+    "..#.#..", //
+    "###.#..", //
+    "....###", //
+    "....#..", //
+];
+
+fn is_floor(x: isize, y: isize) -> bool {
+    (0..7).contains(&x) && (0..5).contains(&y) && MAP[y as usize].as_bytes()[x as usize] == b'.'
+}
+
+fn floor_cells() -> Vec<Position<OrthogonalGrid>> {
+    (0..7)
+        .flat_map(|x| (0..5).map(move |y| (x, y)))
+        .filter(|&(x, y)| is_floor(x, y))
+        .map(|(x, y)| cell(x, y))
+        .collect()
+}
+
+#[test]
+fn flood_fill_stays_inside_walls() {
+    let room = flood_fill(cell(0, 0), |position| is_floor(position.x.0, position.y.0));
+
+    assert_eq!(
+        room,
+        BTreeSet::from([cell(0, 0), cell(0, 1), cell(1, 0), cell(1, 1)])
+    );
+}
+
+#[test]
+fn flood_fill_from_a_wall_is_empty() {
+    assert!(flood_fill(cell(2, 0), |position| is_floor(position.x.0, position.y.0)).is_empty());
+}
+
+#[test]
+fn flood_fill_checks_each_cell_once() {
+    let mut checked = Vec::new();
+    let _ = flood_fill(cell(3, 3), |position| {
+        checked.push(position);
+        is_floor(position.x.0, position.y.0)
+    });
+
+    for (i, a) in checked.iter().enumerate() {
+        assert!(!checked[i + 1..].contains(a));
+    }
+}
+
+#[test]
+fn diagonals_connect_on_adjacent_grids() {
+    let passable = |position: Position<AdjacentGrid>| {
+        position.x.0 == position.y.0 && (0..5).contains(&position.x.0)
+    };
+    let start = Position::<AdjacentGrid>::new(AdjacentGrid(0), AdjacentGrid(0));
+
+    assert_eq!(flood_fill(start, passable).len(), 5);
+    assert_eq!(
+        flood_fill(cell(0, 0), |position| position.x == position.y
+            && (0..5).contains(&position.x.0))
+        .len(),
+        1
+    );
+}
+
+#[test]
+fn regions_partition_the_cells() {
+    let cells = floor_cells();
+    let regions = connected_regions(cells.clone());
+
+    // The top-left room, the long central room, and the bottom-right nook
+    assert_eq!(regions.len(), 3);
+    assert_eq!(
+        regions.iter().map(BTreeSet::len).sum::<usize>(),
+        cells.len()
+    );
+    assert!(regions[0].contains(&cell(0, 0)));
+    assert_eq!(regions[2], BTreeSet::from([cell(5, 4), cell(6, 4)]));
+
+    for region in &regions {
+        let first = *region.iter().next().unwrap();
+        let refilled = flood_fill(first, |position| is_floor(position.x.0, position.y.0));
+        assert_eq!(&refilled, region);
+    }
+}
+
+#[test]
+fn duplicate_cells_are_ignored() {
+    let regions = connected_regions([cell(1, 1), cell(1, 1), cell(1, 2)]);
+
+    assert_eq!(regions, vec![BTreeSet::from([cell(1, 1), cell(1, 2)])]);
+    assert!(connected_regions(Vec::<Position<OrthogonalGrid>>::new()).is_empty());
+}
+
+#[test]
+fn flood_fill_works_on_compact_coordinates() {
+    // Neighbors past the edges of the range are skipped, so filling a corner never overflows
+    let corner = Position::<I8>::new(I8::MAX, I8::MAX);
+    let filled = flood_fill(corner, |position| {
+        position.x.0 >= i8::MAX - 2 && position.y.0 >= i8::MAX - 2
+    });
+
+    assert_eq!(filled.len(), 9);
+    assert!(filled.contains(&Position::new(I8(i8::MAX - 2), I8::MAX)));
+}
@@ -0,0 +1,174 @@
+use bevy::math::Vec2;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::formation;
+use leafwing_2d::orientation::{Orientation, Rotation};
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::transform::Transform2D;
+
+#[test]
+fn empty_formations() {
+    assert!(formation::grid::<F32>(0, 5, 1.0).is_empty());
+    assert!(formation::grid::<F32>(5, 0, 1.0).is_empty());
+    assert!(formation::circle::<F32>(0, 1.0).is_empty());
+    assert!(formation::line::<F32>(0, 1.0).is_empty());
+}
+
+#[test]
+fn single_slots_are_on_the_anchor() {
+    assert_eq!(
+        formation::grid::<F32>(1, 1, 3.0),
+        vec![(Position::new(0.0, 0.0), Rotation::NORTH)]
+    );
+    assert_eq!(
+        formation::line::<F32>(1, 3.0),
+        vec![(Position::new(0.0, 0.0), Rotation::NORTH)]
+    );
+}
+
+#[test]
+fn grids_are_centered() {
+    let slots = formation::grid::<F32>(3, 4, 2.0);
+    assert_eq!(slots.len(), 12);
+
+    let sum = slots
+        .iter()
+        .fold(Position::<F32>::new(0.0, 0.0), |sum, &(position, _)| {
+            sum + position
+        });
+    assert_eq!(sum, Position::new(0.0, 0.0));
+
+    // Front row, left to right
+    assert_eq!(slots[0].0, Position::new(-3.0, 2.0));
+    assert_eq!(slots[3].0, Position::new(3.0, 2.0));
+    // Back row
+    assert_eq!(slots[11].0, Position::new(3.0, -2.0));
+}
+
+#[test]
+fn circle_slots_face_outwards() {
+    for (position, rotation) in formation::circle::<F32>(7, 5.0) {
+        let distance = Vec2::from(position).length();
+        assert!((distance - 5.0).abs() < 1e-4);
+        Rotation::from_vec2(position.into())
+            .unwrap()
+            .assert_approx_eq(rotation);
+    }
+
+    let rotations: Vec<Rotation> = formation::circle::<F32>(8, 1.0)
+        .into_iter()
+        .map(|(_, rotation)| rotation)
+        .collect();
+    assert_eq!(rotations[2], Rotation::EAST);
+    assert_eq!(rotations[4], Rotation::SOUTH);
+    assert_eq!(rotations[7], Rotation::NORTHWEST);
+}
+
+#[test]
+fn placed_formations_follow_the_anchor() {
+    let anchor = Transform2D {
+        position: Position::<F32>::new(-4.0, 1.0),
+        rotation: Rotation::SOUTH,
+        ..Transform2D::IDENTITY
+    };
+
+    let placed = formation::place(anchor, formation::grid::<F32>(2, 1, 2.0));
+    // The front row is still in front, which is now to the south
+    placed[0]
+        .0
+        .assert_approx_eq(Position::<F32>::new(-4.0, 0.0));
+    placed[1]
+        .0
+        .assert_approx_eq(Position::<F32>::new(-4.0, 2.0));
+    assert!(placed
+        .iter()
+        .all(|&(_, rotation)| rotation == Rotation::SOUTH));
+}
+
+#[test]
+fn members_steer_towards_their_slot() {
+    use bevy::ecs::schedule::{Stage, SystemStage};
+    use bevy::prelude::*;
+    use leafwing_2d::formation::{keep_formation, FormationSlot};
+    use leafwing_2d::kinematics::Velocity;
+
+    let mut world = World::new();
+    world.insert_resource(Time::default());
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(keep_formation::<F32>);
+
+    let leader = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(10.0, 0.0), Rotation::EAST))
+        .id();
+
+    // The slot is behind the leader, which is to the west when facing east
+    let slot = FormationSlot::<F32>::new(leader, Position::new(0.0, -2.0), Rotation::NORTH, 4.0);
+    assert_eq!(slot.slowing_distance, 2.0);
+    let member = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Rotation::NORTH,
+            Velocity::<F32>::default(),
+            slot,
+        ))
+        .id();
+
+    // Far away, so the member heads straight for its slot at full speed
+    stage.run(&mut world);
+    let velocity = *world.get::<Velocity<F32>>(member).unwrap();
+    assert!((velocity.x.0 - 4.0).abs() < 1e-5);
+    assert!(velocity.y.0.abs() < 1e-5);
+    world
+        .get::<Rotation>(member)
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+
+    // Close by, so the member slows down and lines up with the leader
+    *world.get_mut::<Position<F32>>(member).unwrap() = Position::new(7.0, 0.0);
+    *world.get_mut::<Rotation>(member).unwrap() = Rotation::SOUTH;
+    stage.run(&mut world);
+    let velocity = *world.get::<Velocity<F32>>(member).unwrap();
+    assert!((velocity.x.0 - 2.0).abs() < 1e-5);
+    assert_eq!(*world.get::<Rotation>(member).unwrap(), Rotation::EAST);
+
+    // Once in the slot, the member stops
+    *world.get_mut::<Position<F32>>(member).unwrap() = Position::new(8.0, 0.0);
+    stage.run(&mut world);
+    let velocity = *world.get::<Velocity<F32>>(member).unwrap();
+    assert!(velocity.x.0.abs() < 1e-5 && velocity.y.0.abs() < 1e-5);
+}
+
+#[test]
+fn members_turn_at_a_limited_rate() {
+    use bevy::ecs::schedule::{Stage, SystemStage};
+    use bevy::prelude::*;
+    use leafwing_2d::formation::{keep_formation, FormationSlot};
+
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(keep_formation::<F32>);
+
+    let leader = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 0.0), Rotation::SOUTH))
+        .id();
+    let mut slot = FormationSlot::<F32>::new(leader, Position::new(0.0, 0.0), Rotation::NORTH, 1.0);
+    slot.turn_rate = Some(Rotation::from_degrees(90.0));
+    let member = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 0.0), Rotation::NORTH, slot))
+        .id();
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    world.resource_mut::<Time>().update();
+    stage.run(&mut world);
+
+    // Only part of the way around
+    let rotation = *world.get::<Rotation>(member).unwrap();
+    assert_ne!(rotation, Rotation::NORTH);
+    assert!(rotation.distance(Rotation::NORTH) < Rotation::from_degrees(45.0));
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::cell;
+use leafwing_2d::discrete::{AdjacentGrid, OrthogonalGrid};
+use leafwing_2d::fov::fov;
+use leafwing_2d::position::Position;
+
+#[test]
+fn open_field_is_a_disc() {
+    let radius = 6;
+    let visible = fov(cell(10, -3), radius, |_| false);
+
+    for x in -8..=8 {
+        for y in -8..=8 {
+            let inside = x * x + y * y <= (radius * radius) as isize;
+            assert_eq!(
+                visible.contains(&cell(10 + x, -3 + y)),
+                inside,
+                "{:?}",
+                (x, y)
+            );
+        }
+    }
+}
+
+#[test]
+fn cells_are_visible_exactly_once() {
+    let visible = fov(cell(0, 0), 8, |position: Position<OrthogonalGrid>| {
+        position.x.0 % 3 == 0 && position.y.0 > 2
+    });
+
+    for (i, a) in visible.iter().enumerate() {
+        assert!(!visible[i + 1..].contains(a));
+    }
+    assert!(visible.contains(&cell(0, 0)));
+}
+
+#[test]
+fn walls_cast_shadows() {
+    // A single pillar directly north of the origin
+    let pillar = cell(0, 2);
+    let visible = fov(cell(0, 0), 10, |position| position == pillar);
+
+    assert!(visible.contains(&pillar));
+    for y in 3..=10 {
+        assert!(!visible.contains(&cell(0, y)));
+    }
+    // Sight lines that miss the pillar are unaffected
+    assert!(visible.contains(&cell(3, 8)));
+    assert!(visible.contains(&cell(-3, 8)));
+    assert!(visible.contains(&cell(0, -10)));
+}
+
+#[test]
+fn enclosed_rooms_hide_the_outside() {
+    // A 5x5 room, with walls on its border
+    let is_wall =
+        |position: Position<OrthogonalGrid>| position.x.0.abs() == 2 || position.y.0.abs() == 2;
+    let visible = fov(cell(0, 0), 20, is_wall);
+
+    assert_eq!(visible.len(), 25);
+    for x in -2..=2 {
+        for y in -2..=2 {
+            assert!(visible.contains(&cell(x, y)));
+        }
+    }
+}
+
+#[test]
+fn opacity_is_only_checked_nearby() {
+    let mut checked = Vec::new();
+    let origin = Position::<AdjacentGrid>::new(AdjacentGrid(100), AdjacentGrid(100));
+    let _ = fov(origin, 3, |position| {
+        checked.push(position);
+        false
+    });
+
+    for position in checked {
+        assert!((position.x.0 - 100).abs() <= 3);
+        assert!((position.y.0 - 100).abs() <= 3);
+    }
+}
+
+#[test]
+fn zero_radius_sees_only_the_origin() {
+    assert_eq!(fov(cell(4, 4), 0, |_| true), vec![cell(4, 4)]);
+}
@@ -0,0 +1,144 @@
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::geometry::*;
+use leafwing_2d::position::Position;
+
+fn p(x: f32, y: f32) -> Position<F32> {
+    Position::new(x, y)
+}
+
+#[test]
+fn segments_touching_at_endpoints() {
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(1., 0.), p(1., 0.), p(1., 5.)),
+        LineIntersection::Point(p(1., 0.))
+    );
+
+    // Collinear, sharing only an endpoint
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(1., 1.), p(1., 1.), p(3., 3.)),
+        LineIntersection::Point(p(1., 1.))
+    );
+}
+
+#[test]
+fn collinear_segments() {
+    // On the same line, but with a gap between them
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(1., 0.), p(2., 0.), p(3., 0.)),
+        LineIntersection::None
+    );
+
+    // Parallel, but on different lines
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(1., 0.), p(0., 1.), p(1., 1.)),
+        LineIntersection::None
+    );
+
+    // The order of the positions does not matter
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(4., 0.), p(3., 0.), p(1., 0.)),
+        LineIntersection::Overlap(p(1., 0.), p(3., 0.))
+    );
+}
+
+#[test]
+fn degenerate_segments() {
+    assert_eq!(
+        segment_segment_intersection(p(1., 1.), p(1., 1.), p(0., 0.), p(2., 2.)),
+        LineIntersection::Point(p(1., 1.))
+    );
+    assert_eq!(
+        segment_segment_intersection(p(0., 0.), p(2., 2.), p(1., 0.), p(1., 0.)),
+        LineIntersection::None
+    );
+
+    assert_eq!(
+        line_line_intersection(p(1., 1.), p(1., 1.), p(5., 5.), p(6., 6.)),
+        LineIntersection::Point(p(1., 1.))
+    );
+    assert_eq!(
+        line_line_intersection(p(1., 1.), p(1., 1.), p(2., 2.), p(2., 2.)),
+        LineIntersection::None
+    );
+}
+
+#[test]
+fn segment_circle_edge_cases() {
+    let center = p(0., 0.);
+
+    // Tangent
+    assert_eq!(
+        segment_circle_intersection(p(-5., 1.), p(5., 1.), center, 1.),
+        CircleIntersection::One(p(0., 1.))
+    );
+
+    // Entirely inside
+    assert_eq!(
+        segment_circle_intersection(p(-0.5, 0.), p(0.5, 0.), center, 1.),
+        CircleIntersection::None
+    );
+
+    // Travelling in the opposite direction reverses the order
+    assert_eq!(
+        segment_circle_intersection(p(0., 5.), p(0., -5.), center, 3.),
+        CircleIntersection::Two(p(0., 3.), p(0., -3.))
+    );
+
+    // A single point on the edge
+    assert_eq!(
+        segment_circle_intersection(p(3., 0.), p(3., 0.), center, 3.),
+        CircleIntersection::One(p(3., 0.))
+    );
+}
+
+#[test]
+fn segment_crossings() {
+    let wall = Segment::new(p(0., 0.), p(0., 10.));
+
+    assert_eq!(wall.length(), 10.);
+    assert_eq!(wall.closest_point(p(-3., 4.)), p(0., 4.));
+    assert_eq!(wall.closest_point(p(1., -2.)), p(0., 0.));
+
+    assert_eq!(
+        wall.intersection(&Segment::new(p(-1., 5.), p(1., 5.))),
+        Some(p(0., 5.))
+    );
+    assert_eq!(
+        wall.intersection(&Segment::new(p(1., 0.), p(1., 10.))),
+        None
+    );
+    // Overlapping walls meet closest to the start of the first wall
+    assert_eq!(
+        wall.intersection(&Segment::new(p(0., 12.), p(0., 4.))),
+        Some(p(0., 4.))
+    );
+}
+
+#[test]
+fn segment_clipping() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0., 4., 0., 2.);
+
+    // Passing straight through
+    let laser = Segment::new(p(-2., 1.), p(6., 1.));
+    assert!(laser.intersects_aabb(&aabb));
+    assert_eq!(
+        laser.clip_to_aabb(&aabb),
+        Some(Segment::new(p(0., 1.), p(4., 1.)))
+    );
+
+    // Reversed, starting inside
+    assert_eq!(
+        Segment::new(p(2., 1.), p(2., -5.)).clip_to_aabb(&aabb),
+        Some(Segment::new(p(2., 1.), p(2., 0.)))
+    );
+
+    // Entirely inside
+    let inside = Segment::new(p(1., 1.), p(3., 1.5));
+    assert_eq!(inside.clip_to_aabb(&aabb), Some(inside));
+
+    // Cutting past the corner
+    assert!(!Segment::new(p(3.5, 3.), p(5., 1.5)).intersects_aabb(&aabb));
+    // Along an edge
+    assert!(Segment::new(p(-1., 2.), p(5., 2.)).intersects_aabb(&aabb));
+}
@@ -0,0 +1,115 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::{Transform, Vec2, World};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::{AxialHex, GridLayout, HexOrientation, Isometric, OrthogonalGrid};
+use leafwing_2d::plugin::sync_transform_with_2d;
+use leafwing_2d::position::Position;
+
+fn tile(x: isize, y: isize) -> Position<OrthogonalGrid> {
+    Position::new(OrthogonalGrid(x), OrthogonalGrid(y))
+}
+
+#[test]
+fn square_layouts_scale_and_offset_cells() {
+    let layout = GridLayout::<OrthogonalGrid> {
+        cell_size: Vec2::new(32.0, 16.0),
+        origin: Vec2::new(-100.0, 50.0),
+        ..Default::default()
+    };
+
+    assert_eq!(layout.cell_to_world(tile(0, 0)), Vec2::new(-100.0, 50.0));
+    assert_eq!(layout.cell_to_world(tile(3, -2)), Vec2::new(-4.0, 18.0));
+
+    for x in -3..=3 {
+        for y in -3..=3 {
+            let center = layout.cell_to_world(tile(x, y));
+            assert_eq!(layout.world_to_cell(center), tile(x, y));
+            assert_eq!(
+                layout.world_to_cell(center + Vec2::new(15.0, -7.0)),
+                tile(x, y)
+            );
+        }
+    }
+
+    // The default layout matches the ordinary conversion
+    let default = GridLayout::<OrthogonalGrid>::default();
+    assert_eq!(default.cell_to_world(tile(5, 7)), Vec2::from(tile(5, 7)));
+}
+
+#[test]
+fn isometric_layouts_keep_their_projection() {
+    let layout = GridLayout::<Isometric>::new(32.0);
+    let position = Position::new(Isometric(1), Isometric(0));
+
+    assert_eq!(layout.cell_to_world(position), Vec2::from(position) * 32.0);
+    assert_eq!(layout.world_to_cell(Vec2::from(position) * 32.0), position);
+}
+
+#[test]
+fn hex_layouts_match_axial_hexes() {
+    for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+        let layout = GridLayout::<OrthogonalGrid> {
+            hex_orientation: Some(orientation),
+            origin: Vec2::new(8.0, 8.0),
+            ..GridLayout::new(10.0)
+        };
+
+        for (q, r) in [(0, 0), (2, -1), (-3, 4)] {
+            let expected = AxialHex::new(q, r).to_world(orientation, 10.0) + Vec2::new(8.0, 8.0);
+            assert_eq!(layout.cell_to_world(tile(q, r)), expected);
+            assert_eq!(layout.world_to_cell(expected), tile(q, r));
+        }
+    }
+}
+
+#[test]
+fn sync_uses_the_grid_layout() {
+    let mut world = World::new();
+    world.insert_resource(GridLayout::<OrthogonalGrid> {
+        origin: Vec2::new(16.0, 16.0),
+        ..GridLayout::new(32.0)
+    });
+    let entity = world
+        .spawn()
+        .insert(tile(2, 3))
+        .insert(Transform::default())
+        .id();
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(sync_transform_with_2d::<OrthogonalGrid>);
+    stage.run(&mut world);
+
+    let transform = *world.get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation.truncate(), Vec2::new(80.0, 112.0));
+
+    // Dragging the transform snaps the position to the cell under it
+    world.get_mut::<Transform>(entity).unwrap().translation.x = 140.0;
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<Position<OrthogonalGrid>>(entity).unwrap(),
+        tile(4, 3)
+    );
+
+    // Changing the layout moves every entity
+    world.resource_mut::<GridLayout<OrthogonalGrid>>().origin = Vec2::ZERO;
+    stage.run(&mut world);
+    let transform = *world.get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation.truncate(), Vec2::new(128.0, 96.0));
+}
+
+#[test]
+fn sync_without_a_layout_is_unchanged() {
+    let mut world = World::new();
+    let entity = world
+        .spawn()
+        .insert(Position::<F32>::new(2.5, -1.0))
+        .insert(Transform::default())
+        .id();
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(sync_transform_with_2d::<F32>);
+    stage.run(&mut world);
+
+    let transform = *world.get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation.truncate(), Vec2::new(2.5, -1.0));
+}
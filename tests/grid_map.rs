@@ -0,0 +1,256 @@
+mod common;
+
+use bevy::math::Vec2;
+use common::cell;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::grid_map::{contours, ChunkedMap, GridMap, TileMap};
+use leafwing_2d::path::Polyline;
+use leafwing_2d::polygon::Polygon;
+use leafwing_2d::position::Position;
+
+/// The signed area enclosed by a closed outline, which is positive when it winds counterclockwise
+fn signed_area(outline: &Polyline<F32>) -> f32 {
+    let points = outline.points();
+    Polygon::new(points[..points.len() - 1].iter().copied()).signed_area()
+}
+
+#[test]
+fn grid_map_indexing() {
+    let mut grid_map = GridMap::from_fn(3, 2, |position| position.x.0 + 10 * position.y.0);
+
+    assert_eq!(grid_map.width(), 3);
+    assert_eq!(grid_map.height(), 2);
+    assert_eq!(grid_map[cell(2, 1)], 12);
+    assert_eq!(grid_map.get(cell(3, 0)), None);
+    assert_eq!(grid_map.get(cell(0, -1)), None);
+    assert!(grid_map.contains(cell(0, 0)));
+    assert!(!grid_map.contains(cell(0, 2)));
+
+    grid_map[cell(1, 0)] = 100;
+    *grid_map.get_mut(cell(0, 1)).unwrap() = 200;
+    for (_, value) in grid_map.iter_mut() {
+        *value += 1;
+    }
+
+    let values: Vec<(Position<OrthogonalGrid>, isize)> = grid_map
+        .iter()
+        .map(|(position, &value)| (position, value))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            (cell(0, 0), 1),
+            (cell(1, 0), 101),
+            (cell(2, 0), 3),
+            (cell(0, 1), 201),
+            (cell(1, 1), 12),
+            (cell(2, 1), 13),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn grid_map_index_out_of_bounds() {
+    let grid_map = GridMap::new(2, 2, 0.0);
+    let _ = grid_map[cell(2, 0)];
+}
+
+#[test]
+fn empty_field_has_no_contours() {
+    let field = GridMap::new(4, 4, 0.0);
+    assert!(contours::<F32>(&field, 0.5).is_empty());
+}
+
+#[test]
+fn contours_are_closed_and_interpolated() {
+    // A 2x2 block of solid cells, with a partially filled neighbor to its right
+    let mut field = GridMap::new(6, 5, 0.0);
+    for position in [cell(1, 1), cell(2, 1), cell(1, 2), cell(2, 2)] {
+        field[position] = 1.0;
+    }
+    field[cell(3, 1)] = 0.25;
+
+    let outlines = contours::<F32>(&field, 0.5);
+    assert_eq!(outlines.len(), 1);
+
+    let points = outlines[0].points();
+    assert_eq!(points.first(), points.last());
+    assert!(signed_area(&outlines[0]) > 0.0);
+
+    // Crossings are interpolated between samples
+    assert!(points.contains(&Position::new(0.5, 1.0)));
+    assert!(points.contains(&Position::new(2.0, 2.5)));
+    // The partially filled cell pulls the outline towards it
+    assert!(points.contains(&Position::new(2.0 + 1.0 / 1.5, 1.0)));
+}
+
+#[test]
+fn holes_wind_clockwise() {
+    // A ring of solid cells around an empty center
+    let field = GridMap::from_fn(5, 5, |position| {
+        let (x, y) = (position.x.0, position.y.0);
+        if (1..=3).contains(&x) && (1..=3).contains(&y) && (x, y) != (2, 2) {
+            1.0
+        } else {
+            0.0
+        }
+    });
+
+    let mut areas: Vec<f32> = contours::<F32>(&field, 0.5)
+        .iter()
+        .map(signed_area)
+        .collect();
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_eq!(areas.len(), 2);
+    // The hole is a diamond around the empty center
+    assert!((areas[0] + 0.5).abs() < 1e-5);
+    assert!(areas[1] > 0.0);
+}
+
+#[test]
+fn contours_close_along_grid_edges() {
+    let field = GridMap::new(3, 2, 1.0);
+
+    let outlines = contours::<F32>(&field, 0.5);
+    assert_eq!(outlines.len(), 1);
+
+    // Completely filled grids are outlined along their outermost samples
+    let points = outlines[0].points();
+    assert!(points
+        .iter()
+        .all(|&point| Vec2::from(point).cmpge(Vec2::ZERO).all()
+            && Vec2::from(point).cmple(Vec2::new(2.0, 1.0)).all()));
+    assert!((signed_area(&outlines[0]) - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn diagonal_saddles_follow_the_center_value() {
+    // Two solid cells that only touch diagonally
+    let mut separated = GridMap::new(4, 4, 0.0);
+    separated[cell(1, 1)] = 1.0;
+    separated[cell(2, 2)] = 1.0;
+    assert_eq!(contours::<F32>(&separated, 0.75).len(), 2);
+
+    // A strong enough field joins them together through the saddle
+    let mut joined = separated.clone();
+    joined[cell(1, 1)] = 2.0;
+    joined[cell(2, 2)] = 2.0;
+    assert_eq!(contours::<F32>(&joined, 0.75).len(), 1);
+}
+
+#[test]
+fn tile_map_storage() {
+    let mut map = TileMap::from_fn(cell(-3, 5), 4, 2, |position| position.x.0 * position.y.0);
+
+    assert_eq!(map.origin(), cell(-3, 5));
+    assert_eq!((map.width(), map.height()), (4, 2));
+    assert_eq!(map[cell(-3, 5)], -15);
+    assert_eq!(map[cell(0, 6)], 0);
+    assert!(!map.contains(cell(1, 5)));
+    assert!(!map.contains(cell(-3, 4)));
+    assert_eq!(map.get(cell(-4, 5)), None);
+
+    assert_eq!(map.set(cell(-1, 6), 100), Some(-6));
+    assert_eq!(map.set(cell(10, 10), 100), None);
+    assert_eq!(map[cell(-1, 6)], 100);
+
+    let positions: Vec<_> = map.iter().map(|(position, _)| position).collect();
+    assert_eq!(positions.len(), 8);
+    assert_eq!(positions[0], cell(-3, 5));
+    assert_eq!(positions[4], cell(-3, 6));
+    assert_eq!(positions[7], cell(0, 6));
+
+    for (position, value) in map.iter_mut() {
+        *value = position.x.0;
+    }
+    assert_eq!(map[cell(-2, 6)], -2);
+}
+
+#[test]
+fn tile_map_world_space() {
+    let mut map = TileMap::new(cell(0, 0), 10, 5, false);
+    map.tile_size = 32.0;
+
+    assert_eq!(
+        map.world_position::<F32>(cell(2, 3)),
+        Position::new(64.0, 96.0)
+    );
+    assert_eq!(
+        map.tile_at(Position::<F32>::new(79.0, 81.0)),
+        Some(cell(2, 3))
+    );
+    // Every tile's center maps back to that tile
+    for (position, _) in map.iter() {
+        assert_eq!(
+            map.tile_at(map.world_position::<F32>(position)),
+            Some(position)
+        );
+    }
+    // Beyond the edges of the map
+    assert_eq!(map.tile_at(Position::<F32>::new(-17.0, 0.0)), None);
+    assert_eq!(map.tile_at(Position::<F32>::new(0.0, 160.0)), None);
+
+    let bounds = map.bounds::<F32>();
+    assert_eq!((bounds.left, bounds.right), (F32(-16.0), F32(304.0)));
+    assert_eq!((bounds.bottom, bounds.top), (F32(-16.0), F32(144.0)));
+}
+
+#[test]
+fn chunked_map_creates_chunks_lazily() {
+    let mut world = ChunkedMap::new(8, 'x');
+
+    assert_eq!(world.get(cell(-100, 40)), &'x');
+    assert!(!world.is_loaded(world.chunk_of(cell(-100, 40))));
+
+    assert_eq!(world.set(cell(-1, -1), 'a'), 'x');
+    assert_eq!(world.set(cell(-8, -8), 'b'), 'x');
+    assert_eq!(world.set(cell(0, 0), 'c'), 'x');
+    *world.get_mut(cell(7, 7)) = 'd';
+
+    // Negative tiles round down into their own chunk
+    assert_eq!(world.chunk_of(cell(-1, -1)), cell(-1, -1));
+    assert_eq!(world.chunk_of(cell(-8, -8)), cell(-1, -1));
+    assert_eq!(world.chunk_of(cell(-9, 0)), cell(-2, 0));
+    assert_eq!(world.chunk_of(cell(7, 7)), cell(0, 0));
+    assert_eq!(
+        world.chunks().collect::<Vec<_>>(),
+        vec![cell(-1, -1), cell(0, 0)]
+    );
+
+    assert_eq!(world.get(cell(-1, -1)), &'a');
+    assert_eq!(world.get(cell(-8, -8)), &'b');
+    assert_eq!(world.get(cell(-2, -1)), &'x');
+    assert_eq!(world.iter().count(), 2 * 8 * 8);
+
+    let chunk = world.chunk(cell(0, 0)).unwrap();
+    assert_eq!(chunk.origin(), cell(0, 0));
+    assert_eq!(chunk[cell(7, 7)], 'd');
+
+    let unloaded = world.unload(cell(-1, -1)).unwrap();
+    assert_eq!(unloaded.origin(), cell(-8, -8));
+    assert_eq!(world.get(cell(-1, -1)), &'x');
+    assert_eq!(world.unload(cell(-1, -1)), None);
+}
+
+#[test]
+fn chunked_map_tracks_changed_chunks() {
+    let mut world = ChunkedMap::new(4, 0);
+    world.set(cell(1, 1), 1);
+    world.set(cell(5, 1), 1);
+
+    assert_eq!(
+        world.changed_chunks().collect::<Vec<_>>(),
+        vec![cell(0, 0), cell(1, 0)]
+    );
+
+    world.clear_changed();
+    assert_eq!(world.changed_chunks().count(), 0);
+
+    // Reading does not mark chunks as changed, but writing does
+    assert_eq!(world.get(cell(1, 1)), &1);
+    world.set(cell(6, 3), 2);
+    assert_eq!(world.changed_chunks().collect::<Vec<_>>(), vec![cell(1, 0)]);
+}
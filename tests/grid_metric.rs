@@ -0,0 +1,137 @@
+use leafwing_2d::discrete::{
+    AdjacentGrid, DiscreteCoordinate, FlatHex, GridMetric, OrthogonalGrid, PointyHex,
+};
+use leafwing_2d::position::Position;
+use std::collections::{HashMap, VecDeque};
+
+/// The number of steps to reach each cell within `radius` of the origin, found by breadth-first search
+fn steps_from_origin<C>(radius: isize) -> HashMap<(isize, isize), usize>
+where
+    C: GridMetric + From<isize> + Into<isize>,
+{
+    let mut steps = HashMap::from([((0, 0), 0)]);
+    let mut frontier = VecDeque::from([(0, 0)]);
+
+    while let Some((x, y)) = frontier.pop_front() {
+        let current = steps[&(x, y)];
+        for neighbor in C::neighbors(Position::new(C::from(x), C::from(y))) {
+            let cell: (isize, isize) = (neighbor.x.into(), neighbor.y.into());
+            // Search a little beyond the radius so that shortest paths can leave the checked area
+            if cell.0.abs() <= 2 * radius && cell.1.abs() <= 2 * radius {
+                steps.entry(cell).or_insert_with(|| {
+                    frontier.push_back(cell);
+                    current + 1
+                });
+            }
+        }
+    }
+
+    steps
+}
+
+fn assert_grid_distance_matches_search<C>()
+where
+    C: GridMetric + From<isize> + Into<isize>,
+{
+    let radius = 5;
+    let steps = steps_from_origin::<C>(radius);
+
+    for x in -radius..=radius {
+        for y in -radius..=radius {
+            let position = Position::new(C::from(x), C::from(y));
+            assert_eq!(
+                C::grid_distance(C::ORIGIN, position),
+                steps[&(x, y)],
+                "{:?}",
+                (x, y)
+            );
+            assert_eq!(
+                C::grid_distance(position, C::ORIGIN),
+                C::grid_distance(C::ORIGIN, position)
+            );
+        }
+    }
+}
+
+#[test]
+fn orthogonal_grid_uses_manhattan_distance() {
+    assert_grid_distance_matches_search::<OrthogonalGrid>();
+
+    let a = Position::new(OrthogonalGrid(-2), OrthogonalGrid(7));
+    let b = Position::new(OrthogonalGrid(1), OrthogonalGrid(3));
+    assert_eq!(OrthogonalGrid::manhattan_distance(a, b), 7);
+    assert_eq!(OrthogonalGrid::grid_distance(a, b), 7);
+}
+
+#[test]
+fn adjacent_grid_uses_chebyshev_distance() {
+    assert_grid_distance_matches_search::<AdjacentGrid>();
+
+    let a = Position::new(AdjacentGrid(-2), AdjacentGrid(7));
+    let b = Position::new(AdjacentGrid(1), AdjacentGrid(3));
+    assert_eq!(AdjacentGrid::chebyshev_distance(a, b), 4);
+    assert_eq!(AdjacentGrid::grid_distance(a, b), 4);
+}
+
+#[test]
+fn hex_grids_use_hex_distance() {
+    assert_grid_distance_matches_search::<FlatHex>();
+    assert_grid_distance_matches_search::<PointyHex>();
+}
+
+#[test]
+fn distances_are_exact_far_from_the_origin() {
+    // Far beyond the range where f32 can tell neighboring cells apart
+    let a = Position::new(OrthogonalGrid(isize::MAX - 3), OrthogonalGrid(0));
+    let b = Position::new(OrthogonalGrid(isize::MAX), OrthogonalGrid(1));
+
+    assert_eq!(OrthogonalGrid::manhattan_distance(a, b), 4);
+    assert_eq!(OrthogonalGrid::chebyshev_distance(a, b), 3);
+}
+
+fn assert_within_range_matches_metric<C>()
+where
+    C: GridMetric + From<isize> + Into<isize>,
+{
+    let center = Position::new(C::from(3), C::from(-2));
+
+    for n in 0..4 {
+        let in_range: Vec<_> = C::within_range(center, n).collect();
+        assert_eq!(in_range[0], center);
+
+        // No duplicates
+        for (i, a) in in_range.iter().enumerate() {
+            assert!(!in_range[i + 1..].contains(a));
+        }
+
+        // Exactly the positions within `n` steps, in order of distance
+        let distances: Vec<usize> = in_range
+            .iter()
+            .map(|&position| C::grid_distance(center, position))
+            .collect();
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(distances.iter().all(|&distance| distance <= n));
+
+        let expected = (-10..=10)
+            .flat_map(|x| (-10..=10).map(move |y| (x, y)))
+            .filter(|&(x, y)| {
+                let position = Position::new(C::from(3 + x), C::from(-2 + y));
+                C::grid_distance(center, position) <= n
+            })
+            .count();
+        assert_eq!(in_range.len(), expected);
+    }
+}
+
+#[test]
+fn within_range_follows_the_grid_shape() {
+    assert_within_range_matches_metric::<OrthogonalGrid>();
+    assert_within_range_matches_metric::<AdjacentGrid>();
+    assert_within_range_matches_metric::<FlatHex>();
+    assert_within_range_matches_metric::<PointyHex>();
+
+    let center = Position::new(OrthogonalGrid(0), OrthogonalGrid(0));
+    // A diamond on orthogonal grids
+    assert_eq!(OrthogonalGrid::within_range(center, 3).count(), 25);
+    assert_eq!(OrthogonalGrid::within_range(center, 0).count(), 1);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::cell;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::{AdjacentGrid, AxialHex, OrthogonalGrid};
+use leafwing_2d::grid_transform::{HexTransform, SquareTransform};
+use leafwing_2d::orientation::Rotation;
+use leafwing_2d::position::{Position, Positionlike};
+
+#[test]
+fn quarter_turns_match_continuous_rotation() {
+    let pivot = Position::<F32>::new(2.0, -1.0);
+    let position = Position::<F32>::new(5.0, 3.0);
+
+    for turns in -4..8 {
+        let rotation = Rotation::from_degrees(90.0 * turns as f32);
+        position
+            .rotated_quarter_turns(pivot, turns)
+            .assert_approx_eq(position.rotated_around(pivot, rotation));
+    }
+}
+
+#[test]
+fn four_quarter_turns_are_the_identity() {
+    let pivot = cell(-3, 7);
+    for position in [cell(0, 0), cell(4, -2), cell(-3, 7)] {
+        let mut rotated = position;
+        for _ in 0..4 {
+            rotated = rotated.rotated_quarter_turns(pivot, 1);
+        }
+
+        assert_eq!(rotated, position);
+    }
+}
+
+#[test]
+fn square_symmetries_are_distinct() {
+    let pivot = Position::<AdjacentGrid>::new(AdjacentGrid(0), AdjacentGrid(0));
+    let position = Position::<AdjacentGrid>::new(AdjacentGrid(1), AdjacentGrid(2));
+
+    let images: Vec<_> = SquareTransform::ALL
+        .iter()
+        .map(|transform| transform.apply(position, pivot))
+        .collect();
+
+    for (i, a) in images.iter().enumerate() {
+        for b in &images[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn square_transforms_preserve_distance_to_pivot() {
+    let pivot = cell(1, 1);
+    let shape = [cell(1, 1), cell(4, 1), cell(1, -2), cell(3, 5)];
+
+    for transform in SquareTransform::ALL {
+        for (before, after) in shape.iter().zip(transform.apply_all(shape, pivot)) {
+            let offset = |p: Position<OrthogonalGrid>| (p.x.0 - pivot.x.0, p.y.0 - pivot.y.0);
+            let (dx, dy) = offset(*before);
+            let (new_dx, new_dy) = offset(after);
+            assert_eq!(dx * dx + dy * dy, new_dx * new_dx + new_dy * new_dy);
+        }
+    }
+}
+
+#[test]
+fn mirroring_twice_is_the_identity() {
+    let transform = SquareTransform::rotation(3).mirrored().mirrored();
+    assert_eq!(transform, SquareTransform::rotation(-1));
+    assert!(!transform.is_mirrored());
+    assert_eq!(transform.quarter_turns(), 3);
+}
+
+#[test]
+fn hex_rotations_follow_neighbor_order() {
+    let pivot = AxialHex::new(3, -2);
+
+    for (i, &offset) in AxialHex::NEIGHBOR_OFFSETS.iter().enumerate() {
+        for turns in -6..12 {
+            let expected = AxialHex::NEIGHBOR_OFFSETS[(i as i32 + turns).rem_euclid(6) as usize];
+            assert_eq!(
+                (pivot + offset).rotated_around(pivot, turns),
+                pivot + expected
+            );
+        }
+    }
+}
+
+#[test]
+fn hex_transforms_preserve_distance_to_pivot() {
+    let pivot = AxialHex::new(-1, 2);
+    let shape = [
+        AxialHex::new(-1, 2),
+        AxialHex::new(2, 0),
+        AxialHex::new(0, -3),
+    ];
+
+    for transform in HexTransform::ALL {
+        for (before, after) in shape.iter().zip(transform.apply_all(shape, pivot)) {
+            assert_eq!(before.distance(pivot), after.distance(pivot));
+        }
+    }
+}
+
+#[test]
+fn hex_symmetries_are_distinct() {
+    let hex = AxialHex::new(2, 1);
+
+    let images: Vec<_> = HexTransform::ALL
+        .iter()
+        .map(|transform| transform.apply(hex, AxialHex::ORIGIN))
+        .collect();
+
+    for (i, a) in images.iter().enumerate() {
+        for b in &images[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn hex_mirror_is_an_involution() {
+    let pivot = AxialHex::new(1, 1);
+    for hex in pivot.neighbors() {
+        assert_eq!(hex.mirrored_across(pivot).mirrored_across(pivot), hex);
+        assert_eq!(hex.mirrored_across(pivot).distance(pivot), 1);
+    }
+}
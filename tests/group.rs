@@ -0,0 +1,82 @@
+mod common;
+
+use bevy::prelude::*;
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::group::{update_groups, Group, GroupMember};
+use leafwing_2d::kinematics::Velocity;
+use leafwing_2d::position::Position;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_system(update_groups::<F32>);
+
+    app
+}
+
+#[test]
+fn groups_aggregate_their_members() {
+    let mut app = test_app();
+
+    let squad = app.world.spawn().insert(Group::<F32>::default()).id();
+    let other_squad = app.world.spawn().insert(Group::<F32>::default()).id();
+
+    app.world.spawn().insert_bundle((
+        GroupMember(squad),
+        Position::<F32>::new(0.0, 0.0),
+        Velocity::<F32> {
+            x: F32(3.0),
+            y: F32(0.0),
+        },
+    ));
+    app.world.spawn().insert_bundle((
+        GroupMember(squad),
+        Position::<F32>::new(4.0, 2.0),
+        AxisAlignedBoundingBox::<F32>::new(3.0, 5.0, 1.0, 3.0),
+    ));
+    app.world.spawn().insert_bundle((
+        GroupMember(squad),
+        Position::<F32>::new(2.0, -2.0),
+        Velocity::<F32> {
+            x: F32(0.0),
+            y: F32(6.0),
+        },
+    ));
+    // Belongs to another group
+    let straggler = app
+        .world
+        .spawn()
+        .insert_bundle((GroupMember(other_squad), Position::<F32>::new(100.0, 0.0)))
+        .id();
+
+    app.update();
+
+    let group = app.world.get::<Group<F32>>(squad).unwrap();
+    assert_eq!(group.count(), 3);
+    assert_eq!(group.centroid(), Position::new(2.0, 0.0));
+    assert_eq!(
+        group.average_velocity(),
+        Velocity {
+            x: F32(1.0),
+            y: F32(2.0)
+        }
+    );
+    assert_eq!(
+        group.bounds(),
+        Some(&AxisAlignedBoundingBox::new(0.0, 5.0, -2.0, 3.0))
+    );
+
+    let other_group = app.world.get::<Group<F32>>(other_squad).unwrap();
+    assert_eq!(other_group.count(), 1);
+    assert_eq!(
+        other_group.bounds(),
+        Some(&AxisAlignedBoundingBox::new(100.0, 100.0, 0.0, 0.0))
+    );
+
+    // Groups without any members are emptied
+    app.world.despawn(straggler);
+    app.update();
+    let other_group = app.world.get::<Group<F32>>(other_squad).unwrap();
+    assert!(other_group.is_empty());
+    assert_eq!(other_group.bounds(), None);
+}
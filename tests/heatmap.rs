@@ -0,0 +1,89 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::heatmap::{update_occupancy_heatmap, OccupancyHeatmap, Occupant};
+use leafwing_2d::position::Position;
+
+#[test]
+fn positions_are_binned_into_cells() {
+    let heatmap = OccupancyHeatmap::<F32>::new(4, 2, Position::new(-20.0, -10.0), 10.0, 1.0);
+
+    assert_eq!(
+        heatmap.cell_at(Position::new(-20.0, -10.0)),
+        Some(Position::new(OrthogonalGrid(0), OrthogonalGrid(0)))
+    );
+    assert_eq!(
+        heatmap.cell_at(Position::new(-0.5, 0.0)),
+        Some(Position::new(OrthogonalGrid(1), OrthogonalGrid(1)))
+    );
+    assert_eq!(
+        heatmap.cell_center(Position::new(OrthogonalGrid(3), OrthogonalGrid(0))),
+        Position::new(15.0, -5.0)
+    );
+
+    // Just outside of the edges
+    assert_eq!(heatmap.cell_at(Position::new(-20.1, 0.0)), None);
+    assert_eq!(heatmap.cell_at(Position::new(20.0, 0.0)), None);
+    assert_eq!(heatmap.cell_at(Position::new(0.0, 10.0)), None);
+}
+
+#[test]
+fn occupants_heat_up_their_cells() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    world.insert_resource(OccupancyHeatmap::<F32>::new(
+        10,
+        10,
+        Position::new(0.0, 0.0),
+        1.0,
+        f32::INFINITY,
+    ));
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_occupancy_heatmap::<F32>);
+
+    let crowd = Position::new(2.5, 2.5);
+    for _ in 0..3 {
+        world.spawn().insert_bundle((crowd, Occupant));
+    }
+    let loner = Position::new(7.5, 7.5);
+    world.spawn().insert_bundle((loner, Occupant));
+    // Not tracked
+    let wall = Position::new(5.5, 5.5);
+    world.spawn().insert(wall);
+    // Out of bounds
+    world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(-5.0, 0.0), Occupant));
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    world.resource_mut::<Time>().update();
+    let seconds = world.resource::<Time>().delta_seconds();
+    stage.run(&mut world);
+
+    let heatmap = world.resource::<OccupancyHeatmap<F32>>();
+    assert_eq!(heatmap.heat_at(crowd), 3. * seconds);
+    assert_eq!(heatmap.heat_at(loner), seconds);
+    assert_eq!(heatmap.heat_at(wall), 0.);
+    assert_eq!(heatmap.hottest_cell_center(), Some(crowd));
+
+    let total: f32 = heatmap.grid().iter().map(|(_, &heat)| heat).sum();
+    assert!((total - 4. * seconds).abs() < 1e-6);
+}
+
+#[test]
+fn heat_fades_away() {
+    let mut heatmap = OccupancyHeatmap::<F32>::new(1, 1, Position::new(0.0, 0.0), 1.0, 2.0);
+    let center = Position::new(0.5, 0.5);
+    heatmap.add(center, 8.0);
+
+    heatmap.decay(core::time::Duration::from_secs(4));
+    assert!((heatmap.heat_at(center) - 2.0).abs() < 1e-5);
+
+    heatmap.clear();
+    assert_eq!(heatmap.heat_at(center), 0.0);
+    assert_eq!(heatmap.hottest_cell_center(), None);
+}
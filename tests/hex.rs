@@ -0,0 +1,94 @@
+use bevy::math::Vec2;
+use leafwing_2d::discrete::{AxialHex, CubeHex, HexOrientation};
+
+const ORIENTATIONS: [HexOrientation; 2] = [HexOrientation::PointyTop, HexOrientation::FlatTop];
+
+#[test]
+fn neighbors_are_one_step_away() {
+    let hex = AxialHex::new(3, -5);
+    let neighbors = hex.neighbors();
+
+    for neighbor in neighbors {
+        assert_eq!(hex.distance(neighbor), 1);
+        assert_eq!(neighbor.distance(hex), 1);
+    }
+
+    for (i, a) in neighbors.iter().enumerate() {
+        for b in &neighbors[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn neighbors_are_clockwise_from_north() {
+    for orientation in ORIENTATIONS {
+        let angles: Vec<f32> = AxialHex::ORIGIN
+            .neighbors()
+            .iter()
+            .map(|neighbor| {
+                let offset = neighbor.to_world(orientation, 1.0);
+                // Clockwise angle from north
+                offset.x.atan2(offset.y).rem_euclid(core::f32::consts::TAU)
+            })
+            .collect();
+
+        for pair in angles.windows(2) {
+            assert!(pair[0] < pair[1], "{orientation:?}: {angles:?}");
+        }
+        // Every neighbor is the same distance away in world space
+        for neighbor in AxialHex::ORIGIN.neighbors() {
+            let length = neighbor.to_world(orientation, 2.0).length();
+            assert!((length - 2.0 * 3f32.sqrt()).abs() < 1e-5);
+        }
+    }
+
+    // Flat-topped hexes have a neighbor directly north
+    let north = AxialHex::ORIGIN.neighbors()[0].to_world(HexOrientation::FlatTop, 1.0);
+    assert!(north.x.abs() < 1e-6 && north.y > 0.0);
+}
+
+#[test]
+fn hex_distance() {
+    assert_eq!(AxialHex::ORIGIN.distance(AxialHex::ORIGIN), 0);
+    assert_eq!(AxialHex::ORIGIN.distance(AxialHex::new(3, 0)), 3);
+    assert_eq!(AxialHex::ORIGIN.distance(AxialHex::new(3, -3)), 3);
+    assert_eq!(AxialHex::ORIGIN.distance(AxialHex::new(3, 3)), 6);
+    assert_eq!(AxialHex::new(-2, 1).distance(AxialHex::new(1, 2)), 4);
+}
+
+#[test]
+fn cube_coordinates_sum_to_zero() {
+    let hex = AxialHex::new(4, -7);
+    let cube = CubeHex::from(hex);
+
+    assert_eq!(cube.q + cube.r + cube.s, 0);
+    assert_eq!(cube.s, hex.s());
+    assert_eq!(AxialHex::from(cube), hex);
+}
+
+#[test]
+fn world_space_round_trip() {
+    for orientation in ORIENTATIONS {
+        for q in -4..=4 {
+            for r in -4..=4 {
+                let hex = AxialHex::new(q, r);
+                let center = hex.to_world(orientation, 16.0);
+
+                assert_eq!(AxialHex::from_world(center, orientation, 16.0), hex);
+
+                // Points well inside the hex still map to it
+                for neighbor in hex.neighbors() {
+                    let toward = neighbor.to_world(orientation, 16.0) - center;
+                    let inside = center + toward * 0.4;
+                    assert_eq!(AxialHex::from_world(inside, orientation, 16.0), hex);
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        AxialHex::from_world(Vec2::ZERO, HexOrientation::PointyTop, 1.0),
+        AxialHex::ORIGIN
+    );
+}
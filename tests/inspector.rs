@@ -0,0 +1,78 @@
+#![cfg(feature = "inspector")]
+
+use bevy::prelude::*;
+use bevy_inspector_egui::egui;
+use bevy_inspector_egui::{Context, Inspectable, InspectableRegistry, InspectorPlugin};
+use leafwing_2d::prelude::*;
+use leafwing_2d::scale::Scale;
+
+/// One of each of the components that can be inspected, shown in a single inspector window
+#[derive(Inspectable)]
+struct InspectedComponents {
+    position: Position<F32>,
+    velocity: Velocity<F32>,
+    acceleration: Acceleration<F32>,
+    aabb: AxisAlignedBoundingBox<F32>,
+    scale: Scale<F32>,
+    rotation: Rotation,
+    direction: Direction,
+    angular_velocity: AngularVelocity,
+    angular_acceleration: AngularAcceleration,
+}
+
+impl Default for InspectedComponents {
+    fn default() -> Self {
+        InspectedComponents {
+            position: Position::new(1.0, -2.0),
+            velocity: Velocity::default(),
+            acceleration: Acceleration::default(),
+            aabb: AxisAlignedBoundingBox::new(-1.0, 1.0, -2.0, 2.0),
+            scale: Scale::default(),
+            rotation: Rotation::from_degrees(90.0),
+            direction: Direction::EAST,
+            angular_velocity: AngularVelocity::from_degrees_per_second(45.0),
+            angular_acceleration: AngularAcceleration::default(),
+        }
+    }
+}
+
+fn inspector_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(TwoDPlugin::default())
+        .add_plugin(InspectorPlugin::<InspectedComponents>::new());
+
+    app
+}
+
+#[test]
+fn plugins_register_inspectables() {
+    let app = inspector_app();
+
+    assert!(app.world.contains_resource::<InspectableRegistry>());
+    assert!(app.world.contains_resource::<InspectedComponents>());
+}
+
+#[test]
+fn components_can_be_drawn() {
+    let mut app = inspector_app();
+    let mut inspected = app.world.resource_mut::<InspectedComponents>();
+
+    // Draw a single frame without any input, which must leave every component untouched
+    let ctx = egui::Context::default();
+    let mut changed = false;
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let mut context = Context::new_shared(Some(ctx));
+            changed = inspected.ui(ui, (), &mut context);
+        });
+    });
+
+    assert!(!changed);
+    assert_eq!(inspected.position, Position::new(1.0, -2.0));
+    assert_eq!(inspected.rotation, Rotation::from_degrees(90.0));
+    assert_eq!(
+        inspected.angular_velocity,
+        AngularVelocity::from_degrees_per_second(45.0)
+    );
+}
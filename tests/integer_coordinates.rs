@@ -0,0 +1,80 @@
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::{DiscreteCoordinate, GridMetric, OrthogonalGrid, I16, I32, I8};
+use leafwing_2d::position::Position;
+
+#[test]
+fn values_are_distinct() {
+    I8::assert_values_distinct();
+    I16::assert_values_distinct();
+    I32::assert_values_distinct();
+}
+
+#[test]
+fn every_small_value_round_trips() {
+    for value in i8::MIN..=i8::MAX {
+        assert_eq!(I8(value).round_trip_coordinate_error(), I8::ZERO);
+    }
+
+    for value in i16::MIN..=i16::MAX {
+        assert_eq!(I16(value).round_trip_coordinate_error(), I16::ZERO);
+    }
+}
+
+#[test]
+fn i32_range_is_exact_in_f32() {
+    let max: f32 = I32::MAX.into();
+    let min: f32 = I32::MIN.into();
+
+    assert_eq!(max, 16_777_216.0);
+    assert_eq!(min, -16_777_216.0);
+    assert_eq!(I32::from(f32::MAX), I32::MAX);
+    assert_eq!(I32::from(f32::MIN), I32::MIN);
+}
+
+#[test]
+fn floats_are_clamped() {
+    assert_eq!(I8::from(1000.0), I8::MAX);
+    assert_eq!(I8::from(-1000.0), I8::MIN);
+    assert_eq!(I16::from(2.6), I16(3));
+}
+
+#[test]
+fn next_and_prev_saturate() {
+    assert_eq!(I8(3).next(), I8(4));
+    assert_eq!(I8::MAX.next(), I8::MAX);
+    assert_eq!(I16::MIN.prev(), I16::MIN);
+    assert_eq!(I32::MAX.next(), I32::MAX);
+    assert_eq!(I32::MIN.prev(), I32::MIN);
+}
+
+#[test]
+fn neighbors_match_orthogonal_grid() {
+    let neighbors = I16::neighbors(Position::new(I16(5), I16(-2)));
+    let expected = OrthogonalGrid::neighbors(Position::new(OrthogonalGrid(5), OrthogonalGrid(-2)));
+
+    assert_eq!(neighbors.len(), expected.len());
+    for (neighbor, expected) in neighbors.iter().zip(expected) {
+        assert_eq!(neighbor.x.0 as isize, expected.x.0);
+        assert_eq!(neighbor.y.0 as isize, expected.y.0);
+    }
+
+    assert_eq!(
+        I16::neighbor_directions(),
+        OrthogonalGrid::neighbor_directions()
+    );
+}
+
+#[test]
+fn neighbors_stop_at_edges() {
+    assert_eq!(I8::neighbors(Position::new(I8::MAX, I8(0))).len(), 3);
+    assert_eq!(I32::neighbors(Position::new(I32::MIN, I32::MAX)).len(), 2);
+}
+
+#[test]
+fn distances_do_not_overflow() {
+    let a = Position::new(I8::MIN, I8::MIN);
+    let b = Position::new(I8::MAX, I8::MAX);
+
+    assert_eq!(I8::grid_distance(a, b), 510);
+    assert_eq!(I8::chebyshev_distance(a, b), 255);
+}
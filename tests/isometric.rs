@@ -0,0 +1,91 @@
+use bevy::math::{Vec2, Vec3};
+use bevy::transform::components::Transform;
+use leafwing_2d::discrete::{DiscreteCoordinate, GridMetric, Isometric};
+use leafwing_2d::orientation::Direction;
+use leafwing_2d::position::{positions_to_vec2, Position};
+
+fn tile(x: isize, y: isize) -> Position<Isometric> {
+    Position::new(Isometric(x), Isometric(y))
+}
+
+#[test]
+fn diamond_projection() {
+    let half_width = Isometric::TILE_WIDTH / 2.;
+    let half_height = Isometric::TILE_HEIGHT / 2.;
+
+    assert_eq!(Vec2::from(tile(0, 0)), Vec2::ZERO);
+    assert_eq!(Vec2::from(tile(1, 0)), Vec2::new(half_width, half_height));
+    assert_eq!(Vec2::from(tile(0, 1)), Vec2::new(-half_width, half_height));
+    // Tiles along the diagonal of the grid stack straight up the screen
+    assert_eq!(Vec2::from(tile(3, 3)), Vec2::new(0.0, 6.0 * half_height));
+    assert_eq!(
+        Vec3::from(tile(2, -1)),
+        Vec3::new(3.0 * half_width, half_height, 0.0)
+    );
+}
+
+#[test]
+fn transforms_round_trip() {
+    for x in -5..=5 {
+        for y in -5..=5 {
+            let position = tile(x, y);
+            let transform = Transform::from(position);
+
+            assert_eq!(transform.translation.truncate(), Vec2::from(position));
+            assert_eq!(Position::<Isometric>::from(transform), position);
+        }
+    }
+}
+
+#[test]
+fn screen_points_map_to_the_diamond_containing_them() {
+    let center = Vec2::from(tile(2, 4));
+
+    // Just inside each of the four corners of the diamond
+    for offset in [
+        Vec2::new(0.0, 0.45 * Isometric::TILE_HEIGHT),
+        Vec2::new(0.45 * Isometric::TILE_WIDTH, 0.0),
+        Vec2::new(0.0, -0.45 * Isometric::TILE_HEIGHT),
+        Vec2::new(-0.45 * Isometric::TILE_WIDTH, 0.0),
+    ] {
+        assert_eq!(Position::<Isometric>::from(center + offset), tile(2, 4));
+    }
+
+    // Just past the top corner is the tile above
+    let above = center + Vec2::new(0.0, 0.55 * Isometric::TILE_HEIGHT);
+    assert_eq!(Position::<Isometric>::from(above), tile(3, 5));
+}
+
+#[test]
+fn neighbors_share_an_edge() {
+    let neighbors = Isometric::neighbors(tile(0, 0));
+    assert_eq!(
+        neighbors,
+        vec![tile(1, 0), tile(0, -1), tile(-1, 0), tile(0, 1)]
+    );
+
+    // Clockwise from north on screen
+    assert_eq!(
+        Isometric::neighbor_directions(),
+        vec![
+            Direction::new(Vec2::new(2.0, 1.0)),
+            Direction::new(Vec2::new(2.0, -1.0)),
+            Direction::new(Vec2::new(-2.0, -1.0)),
+            Direction::new(Vec2::new(-2.0, 1.0)),
+        ]
+    );
+
+    for neighbor in neighbors {
+        assert_eq!(Isometric::grid_distance(tile(0, 0), neighbor), 1);
+    }
+}
+
+#[test]
+fn batch_conversions_are_projected() {
+    let positions = vec![tile(1, 0), tile(0, 1), tile(5, -2)];
+    let mut buffer = Vec::new();
+    positions_to_vec2(&positions, &mut buffer);
+
+    let expected: Vec<Vec2> = positions.iter().map(|&position| position.into()).collect();
+    assert_eq!(buffer, expected);
+}
@@ -0,0 +1,979 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::{Entity, ParallelSystemDescriptorCoercion, Time, Vec2, World};
+use core::time::Duration;
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::systems::{
+    angular_dynamics, angular_kinematics, apply_knockback, carry_riders,
+    direction_angular_kinematics, face_velocity, follow_targets, force_fields, linear_dynamics,
+    linear_kinematics,
+};
+use leafwing_2d::kinematics::{
+    damp_towards, predict, Acceleration, AngularAcceleration, AngularVelocity, CarriedBy,
+    FaceVelocity, Follow, Force, ForceField, Gravity, GravityAffected, IntegratorState, Kinematic,
+    KinematicState, KinematicsIntegrator, Knockback, KnockbackDecay, Mass, MomentOfInertia,
+    RotationRemainder, Torque, Velocity,
+};
+use leafwing_2d::orientation::{Direction, Orientation, Rotation, RotationDirection};
+use leafwing_2d::position::{Position, Positionlike};
+
+#[test]
+fn angular_velocity_units() {
+    let half_turn = AngularVelocity::from_radians_per_second(core::f32::consts::PI);
+    assert!((half_turn.deci_degrees - 1800.).abs() < 1e-3);
+    assert!((half_turn.into_degrees_per_second() - 180.).abs() < 1e-4);
+    assert!((half_turn.into_radians_per_second() - core::f32::consts::PI).abs() < 1e-6);
+
+    // Fractions of a tenth of a degree are kept
+    assert_eq!(
+        AngularVelocity::from_degrees_per_second(12.34).deci_degrees,
+        123.4
+    );
+    assert_eq!(
+        AngularVelocity::from_degrees_per_second(-12.36).deci_degrees,
+        -123.6
+    );
+
+    let clockwise =
+        AngularVelocity::from_rotation_per_second(Rotation::SOUTH, RotationDirection::Clockwise);
+    assert_eq!(clockwise, AngularVelocity::from_degrees_per_second(180.0));
+    assert_eq!(clockwise.direction(), Some(RotationDirection::Clockwise));
+}
+
+#[test]
+fn rotation_per_second() {
+    assert_eq!(
+        AngularVelocity::default().into_rotation_per_second(),
+        (Rotation::NORTH, None)
+    );
+
+    // More than a full turn per second wraps around
+    assert_eq!(
+        AngularVelocity::from_degrees_per_second(450.0).into_rotation_per_second(),
+        (Rotation::EAST, Some(RotationDirection::Clockwise))
+    );
+}
+
+#[test]
+fn vec2_interoperates_with_kinematics() {
+    let vec2 = Vec2::new(-3.0, 4.0);
+    assert_eq!(vec2.magnitude(), 5.0);
+    assert_eq!(vec2.magnitude_squared(), 25.0);
+    assert_eq!(Vec2::ZERO.direction(), None);
+    assert_eq!(
+        <Vec2 as Kinematic>::new(5.0, vec2.direction().unwrap()),
+        vec2
+    );
+
+    let velocity = Velocity::<F32>::from(vec2);
+    assert_eq!(
+        velocity,
+        Velocity {
+            x: F32(-3.0),
+            y: F32(4.0)
+        }
+    );
+    assert_eq!(Vec2::from(velocity), vec2);
+    assert_eq!(velocity.direction(), vec2.direction());
+
+    let acceleration = Acceleration::<F32>::from(vec2);
+    assert_eq!(Vec2::from(acceleration), vec2);
+}
+
+#[test]
+fn force_is_converted_into_acceleration() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(linear_dynamics::<F32>);
+
+    let crate_entity = world
+        .spawn()
+        .insert_bundle((
+            Force::<F32>::default(),
+            Mass(F32(4.0)),
+            Acceleration::<F32>::default(),
+        ))
+        .id();
+    let anchored = world
+        .spawn()
+        .insert_bundle((
+            Force::<F32>::default(),
+            Mass(F32(0.0)),
+            Acceleration::<F32>::default(),
+        ))
+        .id();
+
+    // Forces accumulate from several sources, rather than overwriting each other
+    for entity in [crate_entity, anchored] {
+        let mut force = world.get_mut::<Force<F32>>(entity).unwrap();
+        *force += Force {
+            x: F32(10.0),
+            y: F32(0.0),
+        };
+        *force += Force {
+            x: F32(-2.0),
+            y: F32(-4.0),
+        };
+    }
+
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(crate_entity).unwrap(),
+        Acceleration {
+            x: F32(2.0),
+            y: F32(-1.0)
+        }
+    );
+    assert_eq!(
+        *world.get::<Force<F32>>(crate_entity).unwrap(),
+        Force::default()
+    );
+    // Entities without a positive mass can't be pushed
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(anchored).unwrap(),
+        Acceleration::default()
+    );
+
+    // Once the forces stop, so does the acceleration
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(crate_entity).unwrap(),
+        Acceleration::default()
+    );
+    assert_eq!(Mass::<F32>::default(), Mass(F32(1.0)));
+}
+
+#[test]
+fn torque_is_converted_into_angular_acceleration() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(angular_dynamics);
+
+    let door = world
+        .spawn()
+        .insert_bundle((
+            Torque::default(),
+            MomentOfInertia::rod(3.0, 2.0),
+            AngularAcceleration::default(),
+        ))
+        .id();
+    let anchored = world
+        .spawn()
+        .insert_bundle((
+            Torque::default(),
+            MomentOfInertia(0.0),
+            AngularAcceleration::default(),
+        ))
+        .id();
+
+    // Torque accumulates from several sources
+    for entity in [door, anchored] {
+        let mut torque = world.get_mut::<Torque>(entity).unwrap();
+        *torque += Torque::new(100.0, RotationDirection::Clockwise);
+        *torque += Torque::new(20.0, RotationDirection::CounterClockwise);
+    }
+
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<AngularAcceleration>(door).unwrap(),
+        AngularAcceleration { deci_degrees: 20.0 }
+    );
+    assert_eq!(*world.get::<Torque>(door).unwrap(), Torque(0.0));
+    // Entities without a positive moment of inertia can't be spun
+    assert_eq!(
+        *world.get::<AngularAcceleration>(anchored).unwrap(),
+        AngularAcceleration::default()
+    );
+
+    // Without any torque, the door stops accelerating
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<AngularAcceleration>(door).unwrap(),
+        AngularAcceleration::default()
+    );
+}
+
+/// Advances the [`Time`] in the `world` by a little, returning how long passed
+fn tick(world: &mut World) -> f32 {
+    std::thread::sleep(Duration::from_millis(10));
+    let mut time = world.resource_mut::<Time>();
+    time.update();
+    time.delta_seconds()
+}
+
+#[test]
+fn kinematics_integrators() {
+    for integrator in [
+        KinematicsIntegrator::Euler,
+        KinematicsIntegrator::SemiImplicitEuler,
+        KinematicsIntegrator::Verlet,
+    ] {
+        let mut world = World::new();
+        let mut time = Time::default();
+        time.update();
+        world.insert_resource(time);
+        world.insert_resource(integrator);
+
+        let mut stage = SystemStage::single_threaded();
+        stage.add_system(linear_kinematics::<F32>);
+
+        // Thrown sideways, and falling under gravity
+        let ball = world
+            .spawn()
+            .insert_bundle((
+                Position::<F32>::default(),
+                Velocity::<F32> {
+                    x: F32(1.0),
+                    y: F32(0.0),
+                },
+                Acceleration::<F32> {
+                    x: F32(0.0),
+                    y: F32(-10.0),
+                },
+            ))
+            .id();
+
+        let dt = tick(&mut world);
+        stage.run(&mut world);
+
+        let (expected_height, expected_fall_speed) = match integrator {
+            KinematicsIntegrator::Euler => (0.0, -10.0 * dt),
+            KinematicsIntegrator::SemiImplicitEuler => (-10.0 * dt * dt, -10.0 * dt),
+            KinematicsIntegrator::Verlet => (-5.0 * dt * dt, -10.0 * dt),
+        };
+        let position = *world.get::<Position<F32>>(ball).unwrap();
+        let velocity = *world.get::<Velocity<F32>>(ball).unwrap();
+        assert!((position.x.0 - dt).abs() < 1e-6, "{integrator:?}");
+        assert!(
+            (position.y.0 - expected_height).abs() < 1e-6,
+            "{integrator:?}"
+        );
+        assert_eq!(velocity.x, F32(1.0));
+        assert!(
+            (velocity.y.0 - expected_fall_speed).abs() < 1e-6,
+            "{integrator:?}"
+        );
+
+        if integrator == KinematicsIntegrator::Verlet {
+            // Verlet follows the exact arc, no matter how the frames are split up
+            let second_dt = tick(&mut world);
+            stage.run(&mut world);
+            let total = dt + second_dt;
+            let position = *world.get::<Position<F32>>(ball).unwrap();
+            let velocity = *world.get::<Velocity<F32>>(ball).unwrap();
+            assert!((position.y.0 + 5.0 * total * total).abs() < 1e-5);
+            assert!((velocity.y.0 + 10.0 * total).abs() < 1e-5);
+        }
+    }
+}
+
+/// Spawns an entity at rest, accelerating downwards
+fn spawn_falling(world: &mut World) -> Entity {
+    world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration::<F32> {
+                x: F32(0.0),
+                y: F32(-10.0),
+            },
+        ))
+        .id()
+}
+
+#[test]
+fn verlet_does_not_kick_new_entities() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    world.insert_resource(KinematicsIntegrator::Verlet);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(linear_kinematics::<F32>);
+
+    spawn_falling(&mut world);
+    tick(&mut world);
+    stage.run(&mut world);
+
+    // Spawned after other entities have already been moved
+    let latecomer = spawn_falling(&mut world);
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    let position = *world.get::<Position<F32>>(latecomer).unwrap();
+    let velocity = *world.get::<Velocity<F32>>(latecomer).unwrap();
+    assert!((position.y.0 + 5.0 * dt * dt).abs() < 1e-6);
+    assert!((velocity.y.0 + 10.0 * dt).abs() < 1e-6);
+}
+
+#[test]
+fn switching_to_verlet_does_not_kick_entities() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    world.insert_resource(KinematicsIntegrator::SemiImplicitEuler);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(linear_kinematics::<F32>);
+
+    let ball = spawn_falling(&mut world);
+    tick(&mut world);
+    stage.run(&mut world);
+    let velocity_before = *world.get::<Velocity<F32>>(ball).unwrap();
+
+    world.insert_resource(KinematicsIntegrator::Verlet);
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    let velocity = *world.get::<Velocity<F32>>(ball).unwrap();
+    assert!((velocity.y.0 - velocity_before.y.0 + 10.0 * dt).abs() < 1e-6);
+}
+
+#[test]
+fn gravity_only_pulls_affected_entities() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+    world.insert_resource(Gravity(Acceleration::<F32> {
+        x: F32(0.0),
+        y: F32(-10.0),
+    }));
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(linear_kinematics::<F32>);
+
+    let at_rest = (
+        Position::<F32>::default(),
+        Velocity::<F32>::default(),
+        Acceleration::<F32>::default(),
+    );
+    let falling = world
+        .spawn()
+        .insert_bundle(at_rest)
+        .insert(GravityAffected)
+        .id();
+    let floating = world.spawn().insert_bundle(at_rest).id();
+
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    let velocity = *world.get::<Velocity<F32>>(falling).unwrap();
+    assert_eq!(velocity.x, F32(0.0));
+    assert!((velocity.y.0 + 10.0 * dt).abs() < 1e-6);
+    assert_eq!(
+        *world.get::<Velocity<F32>>(floating).unwrap(),
+        Velocity::default()
+    );
+}
+
+/// The highest point that `entity` will reach, given its current motion and `gravity`
+fn apex(world: &World, entity: Entity, gravity: f32) -> f32 {
+    let height = world.get::<Position<F32>>(entity).unwrap().y.0;
+    let rising_speed = world.get::<Velocity<F32>>(entity).unwrap().y.0;
+
+    height + rising_speed * rising_speed / (2.0 * gravity)
+}
+
+#[test]
+fn verlet_jumps_reach_the_same_height_at_any_frame_rate() {
+    let mut apexes = Vec::new();
+
+    for frame_time in [
+        Duration::from_secs_f32(1. / 30.),
+        Duration::from_secs_f32(1. / 144.),
+    ] {
+        let mut world = World::new();
+        let mut time = Time::default();
+        time.update();
+        world.insert_resource(time);
+        world.insert_resource(KinematicsIntegrator::Verlet);
+        world.insert_resource(Gravity(Acceleration::<F32> {
+            x: F32(0.0),
+            y: F32(-20.0),
+        }));
+
+        let mut stage = SystemStage::single_threaded();
+        stage.add_system(linear_kinematics::<F32>);
+
+        let jumper = world
+            .spawn()
+            .insert_bundle((
+                Position::<F32>::default(),
+                Velocity::<F32> {
+                    x: F32(0.0),
+                    y: F32(10.0),
+                },
+                Acceleration::<F32>::default(),
+                GravityAffected,
+            ))
+            .id();
+
+        for _ in 0..5 {
+            std::thread::sleep(frame_time);
+            world.resource_mut::<Time>().update();
+            stage.run(&mut world);
+
+            // A jump of 10 units per second against a gravity of 20 always peaks at 2.5
+            assert!((apex(&world, jumper, 20.0) - 2.5).abs() < 1e-4);
+        }
+        apexes.push(apex(&world, jumper, 20.0));
+    }
+
+    assert!((apexes[0] - apexes[1]).abs() < 1e-4);
+}
+
+#[test]
+fn entities_turn_to_face_their_velocity() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(face_velocity::<F32>);
+
+    let eastwards = Velocity::<F32> {
+        x: F32(3.0),
+        y: F32(0.0),
+    };
+    let fish = world
+        .spawn()
+        .insert_bundle((eastwards, FaceVelocity::default(), Rotation::NORTH))
+        .id();
+    let arrow = world
+        .spawn()
+        .insert_bundle((eastwards, FaceVelocity::default(), Direction::NORTH))
+        .id();
+    let missile = world
+        .spawn()
+        .insert_bundle((
+            eastwards,
+            FaceVelocity {
+                max_turn_rate: Some(AngularVelocity::from_degrees_per_second(90.0)),
+            },
+            Rotation::NORTH,
+        ))
+        .id();
+    let parked = world
+        .spawn()
+        .insert_bundle((
+            Velocity::<F32>::default(),
+            FaceVelocity::default(),
+            Rotation::SOUTH,
+        ))
+        .id();
+
+    let dt = tick(&mut world);
+    stage.run(&mut world);
+
+    world
+        .get::<Rotation>(fish)
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+    world
+        .get::<Direction>(arrow)
+        .unwrap()
+        .assert_approx_eq(Direction::EAST);
+    assert_eq!(*world.get::<Rotation>(parked).unwrap(), Rotation::SOUTH);
+
+    // The missile turns clockwise, but can't get all of the way there in a single frame
+    let turned = world.get::<Rotation>(missile).unwrap().into_degrees();
+    assert!((turned - 90.0 * dt).abs() <= 0.1, "{turned} {dt}");
+}
+
+#[test]
+fn direction_only_entities_turn_like_rotation_entities() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(angular_kinematics);
+    stage.add_system(direction_angular_kinematics);
+
+    let spin = (
+        AngularVelocity::from_degrees_per_second(90.0),
+        AngularAcceleration {
+            deci_degrees: 450.0,
+        },
+    );
+    let rotation_only = world
+        .spawn()
+        .insert_bundle((Rotation::from_degrees(30.0), spin.0, spin.1))
+        .id();
+    let direction_only = world
+        .spawn()
+        .insert_bundle((
+            Direction::from(Rotation::from_degrees(30.0)),
+            spin.0,
+            spin.1,
+        ))
+        .id();
+    // Entities with both are only turned once, by `angular_kinematics`
+    let both = world
+        .spawn()
+        .insert_bundle((
+            Rotation::from_degrees(30.0),
+            Direction::from(Rotation::from_degrees(30.0)),
+            spin.0,
+            spin.1,
+        ))
+        .id();
+
+    stage.run(&mut world);
+
+    let rotation = *world.get::<Rotation>(rotation_only).unwrap();
+    assert_eq!(
+        *world.get::<Direction>(direction_only).unwrap(),
+        Direction::from(rotation)
+    );
+    assert_eq!(
+        world.get::<AngularVelocity>(direction_only),
+        world.get::<AngularVelocity>(rotation_only)
+    );
+    assert_eq!(*world.get::<Rotation>(both).unwrap(), rotation);
+    assert_eq!(
+        *world.get::<Direction>(both).unwrap(),
+        Direction::from(Rotation::from_degrees(30.0))
+    );
+}
+
+#[test]
+fn angular_motion_within_a_second() {
+    // Less than a second of turning still turns
+    assert_eq!(
+        AngularVelocity::from_degrees_per_second(90.0) * Duration::from_millis(500),
+        Rotation::from_degrees(45.0)
+    );
+    assert_eq!(
+        AngularVelocity::from_degrees_per_second(-90.0) * Duration::from_millis(500),
+        Rotation::from_degrees(-45.0)
+    );
+    assert_eq!(
+        AngularAcceleration {
+            deci_degrees: 100.0
+        } * Duration::from_millis(250),
+        AngularVelocity { deci_degrees: 25.0 }
+    );
+
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(angular_kinematics);
+
+    // Turns far less than a tenth of a degree each frame
+    let slow_spin = world
+        .spawn()
+        .insert_bundle((
+            Rotation::NORTH,
+            AngularVelocity::from_degrees_per_second(2.0),
+            AngularAcceleration::default(),
+        ))
+        .id();
+
+    let mut seconds = 0.;
+    for _ in 0..20 {
+        seconds += tick(&mut world);
+        stage.run(&mut world);
+    }
+
+    let expected = Rotation::from_degrees(2.0 * seconds);
+    let rotation = *world.get::<Rotation>(slow_spin).unwrap();
+    assert!(rotation.distance(expected).deci_degrees() <= 1);
+    assert_ne!(rotation, Rotation::NORTH);
+
+    // The part of the turn that doesn't fit in the rotation is kept on the entity
+    let remainder = world.get::<RotationRemainder>(slow_spin).unwrap();
+    assert!(remainder.deci_degrees.abs() <= 0.5);
+    let turned = rotation.deci_degrees() as f32 + remainder.deci_degrees;
+    assert!((turned - 20.0 * seconds).abs() < 1e-2);
+}
+
+#[test]
+fn force_fields_push_entities_inside_of_them() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(force_fields::<F32, AxisAlignedBoundingBox<F32>>.before("kinematics"));
+    stage.add_system(linear_kinematics::<F32>.label("kinematics"));
+
+    let acceleration = Acceleration {
+        x: F32(10.0),
+        y: F32(0.0),
+    };
+    world.spawn().insert(ForceField::new(
+        AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 10.0),
+        acceleration,
+    ));
+    world.spawn().insert(ForceField::new(
+        AxisAlignedBoundingBox::<F32>::new(5.0, 15.0, 0.0, 10.0),
+        acceleration,
+    ));
+
+    let spawn_at = |world: &mut World, x: f32| {
+        world
+            .spawn()
+            .insert_bundle((
+                Position::<F32>::new(x, 5.0),
+                Velocity::<F32>::default(),
+                Acceleration::<F32>::default(),
+                IntegratorState::<F32>::default(),
+            ))
+            .id()
+    };
+    let outside = spawn_at(&mut world, -5.0);
+    let single = spawn_at(&mut world, 2.0);
+    let overlapping = spawn_at(&mut world, 7.0);
+
+    stage.run(&mut world);
+    let velocity = |world: &World, entity| *world.get::<Velocity<F32>>(entity).unwrap();
+
+    assert_eq!(velocity(&world, outside), Velocity::default());
+    // The push only lasts for a single frame
+    assert_eq!(
+        world
+            .get::<IntegratorState<F32>>(single)
+            .unwrap()
+            .extra_acceleration(),
+        Acceleration::default()
+    );
+
+    let single_velocity = velocity(&world, single);
+    assert!(single_velocity.x > F32(0.0));
+    assert_eq!(single_velocity.y, F32(0.0));
+
+    // Overlapping fields stack
+    let overlapping_velocity = velocity(&world, overlapping);
+    assert!((overlapping_velocity.x.0 - 2.0 * single_velocity.x.0).abs() < 1e-6);
+}
+
+#[test]
+fn force_fields_push_entities_that_linear_kinematics_has_not_seen() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    let delta_seconds = time.delta_seconds();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(force_fields::<F32, AxisAlignedBoundingBox<F32>>.before("kinematics"));
+    stage.add_system(linear_kinematics::<F32>.label("kinematics"));
+
+    world.spawn().insert(ForceField::new(
+        AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 10.0),
+        Acceleration {
+            x: F32(10.0),
+            y: F32(0.0),
+        },
+    ));
+
+    // Not moved yet, so it has no IntegratorState
+    let spawned = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(2.0, 5.0),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+        ))
+        .id();
+    // Never moved by linear_kinematics, which requires an Acceleration
+    let drifting = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(7.0, 5.0), Velocity::<F32>::default()))
+        .id();
+
+    stage.run(&mut world);
+
+    for entity in [spawned, drifting] {
+        let velocity = *world.get::<Velocity<F32>>(entity).unwrap();
+        assert!((velocity.x.0 - 10.0 * delta_seconds).abs() < 1e-6);
+        assert_eq!(velocity.y, F32(0.0));
+    }
+}
+
+#[test]
+fn riders_move_with_their_carrier() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    let delta_seconds = time.delta_seconds();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(carry_riders::<F32>);
+
+    let platform = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Velocity::<F32> {
+                x: F32(10.0),
+                y: F32(0.0),
+            },
+        ))
+        .id();
+    let turntable = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            AngularVelocity::from_degrees_per_second(90.0),
+        ))
+        .id();
+
+    let passenger = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 5.0), CarriedBy(platform)))
+        .id();
+    let dancer = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 5.0), CarriedBy(turntable)))
+        .id();
+    // Carriers that have been despawned are ignored
+    let stranded = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 5.0),
+            CarriedBy(Entity::from_raw(999)),
+        ))
+        .id();
+
+    stage.run(&mut world);
+    let position = |world: &World, entity| *world.get::<Position<F32>>(entity).unwrap();
+
+    let assert_near = |actual: Position<F32>, expected: Vec2| {
+        assert!(
+            Vec2::from(actual).distance(expected) < 1e-4,
+            "{actual:?}, {expected:?}"
+        );
+    };
+
+    assert_near(
+        position(&world, passenger),
+        Vec2::new(10.0 * delta_seconds, 5.0),
+    );
+
+    // Turning clockwise, starting from north
+    let angle = core::f32::consts::FRAC_PI_2 * delta_seconds;
+    assert_near(
+        position(&world, dancer),
+        5.0 * Vec2::new(angle.sin(), angle.cos()),
+    );
+
+    assert_eq!(position(&world, stranded), Position::new(0.0, 5.0));
+}
+
+#[test]
+fn knockback_decays_exponentially() {
+    let mut knockback = Knockback::<F32>::new(KnockbackDecay::Exponential(0.5));
+    knockback.push(Velocity {
+        x: F32(6.0),
+        y: F32(0.0),
+    });
+    // Impulses stack
+    knockback.push(Velocity {
+        x: F32(2.0),
+        y: F32(0.0),
+    });
+
+    knockback.decay(Duration::from_millis(500));
+    assert!((knockback.velocity().x.0 - 4.0).abs() < 1e-5);
+    knockback.decay(Duration::from_secs(1));
+    assert!((knockback.velocity().x.0 - 1.0).abs() < 1e-5);
+
+    // Eventually, it stops entirely
+    knockback.decay(Duration::from_secs(10));
+    assert!(!knockback.is_active());
+    assert_eq!(knockback.velocity(), Velocity::default());
+}
+
+#[test]
+fn knockback_moves_entities() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    let delta_seconds = time.delta_seconds();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(apply_knockback::<F32>);
+
+    let mut knockback = Knockback::<F32>::new(KnockbackDecay::Linear(1.0));
+    knockback.push(Velocity {
+        x: F32(0.0),
+        y: F32(-10.0),
+    });
+    let entity = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 0.0), knockback))
+        .id();
+
+    stage.run(&mut world);
+
+    let position = *world.get::<Position<F32>>(entity).unwrap();
+    assert!((position.y.0 + 10.0 * delta_seconds).abs() < 1e-5);
+    let speed = -world.get::<Knockback<F32>>(entity).unwrap().velocity().y.0;
+    assert!((speed - (10.0 - delta_seconds)).abs() < 1e-4);
+}
+
+#[test]
+fn followers_chase_their_target() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    std::thread::sleep(Duration::from_millis(10));
+    time.update();
+    let delta_seconds = time.delta_seconds();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(follow_targets::<F32>);
+
+    let target = world.spawn().insert(Position::<F32>::new(10.0, 0.0)).id();
+    let offset = Position::<F32>::new(0.0, 2.0);
+
+    let snapping = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Follow {
+                target,
+                offset,
+                smoothing: 0.0,
+                max_speed: None,
+            },
+        ))
+        .id();
+    let smooth = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Follow {
+                target,
+                offset,
+                smoothing: 1.0,
+                max_speed: None,
+            },
+        ))
+        .id();
+    let slow = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Follow {
+                target,
+                offset,
+                smoothing: 0.0,
+                max_speed: Some(F32(1.0)),
+            },
+        ))
+        .id();
+
+    stage.run(&mut world);
+
+    let position_of = |entity: Entity| Vec2::from(*world.get::<Position<F32>>(entity).unwrap());
+    let goal = Vec2::new(10.0, 2.0);
+
+    assert_eq!(position_of(snapping), goal);
+
+    let expected = damp_towards(
+        Position::<F32>::new(0.0, 0.0),
+        Position::new(10.0, 2.0),
+        1.0,
+        Duration::from_secs_f32(delta_seconds),
+    );
+    assert!(position_of(smooth).distance(Vec2::from(expected)) < 1e-4);
+    assert!(position_of(smooth).length() > 0.0);
+
+    let slow_position = position_of(slow);
+    assert!((slow_position.length() - delta_seconds).abs() < 1e-5);
+    assert!(slow_position.normalize().distance(goal.normalize()) < 1e-5);
+}
+
+#[test]
+fn predicting_motion_leaves_entities_untouched() {
+    let mut world = World::new();
+    let entity = world
+        .spawn()
+        .insert(Position::<F32>::new(1.0, 1.0))
+        .insert(Velocity {
+            x: F32(0.0),
+            y: F32(-4.0),
+        })
+        .insert(Acceleration {
+            x: F32(2.0),
+            y: F32(0.0),
+        })
+        .insert(AngularVelocity::from_degrees_per_second(-90.0))
+        .insert(AngularAcceleration {
+            deci_degrees: 400.0,
+        })
+        .id();
+
+    let state = KinematicState::<F32>::from_entity(&world.entity(entity)).unwrap();
+    let future = state.predict(Duration::from_millis(500));
+
+    future
+        .position
+        .assert_approx_eq(Position::<F32>::new(1.25, -1.0));
+    assert_eq!(
+        future.velocity,
+        Velocity {
+            x: F32(1.0),
+            y: F32(-4.0)
+        }
+    );
+    // -45 degrees from turning, plus 5 degrees from speeding up the turn
+    assert_eq!(future.rotation, Rotation::from_degrees(-40.0));
+    assert_eq!(future.angular_velocity.deci_degrees, -700.0);
+    assert_eq!(future.acceleration, state.acceleration);
+
+    // Predicting is consistent with predicting in several steps
+    let stepped = state
+        .predict(Duration::from_millis(250))
+        .predict(Duration::from_millis(250));
+    stepped.position.assert_approx_eq(future.position);
+    stepped.rotation.assert_approx_eq(future.rotation);
+
+    // The entity itself has not moved
+    assert_eq!(
+        *world.get::<Position<F32>>(entity).unwrap(),
+        Position::new(1.0, 1.0)
+    );
+
+    // Entities without a position cannot be predicted
+    let nowhere = world.spawn().insert(Velocity::<F32>::default()).id();
+    assert_eq!(
+        KinematicState::<F32>::from_entity(&world.entity(nowhere)),
+        None
+    );
+
+    let (position, velocity) = predict(
+        state.position,
+        state.velocity,
+        state.acceleration,
+        Duration::ZERO,
+    );
+    assert_eq!(position, state.position);
+    assert_eq!(velocity, state.velocity);
+}
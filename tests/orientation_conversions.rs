@@ -164,3 +164,76 @@ fn holistic_conversions() {
     assert_conversions_match(Position::new(47.8, 0.03));
     assert_conversions_match(Position::new(-4001.0, 432.7));
 }
+
+#[test]
+fn direction_to_quat_is_not_quantized() {
+    use bevy::math::{Vec2, Vec3};
+
+    // Between two deci-degree steps, and exactly south, where the half-angle formula breaks down
+    for unit_vector in [
+        Vec2::new(0.0001, 1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(0.0, -1.0),
+    ] {
+        let direction = Direction::new(unit_vector);
+        let quat = Quat::from(direction);
+
+        assert!((quat.length() - 1.0).abs() < 1e-6);
+        let heading = quat.mul_vec3(Vec3::Y).truncate();
+        assert!(heading.distance(direction.unit_vector()) < 1e-6);
+    }
+}
+
+#[test]
+fn rotation_sweeps() {
+    // Ties take the clockwise arc
+    let sweep: Vec<Rotation> = Rotation::NORTH.sweep_to(Rotation::SOUTH, None, 2).collect();
+    assert_eq!(
+        sweep,
+        vec![Rotation::NORTH, Rotation::EAST, Rotation::SOUTH]
+    );
+
+    // Sweeps that cross north wrap around
+    let sweep = Rotation::WEST.sweep_to(Rotation::EAST, Some(RotationDirection::Clockwise), 4);
+    assert_eq!(sweep.len(), 5);
+    assert_eq!(
+        sweep.rev().collect::<Vec<_>>(),
+        vec![
+            Rotation::EAST,
+            Rotation::NORTHEAST,
+            Rotation::NORTH,
+            Rotation::NORTHWEST,
+            Rotation::WEST
+        ]
+    );
+
+    // Steps that do not divide evenly are rounded to the nearest deci-degree
+    let sweep: Vec<u16> = Rotation::NORTH
+        .sweep_to(Rotation::new(10), None, 3)
+        .map(|rotation| rotation.deci_degrees())
+        .collect();
+    assert_eq!(sweep, vec![0, 3, 7, 10]);
+
+    assert_eq!(Rotation::EAST.sweep_to(Rotation::EAST, None, 3).count(), 4);
+    assert_eq!(Rotation::EAST.sweep_to(Rotation::WEST, None, 0).count(), 1);
+}
+
+#[test]
+fn circular_mean_wraps_around_north() {
+    let headings = [350.0, 355.0, 0.0, 5.0, 20.0].map(Rotation::from_degrees);
+    Rotation::circular_mean(headings)
+        .unwrap()
+        .assert_approx_eq(Rotation::from_degrees(2.0));
+
+    // Three evenly spaced rotations cancel out
+    let spread = Rotation::NORTH.sweep_by(Rotation::new(1200)).take(3);
+    assert_eq!(Rotation::circular_mean(spread), None);
+
+    // Weights of zero are ignored, and negative weights push the mean away
+    Rotation::weighted_circular_mean([(Rotation::EAST, 2.0), (Rotation::NORTH, 0.0)])
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+    Rotation::weighted_circular_mean([(Rotation::EAST, -1.0)])
+        .unwrap()
+        .assert_approx_eq(Rotation::WEST);
+}
@@ -0,0 +1,103 @@
+use bevy::math::Vec2;
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+use leafwing_2d::partitioning::{
+    CardinalOctant, CardinalQuadrant, CardinalSextant, DirectionParitioning, OffsetQuadrant,
+    OffsetSextant,
+};
+
+/// Checks that `P::snap` always picks one of the nearest partitions
+fn assert_snaps_to_nearest<P: DirectionParitioning + PartialEq + core::fmt::Debug>() {
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let rotation = Rotation::new(deci_degrees);
+        let snapped: Rotation = P::snap(rotation).into();
+
+        let nearest_distance = P::rotations()
+            .into_iter()
+            .map(|partition| rotation.distance(partition).deci_degrees())
+            .min()
+            .unwrap();
+
+        assert_eq!(
+            rotation.distance(snapped).deci_degrees(),
+            nearest_distance,
+            "{rotation:?} snapped to {:?}",
+            P::snap(rotation)
+        );
+    }
+}
+
+#[test]
+fn uniform_partitions_snap_to_nearest() {
+    assert_snaps_to_nearest::<CardinalQuadrant>();
+    assert_snaps_to_nearest::<OffsetQuadrant>();
+    assert_snaps_to_nearest::<CardinalOctant>();
+    assert_snaps_to_nearest::<CardinalSextant>();
+    assert_snaps_to_nearest::<OffsetSextant>();
+}
+
+#[test]
+fn partitions_snap_to_themselves() {
+    for &octant in CardinalOctant::PARTITIONS {
+        assert_eq!(CardinalOctant::snap(octant), octant);
+    }
+    for &sextant in OffsetSextant::PARTITIONS {
+        assert_eq!(OffsetSextant::snap(sextant), sextant);
+    }
+}
+
+#[test]
+fn snapping_wraps_around_midnight() {
+    assert_eq!(
+        CardinalSextant::snap(Rotation::from_degrees(-20.0)),
+        CardinalSextant::North
+    );
+    assert_eq!(
+        OffsetSextant::snap(Rotation::from_degrees(-20.0)),
+        OffsetSextant::NorthWest
+    );
+    assert_eq!(
+        OffsetQuadrant::snap(Rotation::from_degrees(10.0)),
+        OffsetQuadrant::NorthEast
+    );
+}
+
+/// A non-uniform partitioning, which relies on the default implementation of `snap`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lanes {
+    Ahead,
+    Right,
+    Left,
+}
+
+impl From<Lanes> for Rotation {
+    fn from(lane: Lanes) -> Rotation {
+        match lane {
+            Lanes::Ahead => Rotation::NORTH,
+            Lanes::Right => Rotation::from_degrees(20.0),
+            Lanes::Left => Rotation::from_degrees(-20.0),
+        }
+    }
+}
+
+impl From<Lanes> for Direction {
+    fn from(lane: Lanes) -> Self {
+        Rotation::from(lane).into()
+    }
+}
+
+impl From<Lanes> for Vec2 {
+    fn from(lane: Lanes) -> Self {
+        Rotation::from(lane).into()
+    }
+}
+
+impl DirectionParitioning for Lanes {
+    const PARTITIONS: &'static [Self] = &[Lanes::Ahead, Lanes::Right, Lanes::Left];
+}
+
+#[test]
+fn custom_partitions_snap_to_nearest() {
+    assert_snaps_to_nearest::<Lanes>();
+    assert_eq!(Lanes::snap(Rotation::from_degrees(5.0)), Lanes::Ahead);
+    assert_eq!(Lanes::snap(Rotation::from_degrees(170.0)), Lanes::Right);
+}
@@ -0,0 +1,163 @@
+use leafwing_2d::continuous::F32;
+use leafwing_2d::orientation::{Direction, Orientation};
+use leafwing_2d::path::{ArcLengthParameterized, CatmullRom, CubicBezier, Polyline, Spline};
+use leafwing_2d::position::{Position, Positionlike};
+
+fn zig_zag() -> Polyline<F32> {
+    Polyline::new([
+        Position::new(0., 0.),
+        Position::new(1., 1.),
+        Position::new(2., 0.),
+        Position::new(3., 1.),
+    ])
+}
+
+#[test]
+fn empty_polyline() {
+    let empty = Polyline::<F32>::default();
+
+    assert_eq!(empty.length(), 0.);
+    assert_eq!(empty.sample_at_distance(1.), None);
+    assert_eq!(empty.closest_point(Position::new(1., 1.)), None);
+    assert_eq!(empty.simplify(1.), empty);
+}
+
+#[test]
+fn single_point_polyline() {
+    let point = Position::new(4., 2.);
+    let polyline = Polyline::<F32>::new([point]);
+
+    assert_eq!(polyline.length(), 0.);
+    assert_eq!(polyline.sample_at_distance(1.), Some(point));
+    assert_eq!(polyline.closest_point(Position::new(0., 0.)), Some(point));
+}
+
+#[test]
+fn polyline_sampling_is_clamped() {
+    let polyline = zig_zag();
+
+    assert_eq!(
+        polyline.sample_at_distance(-5.),
+        Some(Position::new(0., 0.))
+    );
+    assert_eq!(polyline.sample_at_distance(0.), Some(Position::new(0., 0.)));
+    assert_eq!(
+        polyline.sample_at_distance(polyline.length() + 5.),
+        Some(Position::new(3., 1.))
+    );
+
+    let halfway = polyline.sample_at_distance(polyline.length() / 2.).unwrap();
+    assert!((halfway.x.0 - 1.5).abs() < 1e-5);
+    assert!((halfway.y.0 - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn polyline_closest_point() {
+    let polyline = zig_zag();
+
+    // Directly above a vertex
+    assert_eq!(
+        polyline.closest_point(Position::new(1., 3.)),
+        Some(Position::new(1., 1.))
+    );
+    // Past the end of the path
+    assert_eq!(
+        polyline.closest_point(Position::new(5., 1.)),
+        Some(Position::new(3., 1.))
+    );
+}
+
+#[test]
+fn polyline_simplification() {
+    let polyline = zig_zag();
+
+    // Nothing is within tolerance
+    assert_eq!(polyline.simplify(0.1), polyline);
+    // Everything is within tolerance
+    assert_eq!(
+        polyline.simplify(10.).points(),
+        &[Position::new(0., 0.), Position::new(3., 1.)]
+    );
+
+    // Collinear points are always removed
+    let straight = Polyline::<F32>::new((0..10).map(|x| Position::new(x as f32, 2. * x as f32)));
+    assert_eq!(straight.simplify(0.).points().len(), 2);
+}
+
+#[test]
+fn catmull_rom_passes_through_points() {
+    let points = [
+        Position::new(0., 0.),
+        Position::new(2., 1.),
+        Position::new(4., -1.),
+        Position::new(5., 3.),
+        Position::new(7., 0.),
+    ];
+    let spline = CatmullRom::<F32>::new(points);
+
+    for (i, point) in points.into_iter().enumerate() {
+        spline.position_at(i as f32 / 4.).assert_approx_eq(point);
+    }
+}
+
+#[test]
+fn catmull_rom_tangents() {
+    let spline = CatmullRom::<F32>::new([
+        Position::new(0., 0.),
+        Position::new(1., 1.),
+        Position::new(2., 0.),
+    ]);
+
+    // At the peak of the arch, the spline is moving horizontally
+    spline
+        .tangent_at(0.5)
+        .unwrap()
+        .assert_approx_eq(Direction::EAST);
+
+    let single_point = CatmullRom::<F32>::new([Position::new(1., 1.)]);
+    assert_eq!(single_point.position_at(0.5), Position::new(1., 1.));
+    assert!(single_point.tangent_at(0.5).is_err());
+}
+
+#[test]
+fn arc_length_sampling_is_evenly_spaced() {
+    let curve = CubicBezier::<F32>::new(
+        Position::new(0., 0.),
+        Position::new(3., 4.),
+        Position::new(7., -4.),
+        Position::new(10., 2.),
+    );
+    let rail = ArcLengthParameterized::new(curve, 256);
+
+    let steps = 20;
+    let step_length = rail.length() / steps as f32;
+    let polyline: Polyline<F32> = (0..=steps)
+        .map(|i| rail.sample_at_distance(i as f32 * step_length))
+        .collect();
+
+    for (start, end) in polyline.segments() {
+        let distance = bevy::math::Vec2::from(start).distance(end.into());
+        assert!(
+            (distance - step_length).abs() < 0.01 * step_length,
+            "{distance} != {step_length}"
+        );
+    }
+
+    assert_eq!(rail.sample_at_distance(-1.), Position::new(0., 0.));
+    assert_eq!(
+        rail.sample_at_distance(rail.length() + 1.),
+        Position::new(10., 2.)
+    );
+    assert!((rail.length() - curve.to_polyline(256).length()).abs() < 1e-3);
+}
+
+#[test]
+fn polyline_remove_start() {
+    let mut path = zig_zag();
+    path.remove_start(1);
+    assert_eq!(path.points()[0], Position::new(1., 1.));
+    assert_eq!(path.points().len(), 3);
+
+    path.remove_start(10);
+    assert!(path.points().is_empty());
+}
@@ -0,0 +1,169 @@
+use leafwing_2d::discrete::{AdjacentGrid, OrthogonalGrid};
+use leafwing_2d::pathfinding::{jump_point_search, JumpPointGrid};
+use leafwing_2d::position::Position;
+
+/// A 10x8 map, where `#` is a wall
+const MAP: [&str; 8] = [
+    "..........", //
+    ".####.###.", //
+    ".#......#.", //
+    ".#.####.#.", //
+    "...#..#...", //
+    "####..####", //
+    "......#...", //
+    "..##......", //
+];
+
+fn is_floor(x: isize, y: isize) -> bool {
+    (0..10).contains(&x) && (0..8).contains(&y) && MAP[y as usize].as_bytes()[x as usize] == b'.'
+}
+
+fn passable<C: JumpPointGrid>(position: Position<C>) -> bool {
+    is_floor(position.x.into(), position.y.into())
+}
+
+/// The cost of each step along a path, checking that every step is legal
+fn path_cost<C: JumpPointGrid>(path: &[Position<C>]) -> f32 {
+    path.windows(2)
+        .map(|step| {
+            let (x, y): (isize, isize) = (step[0].x.into(), step[0].y.into());
+            let (next_x, next_y): (isize, isize) = (step[1].x.into(), step[1].y.into());
+            let (dx, dy) = (next_x - x, next_y - y);
+
+            assert!(passable(step[1]));
+            assert!(dx.abs() <= 1 && dy.abs() <= 1 && (dx, dy) != (0, 0));
+            if dx != 0 && dy != 0 {
+                assert!(C::DIAGONAL, "Stepped diagonally on an orthogonal grid");
+                assert!(is_floor(x + dx, y) && is_floor(x, y + dy), "Cut a corner");
+                core::f32::consts::SQRT_2
+            } else {
+                1.
+            }
+        })
+        .sum()
+}
+
+/// The cost of the shortest path, found by brute force
+fn shortest_cost(start: (isize, isize), goal: (isize, isize), diagonal: bool) -> Option<f32> {
+    let mut costs = vec![vec![f32::INFINITY; 10]; 8];
+    costs[start.1 as usize][start.0 as usize] = 0.;
+
+    // Relax every step until nothing improves
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..8 {
+            for x in 0..10 {
+                for (dx, dy) in [
+                    (-1, -1),
+                    (-1, 0),
+                    (-1, 1),
+                    (0, -1),
+                    (0, 1),
+                    (1, -1),
+                    (1, 0),
+                    (1, 1),
+                ] {
+                    let diagonal_step = dx != 0 && dy != 0;
+                    if !is_floor(x, y)
+                        || !is_floor(x + dx, y + dy)
+                        || (diagonal_step
+                            && !(diagonal && is_floor(x + dx, y) && is_floor(x, y + dy)))
+                    {
+                        continue;
+                    }
+
+                    let step = if diagonal_step {
+                        core::f32::consts::SQRT_2
+                    } else {
+                        1.
+                    };
+                    let cost = costs[y as usize][x as usize] + step;
+                    let neighbor = &mut costs[(y + dy) as usize][(x + dx) as usize];
+                    if cost + 1e-4 < *neighbor {
+                        *neighbor = cost;
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    let cost = costs[goal.1 as usize][goal.0 as usize];
+    cost.is_finite().then_some(cost)
+}
+
+fn assert_optimal<C: JumpPointGrid>(start: (isize, isize), goal: (isize, isize)) {
+    let cell = |(x, y): (isize, isize)| Position::<C>::new(C::from(x), C::from(y));
+    let path = jump_point_search(cell(start), cell(goal), passable::<C>);
+
+    match (path, shortest_cost(start, goal, C::DIAGONAL)) {
+        (Some(path), Some(expected)) => {
+            assert_eq!(path.first(), Some(&cell(start)));
+            assert_eq!(path.last(), Some(&cell(goal)));
+            let cost = path_cost(&path);
+            assert!(
+                (cost - expected).abs() < 1e-3,
+                "Path from {start:?} to {goal:?} cost {cost}, but the shortest costs {expected}"
+            );
+        }
+        (None, None) => (),
+        (path, expected) => {
+            panic!(
+                "Path from {start:?} to {goal:?} was {path:?}, but the shortest costs {expected:?}"
+            )
+        }
+    }
+}
+
+fn floor_cells() -> Vec<(isize, isize)> {
+    (0..10)
+        .flat_map(|x| (0..8).map(move |y| (x, y)))
+        .filter(|&(x, y)| is_floor(x, y))
+        .collect()
+}
+
+#[test]
+fn orthogonal_paths_are_optimal() {
+    for start in floor_cells() {
+        for goal in floor_cells() {
+            assert_optimal::<OrthogonalGrid>(start, goal);
+        }
+    }
+}
+
+#[test]
+fn adjacent_paths_are_optimal() {
+    for start in floor_cells() {
+        for goal in floor_cells() {
+            assert_optimal::<AdjacentGrid>(start, goal);
+        }
+    }
+}
+
+#[test]
+fn path_to_start_is_just_the_start() {
+    let start = Position::<OrthogonalGrid>::new(OrthogonalGrid(0), OrthogonalGrid(0));
+
+    assert_eq!(jump_point_search(start, start, passable), Some(vec![start]));
+}
+
+#[test]
+fn walls_are_unreachable() {
+    let start = Position::<AdjacentGrid>::new(AdjacentGrid(0), AdjacentGrid(0));
+    let wall = Position::<AdjacentGrid>::new(AdjacentGrid(1), AdjacentGrid(1));
+
+    assert_eq!(jump_point_search(start, wall, passable), None);
+}
+
+#[test]
+fn open_fields_are_crossed_in_a_straight_line() {
+    let start = Position::<AdjacentGrid>::new(AdjacentGrid(0), AdjacentGrid(0));
+    let goal = Position::<AdjacentGrid>::new(AdjacentGrid(500), AdjacentGrid(500));
+    let in_field = |position: Position<AdjacentGrid>| {
+        (-1000..=1000).contains(&position.x.0) && (-1000..=1000).contains(&position.y.0)
+    };
+
+    let path = jump_point_search(start, goal, in_field).unwrap();
+    assert_eq!(path.len(), 501);
+}
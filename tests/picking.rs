@@ -0,0 +1,144 @@
+#![cfg(feature = "picking")]
+
+mod common;
+
+use bevy::prelude::*;
+use common::drain;
+use leafwing_2d::bounding::{AxisAlignedBoundingBox, Circle};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::picking::{
+    pick_entities, CursorPosition, HoverEnded, HoverStarted, HoveredEntity, Pickable, PickedEntity,
+};
+use leafwing_2d::position::Position;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<CursorPosition<F32>>()
+        .init_resource::<HoveredEntity>()
+        .init_resource::<Input<MouseButton>>()
+        .add_event::<HoverStarted>()
+        .add_event::<HoverEnded>()
+        .add_event::<PickedEntity>()
+        .add_system(pick_entities::<F32>);
+
+    app
+}
+
+fn move_cursor(world: &mut World, x: f32, y: f32) {
+    world.resource_mut::<CursorPosition<F32>>().0 = Some(Position::new(x, y));
+}
+
+#[test]
+fn hovering_over_entities() {
+    let mut app = test_app();
+
+    let button = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Pickable,
+            AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 1.0),
+        ))
+        .id();
+    let coin = app
+        .world
+        .spawn()
+        .insert_bundle((Pickable, Circle::<F32>::new(Position::new(5.0, 5.0), 1.0)))
+        .id();
+    // Not pickable, so never hovered
+    app.world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(-5.0, 0.0), 1.0));
+
+    // The cursor is outside of the window
+    app.update();
+    assert_eq!(*app.world.resource::<HoveredEntity>(), HoveredEntity(None));
+
+    move_cursor(&mut app.world, 1.0, 0.5);
+    app.update();
+    assert_eq!(
+        *app.world.resource::<HoveredEntity>(),
+        HoveredEntity(Some(button))
+    );
+    assert_eq!(
+        drain::<HoverStarted>(&mut app.world),
+        vec![HoverStarted { entity: button }]
+    );
+
+    // Staying put doesn't send any more events
+    app.update();
+    assert!(drain::<HoverStarted>(&mut app.world).is_empty());
+
+    move_cursor(&mut app.world, 5.5, 5.0);
+    app.update();
+    assert_eq!(
+        drain::<HoverEnded>(&mut app.world),
+        vec![HoverEnded { entity: button }]
+    );
+    assert_eq!(
+        drain::<HoverStarted>(&mut app.world),
+        vec![HoverStarted { entity: coin }]
+    );
+
+    move_cursor(&mut app.world, -5.0, 0.0);
+    app.update();
+    assert_eq!(
+        drain::<HoverEnded>(&mut app.world),
+        vec![HoverEnded { entity: coin }]
+    );
+    assert_eq!(*app.world.resource::<HoveredEntity>(), HoveredEntity(None));
+}
+
+#[test]
+fn topmost_entity_is_picked() {
+    let mut app = test_app();
+
+    let background = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Pickable,
+            AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -10.0, 10.0),
+            GlobalTransform::from_xyz(0.0, 0.0, 0.0),
+        ))
+        .id();
+    let card = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Pickable,
+            Circle::<F32>::new(Position::new(0.0, 0.0), 1.0),
+            GlobalTransform::from_xyz(0.0, 0.0, 5.0),
+        ))
+        .id();
+    // Drawn underneath the background
+    app.world.spawn().insert_bundle((
+        Pickable,
+        Circle::<F32>::new(Position::new(0.0, 0.0), 1.0),
+        GlobalTransform::from_xyz(0.0, 0.0, -1.0),
+    ));
+
+    move_cursor(&mut app.world, 0.5, 0.0);
+    app.world
+        .resource_mut::<Input<MouseButton>>()
+        .press(MouseButton::Left);
+    app.update();
+
+    assert_eq!(
+        drain::<PickedEntity>(&mut app.world),
+        vec![PickedEntity {
+            entity: card,
+            button: MouseButton::Left
+        }]
+    );
+
+    // Holding the button down doesn't pick again
+    app.world.resource_mut::<Input<MouseButton>>().clear();
+    move_cursor(&mut app.world, 5.0, 5.0);
+    app.update();
+    assert!(drain::<PickedEntity>(&mut app.world).is_empty());
+    assert_eq!(
+        *app.world.resource::<HoveredEntity>(),
+        HoveredEntity(Some(background))
+    );
+}
@@ -187,3 +187,242 @@ fn sync_position() {
     app.update();
     app.assert_positionlike_approx_eq(Transform::from_xyz(4.0, 4.0, 5.0));
 }
+
+#[test]
+fn parallax_layers_follow_the_camera() {
+    use leafwing_2d::parallax::{ParallaxCamera, ParallaxLayer};
+
+    let mut app = App::new();
+    app.insert_resource(Time::default());
+    app.add_plugin(TwoDPlugin::default());
+
+    let camera = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(ParallaxCamera)
+        .id();
+    let background = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Position::<F32>::new(1.0, 0.0))
+        .insert(Transform::from_xyz(0.0, 0.0, -10.0))
+        .insert(ParallaxLayer {
+            factor: Vec2::new(0.5, 1.0),
+        })
+        .id();
+    app.update();
+    assert_eq!(
+        *app.world.get::<Transform>(background).unwrap(),
+        Transform::from_xyz(1.0, 0.0, -10.0)
+    );
+
+    // Moving the camera offsets the layer's transform, but not its position
+    *app.world.get_mut::<Position<F32>>(camera).unwrap() = Position::new(10.0, 4.0);
+    app.update();
+    assert_eq!(
+        *app.world.get::<Transform>(background).unwrap(),
+        Transform::from_xyz(6.0, 4.0, -10.0)
+    );
+
+    // The offset transform is never copied back into the logical position
+    app.update();
+    assert_eq!(
+        *app.world.get::<Position<F32>>(background).unwrap(),
+        Position::new(1.0, 0.0)
+    );
+    assert_eq!(
+        *app.world.get::<Transform>(background).unwrap(),
+        Transform::from_xyz(6.0, 4.0, -10.0)
+    );
+
+    // Rotation is still synchronized as usual
+    *app.world.get_mut::<Rotation>(background).unwrap() = Rotation::from_degrees(90.0);
+    app.update();
+    app.world
+        .get::<Transform>(background)
+        .unwrap()
+        .rotation
+        .assert_approx_eq(Rotation::from_degrees(90.0));
+}
+
+#[test]
+fn anchored_entities_pivot_around_their_anchor() {
+    use leafwing_2d::transform::Anchor;
+
+    let mut app = App::new();
+    // Kinematics would overwrite the position every frame
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..TwoDPlugin::default()
+    });
+
+    // A door 2 units wide, hinged at its left edge
+    let door = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Position::<F32>::new(5.0, 0.0))
+        .insert(Transform::from_scale(Vec3::new(2.0, 1.0, 1.0)))
+        .insert(Anchor {
+            offset: Position::<F32>::new(-0.5, 0.0),
+        })
+        .id();
+    app.update();
+    app.world
+        .get::<Transform>(door)
+        .unwrap()
+        .translation
+        .assert_approx_eq(Position::<F32>::new(6.0, 0.0));
+
+    // Swinging the door shut, clockwise around its hinge
+    *app.world.get_mut::<Rotation>(door).unwrap() = Rotation::EAST;
+    app.update();
+    app.world
+        .get::<Transform>(door)
+        .unwrap()
+        .translation
+        .assert_approx_eq(Position::<F32>::new(5.0, -1.0));
+    assert_eq!(
+        *app.world.get::<Position<F32>>(door).unwrap(),
+        Position::new(5.0, 0.0)
+    );
+
+    // Moving the transform moves the hinge along with it
+    app.world.get_mut::<Transform>(door).unwrap().translation.x += 1.0;
+    app.update();
+    app.world
+        .get::<Position<F32>>(door)
+        .unwrap()
+        .assert_approx_eq(Position::<F32>::new(6.0, 0.0));
+
+    // Changing the anchor moves the transform, while the hinge stays put
+    app.world.get_mut::<Anchor<F32>>(door).unwrap().offset = Position::new(0.0, 0.0);
+    app.update();
+    app.world
+        .get::<Transform>(door)
+        .unwrap()
+        .translation
+        .assert_approx_eq(Position::<F32>::new(6.0, 0.0));
+}
+
+#[test]
+fn tilt_is_preserved_when_syncing_rotation() {
+    use leafwing_2d::transform::PreserveTilt;
+
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..TwoDPlugin::default()
+    });
+
+    let tilt = Quat::from_rotation_x(0.3);
+    let card = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::from_rotation(tilt))
+        .insert(PreserveTilt)
+        .id();
+    let plain = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::from_rotation(tilt))
+        .id();
+    app.update();
+
+    for entity in [card, plain] {
+        *app.world.get_mut::<Rotation>(entity).unwrap() = Rotation::EAST;
+    }
+    app.update();
+
+    // Only the yaw is replaced
+    let card_rotation = app.world.get::<Transform>(card).unwrap().rotation;
+    assert!(card_rotation.abs_diff_eq(tilt * Quat::from(Rotation::EAST), 1e-5));
+
+    // Without the marker, the tilt is lost
+    app.world
+        .get::<Transform>(plain)
+        .unwrap()
+        .rotation
+        .assert_approx_eq(Rotation::EAST);
+    assert!(!app
+        .world
+        .get::<Transform>(plain)
+        .unwrap()
+        .rotation
+        .abs_diff_eq(tilt * Quat::from(Rotation::EAST), 1e-3));
+
+    // Turning the transform around the z-axis updates the rotation, and keeps the tilt
+    let mut transform = app.world.get_mut::<Transform>(card).unwrap();
+    transform.rotation = tilt * Quat::from(Rotation::SOUTH);
+    app.update();
+    app.world
+        .get::<Rotation>(card)
+        .unwrap()
+        .assert_approx_eq(Rotation::SOUTH);
+    assert!(app
+        .world
+        .get::<Transform>(card)
+        .unwrap()
+        .rotation
+        .abs_diff_eq(tilt * Quat::from(Rotation::SOUTH), 1e-5));
+}
+
+#[test]
+fn facing_velocity_overrides_angular_velocity() {
+    use leafwing_2d::kinematics::FaceVelocity;
+
+    let mut app = App::new();
+    let mut time = Time::default();
+    time.update();
+    app.insert_resource(time);
+    app.add_plugin(TwoDPlugin::default());
+
+    let spin = AngularVelocity::from_degrees_per_second(900.0);
+    let moving_east = Velocity::<F32> {
+        x: F32(1.0),
+        y: F32(0.0),
+    };
+    let spinner = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32> {
+            velocity: moving_east,
+            angular_velocity: spin,
+            ..Default::default()
+        })
+        .insert(FaceVelocity::default())
+        .id();
+    // Turned by `direction_angular_kinematics` rather than `angular_kinematics`
+    let pointer = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            moving_east,
+            Acceleration::<F32>::default(),
+            Direction::NORTH,
+            spin,
+            AngularAcceleration::default(),
+            FaceVelocity::default(),
+        ))
+        .id();
+
+    for _ in 0..3 {
+        std::thread::sleep(core::time::Duration::from_millis(10));
+        app.world.resource_mut::<Time>().update();
+        app.update();
+
+        app.world
+            .get::<Rotation>(spinner)
+            .unwrap()
+            .assert_approx_eq(Rotation::EAST);
+        app.world
+            .get::<Direction>(pointer)
+            .unwrap()
+            .assert_approx_eq(Direction::EAST);
+    }
+}
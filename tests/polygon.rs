@@ -0,0 +1,253 @@
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::polygon::{Polygon, Triangle};
+use leafwing_2d::position::{Position, Positionlike};
+
+fn scalene() -> Triangle<F32> {
+    Triangle::new(
+        Position::new(-1., -2.),
+        Position::new(5., 1.),
+        Position::new(2., 4.),
+    )
+}
+
+#[test]
+fn triangle_winding() {
+    let triangle = scalene();
+    let reversed = Triangle::new(triangle.c, triangle.b, triangle.a);
+
+    assert!(triangle.signed_area() > 0.);
+    assert_eq!(reversed.signed_area(), -triangle.signed_area());
+    assert_eq!(reversed.area(), triangle.area());
+}
+
+#[test]
+fn barycentric_round_trip() {
+    let triangle = scalene();
+    let [a, b, c] = triangle.vertexes().map(bevy::math::Vec2::from);
+
+    for position in [
+        Position::<F32>::new(1., 1.),
+        Position::new(-3., 7.),
+        Position::new(5., 1.),
+    ] {
+        let [wa, wb, wc] = triangle.barycentric(position).unwrap();
+        assert!((wa + wb + wc - 1.).abs() < 1e-5);
+        (wa * a + wb * b + wc * c).assert_approx_eq(position);
+    }
+}
+
+#[test]
+fn triangle_centers() {
+    let triangle = scalene();
+
+    assert!(triangle.contains(triangle.centroid()));
+
+    let circumcenter = bevy::math::Vec2::from(triangle.circumcenter().unwrap());
+    let distances = triangle
+        .vertexes()
+        .map(|vertex| circumcenter.distance(vertex.into()));
+    assert!((distances[0] - distances[1]).abs() < 1e-4);
+    assert!((distances[0] - distances[2]).abs() < 1e-4);
+}
+
+#[test]
+fn degenerate_triangle() {
+    let line = Triangle::<F32>::new(
+        Position::new(0., 0.),
+        Position::new(1., 1.),
+        Position::new(2., 2.),
+    );
+
+    assert_eq!(line.area(), 0.);
+    assert_eq!(line.barycentric(Position::new(1., 1.)), None);
+    assert_eq!(line.circumcenter(), None);
+    assert!(!line.contains(Position::new(1., 1.)));
+    assert_eq!(
+        line.closest_point(Position::new(0., 2.)),
+        Position::new(1., 1.)
+    );
+}
+
+#[test]
+fn triangle_closest_point() {
+    let triangle = scalene();
+
+    // Inside positions are unchanged
+    assert_eq!(
+        triangle.closest_point(Position::new(2., 1.)),
+        Position::new(2., 1.)
+    );
+    // Outside positions snap to the nearest corner or edge
+    assert_eq!(
+        triangle.closest_point(Position::new(10., 1.)),
+        Position::new(5., 1.)
+    );
+}
+
+/// A comb shape, with three teeth pointing up
+fn comb() -> Polygon<F32> {
+    Polygon::new([
+        Position::new(0., 0.),
+        Position::new(5., 0.),
+        Position::new(5., 3.),
+        Position::new(4., 3.),
+        Position::new(4., 1.),
+        Position::new(3., 1.),
+        Position::new(3., 3.),
+        Position::new(2., 3.),
+        Position::new(2., 1.),
+        Position::new(1., 1.),
+        Position::new(1., 3.),
+        Position::new(0., 3.),
+    ])
+}
+
+#[test]
+fn polygon_winding() {
+    let polygon = comb();
+    let reversed: Polygon<F32> = polygon.vertexes().iter().rev().copied().collect();
+
+    assert_eq!(polygon.area(), 11.);
+    assert!(polygon.signed_area() > 0.);
+    assert_eq!(reversed.signed_area(), -polygon.signed_area());
+    assert_eq!(polygon.edges().count(), 12);
+}
+
+#[test]
+fn triangulation_covers_polygon() {
+    for polygon in [comb(), comb().vertexes().iter().rev().copied().collect()] {
+        let triangles = polygon.triangulate();
+
+        assert_eq!(triangles.len(), polygon.vertexes().len() - 2);
+        assert!(triangles.iter().all(|triangle| triangle.signed_area() > 0.));
+
+        let total_area: f32 = triangles.iter().map(Triangle::area).sum();
+        assert!((total_area - polygon.area()).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn convex_decomposition_of_comb() {
+    let polygon = comb();
+    let pieces = polygon.convex_decomposition();
+
+    // Each tooth needs its own piece
+    assert!(pieces.len() >= 3);
+    assert!(pieces.len() < polygon.triangulate().len());
+
+    for piece in &pieces {
+        assert!(piece.is_convex());
+        assert!(piece.signed_area() > 0.);
+    }
+
+    let total_area: f32 = pieces.iter().map(Polygon::area).sum();
+    assert!((total_area - polygon.area()).abs() < 1e-4);
+
+    // Every interior point is covered by exactly one piece
+    for point in [
+        Position::new(0.5, 0.5),
+        Position::new(0.5, 2.5),
+        Position::new(2.5, 2.5),
+        Position::new(4.5, 2.5),
+        Position::new(3.5, 0.5),
+    ] {
+        assert!(polygon.contains(point));
+        let containing = pieces.iter().filter(|piece| piece.contains(point)).count();
+        assert_eq!(containing, 1, "{point:?}");
+    }
+}
+
+#[test]
+fn convex_polygon_is_one_piece() {
+    let hexagon: Polygon<F32> = (0..6)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / 6.;
+            Position::new(angle.cos(), angle.sin())
+        })
+        .collect();
+
+    assert!(hexagon.is_convex());
+    let pieces = hexagon.convex_decomposition();
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].vertexes().len(), 6);
+    assert!((pieces[0].area() - hexagon.area()).abs() < 1e-4);
+}
+
+#[test]
+fn degenerate_polygons() {
+    let empty = Polygon::<F32>::default();
+    assert_eq!(empty.area(), 0.);
+    assert!(empty.triangulate().is_empty());
+    assert!(empty.convex_decomposition().is_empty());
+
+    let segment = Polygon::<F32>::new([Position::new(0., 0.), Position::new(1., 1.)]);
+    assert!(segment.is_convex());
+    assert!(segment.triangulate().is_empty());
+    assert!(!segment.contains(Position::new(0.5, 0.5)));
+}
+
+fn square(left: f32, bottom: f32, size: f32) -> Polygon<F32> {
+    AxisAlignedBoundingBox::new(left, left + size, bottom, bottom + size).into()
+}
+
+fn total_area(pieces: &[Polygon<F32>]) -> f32 {
+    pieces.iter().map(Polygon::area).sum()
+}
+
+#[test]
+fn polygon_intersection() {
+    let overlap = square(0., 0., 2.).intersection(&square(1., 1., 2.));
+    assert!((total_area(&overlap) - 1.).abs() < 1e-4);
+
+    // The comb's teeth poke into a bar across its top
+    let teeth = comb().intersection(&square(-1., 2., 7.));
+    assert!((total_area(&teeth) - 3.).abs() < 1e-4);
+    assert!(teeth.iter().all(Polygon::is_convex));
+
+    // Touching along an edge has no area
+    assert!(square(0., 0., 1.)
+        .intersection(&square(1., 0., 1.))
+        .is_empty());
+    assert!(square(0., 0., 1.)
+        .intersection(&square(5., 5., 1.))
+        .is_empty());
+}
+
+#[test]
+fn polygon_difference() {
+    let floor = square(0., 0., 10.);
+
+    // Subtracting a concave obstacle from the middle of the floor leaves a hole
+    let obstacle: Polygon<F32> = comb()
+        .vertexes()
+        .iter()
+        .map(|&position| Position::new(position.x.0 + 2., position.y.0 + 2.))
+        .collect();
+    let walkable = floor.difference(&obstacle);
+
+    assert!((total_area(&walkable) - (100. - obstacle.area())).abs() < 1e-3);
+    for piece in &walkable {
+        assert!(piece.is_convex());
+        assert!(piece.signed_area() > 0.);
+    }
+
+    // Between the teeth is walkable, but the teeth themselves are not
+    let contained = |point: Position<F32>| {
+        walkable
+            .iter()
+            .filter(|piece| piece.contains(point))
+            .count()
+    };
+    assert_eq!(contained(Position::new(3.5, 4.5)), 1);
+    assert_eq!(contained(Position::new(2.5, 4.5)), 0);
+    assert_eq!(contained(Position::new(1., 1.)), 1);
+    assert_eq!(contained(Position::new(9., 9.)), 1);
+
+    // Subtracting a larger region removes everything
+    assert!(square(1., 1., 1.).difference(&floor).is_empty());
+
+    // Subtracting a disjoint region changes nothing
+    let unchanged = square(0., 0., 1.).difference(&square(5., 5., 1.));
+    assert!((total_area(&unchanged) - 1.).abs() < 1e-4);
+}
@@ -1,6 +1,10 @@
-use bevy::math::Vec3;
-use leafwing_2d::continuous::F32;
-use leafwing_2d::position::Position;
+use bevy::math::{DVec2, DVec3, Vec2, Vec3};
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::{F32, F64};
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::position::{
+    positions_to_vec2, vec2_to_positions, Position, Positionlike, QuantizedPosition,
+};
 
 #[test]
 fn position_to_vec3() {
@@ -72,3 +76,134 @@ fn vec3_to_position() {
         Vec3::new(-42., 3., 17.).try_into()
     );
 }
+
+#[test]
+fn double_precision_conversions() {
+    // Not representable in f32
+    let precise = Position::<F64>::new(16_777_217., -0.1);
+
+    assert_eq!(DVec2::from(precise), DVec2::new(16_777_217., -0.1));
+    assert_eq!(DVec3::from(precise), DVec3::new(16_777_217., -0.1, 0.));
+    assert_eq!(
+        Position::<F64>::from(DVec2::new(16_777_217., -0.1)),
+        precise
+    );
+    assert_eq!(
+        Position::<F64>::from(DVec3::new(16_777_217., -0.1, 17.)),
+        precise
+    );
+
+    assert_eq!(DVec2::new(-42., 3.).into_vec2(), Vec2::new(-42., 3.));
+    assert_eq!(DVec3::new(-42., 3., 17.).into_vec2(), Vec2::new(-42., 3.));
+    DVec2::new(-42., 3.).assert_approx_eq(Position::<F64>::new(-42., 3.));
+}
+
+#[test]
+fn quantization_round_trip() {
+    let bounds = AxisAlignedBoundingBox::<F32>::new(-50., 150., 0., 10.);
+
+    // The corners of the bounds are represented exactly
+    let bottom_left = QuantizedPosition::<8>::quantize(Position::new(-50., 0.), &bounds).unwrap();
+    assert_eq!(bottom_left, QuantizedPosition::from_steps(0, 0).unwrap());
+    assert_eq!(
+        bottom_left.dequantize(&bounds),
+        Position::<F32>::new(-50., 0.)
+    );
+
+    let top_right = QuantizedPosition::<8>::quantize(Position::new(150., 10.), &bounds).unwrap();
+    assert_eq!(top_right, QuantizedPosition::from_steps(255, 255).unwrap());
+    assert_eq!(
+        top_right.dequantize(&bounds),
+        Position::<F32>::new(150., 10.)
+    );
+
+    // Everything else stays within the error bounds
+    let max_error = QuantizedPosition::<8>::max_error(&bounds);
+    for i in 0..100 {
+        let position = Position::<F32>::new(-50. + 2. * i as f32, 0.1 * i as f32);
+        let quantized = QuantizedPosition::<8>::quantize(position, &bounds).unwrap();
+        let round_trip = quantized.dequantize(&bounds);
+
+        assert!((round_trip.x.0 - position.x.0).abs() <= max_error.x);
+        assert!((round_trip.y.0 - position.y.0).abs() <= max_error.y);
+    }
+}
+
+#[test]
+fn quantization_clamping() {
+    let bounds = AxisAlignedBoundingBox::<F32>::new(0., 1., 0., 1.);
+    let outlier = Position::<F32>::new(-3., 7.);
+
+    assert!(QuantizedPosition::<12>::quantize(outlier, &bounds).is_err());
+
+    let clamped = QuantizedPosition::<12>::quantize_clamped(outlier, &bounds);
+    assert_eq!(clamped.dequantize(&bounds), Position::<F32>::new(0., 1.));
+}
+
+#[test]
+fn quantization_packing() {
+    let quantized = QuantizedPosition::<32>::from_steps(u32::MAX, 7).unwrap();
+    assert_eq!(
+        QuantizedPosition::<32>::from_bits(quantized.to_bits()),
+        quantized
+    );
+
+    let quantized = QuantizedPosition::<10>::from_steps(1023, 512).unwrap();
+    assert!(quantized.to_bits() < 1 << 20);
+    assert_eq!(
+        QuantizedPosition::<10>::from_bits(quantized.to_bits()),
+        quantized
+    );
+
+    assert_eq!(QuantizedPosition::<10>::from_steps(1024, 0), None);
+}
+
+#[test]
+fn batch_conversions_round_trip() {
+    // Including empty and odd-length buffers
+    for len in [0, 7, 8, 9, 17] {
+        let positions: Vec<Position<F32>> = (0..len)
+            .map(|i| Position::new(i as f32, -2.0 * i as f32))
+            .collect();
+        let mut vectors = Vec::new();
+        let mut round_trip = Vec::new();
+
+        positions_to_vec2(&positions, &mut vectors);
+        let expected: Vec<Vec2> = positions.iter().map(|&position| position.into()).collect();
+        assert_eq!(vectors, expected);
+
+        vec2_to_positions(&vectors, &mut round_trip);
+        assert_eq!(round_trip, positions);
+    }
+}
+
+#[test]
+fn batch_conversions_match_individual_conversions() {
+    let vectors: Vec<Vec2> = (0..9)
+        .map(|i| Vec2::new(i as f32 * 0.7, 3.3 - i as f32))
+        .collect();
+    let mut positions = Vec::new();
+
+    vec2_to_positions::<OrthogonalGrid>(&vectors, &mut positions);
+    let expected: Vec<Position<OrthogonalGrid>> = vectors.iter().map(|&vec| vec.into()).collect();
+    assert_eq!(positions, expected);
+}
+
+#[test]
+fn batch_conversions_clear_output() {
+    let positions = vec![Position::<F32>::new(1., 2.); 9];
+    let mut vectors = vec![Vec2::new(-5., -5.); 20];
+    let capacity = vectors.capacity();
+
+    positions_to_vec2(&positions, &mut vectors);
+    assert_eq!(vectors, vec![Vec2::new(1., 2.); 9]);
+    // The existing allocation is reused
+    assert_eq!(vectors.capacity(), capacity);
+
+    let mut round_trip = vec![Position::<F32>::new(7., 7.); 3];
+    vec2_to_positions(&vectors, &mut round_trip);
+    assert_eq!(round_trip, positions);
+
+    positions_to_vec2::<F32>(&[], &mut vectors);
+    assert!(vectors.is_empty());
+}
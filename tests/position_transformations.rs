@@ -0,0 +1,165 @@
+use leafwing_2d::continuous::F32;
+use leafwing_2d::orientation::{Direction, Rotation};
+use leafwing_2d::position::{Position, Positionlike};
+
+#[test]
+fn rotation_around_pivot() {
+    let pivot = Position::<F32>::new(-2., 5.);
+    let mut orbiter = Position::<F32>::new(1., 9.);
+
+    // Rotating around yourself does nothing
+    assert_eq!(pivot.rotated_around(pivot, Rotation::EAST), pivot);
+    // Neither does a rotation of 0
+    orbiter
+        .rotated_around(pivot, Rotation::NORTH)
+        .assert_approx_eq(orbiter);
+
+    // Distance to the pivot is preserved
+    let distance =
+        |position: Position<F32>| bevy::math::Vec2::from(position).distance(pivot.into());
+    let initial_distance = distance(orbiter);
+
+    // Four quarter turns clockwise bring us back to where we started
+    let start = orbiter;
+    for _ in 0..4 {
+        orbiter.rotate_around(pivot, Rotation::EAST);
+        assert!((distance(orbiter) - initial_distance).abs() < 1e-4);
+    }
+    orbiter.assert_approx_eq(start);
+
+    // Opposite rotations cancel out
+    let rotation = Rotation::from_degrees(37.);
+    start
+        .rotated_around(pivot, rotation)
+        .rotated_around(pivot, -rotation)
+        .assert_approx_eq(start);
+}
+
+#[test]
+fn reflection_across_line() {
+    let origin = Position::<F32>::new(2., -1.);
+    let position = Position::<F32>::new(5., 3.);
+
+    // Reflecting twice returns to the start
+    for axis in [Direction::NORTH, Direction::EAST, Direction::SOUTHWEST] {
+        position
+            .reflected_across(origin, axis)
+            .reflected_across(origin, axis)
+            .assert_approx_eq(position);
+        // Opposite directions describe the same line
+        position
+            .reflected_across(origin, axis)
+            .assert_approx_eq(position.reflected_across(origin, -axis));
+    }
+
+    // Positions on the line are unchanged
+    origin
+        .reflected_across(origin, Direction::SOUTHWEST)
+        .assert_approx_eq(origin);
+
+    // Vertical and horizontal lines match the mirroring shortcuts
+    position
+        .reflected_across(origin, Direction::NORTH)
+        .assert_approx_eq(position.mirror_x(2.));
+    position
+        .reflected_across(origin, Direction::EAST)
+        .assert_approx_eq(position.mirror_y(-1.));
+}
+
+#[test]
+fn mirroring_discrete_positions() {
+    use leafwing_2d::discrete::OrthogonalGrid;
+
+    let cell = Position::<OrthogonalGrid>::new(3_isize, 7);
+
+    assert_eq!(cell.mirror_x(5_isize), Position::new(7_isize, 7));
+    assert_eq!(cell.mirror_y(5_isize), Position::new(3_isize, 3));
+    assert_eq!(cell.mirror_x(5_isize).mirror_x(5_isize), cell);
+}
+
+#[test]
+fn polar_offsets() {
+    let ship = Position::<F32>::new(3., -4.);
+
+    for rotation in [Rotation::NORTH, Rotation::new(1234), Rotation::WEST] {
+        let polar = Position::<F32>::from_polar(rotation, 5.);
+        assert!((bevy::math::Vec2::from(polar).length() - 5.).abs() < 1e-4);
+
+        // Both constructors agree
+        ship.offset_in_direction(rotation.into(), 5.)
+            .assert_approx_eq(ship + polar);
+    }
+
+    // Negative distances travel backwards
+    ship.offset_in_direction(Direction::NORTH, -1.)
+        .assert_approx_eq(Position::<F32>::new(3., -5.));
+}
+
+mod transform_2d {
+    use bevy::prelude::Transform;
+    use leafwing_2d::continuous::F32;
+    use leafwing_2d::orientation::{Orientation, Rotation};
+    use leafwing_2d::position::{Position, Positionlike};
+    use leafwing_2d::scale::Scale;
+    use leafwing_2d::transform::Transform2D;
+
+    fn transforms() -> [Transform2D<F32>; 3] {
+        [
+            Transform2D::new(Position::new(1., 2.), Rotation::new(300), Scale(0.5)),
+            Transform2D::new(Position::new(-4., 0.), Rotation::SOUTHWEST, Scale(3.)),
+            Transform2D::from_rotation(Rotation::new(1234)),
+        ]
+    }
+
+    #[test]
+    fn identity() {
+        let position = Position::<F32>::new(3., -7.);
+
+        assert_eq!(Transform2D::IDENTITY * position, position);
+        for transform in transforms() {
+            assert_eq!(Transform2D::IDENTITY * transform, transform);
+        }
+    }
+
+    #[test]
+    fn composition_is_associative() {
+        let [a, b, c] = transforms();
+        let position = Position::<F32>::new(3., -7.);
+
+        ((a * b) * position).assert_approx_eq(a * (b * position));
+        ((a * b) * c)
+            .position
+            .assert_approx_eq((a * (b * c)).position);
+        assert_eq!(((a * b) * c).rotation, (a * (b * c)).rotation);
+        assert_eq!(((a * b) * c).scale, (a * (b * c)).scale);
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let position = Position::<F32>::new(3., -7.);
+
+        for transform in transforms() {
+            (transform.inverse() * (transform * position)).assert_approx_eq(position);
+            (transform * (transform.inverse() * position)).assert_approx_eq(position);
+
+            let identity = transform * transform.inverse();
+            identity
+                .position
+                .assert_approx_eq(Position::<F32>::default());
+            assert_eq!(identity.rotation, Rotation::NORTH);
+            assert!((identity.scale.0 - 1.).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn bevy_transform_round_trip() {
+        for transform in transforms() {
+            let bevy_transform = Transform::from(transform);
+            let round_trip = Transform2D::<F32>::from(bevy_transform);
+
+            round_trip.position.assert_approx_eq(transform.position);
+            assert!(round_trip.rotation.distance(transform.rotation) <= Rotation::new(1));
+            assert_eq!(round_trip.scale, transform.scale);
+        }
+    }
+}
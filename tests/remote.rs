@@ -0,0 +1,92 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use core::time::Duration;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::Velocity;
+use leafwing_2d::orientation::{Orientation, Rotation};
+use leafwing_2d::position::Position;
+use leafwing_2d::remote::{reconcile_remote_kinematics, RemoteKinematics, RemoteSnapshot};
+
+const EASTWARDS: Velocity<F32> = Velocity {
+    x: F32(1.0),
+    y: F32(0.0),
+};
+
+fn snapshot(x: f32, timestamp: f64) -> RemoteSnapshot<F32> {
+    RemoteSnapshot {
+        position: Position::new(x, 0.0),
+        velocity: EASTWARDS,
+        rotation: Rotation::EAST,
+        timestamp,
+    }
+}
+
+#[test]
+fn extrapolation_is_limited() {
+    let mut remote = RemoteKinematics::<F32>::new(0.1);
+    assert_eq!(remote.extrapolate(0.0), None);
+
+    remote.receive(snapshot(0.0, 2.0));
+    // Snapshots from the future are not extrapolated backwards
+    assert_eq!(
+        remote.extrapolate(1.0),
+        Some((Position::new(0.0, 0.0), Rotation::EAST))
+    );
+    // Nor are they extrapolated for too long
+    assert_eq!(
+        remote.extrapolate(10.0),
+        Some((Position::new(0.25, 0.0), Rotation::EAST))
+    );
+
+    // Newer snapshots replace older ones
+    assert!(remote.receive(snapshot(5.0, 2.0)));
+    assert_eq!(remote.latest(), Some(&snapshot(5.0, 2.0)));
+}
+
+#[test]
+fn displayed_state_converges_on_the_prediction() {
+    let mut world = World::new();
+    let mut time = Time::default();
+    time.update();
+    world.insert_resource(time);
+
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(reconcile_remote_kinematics::<F32>);
+
+    let mut remote = RemoteKinematics::<F32>::new(0.001);
+    remote.max_extrapolation = 0.0;
+    remote.receive(snapshot(10.0, 0.0));
+    let smoothed = world
+        .spawn()
+        .insert_bundle((
+            remote.clone(),
+            Position::<F32>::new(0.0, 0.0),
+            Rotation::NORTH,
+            Velocity::<F32>::default(),
+        ))
+        .id();
+
+    remote.correction_half_life = 1000.0;
+    remote.snap_distance = 1.0;
+    let snapped = world
+        .spawn()
+        .insert_bundle((remote, Position::<F32>::new(0.0, 0.0)))
+        .id();
+
+    std::thread::sleep(Duration::from_millis(10));
+    world.resource_mut::<Time>().update();
+    stage.run(&mut world);
+
+    // Ten milliseconds is many half-lives, so the error is almost entirely corrected
+    let position = *world.get::<Position<F32>>(smoothed).unwrap();
+    assert!(position.x.0 > 9.9 && position.x.0 < 10.0);
+    let rotation = *world.get::<Rotation>(smoothed).unwrap();
+    assert!(rotation.distance(Rotation::EAST) <= Rotation::new(1));
+    assert_eq!(*world.get::<Velocity<F32>>(smoothed).unwrap(), EASTWARDS);
+
+    // The error was too large to smooth over
+    assert_eq!(
+        *world.get::<Position<F32>>(snapped).unwrap(),
+        Position::new(10.0, 0.0)
+    );
+}
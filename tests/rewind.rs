@@ -0,0 +1,106 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::Velocity;
+use leafwing_2d::orientation::Rotation;
+use leafwing_2d::position::Position;
+use leafwing_2d::rewind::{update_kinematic_history, KinematicHistory, RewindActive};
+
+fn moved_to(world: &mut World, entity: Entity, x: f32) {
+    *world.get_mut::<Position<F32>>(entity).unwrap() = Position::new(x, 0.0);
+    *world.get_mut::<Rotation>(entity).unwrap() = Rotation::from_degrees(x);
+}
+
+fn x_of(world: &World, entity: Entity) -> f32 {
+    world.get::<Position<F32>>(entity).unwrap().x.into()
+}
+
+#[test]
+fn rewinding_plays_history_backwards_then_resumes() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_kinematic_history::<F32>);
+
+    let runner = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Rotation::default(),
+            KinematicHistory::<F32>::new(3),
+        ))
+        .id();
+    // Without a history, entities are unaffected by rewinding
+    let bystander = world.spawn().insert(Position::<F32>::new(0.0, 0.0)).id();
+
+    for x in 1..=4 {
+        moved_to(&mut world, runner, x as f32);
+        stage.run(&mut world);
+    }
+    // Only the newest frames are remembered
+    let history = world.get::<KinematicHistory<F32>>(runner).unwrap();
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.snapshots().next().unwrap().position,
+        Position::new(2.0, 0.0)
+    );
+    assert_eq!(history.latest().unwrap().velocity, None);
+
+    world.insert_resource(RewindActive);
+    let mut rewound = Vec::new();
+    for _ in 0..4 {
+        stage.run(&mut world);
+        rewound.push(x_of(&world, runner));
+    }
+    // The entity stays at its oldest state once the history runs out
+    assert_eq!(rewound, vec![4.0, 3.0, 2.0, 2.0]);
+    assert_eq!(
+        *world.get::<Rotation>(runner).unwrap(),
+        Rotation::from_degrees(2.0)
+    );
+    assert_eq!(x_of(&world, bystander), 0.0);
+
+    // Recording resumes from the rewound state
+    world.remove_resource::<RewindActive>();
+    stage.run(&mut world);
+    let history = world.get::<KinematicHistory<F32>>(runner).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.latest().unwrap().position, Position::new(2.0, 0.0));
+}
+
+#[test]
+fn velocity_is_restored_when_rewinding() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_kinematic_history::<F32>);
+
+    let ball = world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Velocity::<F32> {
+                x: F32(1.0),
+                y: F32(0.0),
+            },
+            KinematicHistory::<F32>::new(10),
+        ))
+        .id();
+    stage.run(&mut world);
+
+    // The ball bounces off of a wall
+    *world.get_mut::<Velocity<F32>>(ball).unwrap() = Velocity {
+        x: F32(-1.0),
+        y: F32(0.0),
+    };
+    stage.run(&mut world);
+
+    world.insert_resource(RewindActive);
+    stage.run(&mut world);
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<Velocity<F32>>(ball).unwrap(),
+        Velocity {
+            x: F32(1.0),
+            y: F32(0.0),
+        }
+    );
+}
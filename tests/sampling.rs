@@ -0,0 +1,82 @@
+#![cfg(feature = "rand")]
+
+use leafwing_2d::bounding::{
+    Annulus, AxisAlignedBoundingBox, BoundingRegion, Capsule, Circle, CompositeMode,
+    CompositeRegion, Ellipse,
+};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::orientation::Rotation;
+use leafwing_2d::position::Position;
+use rand::{rngs::StdRng, SeedableRng};
+
+const SAMPLES: usize = 4000;
+
+/// Samples the `region` many times, checking that every sample is inside of it
+fn sample_many(region: &impl BoundingRegion<C = F32>) -> Vec<Position<F32>> {
+    let mut rng = StdRng::seed_from_u64(7);
+    let samples: Vec<Position<F32>> = (0..SAMPLES).map(|_| region.sample(&mut rng)).collect();
+
+    for &sample in &samples {
+        assert!(region.contains(sample), "{sample:?} is outside the region");
+    }
+    samples
+}
+
+/// The fraction of the `samples` for which `predicate` is true
+fn fraction(samples: &[Position<F32>], predicate: impl Fn(&Position<F32>) -> bool) -> f32 {
+    samples.iter().filter(|&sample| predicate(sample)).count() as f32 / samples.len() as f32
+}
+
+#[test]
+fn aabb_samples_are_uniform() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-2., 6., 1., 3.);
+    let samples = sample_many(&aabb);
+
+    assert!((fraction(&samples, |sample| sample.x.0 < 2.) - 0.5).abs() < 0.03);
+    assert!((fraction(&samples, |sample| sample.y.0 < 1.5) - 0.25).abs() < 0.03);
+}
+
+#[test]
+fn circle_samples_are_uniform() {
+    let circle = Circle::<F32>::new(Position::new(1., 1.), 4.);
+    let samples = sample_many(&circle);
+
+    // The inner circle of half the radius has a quarter of the area
+    let inner = Circle::<F32>::new(Position::new(1., 1.), 2.);
+    assert!((fraction(&samples, |&sample| inner.contains(sample)) - 0.25).abs() < 0.03);
+    assert!((fraction(&samples, |sample| sample.x.0 < 1.) - 0.5).abs() < 0.03);
+}
+
+#[test]
+fn curved_regions_can_be_sampled() {
+    let ellipse = Ellipse::<F32>::new(Position::new(0., 0.), 4., 1., Rotation::from_degrees(30.));
+    let samples = sample_many(&ellipse);
+    let inner = Ellipse::<F32>::new(Position::new(0., 0.), 2., 0.5, Rotation::from_degrees(30.));
+    assert!((fraction(&samples, |&sample| inner.contains(sample)) - 0.25).abs() < 0.03);
+
+    sample_many(&Capsule::<F32>::new(
+        Position::new(-3., 2.),
+        Position::new(5., -1.),
+        0.5,
+    ));
+
+    // Annuli have an inherent `sample` method, which takes the random numbers directly
+    let ring = Annulus::<F32>::new(Position::new(0., 0.), 3., 5.);
+    let mut rng = StdRng::seed_from_u64(7);
+    assert!(ring.contains(BoundingRegion::sample(&ring, &mut rng)));
+}
+
+#[test]
+fn composite_regions_can_be_sampled() {
+    let union = CompositeRegion::<F32>::new(CompositeMode::Union)
+        .with(AxisAlignedBoundingBox::new(0., 10., 0., 10.))
+        .with(AxisAlignedBoundingBox::new(10., 20., 4., 6.));
+    let samples = sample_many(&union);
+    // The corridor has a sixth of the total area
+    assert!((fraction(&samples, |sample| sample.x.0 > 10.) - 1. / 6.).abs() < 0.03);
+
+    let intersection = CompositeRegion::<F32>::new(CompositeMode::Intersection)
+        .with(Circle::new(Position::new(0., 0.), 5.))
+        .with(AxisAlignedBoundingBox::new(0., 10., -10., 10.));
+    sample_many(&intersection);
+}
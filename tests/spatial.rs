@@ -0,0 +1,285 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoxedRegion, Circle};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::position::Position;
+use leafwing_2d::spatial::{
+    update_quad_tree, update_spatial_hash, QuadTree, SpatialHash, SpatialQuery,
+};
+
+#[test]
+fn entities_in_region() {
+    let mut world = World::new();
+    let near = world.spawn().insert(Position::<F32>::new(1.0, 1.0)).id();
+    let edge = world.spawn().insert(Position::<F32>::new(3.0, 4.0)).id();
+    let far = world.spawn().insert(Position::<F32>::new(10.0, 0.0)).id();
+    // Entities without a position can't be found
+    world.spawn();
+
+    let mut system_state: SystemState<SpatialQuery<F32>> = SystemState::new(&mut world);
+    let spatial_query = system_state.get_mut(&mut world);
+
+    let mut found = spatial_query.entities_in(&Circle::new(Position::new(0.0, 0.0), 5.0));
+    found.sort();
+    assert_eq!(found, vec![near, edge]);
+
+    // Trait objects work too
+    let boxed: BoxedRegion<F32> = Box::new(AxisAlignedBoundingBox::new(5.0, 15.0, -1.0, 1.0));
+    assert_eq!(spatial_query.entities_in(boxed.as_ref()), vec![far]);
+
+    assert!(spatial_query.any_in(&Circle::new(Position::new(10.0, 1.0), 2.0)));
+    assert!(!spatial_query.any_in(&Circle::new(Position::new(-10.0, 0.0), 2.0)));
+}
+
+#[test]
+fn spatial_hash_queries() {
+    let entity = Entity::from_raw;
+    let mut spatial_hash = SpatialHash::<F32>::new(2.0);
+    spatial_hash.insert(entity(0), Position::new(0.5, 0.5));
+    spatial_hash.insert(entity(1), Position::new(-3.0, 1.0));
+    spatial_hash.insert(entity(2), Position::new(3.0, 4.0));
+    spatial_hash.insert(entity(3), Position::new(50.0, -50.0));
+    assert_eq!(spatial_hash.len(), 4);
+
+    assert_eq!(
+        spatial_hash.entities_in_radius(Position::new(0.0, 0.0), 5.0),
+        vec![entity(0), entity(1), entity(2)]
+    );
+    assert_eq!(
+        spatial_hash.entities_in_aabb(&AxisAlignedBoundingBox::new(-4.0, 1.0, 0.0, 4.0)),
+        vec![entity(0), entity(1)]
+    );
+
+    // The nearest entity may be several cells away
+    assert_eq!(
+        spatial_hash.nearest(Position::new(0.0, 0.0)),
+        Some(entity(0))
+    );
+    assert_eq!(
+        spatial_hash.nearest(Position::new(30.0, -30.0)),
+        Some(entity(3))
+    );
+    assert_eq!(
+        spatial_hash.nearest(Position::new(-10.0, 1.0)),
+        Some(entity(1))
+    );
+
+    // Moving into another cell
+    spatial_hash.insert(entity(3), Position::new(-1.0, -1.0));
+    assert_eq!(spatial_hash.len(), 4);
+    assert_eq!(
+        spatial_hash.entities_in_radius(Position::new(0.0, 0.0), 2.0),
+        vec![entity(0), entity(3)]
+    );
+
+    assert_eq!(
+        spatial_hash.remove(entity(0)),
+        Some(Position::new(0.5, 0.5))
+    );
+    assert_eq!(spatial_hash.remove(entity(0)), None);
+    assert_eq!(
+        spatial_hash.nearest(Position::new(0.0, 0.0)),
+        Some(entity(3))
+    );
+
+    spatial_hash.clear();
+    assert!(spatial_hash.is_empty());
+    assert_eq!(spatial_hash.nearest(Position::new(0.0, 0.0)), None);
+}
+
+#[test]
+fn spatial_hash_matches_brute_force() {
+    let mut spatial_hash = SpatialHash::<F32>::new(3.0);
+    let mut positions = Vec::new();
+    for i in 0..200_u32 {
+        // Scatter the points deterministically
+        let x = ((i * 37) % 101) as f32 - 50.0;
+        let y = ((i * 53) % 89) as f32 * 0.7 - 30.0;
+        spatial_hash.insert(Entity::from_raw(i), Position::new(x, y));
+        positions.push((Entity::from_raw(i), Vec2::new(x, y)));
+    }
+
+    for target in [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(-49.0, 20.0),
+        Vec2::new(200.0, -200.0),
+        Vec2::new(12.3, -4.5),
+    ] {
+        let expected = positions
+            .iter()
+            .min_by(|a, b| {
+                a.1.distance_squared(target)
+                    .total_cmp(&b.1.distance_squared(target))
+                    .then(a.0.cmp(&b.0))
+            })
+            .map(|&(entity, _)| entity);
+        assert_eq!(spatial_hash.nearest(Position::from(target)), expected);
+
+        let mut in_radius: Vec<Entity> = positions
+            .iter()
+            .filter(|(_, point)| point.distance(target) <= 10.0)
+            .map(|&(entity, _)| entity)
+            .collect();
+        in_radius.sort();
+        assert_eq!(
+            spatial_hash.entities_in_radius(Position::from(target), 10.0),
+            in_radius
+        );
+    }
+}
+
+#[test]
+fn spatial_hash_tracks_position_changes() {
+    let mut world = World::new();
+    world.insert_resource(SpatialHash::<F32>::new(1.0));
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_spatial_hash::<F32>);
+
+    let mover = world.spawn().insert(Position::<F32>::new(0.0, 0.0)).id();
+    let despawned = world.spawn().insert(Position::<F32>::new(5.0, 5.0)).id();
+    stage.run(&mut world);
+    assert_eq!(world.resource::<SpatialHash<F32>>().len(), 2);
+
+    *world.get_mut::<Position<F32>>(mover).unwrap() = Position::new(10.0, 0.0);
+    world.despawn(despawned);
+    stage.run(&mut world);
+
+    let spatial_hash = world.resource::<SpatialHash<F32>>();
+    assert_eq!(spatial_hash.len(), 1);
+    assert_eq!(spatial_hash.position(mover), Some(Position::new(10.0, 0.0)));
+    assert_eq!(
+        spatial_hash.entities_in_radius(Position::new(10.0, 0.0), 1.0),
+        vec![mover]
+    );
+}
+
+#[test]
+fn quad_tree_queries() {
+    let entity = Entity::from_raw;
+    let mut quad_tree = QuadTree::<F32>::new(AxisAlignedBoundingBox::new(-10.0, 10.0, -10.0, 10.0));
+    quad_tree.insert(entity(0), Position::new(0.5, 0.5));
+    quad_tree.insert(entity(1), Position::new(-3.0, 1.0));
+    quad_tree.insert(entity(2), Position::new(3.0, 4.0));
+    // Outside of the bounds, but still tracked
+    quad_tree.insert(entity(3), Position::new(50.0, -50.0));
+    assert_eq!(quad_tree.len(), 4);
+
+    assert_eq!(
+        quad_tree.entities_in_radius(Position::new(0.0, 0.0), 5.0),
+        vec![entity(0), entity(1), entity(2)]
+    );
+    assert_eq!(
+        quad_tree.entities_in_aabb(&AxisAlignedBoundingBox::new(-4.0, 1.0, 0.0, 4.0)),
+        vec![entity(0), entity(1)]
+    );
+    assert_eq!(
+        quad_tree.k_nearest(Position::new(0.0, 0.0), 3),
+        vec![entity(0), entity(1), entity(2)]
+    );
+    assert_eq!(quad_tree.k_nearest(Position::new(0.0, 0.0), 0), vec![]);
+    assert_eq!(quad_tree.k_nearest(Position::new(0.0, 0.0), 10).len(), 4);
+    assert_eq!(
+        quad_tree.nearest(Position::new(30.0, -30.0)),
+        Some(entity(3))
+    );
+
+    // Moving from outside of the bounds to inside
+    quad_tree.insert(entity(3), Position::new(-1.0, -1.0));
+    assert_eq!(quad_tree.len(), 4);
+    assert_eq!(
+        quad_tree.entities_in_radius(Position::new(0.0, 0.0), 2.0),
+        vec![entity(0), entity(3)]
+    );
+
+    assert_eq!(quad_tree.remove(entity(0)), Some(Position::new(0.5, 0.5)));
+    assert_eq!(quad_tree.remove(entity(0)), None);
+    assert_eq!(quad_tree.nearest(Position::new(0.0, 0.0)), Some(entity(3)));
+
+    quad_tree.clear();
+    assert!(quad_tree.is_empty());
+    assert_eq!(quad_tree.nearest(Position::new(0.0, 0.0)), None);
+}
+
+#[test]
+fn quad_tree_matches_brute_force() {
+    let mut positions = Vec::new();
+    for i in 0..300_u32 {
+        // A dense cluster, scattered across a wide, mostly empty world
+        let (x, y) = if i % 3 == 0 {
+            (
+                ((i * 37) % 101) as f32 * 20.0 - 1000.0,
+                ((i * 53) % 89) as f32 * 20.0 - 900.0,
+            )
+        } else {
+            (((i * 37) % 101) as f32 * 0.1, ((i * 53) % 89) as f32 * 0.1)
+        };
+        positions.push((Entity::from_raw(i), Vec2::new(x, y)));
+    }
+
+    let mut quad_tree: QuadTree<F32> = positions
+        .iter()
+        .map(|&(entity, point)| (entity, Position::from(point)))
+        .collect();
+    // Removing entities merges sparse quadrants back together
+    for &(entity, _) in positions.iter().skip(1).step_by(4) {
+        quad_tree.remove(entity);
+    }
+    positions = positions
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| i % 4 != 1)
+        .map(|(_, entry)| entry)
+        .collect();
+    assert_eq!(quad_tree.len(), positions.len());
+
+    for target in [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(5.0, 4.0),
+        Vec2::new(-700.0, 200.0),
+        Vec2::new(3000.0, -3000.0),
+    ] {
+        let mut expected = positions.clone();
+        expected.sort_by(|a, b| {
+            a.1.distance_squared(target)
+                .total_cmp(&b.1.distance_squared(target))
+                .then(a.0.cmp(&b.0))
+        });
+        let expected: Vec<Entity> = expected.iter().take(5).map(|&(entity, _)| entity).collect();
+        assert_eq!(quad_tree.k_nearest(Position::from(target), 5), expected);
+
+        let mut in_radius: Vec<Entity> = positions
+            .iter()
+            .filter(|(_, point)| point.distance(target) <= 50.0)
+            .map(|&(entity, _)| entity)
+            .collect();
+        in_radius.sort();
+        assert_eq!(
+            quad_tree.entities_in_radius(Position::from(target), 50.0),
+            in_radius
+        );
+    }
+}
+
+#[test]
+fn quad_tree_tracks_position_changes() {
+    let mut world = World::new();
+    world.insert_resource(QuadTree::<F32>::new(AxisAlignedBoundingBox::new(
+        -20.0, 20.0, -20.0, 20.0,
+    )));
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_quad_tree::<F32>);
+
+    let mover = world.spawn().insert(Position::<F32>::new(0.0, 0.0)).id();
+    let despawned = world.spawn().insert(Position::<F32>::new(5.0, 5.0)).id();
+    stage.run(&mut world);
+    assert_eq!(world.resource::<QuadTree<F32>>().len(), 2);
+
+    *world.get_mut::<Position<F32>>(mover).unwrap() = Position::new(10.0, 0.0);
+    world.despawn(despawned);
+    stage.run(&mut world);
+
+    let quad_tree = world.resource::<QuadTree<F32>>();
+    assert_eq!(quad_tree.len(), 1);
+    assert_eq!(quad_tree.position(mover), Some(Position::new(10.0, 0.0)));
+    assert_eq!(quad_tree.nearest(Position::new(0.0, 0.0)), Some(mover));
+}
@@ -0,0 +1,368 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::{ParallelSystemDescriptorCoercion, World};
+use leafwing_2d::bounding::{AxisAlignedBoundingBox, Circle};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::position::Position;
+use leafwing_2d::spatial::{update_spatial_hash, SpatialHash};
+use leafwing_2d::steering::{
+    apply_steering, avoid_obstacles, flock, pursue_and_evade, seek_flee_and_arrive, Arrive,
+    AvoidObstacles, Evade, Flee, Flocking, Obstacle, Pursue, Seek, Steering, SteeringAgent,
+};
+
+fn acceleration(x: f32, y: f32) -> Acceleration<F32> {
+    Acceleration {
+        x: F32(x),
+        y: F32(y),
+    }
+}
+
+fn velocity(x: f32, y: f32) -> Velocity<F32> {
+    Velocity {
+        x: F32(x),
+        y: F32(y),
+    }
+}
+
+#[test]
+fn seek_flee_and_arrive_behaviors() {
+    let agent = SteeringAgent::new(5.0, 100.0);
+    let origin = Position::<F32>::new(0.0, 0.0);
+    let target = Position::new(0.0, 10.0);
+
+    assert_eq!(
+        agent.seek(origin, velocity(0.0, 0.0), target),
+        acceleration(0.0, 5.0)
+    );
+    // Steering cancels out sideways motion
+    assert_eq!(
+        agent.seek(origin, velocity(3.0, 0.0), target),
+        acceleration(-3.0, 5.0)
+    );
+    assert_eq!(
+        agent.flee(origin, velocity(0.0, 0.0), target),
+        acceleration(0.0, -5.0)
+    );
+    assert_eq!(
+        agent.flee(target, velocity(0.0, 0.0), target),
+        Acceleration::default()
+    );
+
+    // Full speed outside of the slowing radius, slowing to a stop inside of it
+    assert_eq!(
+        agent.arrive(origin, velocity(0.0, 0.0), target, 5.0),
+        acceleration(0.0, 5.0)
+    );
+    assert_eq!(
+        agent.arrive(origin, velocity(0.0, 5.0), target, 20.0),
+        acceleration(0.0, -2.5)
+    );
+    assert_eq!(
+        agent.arrive(target, velocity(0.0, 5.0), target, 20.0),
+        acceleration(0.0, -5.0)
+    );
+
+    // Steering is never stronger than the maximum acceleration
+    let sluggish = SteeringAgent::new(5.0, 2.0);
+    assert_eq!(
+        sluggish.seek(origin, velocity(0.0, 0.0), target),
+        acceleration(0.0, 2.0)
+    );
+}
+
+#[test]
+fn behaviors_are_combined_into_acceleration() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(seek_flee_and_arrive::<F32>.before("apply"));
+    stage.add_system(apply_steering::<F32>.label("apply"));
+
+    let agent = world
+        .spawn()
+        .insert_bundle((
+            SteeringAgent::new(5.0, 4.0),
+            Steering::<F32>::default(),
+            Position::<F32>::new(0.0, 0.0),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+            Seek {
+                target: Position::<F32>::new(10.0, 0.0),
+            },
+            Flee {
+                target: Position::<F32>::new(0.0, -10.0),
+            },
+        ))
+        .id();
+    let arriving = world
+        .spawn()
+        .insert_bundle((
+            SteeringAgent::new(5.0, 10.0),
+            Steering::<F32>::default(),
+            Position::<F32>::new(0.0, 0.0),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+            Arrive {
+                target: Position::<F32>::new(-2.0, 0.0),
+                slowing_radius: 4.0,
+            },
+        ))
+        .id();
+
+    stage.run(&mut world);
+
+    // Seeking east and fleeing south add up to north-east, limited to the maximum acceleration
+    let combined = *world.get::<Acceleration<F32>>(agent).unwrap();
+    assert!((combined.x.0 - combined.y.0).abs() < 1e-5);
+    assert!((combined.x.0.hypot(combined.y.0) - 4.0).abs() < 1e-5);
+    assert_eq!(
+        *world.get::<Steering<F32>>(agent).unwrap(),
+        Steering::default()
+    );
+
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(arriving).unwrap(),
+        acceleration(-2.5, 0.0)
+    );
+}
+
+#[test]
+fn pursuers_cut_off_their_target() {
+    let agent = SteeringAgent::new(5.0, 100.0);
+    let origin = Position::<F32>::new(0.0, 0.0);
+    let target = Position::new(10.0, 0.0);
+
+    // Two seconds away at top speed, by which time the target will have moved 10 units north
+    let diagonal = 5.0 / 2.0_f32.sqrt();
+    let pursuit = agent.pursue(origin, velocity(0.0, 0.0), target, velocity(0.0, 5.0));
+    assert!((pursuit.x.0 - diagonal).abs() < 1e-5);
+    assert!((pursuit.y.0 - diagonal).abs() < 1e-5);
+
+    let evasion = agent.evade(origin, velocity(0.0, 0.0), target, velocity(0.0, 5.0));
+    assert!((evasion.x.0 + diagonal).abs() < 1e-5);
+    assert!((evasion.y.0 + diagonal).abs() < 1e-5);
+
+    // Stationary targets are simply sought
+    assert_eq!(
+        agent.pursue(origin, velocity(0.0, 0.0), target, velocity(0.0, 0.0)),
+        agent.seek(origin, velocity(0.0, 0.0), target)
+    );
+
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(pursue_and_evade::<F32>.before("apply"));
+    stage.add_system(apply_steering::<F32>.label("apply"));
+
+    let runner = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(10.0, 0.0), velocity(0.0, 5.0)))
+        .id();
+    let spawn_agent = |world: &mut World| {
+        world
+            .spawn()
+            .insert_bundle((
+                agent,
+                Steering::<F32>::default(),
+                origin,
+                Velocity::<F32>::default(),
+                Acceleration::<F32>::default(),
+            ))
+            .id()
+    };
+    let pursuer = spawn_agent(&mut world);
+    world.entity_mut(pursuer).insert(Pursue { target: runner });
+    let evader = spawn_agent(&mut world);
+    world.entity_mut(evader).insert(Evade { target: runner });
+
+    stage.run(&mut world);
+    assert_eq!(*world.get::<Acceleration<F32>>(pursuer).unwrap(), pursuit);
+    assert_eq!(*world.get::<Acceleration<F32>>(evader).unwrap(), evasion);
+
+    // Once the target is gone, there's nothing left to chase
+    world.despawn(runner);
+    stage.run(&mut world);
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(pursuer).unwrap(),
+        Acceleration::default()
+    );
+}
+
+#[test]
+fn flocking_behaviors() {
+    let agent = SteeringAgent::new(5.0, 100.0);
+    let origin = Position::<F32>::new(0.0, 0.0);
+    let still = velocity(0.0, 0.0);
+
+    assert_eq!(
+        agent.separation(origin, still, [Position::new(1.0, 0.0)]),
+        acceleration(-5.0, 0.0)
+    );
+    // The closest neighbor pushes hardest
+    let separation = agent.separation(
+        origin,
+        still,
+        [Position::new(0.0, 1.0), Position::new(-4.0, 0.0)],
+    );
+    assert!(separation.y.0 < -4.0 && separation.x.0 > 0.0);
+
+    assert_eq!(
+        agent.alignment(still, [velocity(0.0, 2.0), velocity(0.0, 4.0)]),
+        acceleration(0.0, 5.0)
+    );
+    assert_eq!(
+        agent.cohesion(
+            origin,
+            still,
+            [Position::new(2.0, 0.0), Position::new(4.0, 0.0)]
+        ),
+        acceleration(5.0, 0.0)
+    );
+
+    // Loners have nothing to flock with
+    assert_eq!(agent.separation(origin, still, []), Acceleration::default());
+    assert_eq!(agent.alignment(still, []), Acceleration::default());
+    assert_eq!(agent.cohesion(origin, still, []), Acceleration::default());
+
+    let mut flocking = Flocking::new(4.0);
+    flocking.separation_weight = 0.0;
+    flocking.alignment_weight = 0.0;
+    assert_eq!(
+        flocking.acceleration(&agent, origin, still, &[(Position::new(3.0, 0.0), still)]),
+        acceleration(5.0, 0.0)
+    );
+}
+
+#[test]
+fn flocks_find_neighbors_through_the_spatial_hash() {
+    let mut world = World::new();
+    world.insert_resource(SpatialHash::<F32>::new(4.0));
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(update_spatial_hash::<F32>.before("flock"));
+    stage.add_system(flock::<F32>.label("flock").before("apply"));
+    stage.add_system(apply_steering::<F32>.label("apply"));
+
+    let agent = SteeringAgent::new(5.0, 100.0);
+    let flocking = Flocking::new(3.0);
+    let mut spawn_bird = |x: f32, y: f32| {
+        world
+            .spawn()
+            .insert_bundle((
+                agent,
+                flocking,
+                Steering::<F32>::default(),
+                Position::<F32>::new(x, y),
+                Velocity::<F32>::default(),
+                Acceleration::<F32>::default(),
+            ))
+            .id()
+    };
+    let left = spawn_bird(0.0, 0.0);
+    let right = spawn_bird(2.0, 0.0);
+    // Too far away to be part of the flock
+    let straggler = spawn_bird(20.0, 0.0);
+    // Not flocking, so ignored by the birds
+    world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(1.0, 1.0), Velocity::<F32>::default()));
+
+    stage.run(&mut world);
+
+    let still = Velocity::default();
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(left).unwrap(),
+        flocking.acceleration(
+            &agent,
+            Position::new(0.0, 0.0),
+            still,
+            &[(Position::new(2.0, 0.0), still)]
+        )
+    );
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(right).unwrap(),
+        flocking.acceleration(
+            &agent,
+            Position::new(2.0, 0.0),
+            still,
+            &[(Position::new(0.0, 0.0), still)]
+        )
+    );
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(straggler).unwrap(),
+        Acceleration::default()
+    );
+}
+
+#[test]
+fn agents_swerve_around_obstacles() {
+    let agent = SteeringAgent::new(5.0, 2.0);
+    let origin = Position::<F32>::new(0.0, 0.0);
+    let north = velocity(0.0, 5.0);
+
+    // Swerve away from whichever side the obstacle is on, passing dead-ahead obstacles on the right
+    assert_eq!(
+        agent.avoid(origin, north, Position::new(1.0, 3.0)),
+        acceleration(-2.0, 0.0)
+    );
+    assert_eq!(
+        agent.avoid(origin, north, Position::new(-1.0, 3.0)),
+        acceleration(2.0, 0.0)
+    );
+    assert_eq!(
+        agent.avoid(origin, north, Position::new(0.0, 3.0)),
+        acceleration(2.0, 0.0)
+    );
+    assert_eq!(
+        agent.avoid(origin, velocity(0.0, 0.0), Position::new(0.0, 3.0)),
+        Acceleration::default()
+    );
+
+    let avoidance = AvoidObstacles::new(5.0, 0.5);
+    assert_eq!(avoidance.probe(origin, velocity(0.0, 0.0)), None);
+
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(avoid_obstacles::<F32>.before("apply"));
+    stage.add_system(apply_steering::<F32>.label("apply"));
+
+    let mut spawn_agent = |x: f32| {
+        world
+            .spawn()
+            .insert_bundle((
+                agent,
+                avoidance,
+                Steering::<F32>::default(),
+                Position::<F32>::new(x, 0.0),
+                north,
+                Acceleration::<F32>::default(),
+                // Agents can be obstacles to each other, but never to themselves
+                Obstacle,
+                Circle::<F32>::new(Position::new(x, 0.0), 0.5),
+            ))
+            .id()
+    };
+    let blocked = spawn_agent(0.0);
+    let clear = spawn_agent(10.0);
+
+    // A wall just to the left of the first agent's path, and a more distant rock to its right
+    world.spawn().insert_bundle((
+        Obstacle,
+        AxisAlignedBoundingBox::<F32>::new(-2.0, -0.25, 2.0, 3.0),
+    ));
+    world
+        .spawn()
+        .insert_bundle((Obstacle, Circle::<F32>::new(Position::new(0.5, 4.5), 0.5)));
+    // Not marked as an obstacle, so driven straight through
+    world
+        .spawn()
+        .insert(Circle::<F32>::new(Position::new(10.0, 2.0), 1.0));
+
+    stage.run(&mut world);
+
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(blocked).unwrap(),
+        acceleration(2.0, 0.0)
+    );
+    assert_eq!(
+        *world.get::<Acceleration<F32>>(clear).unwrap(),
+        Acceleration::default()
+    );
+}
@@ -0,0 +1,143 @@
+use core::time::Duration;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{AngularVelocity, Velocity};
+use leafwing_2d::orientation::Rotation;
+use leafwing_2d::position::Position;
+use leafwing_2d::sweep::{time_of_impact, RigidShape, Sweep};
+
+fn still(shape: RigidShape<F32>, x: f32, y: f32) -> Sweep<F32> {
+    Sweep {
+        shape,
+        position: Position::new(x, y),
+        rotation: Rotation::NORTH,
+        velocity: Velocity::default(),
+        angular_velocity: AngularVelocity::default(),
+    }
+}
+
+fn circle(radius: f32) -> RigidShape<F32> {
+    RigidShape::Circle {
+        radius: F32(radius),
+    }
+}
+
+fn rectangle(half_width: f32, half_height: f32) -> RigidShape<F32> {
+    RigidShape::Box {
+        half_width: F32(half_width),
+        half_height: F32(half_height),
+    }
+}
+
+fn assert_near(actual: Option<Duration>, expected_seconds: f32) {
+    let actual = actual.expect("The shapes should collide").as_secs_f32();
+    assert!(
+        (actual - expected_seconds).abs() < 1e-3,
+        "{actual} != {expected_seconds}"
+    );
+}
+
+#[test]
+fn fast_bullets_do_not_tunnel_through_thin_walls() {
+    let wall = still(rectangle(0.05, 2.0), 5.0, 0.0);
+    let mut bullet = still(circle(0.1), 0.0, 0.0);
+    bullet.velocity = Velocity {
+        x: F32(1000.0),
+        y: F32(0.0),
+    };
+
+    // The bullet ends the frame well past the wall
+    let impact = time_of_impact(&bullet, &wall, Duration::from_millis(16));
+    assert_near(impact, 4.85e-3);
+    // The order of the shapes does not matter
+    assert_eq!(
+        time_of_impact(&wall, &bullet, Duration::from_millis(16)),
+        impact
+    );
+
+    // Aimed above the wall
+    bullet.position = Position::new(0.0, 3.0);
+    assert_eq!(
+        time_of_impact(&bullet, &wall, Duration::from_millis(16)),
+        None
+    );
+}
+
+#[test]
+fn approaching_circles() {
+    let mut a = still(circle(1.0), 0.0, 0.0);
+    let mut b = still(circle(1.0), 10.0, 0.0);
+    a.velocity = Velocity {
+        x: F32(2.0),
+        y: F32(0.0),
+    };
+    b.velocity = Velocity {
+        x: F32(-2.0),
+        y: F32(0.0),
+    };
+
+    assert_near(time_of_impact(&a, &b, Duration::from_secs(5)), 2.0);
+    assert_eq!(time_of_impact(&a, &b, Duration::from_secs(1)), None);
+
+    // Spinning a circle doesn't change its shape
+    a.angular_velocity = AngularVelocity::from_degrees_per_second(3600.0);
+    assert_near(time_of_impact(&a, &b, Duration::from_secs(5)), 2.0);
+}
+
+#[test]
+fn spinning_blades_hit_players_standing_still() {
+    let mut blade = still(rectangle(0.1, 5.0), 0.0, 0.0);
+    blade.angular_velocity = AngularVelocity::from_degrees_per_second(360.0);
+    let player = still(circle(0.5), 3.0, 0.0);
+
+    // The edge of the blade reaches the player once 3 * cos(angle) = 0.6
+    let angle = (0.6_f32 / 3.0).acos();
+    let impact = angle / core::f32::consts::TAU;
+    assert_near(
+        time_of_impact(&blade, &player, Duration::from_millis(400)),
+        impact,
+    );
+    assert_eq!(
+        time_of_impact(&blade, &player, Duration::from_millis(200)),
+        None
+    );
+
+    // The blade is symmetrical, so spinning the other way takes just as long
+    blade.angular_velocity = AngularVelocity::from_degrees_per_second(-360.0);
+    assert_near(
+        time_of_impact(&blade, &player, Duration::from_millis(400)),
+        impact,
+    );
+}
+
+#[test]
+fn rotating_boxes() {
+    // Two boxes side by side, with a gap of 1 between them
+    let mut a = still(rectangle(2.0, 0.5), 0.0, 0.0);
+    let b = still(rectangle(0.5, 0.5), 3.5, 0.0);
+
+    assert_eq!(time_of_impact(&a, &b, Duration::from_secs(1)), None);
+
+    // Overlapping shapes collide immediately
+    let c = still(rectangle(0.5, 0.5), 2.0, 0.0);
+    assert_eq!(
+        time_of_impact(&a, &c, Duration::from_secs(1)),
+        Some(Duration::ZERO)
+    );
+
+    // Swinging the long box around doesn't reach the box beside it
+    a.angular_velocity = AngularVelocity::from_degrees_per_second(90.0);
+    assert_eq!(time_of_impact(&a, &b, Duration::from_secs(4)), None);
+
+    // But does hit a box that is above its far end, once it has turned far enough
+    let above = still(rectangle(0.5, 0.5), -1.5, 2.0);
+    assert_eq!(
+        time_of_impact(
+            &still(rectangle(2.0, 0.5), 0.0, 0.0),
+            &above,
+            Duration::from_secs(1)
+        ),
+        None
+    );
+    let impact = time_of_impact(&a, &above, Duration::from_secs(1)).unwrap();
+    assert!(impact > Duration::ZERO && impact < Duration::from_secs(1));
+}
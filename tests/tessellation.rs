@@ -0,0 +1,141 @@
+use bevy::math::Vec2;
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::position::Position;
+use leafwing_2d::tessellation::{delaunay, voronoi};
+
+fn distance(a: Position<F32>, b: Position<F32>) -> f32 {
+    Vec2::from(a).distance(Vec2::from(b))
+}
+
+/// A deterministic scattering of points, generated with a simple linear congruential generator
+fn scattered(count: usize) -> Vec<Position<F32>> {
+    let mut state: u32 = 12345;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (state >> 8) as f32 / (1 << 24) as f32 * 20. - 10.
+    };
+
+    (0..count).map(|_| Position::new(next(), next())).collect()
+}
+
+fn convex_hull_area(positions: &[Position<F32>]) -> f32 {
+    // Gift wrapping is plenty for test-sized inputs
+    let points: Vec<(f32, f32)> = positions.iter().map(|p| (p.x.0, p.y.0)).collect();
+    let start = points
+        .iter()
+        .copied()
+        .fold(points[0], |a, b| if b.0 < a.0 { b } else { a });
+
+    let mut hull = vec![start];
+    loop {
+        let current = *hull.last().unwrap();
+        let mut candidate = points[0];
+        for &point in &points {
+            let cross = (candidate.0 - current.0) * (point.1 - current.1)
+                - (candidate.1 - current.1) * (point.0 - current.0);
+            if candidate == current || cross < 0. {
+                candidate = point;
+            }
+        }
+        if candidate == start {
+            break;
+        }
+        hull.push(candidate);
+    }
+
+    let n = hull.len();
+    (0..n)
+        .map(|i| hull[i].0 * hull[(i + 1) % n].1 - hull[(i + 1) % n].0 * hull[i].1)
+        .sum::<f32>()
+        .abs()
+        / 2.
+}
+
+#[test]
+fn delaunay_circumcircles_are_empty() {
+    let positions = scattered(60);
+    let triangles = delaunay(positions.iter().copied());
+
+    assert!(!triangles.is_empty());
+    for triangle in &triangles {
+        assert!(triangle.signed_area() > 0.);
+
+        let center = triangle.circumcenter().unwrap();
+        let radius = distance(center, triangle.a);
+        for &position in &positions {
+            assert!(distance(center, position) >= radius * (1. - 1e-4));
+        }
+    }
+}
+
+#[test]
+fn delaunay_covers_convex_hull() {
+    let positions = scattered(40);
+    let triangles = delaunay(positions.iter().copied());
+
+    let total_area: f32 = triangles.iter().map(|triangle| triangle.area()).sum();
+    let hull_area = convex_hull_area(&positions);
+    assert!((total_area - hull_area).abs() < 1e-2 * hull_area);
+}
+
+#[test]
+fn delaunay_degenerate_inputs() {
+    assert!(delaunay(Vec::<Position<F32>>::new()).is_empty());
+
+    let collinear = (0..5).map(|i| Position::<F32>::new(i as f32, 2. * i as f32));
+    assert!(delaunay(collinear).is_empty());
+
+    let with_duplicates = [
+        Position::<F32>::new(0., 0.),
+        Position::new(1., 0.),
+        Position::new(0., 0.),
+        Position::new(0., 1.),
+    ];
+    assert_eq!(delaunay(with_duplicates).len(), 1);
+}
+
+#[test]
+fn voronoi_cells_partition_bounds() {
+    let positions = scattered(30);
+    let bounds = AxisAlignedBoundingBox::<F32>::new(-12., 12., -12., 12.);
+    let cells = voronoi(positions.iter().copied(), &bounds);
+
+    assert_eq!(cells.len(), positions.len());
+
+    let total_area: f32 = cells.iter().map(|cell| cell.area()).sum();
+    assert!((total_area - 24. * 24.).abs() < 1e-2);
+
+    for (cell, &site) in cells.iter().zip(&positions) {
+        assert!(cell.is_convex());
+        assert!(cell.signed_area() > 0.);
+        assert!(cell.contains(site));
+    }
+
+    // Every sample point belongs to the cell of its nearest site
+    for sample in scattered(200).into_iter().skip(100) {
+        let nearest = (0..positions.len())
+            .min_by(|&a, &b| {
+                distance(sample, positions[a])
+                    .partial_cmp(&distance(sample, positions[b]))
+                    .unwrap()
+            })
+            .unwrap();
+        assert!(cells[nearest].contains(sample));
+    }
+}
+
+#[test]
+fn voronoi_collinear_sites() {
+    let sites = [
+        Position::<F32>::new(-4., 0.),
+        Position::new(0., 0.),
+        Position::new(4., 0.),
+    ];
+    let bounds = AxisAlignedBoundingBox::<F32>::new(-6., 6., -1., 1.);
+    let cells = voronoi(sites, &bounds);
+
+    assert_eq!(cells[0].area(), 8.);
+    assert_eq!(cells[1].area(), 8.);
+    assert_eq!(cells[2].area(), 8.);
+}
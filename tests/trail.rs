@@ -0,0 +1,42 @@
+use bevy::ecs::schedule::{Stage, SystemStage};
+use bevy::prelude::*;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::position::Position;
+use leafwing_2d::trail::{record_trails, Trail};
+
+#[test]
+fn trails_follow_moving_entities() {
+    let mut world = World::new();
+    let mut stage = SystemStage::single_threaded();
+    stage.add_system(record_trails::<F32>);
+
+    let missile = world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 0.0), Trail::<F32>::new(4, 1.0)))
+        .id();
+
+    for y in [0.0, 0.5, 1.0, 2.0, 2.5, 3.0, 4.0, 5.0] {
+        *world.get_mut::<Position<F32>>(missile).unwrap() = Position::new(0.0, y);
+        stage.run(&mut world);
+    }
+
+    // Points that were too close together were skipped, and the oldest were forgotten
+    let trail = world.get::<Trail<F32>>(missile).unwrap();
+    assert_eq!(
+        trail.polyline().points(),
+        &[
+            Position::new(0.0, 2.0),
+            Position::new(0.0, 3.0),
+            Position::new(0.0, 4.0),
+            Position::new(0.0, 5.0),
+        ]
+    );
+
+    world.get_mut::<Trail<F32>>(missile).unwrap().clear();
+    assert!(world
+        .get::<Trail<F32>>(missile)
+        .unwrap()
+        .polyline()
+        .points()
+        .is_empty());
+}
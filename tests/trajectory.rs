@@ -0,0 +1,101 @@
+use core::time::Duration;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::trajectory::{launch_velocities, Trajectory};
+
+fn gravity(x: f32, y: f32) -> Acceleration<F32> {
+    Acceleration {
+        x: F32(x),
+        y: F32(y),
+    }
+}
+
+/// The closest that the `trajectory` comes to the `target` in its first ten seconds, checked every millisecond
+fn closest_approach(trajectory: &Trajectory<F32>, target: Position<F32>) -> f32 {
+    (0..10_000)
+        .map(|millis| {
+            let position = trajectory.position_at(Duration::from_millis(millis));
+            let (dx, dy) = (position.x.0 - target.x.0, position.y.0 - target.y.0);
+            dx.hypot(dy)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+#[test]
+fn trajectories_follow_constant_acceleration() {
+    let trajectory = Trajectory::new(
+        Position::<F32>::new(1.0, 2.0),
+        Velocity {
+            x: F32(4.0),
+            y: F32(0.0),
+        },
+        gravity(0.0, -2.0),
+    );
+
+    assert_eq!(trajectory.position_at(Duration::ZERO), trajectory.start);
+    trajectory
+        .position_at(Duration::from_millis(1500))
+        .assert_approx_eq(Position::<F32>::new(7.0, -0.25));
+    assert_eq!(
+        trajectory.velocity_at(Duration::from_secs(2)),
+        Velocity {
+            x: F32(4.0),
+            y: F32(-4.0)
+        }
+    );
+
+    let preview = trajectory.positions(Duration::from_secs(2), 5);
+    assert_eq!(preview.len(), 5);
+    assert_eq!(preview[0], trajectory.start);
+    preview[2].assert_approx_eq(trajectory.position_at(Duration::from_secs(1)));
+    preview[4].assert_approx_eq(trajectory.position_at(Duration::from_secs(2)));
+    assert!(trajectory.positions(Duration::from_secs(1), 0).is_empty());
+}
+
+#[test]
+fn launch_velocities_hit_their_target() {
+    let start = Position::<F32>::new(2.0, 1.0);
+
+    for acceleration in [
+        gravity(0.0, -10.0),
+        // Wind blowing everything sideways
+        gravity(-6.0, -8.0),
+    ] {
+        for target in [
+            Position::<F32>::new(10.0, 3.0),
+            Position::new(-5.0, -4.0),
+            Position::new(2.0, 4.0),
+        ] {
+            let [low, high] = launch_velocities(start, target, 15.0, acceleration).unwrap();
+
+            for velocity in [low, high] {
+                let speed = velocity.x.0.hypot(velocity.y.0);
+                assert!((speed - 15.0).abs() < 1e-3);
+
+                let trajectory = Trajectory::new(start, velocity, acceleration);
+                assert!(closest_approach(&trajectory, target) < 0.05);
+            }
+        }
+    }
+
+    // Without acceleration, aim straight at the target
+    let [low, high] =
+        launch_velocities(start, Position::new(5.0, 5.0), 5.0, gravity(0.0, 0.0)).unwrap();
+    assert_eq!(low, high);
+    assert!((low.x.0 - 3.0).abs() < 1e-5 && (low.y.0 - 4.0).abs() < 1e-5);
+
+    // Out of range
+    assert_eq!(
+        launch_velocities(start, Position::new(50.0, 1.0), 12.0, gravity(0.0, -10.0)),
+        None
+    );
+    assert_eq!(
+        launch_velocities(start, Position::new(2.0, 10.0), 12.0, gravity(0.0, -10.0)),
+        None
+    );
+    assert_eq!(
+        launch_velocities(start, start, 12.0, gravity(0.0, -10.0)),
+        None
+    );
+}
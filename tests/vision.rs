@@ -0,0 +1,135 @@
+mod common;
+
+use bevy::prelude::*;
+use common::drain;
+use leafwing_2d::bounding::AxisAlignedBoundingBox;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::orientation::{Direction, Rotation};
+use leafwing_2d::polygon::Polygon;
+use leafwing_2d::position::Position;
+use leafwing_2d::vision::{
+    vision_cones, Detectable, Occluder, TargetLost, TargetSpotted, VisionCone,
+};
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_event::<TargetSpotted>()
+        .add_event::<TargetLost>()
+        .add_system(vision_cones::<F32>);
+
+    app
+}
+
+#[test]
+fn targets_are_spotted_and_lost() {
+    let mut app = test_app();
+
+    let guard = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Direction::NORTH,
+            VisionCone::<F32>::new(Rotation::from_degrees(45.0), 10.0),
+            // Observers never see themselves
+            Detectable,
+        ))
+        .id();
+    let thief = app
+        .world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 5.0), Detectable))
+        .id();
+    // Not detectable, so never spotted
+    app.world.spawn().insert(Position::<F32>::new(1.0, 5.0));
+
+    app.update();
+    assert_eq!(
+        drain::<TargetSpotted>(&mut app.world),
+        vec![TargetSpotted {
+            observer: guard,
+            target: thief
+        }]
+    );
+    assert_eq!(
+        app.world.get::<VisionCone<F32>>(guard).unwrap().visible(),
+        &[thief]
+    );
+
+    // Nothing changes while the thief stays in view
+    app.update();
+    assert!(drain::<TargetSpotted>(&mut app.world).is_empty());
+    assert!(drain::<TargetLost>(&mut app.world).is_empty());
+
+    // Sneaking behind the guard
+    *app.world.get_mut::<Position<F32>>(thief).unwrap() = Position::new(0.0, -5.0);
+    app.update();
+    assert_eq!(
+        drain::<TargetLost>(&mut app.world),
+        vec![TargetLost {
+            observer: guard,
+            target: thief
+        }]
+    );
+
+    // Despawned targets are lost too
+    *app.world.get_mut::<Position<F32>>(thief).unwrap() = Position::new(0.0, 5.0);
+    app.update();
+    assert_eq!(drain::<TargetSpotted>(&mut app.world).len(), 1);
+    app.world.despawn(thief);
+    app.update();
+    assert_eq!(drain::<TargetLost>(&mut app.world).len(), 1);
+    assert!(app
+        .world
+        .get::<VisionCone<F32>>(guard)
+        .unwrap()
+        .visible()
+        .is_empty());
+}
+
+#[test]
+fn occluders_block_line_of_sight() {
+    let mut app = test_app();
+
+    let cone = VisionCone::<F32>::new(Rotation::from_degrees(45.0), 10.0);
+    let x_ray = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Direction::NORTH,
+            cone.clone(),
+        ))
+        .id();
+    let sentry = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(0.0, 0.0),
+            Direction::NORTH,
+            cone.with_line_of_sight(),
+        ))
+        .id();
+
+    app.world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(0.0, 5.0), Detectable));
+    app.world
+        .spawn()
+        .insert(Occluder(Polygon::from(AxisAlignedBoundingBox::<F32>::new(
+            -1.0, 1.0, 2.0, 3.0,
+        ))));
+
+    app.update();
+    let observers: Vec<Entity> = drain::<TargetSpotted>(&mut app.world)
+        .into_iter()
+        .map(|event| event.observer)
+        .collect();
+    assert_eq!(observers, vec![x_ray]);
+    assert!(app
+        .world
+        .get::<VisionCone<F32>>(sentry)
+        .unwrap()
+        .visible()
+        .is_empty());
+}
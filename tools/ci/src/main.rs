@@ -19,6 +19,16 @@ fn main() {
         .run()
         .expect("Please fix failing doc-tests in output above.");
 
+    // Check that the core math still compiles without the standard library or `bevy`
+    cmd!("cargo check --no-default-features --features libm")
+        .run()
+        .expect("Please fix the `no_std` compiler errors in output above.");
+
+    // Check that the `bevy-inspector-egui` integration still compiles
+    cmd!("cargo check --features inspector")
+        .run()
+        .expect("Please fix the `inspector` compiler errors in output above.");
+
     // See if clippy has any complaints.
     // - Type complexity must be ignored because we use huge templates for queries
     cmd!("cargo clippy --workspace --all-targets --all-features -- -D warnings -A clippy::type_complexity -W clippy::doc_markdown")